@@ -0,0 +1,31 @@
+use serde_json::Value;
+
+/// Share of shots that hit, from the optional `ShotsHit` / `ShotsFired` match stats.
+pub fn accuracy(stats: &Value) -> Option<f64> {
+    let fired = stats["ShotsFired"].as_f64().filter(|fired| *fired > 0.0)?;
+    Some(stats["ShotsHit"].as_f64()? / fired)
+}
+
+#[derive(Debug, Clone)]
+pub struct AbilityUsage {
+    pub name: String,
+    pub casts: i64,
+    pub damage: Option<i64>,
+}
+
+/// Per-ability casts and damage from a match row's optional `abilities` list
+/// (`[{name, casts, damage}]`). Empty when the payload doesn't include it.
+pub fn ability_usage(row: &Value) -> Vec<AbilityUsage> {
+    row["abilities"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|ability| {
+            Some(AbilityUsage {
+                name: ability["name"].as_str()?.to_string(),
+                casts: ability["casts"].as_i64()?,
+                damage: ability["damage"].as_i64(),
+            })
+        })
+        .collect()
+}
@@ -1,27 +1,111 @@
-use anyhow::{Context, Result};
+use crate::connectivity;
+use crate::demo::DemoData;
+use anyhow::{anyhow, bail, Result};
 use chrono::{DateTime, Utc};
-use reqwest::blocking::Client;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const BASE_URL: &str = "https://op.gg/supervive/";
 const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/139.0.0.0 Safari/537.36";
 
+/// Upper bound on match history pages fetched for one player, matching the Python tooling.
+pub const MAX_HISTORY_PAGES: i64 = 20;
+
+/// How long a fetched history page is kept for offline use.
+const OFFLINE_PAGE_TTL: f64 = 7.0 * 24.0 * 3600.0;
+
+/// Match history rows shared by views, the poller and home tiles without copying the JSON.
+/// Appending to an unshared list (`Arc::make_mut`) doesn't copy either.
+pub type MatchRows = Arc<Vec<Value>>;
+
+/// Moves the rows out of a match list response instead of cloning them.
+pub fn take_rows(mut data: Value) -> Vec<Value> {
+    match data["data"].take() {
+        Value::Array(rows) => rows,
+        _ => Vec::new(),
+    }
+}
+
+/// Wait used when a 429 response doesn't say how long to back off.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+/// op.gg answered 429 Too Many Requests.
+#[derive(Debug)]
+pub struct RateLimited {
+    /// From the Retry-After header, in either of its forms (seconds or an HTTP date)
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Rate limited by op.gg, retry in {}s", self.retry_after.as_secs())
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+fn retry_after(headers: &HeaderMap) -> Duration {
+    let Some(value) = headers.get(RETRY_AFTER).and_then(|value| value.to_str().ok()) else {
+        return DEFAULT_RETRY_AFTER;
+    };
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Duration::from_secs(seconds);
+    }
+    DateTime::parse_from_rfc2822(value.trim())
+        .ok()
+        .and_then(|at| (at.with_timezone(&Utc) - Utc::now()).to_std().ok())
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+}
+
+/// `error_for_status`, except that 429 becomes [`RateLimited`] so views can wait it out.
+fn check_status(resp: Response) -> Result<Response> {
+    if resp.status() == StatusCode::TOO_MANY_REQUESTS {
+        return Err(RateLimited {
+            retry_after: retry_after(resp.headers()),
+        }
+        .into());
+    }
+    Ok(resp.error_for_status()?)
+}
+
+/// How long to wait before retrying, when the request failed on the rate limit.
+pub fn rate_limit_of(err: &anyhow::Error) -> Option<Duration> {
+    err.downcast_ref::<RateLimited>().map(|limit| limit.retry_after)
+}
+
+/// Whether op.gg answered 404, i.e. the requested player or match doesn't exist.
+pub fn is_not_found(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<reqwest::Error>()
+        .and_then(|err| err.status())
+        == Some(reqwest::StatusCode::NOT_FOUND)
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct CacheItem {
     value: Value,
     expires_at: f64,
     sliding: bool,
     sliding_ttl: f64,
+    /// When the entry was written; missing (0) in caches from before it was recorded
+    #[serde(default)]
+    stored_at: f64,
 }
 
+/// Response cache kept in memory and written out by `flush`. The file holds every cached
+/// scoreboard, so writing it on each change would rewrite hundreds of MB per request.
 pub struct DiskCache {
     path: PathBuf,
     data: HashMap<String, CacheItem>,
+    /// Changed since the last flush
+    dirty: bool,
 }
 
 impl DiskCache {
@@ -29,6 +113,7 @@ impl DiskCache {
         let mut cache = Self {
             path,
             data: HashMap::new(),
+            dirty: false,
         };
         cache.load();
         cache
@@ -44,9 +129,24 @@ impl DiskCache {
         }
     }
 
-    fn save(&self) {
-        if let Ok(file) = fs::File::create(&self.path) {
-            let _ = serde_json::to_writer(file, &self.data);
+    /// Writes the cache if it changed, through a temporary file so an interrupted write never
+    /// leaves half a cache.
+    pub fn flush(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        let tmp = self.path.with_extension("json.tmp");
+        let written = fs::File::create(&tmp)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| {
+                let mut writer = std::io::BufWriter::new(file);
+                serde_json::to_writer(&mut writer, &self.data)?;
+                writer.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+                Ok(fs::rename(&tmp, &self.path)?)
+            });
+        if let Err(err) = written {
+            tracing::warn!(error = %err, "Failed to write the response cache");
         }
     }
 
@@ -77,12 +177,12 @@ impl DiskCache {
 
         if remove {
             self.data.remove(key);
-            self.save();
+            self.dirty = true;
             return None;
         }
 
         if save_needed {
-            self.save();
+            self.dirty = true;
         }
 
         value
@@ -97,15 +197,169 @@ impl DiskCache {
                 expires_at: now + ttl_seconds,
                 sliding,
                 sliding_ttl: if sliding { ttl_seconds } else { 0.0 },
+                stored_at: now,
             },
         );
-        self.save();
+        self.dirty = true;
+    }
+
+    /// Drops every entry, returning how many were removed.
+    pub fn clear(&mut self) -> usize {
+        let removed = self.data.len();
+        self.data.clear();
+        self.dirty = true;
+        self.flush();
+        removed
+    }
+
+    /// Drops entries under any of `prefixes` written before `cutoff` (Unix seconds), returning
+    /// how many were removed.
+    pub fn drop_stored_before(&mut self, cutoff: f64, prefixes: &[&str]) -> usize {
+        let before = self.data.len();
+        self.data
+            .retain(|key, item| item.stored_at >= cutoff || !prefixes.iter().any(|prefix| key.starts_with(prefix)));
+        let removed = before - self.data.len();
+        if removed > 0 {
+            self.dirty = true;
+        }
+        removed
+    }
+
+    /// Drops every expired entry, returning how many were removed.
+    pub fn prune(&mut self) -> usize {
+        let now = Self::now();
+        let before = self.data.len();
+        self.data.retain(|_, item| now < item.expires_at);
+        let removed = before - self.data.len();
+        if removed > 0 {
+            self.dirty = true;
+        }
+        removed
+    }
+}
+
+impl Drop for DiskCache {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Extracts `(platform, player_id)` from an op.gg profile URL or a raw `platform-id` string.
+///
+/// Accepts `https://op.gg/supervive/players/steam-<id>`, `steam-<id>`, `steam:<id>`, or a bare
+/// 32-character hex / dashed UUID id (assumed to be a Steam account).
+pub fn parse_profile_reference(input: &str) -> Option<(String, String)> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+
+    let reference = match input.find("/players/") {
+        Some(idx) => input[idx + "/players/".len()..]
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or(""),
+        None if input.contains("://") => return None,
+        None => input,
+    };
+
+    if is_player_id(reference) {
+        return Some(("steam".to_string(), reference.replace('-', "")));
+    }
+
+    let (platform, id) = reference.split_once(['-', ':'])?;
+    if platform.is_empty() || !platform.chars().all(|c| c.is_ascii_alphabetic()) || !is_player_id(id) {
+        return None;
+    }
+    Some((platform.to_ascii_lowercase(), id.replace('-', "")))
+}
+
+fn is_player_id(value: &str) -> bool {
+    let hex: String = value.chars().filter(|c| *c != '-').collect();
+    let dashes = value.len() - hex.len();
+    hex.len() == 32 && (dashes == 0 || dashes == 4) && hex.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Whether a failed or empty match-history response means the profile is hidden by its owner.
+pub fn is_private_profile(result: &Result<Value>) -> bool {
+    match result {
+        Ok(data) => data["message"]
+            .as_str()
+            .is_some_and(|message| message.to_ascii_lowercase().contains("private")),
+        Err(err) => err
+            .downcast_ref::<reqwest::Error>()
+            .and_then(|err| err.status())
+            .is_some_and(|status| {
+                status == reqwest::StatusCode::FORBIDDEN || status == reqwest::StatusCode::UNAUTHORIZED
+            }),
+    }
+}
+
+/// Rank name recorded on a match row, e.g. "Diamond1". Its location varies between API versions.
+pub fn rank_of(row: &Value) -> Option<&str> {
+    row["player"]["rank"]
+        .as_str()
+        .or_else(|| row["rank"]["name"].as_str())
+        .or_else(|| row["rank"].as_str())
+}
+
+/// Rank points after a match, where the row records them.
+pub fn rank_rating(row: &Value) -> Option<i64> {
+    row["player"]["rank_rating"]
+        .as_i64()
+        .or_else(|| row["rank"]["rating"].as_i64())
+        .or_else(|| row["rank_rating"].as_i64())
+}
+
+/// Lightweight per-player details used to tell apart accounts sharing a display name.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PlayerSummary {
+    pub last_seen: Option<DateTime<Utc>>,
+    pub level: Option<i64>,
+    pub rank: Option<String>,
+    pub region: Option<String>,
+}
+
+impl PlayerSummary {
+    /// Builds a summary from the first page of a player's matches (most recent first).
+    pub fn from_matches(data: &Value) -> Self {
+        let items = data["data"].as_array().cloned().unwrap_or_default();
+        let latest = items.first();
+
+        let last_seen = latest
+            .and_then(|m| m["match_end"].as_str().or_else(|| m["match_start"].as_str()))
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc));
+
+        let level = latest.and_then(|m| {
+            m["player"]["level"]
+                .as_i64()
+                .or_else(|| m["level"].as_i64())
+        });
+
+        let rank = items.iter().find_map(|m| rank_of(m).map(|s| s.to_string()));
+
+        let region = latest.and_then(|m| {
+            m["region"]
+                .as_str()
+                .or_else(|| m["player"]["region"].as_str())
+                .map(|s| s.to_string())
+        });
+
+        Self {
+            last_seen,
+            level,
+            rank,
+            region,
+        }
     }
 }
 
 pub struct SuperviveService {
     client: Client,
     cache: DiskCache,
+    /// Bundled sample data answering every request instead of op.gg (`--demo`)
+    demo: Option<DemoData>,
 }
 
 impl SuperviveService {
@@ -122,45 +376,40 @@ impl SuperviveService {
         Ok(Self {
             client,
             cache: DiskCache::new(cache_path),
+            demo: None,
         })
     }
 
-    fn get_url(path: &str) -> String {
-        format!("{}{}", BASE_URL.trim_end_matches('/'), path)
+    /// A service that never touches the network, answering from the bundled sample matches.
+    pub fn demo() -> Result<Self> {
+        let mut service = Self::new()?;
+        service.demo = Some(DemoData::load());
+        Ok(service)
     }
 
-    pub fn check_player_exists(&self, platform: &str, unique_display_name: &str) -> Result<bool> {
-        let url = Self::get_url("/api/players/check");
-        let resp = self
-            .client
-            .get(&url)
-            .query(&[
-                ("platform", platform),
-                ("uniqueDisplayName", unique_display_name),
-            ])
-            .send()?
-            .error_for_status()?;
+    /// Whether requests must be answered from the cache alone.
+    fn offline(&self) -> bool {
+        self.demo.is_none() && connectivity::is_offline()
+    }
 
-        let json: Value = resp.json()?;
-        let exists = json["exists"]
-            .as_bool()
-            .or_else(|| json["Exists"].as_bool());
-        exists.context("Missing 'exists' field")
+    fn get_url(path: &str) -> String {
+        format!("{}{}", BASE_URL.trim_end_matches('/'), path)
     }
 
     pub fn search_players(&mut self, query: &str) -> Result<Value> {
+        if let Some(demo) = &self.demo {
+            return Ok(demo.search(query));
+        }
         let key = format!("search:{}", query);
         if let Some(cached) = self.cache.get(&key) {
             return Ok(cached);
         }
+        if self.offline() {
+            bail!("Offline and this search isn't cached");
+        }
 
         let url = Self::get_url("/api/players/search");
-        let resp = self
-            .client
-            .get(&url)
-            .query(&[("query", query)])
-            .send()?
-            .error_for_status()?;
+        let resp = check_status(self.client.get(&url).query(&[("query", query)]).send()?)?;
 
         let data: Value = resp.json()?;
         self.cache
@@ -169,31 +418,183 @@ impl SuperviveService {
     }
 
     pub fn get_match(&mut self, platform: &str, match_id: &str) -> Result<Value> {
+        if let Some(demo) = &self.demo {
+            return demo.get_match(match_id).ok_or_else(|| anyhow!("No sample match {}", match_id));
+        }
         let key = format!("match:{}:{}", platform, match_id);
         if let Some(cached) = self.cache.get(&key) {
             return Ok(cached);
         }
-
-        let url = Self::get_url(&format!("/api/matches/{}-{}", platform, match_id));
-        let resp = self.client.get(&url).send()?.error_for_status()?;
-
-        let data: Value = resp.json()?;
+        if self.offline() {
+            bail!("Offline and this match isn't cached");
+        }
+        let data = self.download_match(platform, match_id)?;
         self.cache
             .set(key, data.clone(), 15.0 * 24.0 * 3600.0, true);
         Ok(data)
     }
 
-    pub fn get_player_matches(&self, platform: &str, player_id: &str, page: i32) -> Result<Value> {
+    /// A scoreboard for the match archive, which keeps its own copy: the cached one if there is
+    /// one, otherwise fetched without adding it to the cache.
+    pub fn get_match_for_archive(&mut self, platform: &str, match_id: &str) -> Result<Value> {
+        if let Some(demo) = &self.demo {
+            return demo.get_match(match_id).ok_or_else(|| anyhow!("No sample match {}", match_id));
+        }
+        if let Some(cached) = self.cache.get(&format!("match:{}:{}", platform, match_id)) {
+            return Ok(cached);
+        }
+        if self.offline() {
+            bail!("Offline and this match isn't cached");
+        }
+        self.download_match(platform, match_id)
+    }
+
+    fn download_match(&self, platform: &str, match_id: &str) -> Result<Value> {
+        let url = Self::get_url(&format!("/api/matches/{}-{}", platform, match_id));
+        let resp = check_status(self.client.get(&url).send()?)?;
+
+        // Scoreboards run to hundreds of KB; parse straight off the socket instead of buffering
+        Ok(serde_json::from_reader(std::io::BufReader::new(resp))?)
+    }
+
+    fn player_matches_key(platform: &str, player_id: &str, page: i32) -> String {
+        format!("matches:{}:{}:{}", platform, player_id.replace("-", ""), page)
+    }
+
+    /// The last fetched copy of a history page, shown while a fresh one loads.
+    pub fn cached_player_matches(&mut self, platform: &str, player_id: &str, page: i32) -> Option<Value> {
+        if self.demo.is_some() {
+            return None;
+        }
+        self.cache.get(&Self::player_matches_key(platform, player_id, page))
+    }
+
+    /// Always fetches fresh pages while online; the last copy of each page is only served offline.
+    pub fn get_player_matches(&mut self, platform: &str, player_id: &str, page: i32) -> Result<Value> {
+        if let Some(demo) = &self.demo {
+            return Ok(demo.player_matches(player_id, page));
+        }
         let normalized = player_id.replace("-", "");
+        let key = Self::player_matches_key(platform, player_id, page);
+        if self.offline() {
+            return self
+                .cache
+                .get(&key)
+                .ok_or_else(|| anyhow!("Offline and page {} of this history isn't cached", page));
+        }
         let url = Self::get_url(&format!("/api/players/{}-{}/matches", platform, normalized));
-        let resp = self
-            .client
-            .get(&url)
-            .query(&[("page", page.to_string())])
-            .send()?
-            .error_for_status()?;
+        let resp = check_status(self.client.get(&url).query(&[("page", page.to_string())]).send()?)?;
 
         let data: Value = resp.json()?;
+        self.cache.set(key, data.clone(), OFFLINE_PAGE_TTL, false);
         Ok(data)
     }
+
+    pub fn prune_cache(&mut self) -> usize {
+        self.cache.prune()
+    }
+
+    pub fn clear_cache(&mut self) -> usize {
+        self.cache.clear()
+    }
+
+    /// Writes cache changes made since the last flush.
+    pub fn flush_cache(&mut self) {
+        self.cache.flush();
+    }
+
+    /// Forgets searches, history pages and summaries fetched before a game update, so ranks and
+    /// hero stats are fetched again. Scoreboards are kept since finished matches don't change.
+    pub fn invalidate_stats_before(&mut self, cutoff: DateTime<Utc>) -> usize {
+        self.cache
+            .drop_stored_before(cutoff.timestamp() as f64, &["search:", "matches:", "summary:"])
+    }
+
+    pub fn get_player_summary(&mut self, platform: &str, player_id: &str) -> Result<PlayerSummary> {
+        if self.demo.is_some() {
+            return Ok(PlayerSummary::from_matches(&self.get_player_matches(platform, player_id, 1)?));
+        }
+        let key = format!("summary:{}:{}", platform, player_id);
+        if let Some(cached) = self.cache.get(&key) {
+            if let Ok(summary) = serde_json::from_value(cached) {
+                return Ok(summary);
+            }
+        }
+
+        let data = self.get_player_matches(platform, player_id, 1)?;
+        let summary = PlayerSummary::from_matches(&data);
+        self.cache
+            .set(key, serde_json::to_value(&summary)?, 3600.0, false);
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("supervive-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn parse_profile_reference_table() {
+        const ID: &str = "6513270e269e0d37f2a74de452e6b438";
+        let cases: &[(&str, Option<(&str, &str)>)] = &[
+            (ID, Some(("steam", ID))),
+            ("6513270e-269e-0d37-f2a7-4de452e6b438", Some(("steam", ID))),
+            ("  6513270e269e0d37f2a74de452e6b438\n", Some(("steam", ID))),
+            ("steam-6513270e269e0d37f2a74de452e6b438", Some(("steam", ID))),
+            ("EPIC:6513270e269e0d37f2a74de452e6b438", Some(("epic", ID))),
+            ("epic-6513270e-269e-0d37-f2a7-4de452e6b438", Some(("epic", ID))),
+            ("https://op.gg/supervive/players/steam-6513270e269e0d37f2a74de452e6b438", Some(("steam", ID))),
+            ("https://op.gg/supervive/players/steam-6513270e269e0d37f2a74de452e6b438/matches?page=2", Some(("steam", ID))),
+            ("op.gg/supervive/players/epic-6513270e269e0d37f2a74de452e6b438#top", Some(("epic", ID))),
+            ("", None),
+            ("DemoPlayer#0001", None),
+            ("6513270e269e0d37f2a74de452e6b43", None),
+            ("6513270e269e0d37f2a74de452e6b43z", None),
+            ("6513-270e269e0d37f2a74de452e6b438", None),
+            ("st3am-6513270e269e0d37f2a74de452e6b438", None),
+            ("-6513270e269e0d37f2a74de452e6b438", None),
+            ("https://example.com/6513270e269e0d37f2a74de452e6b438", None),
+            ("https://op.gg/supervive/players/", None),
+        ];
+        for (input, expected) in cases {
+            let expected = expected.map(|(platform, id)| (platform.to_string(), id.to_string()));
+            assert_eq!(parse_profile_reference(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn cache_writes_only_on_flush() {
+        let path = temp_path("cache-flush");
+        let _ = fs::remove_file(&path);
+        let mut cache = DiskCache::new(path.clone());
+        cache.set("matches:steam:abc:1".to_string(), json!({ "data": [] }), 60.0, false);
+        assert!(!path.exists());
+        cache.flush();
+        assert!(path.exists());
+        drop(cache);
+
+        let mut reloaded = DiskCache::new(path.clone());
+        assert_eq!(reloaded.get("matches:steam:abc:1"), Some(json!({ "data": [] })));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn cache_drop_stored_before_keeps_other_prefixes() {
+        let path = temp_path("cache-invalidate");
+        let mut cache = DiskCache::new(path.clone());
+        cache.set("matches:steam:abc:1".to_string(), json!(1), 60.0, false);
+        cache.set("match:steam:def".to_string(), json!(2), 60.0, false);
+        let removed = cache.drop_stored_before(DiskCache::now() + 1.0, &["matches:"]);
+        assert_eq!(removed, 1);
+        assert_eq!(cache.get("matches:steam:abc:1"), None);
+        assert_eq!(cache.get("match:steam:def"), Some(json!(2)));
+        cache.dirty = false;
+        drop(cache);
+        let _ = fs::remove_file(&path);
+    }
 }
@@ -0,0 +1,188 @@
+use crate::api::{take_rows, SuperviveService, MAX_HISTORY_PAGES};
+use crate::csv;
+use crate::match_archive::MatchArchive;
+use crate::session::parse_time;
+use crate::settings::PlayerRef;
+use anyhow::{bail, Result};
+use chrono::{Local, SecondsFormat};
+use rusqlite::types::ValueRef;
+use rusqlite::{params, Connection};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+
+/// Rows kept from one query; the rest are counted but not shown or exported.
+pub const MAX_ROWS: usize = 1000;
+
+/// Tables the console can query, shown next to it as a reminder.
+pub const SCHEMA: &str = "\
+participants(source, platform, match_id, match_start, match_end, region, is_ranked, player_id, player_name, hero, team_id, placement, kills, deaths, assists, damage, damage_taken, healing, shielding, revives, survival_duration)
+accounts(platform, player_id, name)";
+
+const CREATE_TABLES: &str = "
+CREATE TABLE participants (
+    source TEXT NOT NULL,
+    platform TEXT NOT NULL,
+    match_id TEXT NOT NULL,
+    match_start TEXT,
+    match_end TEXT,
+    region TEXT,
+    is_ranked INTEGER,
+    player_id TEXT NOT NULL,
+    player_name TEXT,
+    hero TEXT,
+    team_id INTEGER,
+    placement INTEGER,
+    kills INTEGER,
+    deaths INTEGER,
+    assists INTEGER,
+    damage REAL,
+    damage_taken REAL,
+    healing REAL,
+    shielding REAL,
+    revives INTEGER,
+    survival_duration REAL,
+    PRIMARY KEY (platform, match_id, player_id)
+);
+CREATE TABLE accounts (platform TEXT NOT NULL, player_id TEXT NOT NULL, name TEXT);
+";
+
+/// Ready-made questions offered as a starting point, as `(label, sql)`.
+pub const EXAMPLES: [(&str, &str); 3] = [
+    (
+        "Average placement by weekday",
+        "SELECT strftime('%w', match_start) AS weekday, COUNT(*) AS games, ROUND(AVG(placement), 2) AS avg_placement FROM participants JOIN accounts USING (platform, player_id) GROUP BY weekday ORDER BY weekday",
+    ),
+    (
+        "Most played heroes",
+        "SELECT hero, COUNT(*) AS games, ROUND(AVG(placement), 2) AS avg_placement FROM participants JOIN accounts USING (platform, player_id) GROUP BY hero ORDER BY games DESC",
+    ),
+    (
+        "Top killers in archived matches",
+        "SELECT player_name, SUM(kills) AS kills, COUNT(*) AS games FROM participants WHERE source = 'archive' GROUP BY player_id ORDER BY kills DESC LIMIT 20",
+    ),
+];
+
+/// Adds one scoreboard or history row. A match seen through both the archive and a history page
+/// keeps the first copy.
+fn insert_row(db: &Connection, source: &str, platform: &str, row: &Value) -> rusqlite::Result<()> {
+    let time = |key: &str| parse_time(&row[key]).map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true));
+    let stat = |key: &str| row["stats"][key].as_f64();
+    db.execute(
+        "INSERT OR IGNORE INTO participants VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
+        params![
+            source,
+            row["platform"]["code"].as_str().unwrap_or(platform),
+            row["match_id"].as_str(),
+            time("match_start"),
+            time("match_end"),
+            row["region"].as_str(),
+            row["is_ranked"].as_bool(),
+            row["player_id"].as_str().map(|id| id.replace('-', "")),
+            row["player"]["unique_display_name"].as_str(),
+            row["hero"]["name"].as_str(),
+            row["team_id"].as_i64(),
+            row["placement"].as_i64(),
+            stat("Kills"),
+            stat("Deaths"),
+            stat("Assists"),
+            stat("HeroEffectiveDamageDone"),
+            stat("HeroEffectiveDamageTaken"),
+            stat("HealingGiven"),
+            stat("ShieldMitigatedDamage"),
+            stat("Revived"),
+            row["survival_duration"].as_f64(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Copies the match archive and the cached history of the linked accounts into an in-memory
+/// database. Queries run against this copy, so nothing they do can touch the files on disk.
+pub fn snapshot(archive: &MatchArchive, service: &mut SuperviveService, accounts: &[PlayerRef]) -> Result<Connection> {
+    let db = Connection::open_in_memory()?;
+    db.execute_batch(CREATE_TABLES)?;
+    db.execute_batch("BEGIN")?;
+    for entry in archive.entries() {
+        let Some(Value::Array(rows)) = archive.get(&entry.platform, &entry.match_id) else {
+            continue;
+        };
+        for row in &rows {
+            insert_row(&db, "archive", &entry.platform, row)?;
+        }
+    }
+    for account in accounts {
+        db.execute(
+            "INSERT INTO accounts VALUES (?1, ?2, ?3)",
+            params![account.platform, account.player_id.replace('-', ""), account.name],
+        )?;
+        for page in 1..=MAX_HISTORY_PAGES as i32 {
+            let Some(data) = service.cached_player_matches(&account.platform, &account.player_id, page) else {
+                break;
+            };
+            for row in &take_rows(data) {
+                insert_row(&db, "history", &account.platform, row)?;
+            }
+        }
+    }
+    db.execute_batch("COMMIT; PRAGMA query_only = ON;")?;
+    Ok(db)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// Rows the query returned beyond [`MAX_ROWS`]
+    pub truncated: usize,
+}
+
+fn cell_text(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null => String::new(),
+        ValueRef::Integer(value) => value.to_string(),
+        ValueRef::Real(value) => value.to_string(),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+        ValueRef::Blob(bytes) => format!("<{} bytes>", bytes.len()),
+    }
+}
+
+/// Runs a single read-only statement and collects its rows as text.
+pub fn run(db: &Connection, sql: &str) -> Result<QueryResult> {
+    let mut statement = db.prepare(sql.trim().trim_end_matches(';'))?;
+    if !statement.readonly() {
+        bail!("Only queries that read data (SELECT, WITH, EXPLAIN) can run here");
+    }
+    let columns: Vec<String> = statement.column_names().into_iter().map(String::from).collect();
+    let mut result = QueryResult {
+        columns,
+        ..QueryResult::default()
+    };
+    let mut rows = statement.query([])?;
+    while let Some(row) = rows.next()? {
+        if result.rows.len() == MAX_ROWS {
+            result.truncated += 1;
+            continue;
+        }
+        result
+            .rows
+            .push((0..result.columns.len()).map(|idx| row.get_ref(idx).map(cell_text)).collect::<rusqlite::Result<_>>()?);
+    }
+    Ok(result)
+}
+
+/// Writes the result as CSV to the downloads folder and returns its path.
+pub fn export_csv(result: &QueryResult) -> Result<PathBuf> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let path = dir.join(format!("supervive-query-{}.csv", Local::now().format("%Y%m%d-%H%M%S")));
+    let mut text = csv::row(&result.columns);
+    text.push('\n');
+    for row in &result.rows {
+        text.push_str(&csv::row(row));
+        text.push('\n');
+    }
+    fs::write(&path, text)?;
+    Ok(path)
+}
@@ -0,0 +1,162 @@
+use crate::match_archive::{self, MatchArchive};
+use crate::match_notes::MatchNote;
+use crate::player_index::IndexedPlayer;
+use crate::series::Series;
+use crate::settings::{Settings, SyncSettings};
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use zip::write::FileOptions;
+use zip::CompressionMethod;
+
+/// Bumped when the layout of the backup changes. Version 2 added match notes and series; version 1
+/// backups import without them.
+const FORMAT_VERSION: u32 = 2;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    format_version: u32,
+    app_version: String,
+    exported_at: String,
+}
+
+/// Folder in the backup holding the match archive's scoreboards.
+const MATCHES_DIR: &str = "matches/";
+
+/// Everything that moves between machines: settings (watched players, linked accounts, webhooks,
+/// shortcuts), the local player archive, match notes, series and the match archive. The API
+/// response cache is left out since it expires.
+pub struct AppData {
+    pub settings: Settings,
+    pub players: HashMap<String, IndexedPlayer>,
+    pub notes: HashMap<String, MatchNote>,
+    pub series: Vec<Series>,
+    /// Scoreboard files of the match archive, copied into the backup as they are
+    pub match_files: Vec<PathBuf>,
+}
+
+/// Where backups go when no folder has been chosen.
+pub fn default_dir() -> PathBuf {
+    dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Writes `data` to a single timestamped zip in `dir` and returns its path. Unlike the
+/// diagnostics bundle nothing is redacted, so the file holds webhook URLs; sync credentials are
+/// the exception and stay on this machine. Uncompressed backups
+/// are bigger but quicker to write and can be read with any zip tool.
+pub fn export(data: &AppData, dir: &Path, compress: bool) -> Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "supervive-backup-{}.zip",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    let manifest = Manifest {
+        format_version: FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Local::now().to_rfc3339(),
+    };
+
+    let mut zip = zip::ZipWriter::new(fs::File::create(&path)?);
+    let options = FileOptions::default().compression_method(if compress {
+        CompressionMethod::Deflated
+    } else {
+        CompressionMethod::Stored
+    });
+    zip.start_file("manifest.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+    let mut settings = data.settings.clone();
+    settings.sync = SyncSettings::default();
+    zip.start_file("settings.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&settings)?.as_bytes())?;
+    zip.start_file("players.json", options)?;
+    zip.write_all(serde_json::to_string(&data.players)?.as_bytes())?;
+    zip.start_file("match_notes.json", options)?;
+    zip.write_all(serde_json::to_string(&data.notes)?.as_bytes())?;
+    zip.start_file("series.json", options)?;
+    zip.write_all(serde_json::to_string(&data.series)?.as_bytes())?;
+    for file in &data.match_files {
+        // Pruned since the list was taken
+        let Ok(mut source) = fs::File::open(file) else {
+            continue;
+        };
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        zip.start_file(format!("{}{}", MATCHES_DIR, name), options)?;
+        std::io::copy(&mut source, &mut zip)?;
+    }
+    zip.finish()?;
+    Ok(path)
+}
+
+fn read_entry(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Result<String> {
+    let mut contents = String::new();
+    archive
+        .by_name(name)
+        .with_context(|| format!("{} is missing from the backup", name))?
+        .read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+/// Like `read_entry`, for entries that older backups don't have.
+fn read_optional_entry<T: DeserializeOwned + Default>(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Result<T> {
+    let Ok(mut file) = archive.by_name(name) else {
+        return Ok(T::default());
+    };
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    serde_json::from_str(&contents).with_context(|| format!("{} in the backup is damaged", name))
+}
+
+/// Reads a backup written by `export`, rejecting files from a newer format.
+pub fn import(path: &Path) -> Result<AppData> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(path)?)
+        .context("Not a Supervive Dashboard backup")?;
+    let manifest: Manifest = serde_json::from_str(&read_entry(&mut archive, "manifest.json")?)?;
+    if manifest.format_version > FORMAT_VERSION {
+        bail!(
+            "Backup was made by a newer version ({}); update the app to import it",
+            manifest.app_version
+        );
+    }
+    Ok(AppData {
+        settings: serde_json::from_str(&read_entry(&mut archive, "settings.json")?)?,
+        players: serde_json::from_str(&read_entry(&mut archive, "players.json")?)?,
+        notes: read_optional_entry(&mut archive, "match_notes.json")?,
+        series: read_optional_entry(&mut archive, "series.json")?,
+        match_files: Vec::new(),
+    })
+}
+
+/// Adds the backup's archived matches to `matches`, one at a time so a large archive never sits
+/// in memory whole. Matches already archived are kept as they are. Returns how many were added;
+/// backups made before matches were included simply add none.
+pub fn restore_matches(path: &Path, matches: &Mutex<MatchArchive>) -> Result<usize> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(path)?)
+        .context("Not a Supervive Dashboard backup")?;
+    let mut added = 0;
+    for idx in 0..archive.len() {
+        let file = archive.by_index(idx)?;
+        let Some((platform, match_id)) = file
+            .name()
+            .strip_prefix(MATCHES_DIR)
+            .and_then(match_archive::parse_file_name)
+        else {
+            continue;
+        };
+        if matches.lock().unwrap().contains(&platform, &match_id) {
+            continue;
+        }
+        let data: serde_json::Value = serde_json::from_reader(std::io::BufReader::new(file))
+            .with_context(|| format!("Match {} in the backup is damaged", match_id))?;
+        matches.lock().unwrap().insert(&platform, &match_id, &data)?;
+        added += 1;
+    }
+    Ok(added)
+}
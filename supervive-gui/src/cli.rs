@@ -0,0 +1,359 @@
+use crate::api::{parse_profile_reference, SuperviveService, MAX_HISTORY_PAGES};
+use crate::csv;
+use crate::notifier::Notifier;
+use crate::player_index::PlayerIndex;
+use crate::settings::SettingsStore;
+use anyhow::{anyhow, bail, Result};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage:
+  supervive-gui                          Open the dashboard
+  supervive-gui --demo                   Open the dashboard with bundled sample
+                                         data instead of op.gg
+  supervive-gui lookup <name>            Search players by name
+  supervive-gui export <player> [--csv]  Print a player's match history as CSV
+  supervive-gui watch <player> [--interval <seconds>]
+                                         Print new matches as they are played and
+                                         post them to the webhooks in settings.json
+
+<player> is a unique display name, a profile URL, or a platform-id reference.
+Every command accepts --json to print structured output instead (one object per
+line for watch).";
+
+const DEFAULT_WATCH_INTERVAL: u64 = 60;
+
+/// A headless subcommand. Runs against the same service and player index as the window.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Help,
+    Lookup { query: String, json: bool },
+    Export { player: String, json: bool },
+    Watch { player: String, interval: u64, json: bool },
+}
+
+impl Command {
+    /// Parses the process arguments (without the program name). Returns `None` when no
+    /// subcommand was given, in which case the dashboard window should be opened.
+    pub fn parse(args: &[String]) -> Option<Result<Self>> {
+        let (name, rest) = args.split_first()?;
+        Some(Self::parse_subcommand(name, rest))
+    }
+
+    fn parse_subcommand(name: &str, rest: &[String]) -> Result<Self> {
+        let mut positional = Vec::new();
+        let mut interval = DEFAULT_WATCH_INTERVAL;
+        let mut json = false;
+        let mut flags = rest.iter();
+        while let Some(arg) = flags.next() {
+            match arg.as_str() {
+                "--csv" if name == "export" => json = false,
+                "--json" => json = true,
+                "--interval" if name == "watch" => {
+                    interval = flags
+                        .next()
+                        .and_then(|value| value.parse().ok())
+                        .filter(|seconds| *seconds > 0)
+                        .ok_or_else(|| anyhow!("--interval expects a number of seconds"))?;
+                }
+                flag if flag.starts_with("--") => bail!("Unknown option '{}' for '{}'", flag, name),
+                value => positional.push(value.to_string()),
+            }
+        }
+        let argument = positional.join(" ");
+
+        match name {
+            "help" | "--help" | "-h" => Ok(Self::Help),
+            "lookup" | "export" | "watch" if argument.is_empty() => {
+                bail!("'{}' expects a player argument\n\n{}", name, USAGE)
+            }
+            "lookup" => Ok(Self::Lookup { query: argument, json }),
+            "export" => Ok(Self::Export { player: argument, json }),
+            "watch" => Ok(Self::Watch { player: argument, interval, json }),
+            other => bail!("Unknown command '{}'\n\n{}", other, USAGE),
+        }
+    }
+
+    pub fn run(self) -> Result<()> {
+        let mut service = SuperviveService::new()?;
+        let mut index = PlayerIndex::new(PlayerIndex::default_path());
+
+        match self {
+            Self::Help => println!("{}", USAGE),
+            Self::Lookup { query, json } => lookup(&mut service, &mut index, &query, json)?,
+            Self::Export { player, json } => export(&mut service, &mut index, &player, json)?,
+            Self::Watch { player, interval, json } => {
+                watch(&mut service, &mut index, &player, interval, json)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A player resolved from a command-line reference.
+struct ResolvedPlayer {
+    platform: String,
+    player_id: String,
+    name: String,
+}
+
+fn search(service: &mut SuperviveService, index: &mut PlayerIndex, query: &str) -> Result<Vec<Value>> {
+    let data = service.search_players(query)?;
+    let results = data.as_array().cloned().unwrap_or_default();
+    index.record_search_results(&results);
+    Ok(results)
+}
+
+impl ResolvedPlayer {
+    fn to_json(&self) -> Value {
+        json!({
+            "platform": self.platform,
+            "player_id": self.player_id,
+            "unique_display_name": self.name,
+        })
+    }
+}
+
+fn lookup(service: &mut SuperviveService, index: &mut PlayerIndex, query: &str, as_json: bool) -> Result<()> {
+    let results = search(service, index, query)?;
+    if results.is_empty() && !as_json {
+        bail!("No players found for '{}'", query);
+    }
+
+    let mut entries = Vec::new();
+    for player in &results {
+        let platform = player["platform"].as_str().unwrap_or("steam");
+        let player_id = player["userId"].as_str().unwrap_or("");
+        let summary = service.get_player_summary(platform, player_id).unwrap_or_default();
+        if as_json {
+            entries.push(json!({
+                "platform": platform,
+                "player_id": player_id,
+                "unique_display_name": player["uniqueDisplayName"],
+                "display_name": player["displayName"],
+                "summary": summary,
+            }));
+            continue;
+        }
+        let last_seen = summary
+            .last_seen
+            .map(|time| time.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        println!(
+            "{:<24} {:<8} {:<34} last seen {}",
+            player["uniqueDisplayName"].as_str().unwrap_or("Unknown"),
+            platform,
+            player_id,
+            last_seen,
+        );
+    }
+    if as_json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+    }
+    Ok(())
+}
+
+/// Accepts profile URLs and platform ids directly; otherwise looks the name up and requires
+/// an exact (case-insensitive) match or a single result.
+fn resolve_player(service: &mut SuperviveService, index: &mut PlayerIndex, input: &str) -> Result<ResolvedPlayer> {
+    if let Some((platform, player_id)) = parse_profile_reference(input) {
+        return Ok(ResolvedPlayer {
+            platform,
+            name: player_id.clone(),
+            player_id,
+        });
+    }
+
+    let results = search(service, index, input)?;
+    let mut exact: Vec<&Value> = results
+        .iter()
+        .filter(|player| {
+            player["uniqueDisplayName"]
+                .as_str()
+                .is_some_and(|name| name.eq_ignore_ascii_case(input))
+        })
+        .collect();
+    if exact.is_empty() && results.len() == 1 {
+        exact.extend(results.first());
+    }
+    let player = match exact.as_slice() {
+        [player] => *player,
+        [] if results.is_empty() => bail!("No players found for '{}'", input),
+        _ => bail!(
+            "'{}' matches several players; run 'supervive-gui lookup {}' and pass a platform-id instead",
+            input,
+            input
+        ),
+    };
+
+    Ok(ResolvedPlayer {
+        platform: player["platform"].as_str().unwrap_or("steam").to_string(),
+        player_id: player["userId"].as_str().unwrap_or("").to_string(),
+        name: player["uniqueDisplayName"].as_str().unwrap_or(input).to_string(),
+    })
+}
+
+fn fetch_history(service: &mut SuperviveService, index: &mut PlayerIndex, player: &ResolvedPlayer) -> Result<Vec<Value>> {
+    let mut matches = Vec::new();
+    let mut page = 1;
+    loop {
+        let data = service.get_player_matches(&player.platform, &player.player_id, page as i32)?;
+        let rows = data["data"].as_array().cloned().unwrap_or_default();
+        index.record_match_rows(&player.platform, &rows);
+        matches.extend(rows);
+
+        let last_page = data["meta"]["last_page"].as_i64().unwrap_or(1);
+        if page >= last_page.min(MAX_HISTORY_PAGES) {
+            break;
+        }
+        page += 1;
+    }
+    Ok(matches)
+}
+
+const CSV_HEADER: &str = "match_id,match_start,match_end,hero,placement,kills,deaths,assists,damage,healing,ranked,survival_duration";
+
+fn csv_row(row: &Value) -> String {
+    let stat = |key: &str| row["stats"][key].as_i64().unwrap_or(0).to_string();
+    let fields = [
+        row["match_id"].as_str().unwrap_or("").to_string(),
+        row["match_start"].as_str().unwrap_or("").to_string(),
+        row["match_end"].as_str().unwrap_or("").to_string(),
+        row["hero"]["name"].as_str().unwrap_or("").to_string(),
+        row["placement"].as_i64().map(|p| p.to_string()).unwrap_or_default(),
+        stat("Kills"),
+        stat("Deaths"),
+        stat("Assists"),
+        stat("HeroEffectiveDamageDone"),
+        stat("HealingGiven"),
+        row["is_ranked"].as_bool().unwrap_or(false).to_string(),
+        row["survival_duration"].as_f64().map(|d| d.to_string()).unwrap_or_default(),
+    ];
+    csv::row(fields)
+}
+
+fn export(service: &mut SuperviveService, index: &mut PlayerIndex, input: &str, as_json: bool) -> Result<()> {
+    let player = resolve_player(service, index, input)?;
+    let matches = fetch_history(service, index, &player)?;
+    eprintln!("Exporting {} matches for {}", matches.len(), player.name);
+
+    if as_json {
+        let export = json!({ "player": player.to_json(), "matches": matches });
+        println!("{}", serde_json::to_string_pretty(&export)?);
+        return Ok(());
+    }
+
+    println!("{}", CSV_HEADER);
+    for row in &matches {
+        println!("{}", csv_row(row));
+    }
+    Ok(())
+}
+
+/// Polls the first history page and prints each match not seen on a previous poll, also
+/// forwarding it to the configured webhooks.
+fn watch(service: &mut SuperviveService, index: &mut PlayerIndex, input: &str, interval: u64, as_json: bool) -> Result<()> {
+    let player = resolve_player(service, index, input)?;
+    let mut seen = HashSet::new();
+    let mut first_poll = true;
+    let settings = SettingsStore::new(SettingsStore::default_path());
+    let notifier = Notifier::new(settings.settings().webhooks.clone());
+    eprintln!("Watching {} every {}s (Ctrl+C to stop)", player.name, interval);
+    if !notifier.is_empty() {
+        eprintln!("Forwarding new matches to webhooks");
+    }
+
+    loop {
+        match service.get_player_matches(&player.platform, &player.player_id, 1) {
+            Ok(data) => {
+                let rows = data["data"].as_array().cloned().unwrap_or_default();
+                index.record_match_rows(&player.platform, &rows);
+                // Oldest first so new matches print in the order they were played
+                for row in rows.iter().rev() {
+                    let match_id = row["match_id"].as_str().unwrap_or("").to_string();
+                    if !seen.insert(match_id) || first_poll {
+                        continue;
+                    }
+                    let name = row["player"]["unique_display_name"].as_str().unwrap_or(&player.name);
+                    notifier.notify(name, row);
+                    if as_json {
+                        println!("{}", json!({ "player": player.to_json(), "match": row }));
+                    } else {
+                        println!(
+                            "{}  #{:<3} {:<12} {}/{}/{}",
+                            row["match_end"].as_str().unwrap_or(""),
+                            row["placement"].as_i64().unwrap_or(0),
+                            row["hero"]["name"].as_str().unwrap_or("Unknown"),
+                            row["stats"]["Kills"].as_i64().unwrap_or(0),
+                            row["stats"]["Deaths"].as_i64().unwrap_or(0),
+                            row["stats"]["Assists"].as_i64().unwrap_or(0),
+                        );
+                    }
+                }
+                first_poll = false;
+            }
+            Err(err) => tracing::warn!(player = %player.name, error = %err, "Failed to poll matches"),
+        }
+        thread::sleep(Duration::from_secs(interval));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Option<Result<Command>> {
+        Command::parse(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn parse_table() {
+        let cases: &[(&[&str], Command)] = &[
+            (&["help"], Command::Help),
+            (&["--help"], Command::Help),
+            (&["-h"], Command::Help),
+            (&["lookup", "Demo"], Command::Lookup { query: "Demo".to_string(), json: false }),
+            (&["lookup", "Demo", "Player", "--json"], Command::Lookup { query: "Demo Player".to_string(), json: true }),
+            (&["export", "steam-abc"], Command::Export { player: "steam-abc".to_string(), json: false }),
+            (&["export", "--json", "steam-abc", "--csv"], Command::Export { player: "steam-abc".to_string(), json: false }),
+            (
+                &["watch", "Demo"],
+                Command::Watch { player: "Demo".to_string(), interval: DEFAULT_WATCH_INTERVAL, json: false },
+            ),
+            (
+                &["watch", "--interval", "15", "Demo", "--json"],
+                Command::Watch { player: "Demo".to_string(), interval: 15, json: true },
+            ),
+        ];
+        for (args, expected) in cases {
+            assert_eq!(parse(args).unwrap().unwrap(), *expected, "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn parse_errors_table() {
+        let cases: &[(&[&str], &str)] = &[
+            (&["lookup"], "'lookup' expects a player argument"),
+            (&["export", "--json"], "'export' expects a player argument"),
+            (&["watch", "Demo", "--interval"], "--interval expects a number of seconds"),
+            (&["watch", "Demo", "--interval", "0"], "--interval expects a number of seconds"),
+            (&["watch", "Demo", "--interval", "soon"], "--interval expects a number of seconds"),
+            (&["lookup", "Demo", "--csv"], "Unknown option '--csv' for 'lookup'"),
+            (&["export", "Demo", "--interval", "5"], "Unknown option '--interval' for 'export'"),
+            (&["frobnicate"], "Unknown command 'frobnicate'"),
+        ];
+        for (args, message) in cases {
+            let err = parse(args).unwrap().unwrap_err().to_string();
+            assert!(err.starts_with(message), "{:?}: {}", args, err);
+        }
+    }
+
+    #[test]
+    fn no_arguments_opens_the_window() {
+        assert!(parse(&[]).is_none());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::state::AppState;
+use gpui::App;
+use std::time::Duration;
+
+/// How often the clipboard is checked for a new player name.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Heuristic for text copied from the in-game death screen or scoreboard: a short single line
+/// with at least one letter, optionally with a `#1234` tag. URLs and sentences don't qualify.
+pub fn looks_like_player_name(text: &str) -> bool {
+    let text = text.trim();
+    let length = text.chars().count();
+    (3..=24).contains(&length)
+        && !text.contains(['\n', '\r', '\t', '/', '@'])
+        && !text.contains("  ")
+        && text.split(' ').count() <= 3
+        && text.chars().any(char::is_alphabetic)
+        && text
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '_' | '-' | '.' | '#'))
+}
+
+/// Watches the clipboard while `clipboard_watch` is enabled and offers to look up newly copied
+/// player names. Whatever is on the clipboard when watching starts is ignored.
+pub fn start(cx: &mut App) {
+    cx.spawn(async move |cx| {
+        let mut last_seen: Option<Option<String>> = None;
+        loop {
+            let result = cx.update(|cx| {
+                if !cx.global::<AppState>().settings().clipboard_watch {
+                    last_seen = None;
+                    return;
+                }
+                let text = cx.read_from_clipboard().and_then(|item| item.text());
+                let previous = last_seen.replace(text.clone());
+                let (Some(previous), Some(text)) = (previous, text) else {
+                    return;
+                };
+                if previous.as_deref() == Some(text.as_str()) || !looks_like_player_name(&text) {
+                    return;
+                }
+                let name = text.trim().to_string();
+                tracing::debug!(name = %name, "Player name copied to clipboard");
+                *cx.global::<AppState>().clipboard_name.lock().unwrap() = Some(name);
+                cx.refresh_windows();
+            });
+            if result.is_err() {
+                break;
+            }
+            cx.background_executor().timer(POLL_INTERVAL).await;
+        }
+    })
+    .detach();
+}
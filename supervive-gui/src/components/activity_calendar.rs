@@ -0,0 +1,263 @@
+use crate::components::focus::FocusRingExt;
+use crate::format;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Weeks shown, ending with the current one.
+const WEEKS: i64 = 26;
+const CELL: Pixels = px(12.0);
+const GAP: Pixels = px(3.0);
+
+/// Cell shades from no games to the busiest days.
+const LEVELS: [u32; 5] = [0x313244, 0x1e4d3a, 0x2e7d4f, 0x40a865, 0xa6e3a1];
+
+type DayHandler = Rc<dyn Fn(Option<NaiveDate>, &mut Window, &mut App)>;
+
+/// GitHub-style grid of games played per day, one column per week. Clicking a day selects it;
+/// clicking the selected day clears the selection. With a focus handle the arrow keys move the
+/// selection between days with games and Escape clears it. The cells are painted in one canvas
+/// and hit tested by position, so the grid costs one element rather than one per day.
+#[derive(IntoElement)]
+pub struct ActivityCalendar {
+    games_per_day: HashMap<NaiveDate, usize>,
+    selected: Option<NaiveDate>,
+    on_select: DayHandler,
+    /// Day under the pointer, described in the caption
+    hovered: Option<NaiveDate>,
+    on_hover: Option<DayHandler>,
+    focus: Option<FocusHandle>,
+}
+
+impl ActivityCalendar {
+    pub fn new(
+        games_per_day: HashMap<NaiveDate, usize>,
+        selected: Option<NaiveDate>,
+        on_select: impl Fn(Option<NaiveDate>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            games_per_day,
+            selected,
+            on_select: Rc::new(on_select),
+            hovered: None,
+            on_hover: None,
+            focus: None,
+        }
+    }
+
+    /// Makes the grid a tab stop whose selection follows the arrow keys.
+    pub fn track_focus(mut self, handle: &FocusHandle) -> Self {
+        self.focus = Some(handle.clone());
+        self
+    }
+
+    /// Reports the day under the pointer so the owner can pass it back as `hovered`.
+    pub fn on_hover(
+        mut self,
+        hovered: Option<NaiveDate>,
+        on_hover: impl Fn(Option<NaiveDate>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.hovered = hovered;
+        self.on_hover = Some(Rc::new(on_hover));
+        self
+    }
+}
+
+fn level(games: usize, busiest: usize) -> u32 {
+    if games == 0 {
+        return LEVELS[0];
+    }
+    let step = (games * (LEVELS.len() - 1)).div_ceil(busiest.max(1));
+    LEVELS[step.clamp(1, LEVELS.len() - 1)]
+}
+
+/// Day of the cell under `position`, if it is over a cell rather than a gap or outside the grid.
+fn day_at(grid: Bounds<Pixels>, position: Point<Pixels>, first_day: NaiveDate) -> Option<NaiveDate> {
+    if !grid.contains(&position) {
+        return None;
+    }
+    let x = position.x - grid.origin.x;
+    let y = position.y - grid.origin.y;
+    let pitch = CELL + GAP;
+    let week = (x / pitch).floor() as i64;
+    let weekday = (y / pitch).floor() as i64;
+    let in_cell = x - pitch * week as f32 <= CELL && y - pitch * weekday as f32 <= CELL;
+    (in_cell && (0..WEEKS).contains(&week) && (0..7).contains(&weekday))
+        .then(|| first_day + Duration::days(week * 7 + weekday))
+}
+
+/// Selection after an arrow key: the nearest day with games one row (a day) or one column (a
+/// week) away, or the latest such day when nothing is selected yet. `None` when there is none.
+fn step_selection(
+    selected: Option<NaiveDate>,
+    key: &str,
+    games_per_day: &HashMap<NaiveDate, usize>,
+    first_day: NaiveDate,
+    today: NaiveDate,
+) -> Option<NaiveDate> {
+    let step = match key {
+        "up" => -1,
+        "down" => 1,
+        "left" => -7,
+        "right" => 7,
+        _ => return None,
+    };
+    let played = |day: &NaiveDate| games_per_day.get(day).copied().unwrap_or(0) > 0;
+    let Some(mut day) = selected else {
+        return (0..=(today - first_day).num_days())
+            .map(|back| today - Duration::days(back))
+            .find(played);
+    };
+    loop {
+        day += Duration::days(step);
+        if day < first_day || day > today {
+            return None;
+        }
+        if played(&day) {
+            return Some(day);
+        }
+    }
+}
+
+fn cell_bounds(grid: Bounds<Pixels>, week: i64, weekday: i64) -> Bounds<Pixels> {
+    let pitch = CELL + GAP;
+    Bounds::new(
+        point(grid.origin.x + pitch * week as f32, grid.origin.y + pitch * weekday as f32),
+        size(CELL, CELL),
+    )
+}
+
+impl RenderOnce for ActivityCalendar {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let today = Local::now().date_naive();
+        // Columns start on Monday so each one is a calendar week
+        let first_day = today - Duration::days(today.weekday().num_days_from_monday() as i64 + (WEEKS - 1) * 7);
+        let busiest = self.games_per_day.values().copied().max().unwrap_or(0);
+        let total: usize = self
+            .games_per_day
+            .iter()
+            .filter(|(day, _)| **day >= first_day)
+            .map(|(_, games)| games)
+            .sum();
+        let caption = match self.hovered {
+            Some(day) => format!(
+                "{} games on {}",
+                self.games_per_day.get(&day).copied().unwrap_or(0),
+                format::date(day)
+            ),
+            None => format!("{} games in the last {} weeks", total, WEEKS),
+        };
+        // Filled in at prepaint, read by the mouse handlers of the same frame
+        let grid: Rc<Cell<Option<Bounds<Pixels>>>> = Rc::new(Cell::new(None));
+        let games_per_day = self.games_per_day.clone();
+        let (selected, hovered) = (self.selected, self.hovered);
+        let pitch = CELL + GAP;
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(div().text_xs().text_color(rgb(0x9399b2)).child(caption))
+            .child(
+                div()
+                    .id("activity-grid")
+                    .w(pitch * WEEKS as f32 - GAP)
+                    .h(pitch * 7.0 - GAP)
+                    .cursor_pointer()
+                    .when_some(self.on_hover.clone(), |el, on_hover| {
+                        let leave = on_hover.clone();
+                        let grid = grid.clone();
+                        el.on_hover(move |hovering, window, cx| {
+                            if !*hovering {
+                                leave(None, window, cx);
+                            }
+                        })
+                        .on_mouse_move(move |event: &MouseMoveEvent, window, cx| {
+                            let day = grid.get().and_then(|grid| day_at(grid, event.position, first_day));
+                            let day = day.filter(|day| *day <= today);
+                            if day != hovered {
+                                on_hover(day, window, cx);
+                            }
+                        })
+                    })
+                    .on_mouse_down(MouseButton::Left, {
+                        let grid = grid.clone();
+                        let on_select = self.on_select.clone();
+                        let games_per_day = self.games_per_day.clone();
+                        move |event: &MouseDownEvent, window, cx| {
+                            let Some(day) = grid.get().and_then(|grid| day_at(grid, event.position, first_day)) else {
+                                return;
+                            };
+                            if games_per_day.get(&day).copied().unwrap_or(0) > 0 {
+                                on_select((selected != Some(day)).then_some(day), window, cx);
+                            }
+                        }
+                    })
+                    .when_some(self.focus.clone(), |el, focus| {
+                        let on_select = self.on_select.clone();
+                        let games_per_day = self.games_per_day.clone();
+                        el.focus_ring(&focus).on_key_down(move |event: &KeyDownEvent, window, cx| {
+                            let key = event.keystroke.key.as_str();
+                            if key == "escape" && selected.is_some() {
+                                cx.stop_propagation();
+                                on_select(None, window, cx);
+                            } else if let Some(day) = step_selection(selected, key, &games_per_day, first_day, today) {
+                                cx.stop_propagation();
+                                on_select(Some(day), window, cx);
+                            }
+                        })
+                    })
+                    .child(
+                        canvas(
+                            move |bounds, _, _| grid.set(Some(bounds)),
+                            move |bounds, _, window, _| {
+                                for week in 0..WEEKS {
+                                    for weekday in 0..7 {
+                                        let day = first_day + Duration::days(week * 7 + weekday);
+                                        if day > today {
+                                            continue;
+                                        }
+                                        let games = games_per_day.get(&day).copied().unwrap_or(0);
+                                        let mut quad = fill(cell_bounds(bounds, week, weekday), rgb(level(games, busiest)))
+                                            .corner_radii(px(2.0));
+                                        if selected == Some(day) {
+                                            quad = quad.border_widths(px(1.0)).border_color(rgb(0xcdd6f4));
+                                        } else if hovered == Some(day) {
+                                            quad = quad.border_widths(px(1.0)).border_color(rgb(0x9399b2));
+                                        }
+                                        window.paint_quad(quad);
+                                    }
+                                }
+                            },
+                        )
+                        .size_full(),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step_selection, HashMap, NaiveDate};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn arrows_skip_to_the_nearest_day_with_games() {
+        let games: HashMap<_, _> = [(day(2), 1), (day(4), 3), (day(16), 2)].into_iter().collect();
+        let (first, today) = (day(2), day(20));
+        assert_eq!(step_selection(None, "up", &games, first, today), Some(day(16)));
+        assert_eq!(step_selection(Some(day(2)), "down", &games, first, today), Some(day(4)));
+        assert_eq!(step_selection(Some(day(4)), "up", &games, first, today), Some(day(2)));
+        assert_eq!(step_selection(Some(day(2)), "right", &games, first, today), Some(day(16)));
+        assert_eq!(step_selection(Some(day(16)), "right", &games, first, today), None);
+        assert_eq!(step_selection(Some(day(2)), "up", &games, first, today), None);
+        assert_eq!(step_selection(Some(day(2)), "a", &games, first, today), None);
+        assert_eq!(step_selection(None, "up", &HashMap::new(), first, today), None);
+    }
+}
@@ -0,0 +1,181 @@
+//! Charts paint their marks straight into a canvas instead of laying out a div per point or
+//! bar, so their cost stays flat however much history is loaded.
+
+use crate::format;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Strokes `values` across `bounds`, with `min` on the bottom edge and `min + range` on the top.
+pub fn paint_line(window: &mut Window, bounds: Bounds<Pixels>, values: &[f64], min: f64, range: f64, color: Rgba) {
+    if values.len() < 2 {
+        return;
+    }
+    let step = bounds.size.width / (values.len() - 1) as f32;
+    let mut builder = PathBuilder::stroke(px(2.0));
+    for (idx, value) in values.iter().enumerate() {
+        let point = point(
+            bounds.origin.x + step * idx as f32,
+            bounds.origin.y + bounds.size.height * (1.0 - ((value - min) / range) as f32),
+        );
+        if idx == 0 {
+            builder.move_to(point);
+        } else {
+            builder.line_to(point);
+        }
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Minimal line chart of a series, scaled to its own range, with the range labelled on the left.
+#[derive(IntoElement)]
+pub struct LineChart {
+    values: Vec<f64>,
+    color: Rgba,
+    height: Pixels,
+    /// Points to draw a dashed vertical rule through, e.g. where a patch landed
+    markers: Vec<usize>,
+}
+
+impl LineChart {
+    pub fn new(values: Vec<f64>) -> Self {
+        Self {
+            values,
+            color: rgb(0x89b4fa),
+            height: px(120.0),
+            markers: Vec::new(),
+        }
+    }
+
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn markers(mut self, markers: Vec<usize>) -> Self {
+        self.markers = markers;
+        self
+    }
+}
+
+/// Dashed vertical rules through the given points of a `len`-point line.
+fn paint_markers(window: &mut Window, bounds: Bounds<Pixels>, markers: &[usize], len: usize) {
+    if len < 2 {
+        return;
+    }
+    let step = bounds.size.width / (len - 1) as f32;
+    let dash = px(4.0);
+    for idx in markers {
+        let x = bounds.origin.x + step * *idx as f32;
+        let mut y = bounds.origin.y;
+        while y < bounds.bottom() {
+            let rule = Bounds::new(point(x, y), size(px(1.0), dash.min(bounds.bottom() - y)));
+            window.paint_quad(fill(rule, rgb(0xf9e2af)));
+            y += dash * 2.0;
+        }
+    }
+}
+
+impl RenderOnce for LineChart {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let min = self.values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = self.values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let range = (max - min).max(1.0);
+        let values = self.values;
+        let color = self.color;
+        let markers = self.markers;
+
+        div()
+            .flex()
+            .gap_2()
+            .h(self.height)
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .justify_between()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child(format::compact(max))
+                    .child(format::compact(min)),
+            )
+            .child(
+                canvas(
+                    |_, _, _| {},
+                    move |bounds, _, window, _| {
+                        paint_markers(window, bounds, &markers, values.len());
+                        paint_line(window, bounds, &values, min, range, color);
+                    },
+                )
+                .flex_1()
+                .h_full(),
+            )
+    }
+}
+
+/// One bar of a histogram and the caption under it.
+pub struct Bar {
+    pub label: SharedString,
+    pub value: f64,
+}
+
+/// Vertical bars scaled to the tallest one, captioned underneath.
+#[derive(IntoElement)]
+pub struct Histogram {
+    bars: Vec<Bar>,
+    color: Rgba,
+    height: Pixels,
+}
+
+impl Histogram {
+    pub fn new(bars: Vec<Bar>, color: Rgba) -> Self {
+        Self {
+            bars,
+            color,
+            height: px(80.0),
+        }
+    }
+}
+
+impl RenderOnce for Histogram {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let heights: Vec<f64> = self.bars.iter().map(|bar| bar.value).collect();
+        let tallest = heights.iter().copied().fold(0.0, f64::max).max(f64::EPSILON);
+        let color = self.color;
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                canvas(
+                    |_, _, _| {},
+                    move |bounds, _, window, _| {
+                        if heights.is_empty() {
+                            return;
+                        }
+                        let slot = bounds.size.width / heights.len() as f32;
+                        let gap = slot * 0.2;
+                        for (idx, value) in heights.iter().enumerate() {
+                            let height = bounds.size.height * (value / tallest) as f32;
+                            let bar = Bounds::new(
+                                point(bounds.origin.x + slot * idx as f32 + gap / 2.0, bounds.bottom() - height),
+                                size(slot - gap, height),
+                            );
+                            window.paint_quad(fill(bar, color).corner_radii(px(3.0)));
+                        }
+                    },
+                )
+                .w_full()
+                .h(self.height),
+            )
+            .child(
+                div()
+                    .flex()
+                    .text_xs()
+                    .text_color(rgb(0x9399b2))
+                    .children(self.bars.into_iter().map(|bar| div().flex_1().text_center().child(bar.label))),
+            )
+    }
+}
@@ -0,0 +1,81 @@
+use crate::components::tooltip::Tooltip;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+
+/// Damage sources with their stat key, legend label and color.
+const SOURCES: [(&str, &str, u32); 3] = [
+    ("AbilityDamageDone", "Abilities", 0xcba6f7),
+    ("WeaponDamageDone", "Weapons", 0x89b4fa),
+    ("MeleeDamageDone", "Melee", 0xfab387),
+];
+
+/// Stacked bar of a player's damage split by source, with a legend.
+#[derive(IntoElement)]
+pub struct DamageBar {
+    /// (label, color, damage) for every source with damage
+    parts: Vec<(&'static str, u32, f64)>,
+}
+
+impl DamageBar {
+    /// Reads the per-source damage from a match row's `stats`; `None` if the payload doesn't
+    /// break damage down.
+    pub fn from_stats(stats: &Value) -> Option<Self> {
+        let parts: Vec<_> = SOURCES
+            .iter()
+            .filter_map(|(key, label, color)| {
+                let damage = stats[*key].as_f64()?;
+                (damage > 0.0).then_some((*label, *color, damage))
+            })
+            .collect();
+        (!parts.is_empty()).then_some(Self { parts })
+    }
+}
+
+impl RenderOnce for DamageBar {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let total: f64 = self.parts.iter().map(|(_, _, damage)| damage).sum();
+        let share = |damage: f64| (damage / total) as f32;
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .flex()
+                    .w_full()
+                    .h_3()
+                    .rounded_sm()
+                    .overflow_hidden()
+                    .children(self.parts.iter().map(|(label, color, damage)| {
+                        div()
+                            .id(ElementId::Name(format!("damage-{}", label).into()))
+                            .h_full()
+                            .w(relative(share(*damage)))
+                            .bg(rgb(*color))
+                            .tooltip(Tooltip::text(format!(
+                                "{}: {:.0} ({:.0}%)",
+                                label,
+                                damage,
+                                share(*damage) * 100.0
+                            )))
+                    })),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap_3()
+                    .text_xs()
+                    .text_color(rgb(0x9399b2))
+                    .children(self.parts.iter().map(|(label, color, damage)| {
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .child(div().size_2().rounded_sm().bg(rgb(*color)))
+                            .child(format!("{} {:.0}%", label, share(*damage) * 100.0))
+                    })),
+            )
+    }
+}
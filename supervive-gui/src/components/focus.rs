@@ -0,0 +1,35 @@
+//! Keyboard focus for clickable elements: a tab stop, the shared focus ring and Enter/Space
+//! activation. Every clickable control should go through these helpers.
+//!
+//! Still open: screen-reader names and roles. gpui 0.2.2 has no accessibility tree (no
+//! accesskit, no role or label on elements), so controls are reachable by keyboard but not
+//! announced. Once gpui exposes those hooks, they belong here next to `focus_ring`.
+
+use gpui::*;
+
+const FOCUS_RING: u32 = 0xf9e2af;
+
+/// Whether a key press should activate the focused control the same way a click does.
+pub fn is_activation_key(event: &KeyDownEvent) -> bool {
+    matches!(event.keystroke.key.as_str(), "enter" | "space")
+}
+
+/// Resizes `handles` to `len` tab stops, reusing existing handles so focus survives re-renders.
+pub fn sync_focus_handles(handles: &mut Vec<FocusHandle>, len: usize, cx: &mut App) {
+    handles.truncate(len);
+    while handles.len() < len {
+        handles.push(cx.focus_handle().tab_stop(true));
+    }
+}
+
+pub trait FocusRingExt: InteractiveElement + Styled + Sized {
+    /// Makes the element a keyboard focus target that draws the shared focus ring when focused.
+    fn focus_ring(self, handle: &FocusHandle) -> Self {
+        self.border_1()
+            .border_color(transparent_black())
+            .track_focus(handle)
+            .focus(|style| style.border_color(rgb(FOCUS_RING)))
+    }
+}
+
+impl<E: InteractiveElement + Styled> FocusRingExt for E {}
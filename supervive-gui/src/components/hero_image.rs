@@ -0,0 +1,123 @@
+use crate::components::tooltip::Tooltip;
+use crate::demo;
+use crate::heroes::HeroRole;
+use crate::state::AppState;
+use gpui::prelude::*;
+use gpui::*;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+static FALLBACK_ICON: LazyLock<Arc<Image>> = LazyLock::new(|| {
+    Arc::new(Image::from_bytes(
+        ImageFormat::Svg,
+        include_bytes!("../../assets/hero_placeholder.svg").to_vec(),
+    ))
+});
+
+/// Hero portrait that reserves its space with a role-colored placeholder, fades the
+/// image in once loaded, and shows a bundled generic icon if loading fails.
+///
+/// Portraits are served from the local hero asset store when available; the remote URL is
+/// only used (and downloaded in the background) for heroes not seen before.
+#[derive(IntoElement)]
+pub struct HeroImage {
+    id: ElementId,
+    url: String,
+    hero_name: String,
+    size: Pixels,
+}
+
+impl HeroImage {
+    pub fn new(id: impl Into<ElementId>, url: impl Into<String>, hero_name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            url: url.into(),
+            hero_name: hero_name.into(),
+            size: px(48.0),
+        }
+    }
+
+    pub fn size(mut self, size: Pixels) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl RenderOnce for HeroImage {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let size = self.size;
+        let radius = size / 6.0;
+        let role = HeroRole::for_hero(&self.hero_name);
+        let role_color = role.map(|role| role.color()).unwrap_or(rgb(0x45475a));
+        let tooltip = match role {
+            Some(role) => Tooltip::with_description(self.hero_name.clone(), role.label()),
+            None => Tooltip::with_description(self.hero_name.clone(), "Unknown role"),
+        };
+
+        let fallback = move || {
+            img(FALLBACK_ICON.clone())
+                .size(size)
+                .rounded(radius)
+                .into_any_element()
+        };
+
+        let source = if self.url.is_empty() {
+            None
+        } else {
+            local_or_remote_source(&self.hero_name, &self.url, cx)
+        };
+
+        div()
+            .id(self.id.clone())
+            .flex_none()
+            .size(size)
+            .rounded(radius)
+            .bg(role_color)
+            .tooltip(tooltip)
+            .map(|parent| match source {
+                None => parent.child(fallback()),
+                Some(source) => parent.child(
+                    img(source)
+                        .size(size)
+                        .rounded(radius)
+                        .object_fit(ObjectFit::Cover)
+                        .with_loading(move || div().size(size).into_any_element())
+                        .with_fallback(fallback)
+                        .with_animation(
+                            self.id,
+                            Animation::new(Duration::from_millis(250)).with_easing(ease_in_out),
+                            |image, delta| image.opacity(delta),
+                        ),
+                ),
+            })
+    }
+}
+
+/// The stored portrait, or the remote one while it downloads. Demo mode stays offline and shows
+/// the placeholder for portraits that aren't stored yet.
+fn local_or_remote_source(hero_name: &str, url: &str, cx: &mut App) -> Option<ImageSource> {
+    let assets = cx.global::<AppState>().hero_assets.clone();
+    let mut store = assets.lock().unwrap();
+    if let Some(path) = store.local_path(hero_name, url) {
+        return Some(path.into());
+    }
+    if demo::is_enabled() {
+        return None;
+    }
+
+    if store.begin_download(hero_name) {
+        let job = store.download_job(hero_name, url);
+        let hero_name = hero_name.to_string();
+        let assets = assets.clone();
+        cx.background_executor()
+            .spawn(async move {
+                let result = job();
+                if let Err(err) = &result {
+                    tracing::warn!(hero = %hero_name, error = %err, "Failed to store portrait");
+                }
+                assets.lock().unwrap().finish_download(&hero_name, result.is_ok());
+            })
+            .detach();
+    }
+    Some(url.to_string().into())
+}
@@ -0,0 +1,176 @@
+use crate::components::tooltip::Tooltip;
+use crate::format;
+use crate::theme::team_color;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+
+const MAP_SIZE: f32 = 360.0;
+const MARKER: f32 = 8.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    fn parse(value: &Value) -> Option<Self> {
+        Some(Self {
+            x: value["x"].as_f64()?,
+            y: value["y"].as_f64()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Death {
+    player: String,
+    team_id: i64,
+    position: Point,
+    /// Seconds into the match
+    time: Option<f64>,
+}
+
+/// Minimap of where each player died and where the final circle closed, in world coordinates
+/// scaled to fit. Built from the optional `death_locations` (`[{x, y, time}]`) on each player row
+/// and `final_circle` (`{x, y, radius}`) on any row; matches without them have no map.
+#[derive(IntoElement, Clone)]
+pub struct MatchMap {
+    deaths: Vec<Death>,
+    final_circle: Option<(Point, f64)>,
+}
+
+impl MatchMap {
+    pub fn from_rows(rows: &[Value]) -> Option<Self> {
+        let deaths: Vec<Death> = rows
+            .iter()
+            .flat_map(|row| {
+                let player = row["player"]["unique_display_name"].as_str().unwrap_or("Unknown").to_string();
+                let team_id = row["team_id"].as_i64().unwrap_or(0);
+                row["death_locations"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(move |death| {
+                        Some(Death {
+                            player: player.clone(),
+                            team_id,
+                            position: Point::parse(death)?,
+                            time: death["time"].as_f64(),
+                        })
+                    })
+            })
+            .collect();
+        let final_circle = rows.iter().find_map(|row| {
+            let circle = &row["final_circle"];
+            Some((Point::parse(circle)?, circle["radius"].as_f64()?))
+        });
+        if deaths.is_empty() && final_circle.is_none() {
+            return None;
+        }
+        Some(Self { deaths, final_circle })
+    }
+
+    /// Square world-space area covering every death and the final circle, with some margin.
+    fn extent(&self) -> (Point, f64) {
+        let mut points: Vec<(Point, f64)> = self.deaths.iter().map(|death| (death.position, 0.0)).collect();
+        points.extend(self.final_circle);
+        let min_x = points.iter().map(|(p, r)| p.x - r).fold(f64::INFINITY, f64::min);
+        let max_x = points.iter().map(|(p, r)| p.x + r).fold(f64::NEG_INFINITY, f64::max);
+        let min_y = points.iter().map(|(p, r)| p.y - r).fold(f64::INFINITY, f64::min);
+        let max_y = points.iter().map(|(p, r)| p.y + r).fold(f64::NEG_INFINITY, f64::max);
+        let size = (max_x - min_x).max(max_y - min_y).max(1.0) * 1.1;
+        let center = Point {
+            x: (min_x + max_x) / 2.0,
+            y: (min_y + max_y) / 2.0,
+        };
+        (Point { x: center.x - size / 2.0, y: center.y - size / 2.0 }, size)
+    }
+}
+
+impl RenderOnce for MatchMap {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let (origin, size) = self.extent();
+        let scale = MAP_SIZE as f64 / size;
+        let to_map = |point: Point| ((point.x - origin.x) * scale, (point.y - origin.y) * scale);
+
+        div()
+            .relative()
+            .size(px(MAP_SIZE))
+            .overflow_hidden()
+            .rounded_md()
+            .bg(rgb(0x11111b))
+            .border_1()
+            .border_color(rgb(0x313244))
+            .when_some(self.final_circle, |map, (center, radius)| {
+                let (x, y) = to_map(center);
+                let diameter = (radius * 2.0 * scale) as f32;
+                map.child(
+                    div()
+                        .id("final-circle")
+                        .absolute()
+                        .left(px(x as f32 - diameter / 2.0))
+                        .top(px(y as f32 - diameter / 2.0))
+                        .size(px(diameter))
+                        .rounded_full()
+                        .border_2()
+                        .border_color(rgb(0xcdd6f4))
+                        .tooltip(Tooltip::text("Final circle")),
+                )
+            })
+            .children(self.deaths.into_iter().enumerate().map(|(idx, death)| {
+                let (x, y) = to_map(death.position);
+                let label = match death.time {
+                    Some(time) => format!(
+                        "{} (team {}) died at {}",
+                        death.player,
+                        death.team_id,
+                        format::duration(time as i64)
+                    ),
+                    None => format!("{} (team {}) died here", death.player, death.team_id),
+                };
+                div()
+                    .id(("death", idx))
+                    .absolute()
+                    .left(px(x as f32 - MARKER / 2.0))
+                    .top(px(y as f32 - MARKER / 2.0))
+                    .size(px(MARKER))
+                    .rounded_full()
+                    .bg(team_color(death.team_id))
+                    .border_1()
+                    .border_color(rgb(0x11111b))
+                    .tooltip(Tooltip::text(label))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MatchMap;
+    use serde_json::json;
+
+    #[test]
+    fn map_reads_death_locations_and_final_circle() {
+        let rows = vec![
+            json!({
+                "player": { "unique_display_name": "Out#1" },
+                "team_id": 2,
+                "death_locations": [{ "x": 10.0, "y": -20.0, "time": 300.0 }, { "x": 5.0 }]
+            }),
+            json!({ "final_circle": { "x": 0.0, "y": 0.0, "radius": 50.0 } }),
+        ];
+        let map = MatchMap::from_rows(&rows).unwrap();
+        assert_eq!(map.deaths.len(), 1);
+        assert_eq!(map.deaths[0].player, "Out#1");
+        assert_eq!(map.deaths[0].team_id, 2);
+        assert_eq!(map.deaths[0].time, Some(300.0));
+        assert_eq!(map.final_circle.map(|(_, radius)| radius), Some(50.0));
+    }
+
+    #[test]
+    fn matches_without_locations_have_no_map() {
+        assert!(MatchMap::from_rows(&[json!({ "final_circle": { "x": 1.0, "y": 1.0 } })]).is_none());
+        assert!(MatchMap::from_rows(&[json!({ "player_id": "aaaa" })]).is_none());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::components::tooltip::Tooltip;
+use crate::format;
+use crate::phases::{self, Phase};
+use crate::theme::team_color;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+
+#[derive(Clone)]
+struct Elimination {
+    player: String,
+    team_id: i64,
+    /// Seconds into the match
+    time: f64,
+}
+
+fn format_time(seconds: f64) -> String {
+    format::duration(seconds as i64)
+}
+
+/// Horizontal match timeline with a tick per eliminated player (from their survival time) and,
+/// when the match has phase timings, a marker where each storm phase begins.
+#[derive(IntoElement, Clone)]
+pub struct MatchTimeline {
+    duration: f64,
+    eliminations: Vec<Elimination>,
+    phases: Vec<Phase>,
+}
+
+impl MatchTimeline {
+    pub fn from_rows(rows: &[Value]) -> Option<Self> {
+        let duration = rows
+            .iter()
+            .filter_map(|row| row["survival_duration"].as_f64())
+            .fold(0.0, f64::max);
+        if duration <= 0.0 {
+            return None;
+        }
+        let eliminations = rows
+            .iter()
+            .filter(|row| row["placement"].as_i64() != Some(1))
+            .filter_map(|row| {
+                Some(Elimination {
+                    player: row["player"]["unique_display_name"].as_str().unwrap_or("Unknown").to_string(),
+                    team_id: row["team_id"].as_i64().unwrap_or(0),
+                    time: row["survival_duration"].as_f64()?,
+                })
+            })
+            .collect();
+        let phases = rows
+            .iter()
+            .map(phases::phases)
+            .find(|phases| !phases.is_empty())
+            .unwrap_or_default();
+        Some(Self { duration, eliminations, phases })
+    }
+
+    fn position(&self, time: f64) -> f32 {
+        (time / self.duration).clamp(0.0, 1.0) as f32
+    }
+}
+
+impl RenderOnce for MatchTimeline {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let phase_markers: Vec<_> = self
+            .phases
+            .iter()
+            .map(|phase| {
+                div()
+                    .id(("phase", phase.number as usize))
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(relative(self.position(phase.start)))
+                    .w(px(1.0))
+                    .bg(rgb(0x6c7086))
+                    .tooltip(Tooltip::text(format!("Phase {} starts at {}", phase.number, format_time(phase.start))))
+            })
+            .collect();
+        let ticks: Vec<_> = self
+            .eliminations
+            .iter()
+            .enumerate()
+            .map(|(idx, elimination)| {
+                div()
+                    .id(("elimination", idx))
+                    .absolute()
+                    .top(px(8.0))
+                    .left(relative(self.position(elimination.time)))
+                    .w(px(3.0))
+                    .h(px(16.0))
+                    .rounded_sm()
+                    .bg(team_color(elimination.team_id))
+                    .tooltip(Tooltip::text(format!(
+                        "{} (team {}) out at {}",
+                        elimination.player,
+                        elimination.team_id,
+                        format_time(elimination.time)
+                    )))
+            })
+            .collect();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .relative()
+                    .w_full()
+                    .h(px(32.0))
+                    .rounded_md()
+                    .bg(rgb(0x181825))
+                    .border_1()
+                    .border_color(rgb(0x313244))
+                    .children(phase_markers)
+                    .children(ticks),
+            )
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child("0:00")
+                    .child(format_time(self.duration)),
+            )
+    }
+}
@@ -0,0 +1,13 @@
+pub mod activity_calendar;
+pub mod chart;
+pub mod damage_bar;
+pub mod focus;
+pub mod hero_image;
+pub mod match_map;
+pub mod match_timeline;
+pub mod participation;
+pub mod progress_bar;
+pub mod rate_limit;
+pub mod text_input;
+pub mod tooltip;
+pub mod truncated_text;
@@ -0,0 +1,214 @@
+use crate::format;
+use crate::theme::team_color;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+
+const KNOCKED: u32 = 0x7f849c;
+const DEAD: u32 = 0x313244;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Alive,
+    Knocked,
+    Dead,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    state: State,
+    /// Seconds into the match
+    start: f64,
+    end: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Lane {
+    player: String,
+    team_id: i64,
+    segments: Vec<Segment>,
+}
+
+/// Alive, knocked and dead stretches of one player across `duration`. Players are alive until
+/// their survival time and dead after it; the optional `knocks` (`[{start, end}]`) and the
+/// respawns of `deaths` (`[{time, respawn}]`) fill in what happened before then.
+fn segments(row: &Value, duration: f64) -> Vec<Segment> {
+    let out = if row["placement"].as_i64() == Some(1) {
+        duration
+    } else {
+        row["survival_duration"].as_f64().unwrap_or(duration).min(duration)
+    };
+    let knocks = row["knocks"].as_array().into_iter().flatten().filter_map(|knock| {
+        let start = knock["start"].as_f64()?;
+        Some((State::Knocked, start, knock["end"].as_f64().unwrap_or(out)))
+    });
+    let respawns = row["deaths"].as_array().into_iter().flatten().filter_map(|death| {
+        Some((State::Dead, death["time"].as_f64()?, death["respawn"].as_f64()?))
+    });
+    let mut marks: Vec<(State, f64, f64)> = knocks.chain(respawns).filter(|(_, start, _)| *start < out).collect();
+    marks.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let mut segments = Vec::new();
+    let mut at = 0.0;
+    for (state, start, end) in marks {
+        let (start, end) = (start.max(at), end.min(out));
+        if end <= start {
+            continue;
+        }
+        if start > at {
+            segments.push(Segment { state: State::Alive, start: at, end: start });
+        }
+        segments.push(Segment { state, start, end });
+        at = end;
+    }
+    if out > at {
+        segments.push(Segment { state: State::Alive, start: at, end: out });
+    }
+    if duration > out {
+        segments.push(Segment { state: State::Dead, start: out, end: duration });
+    }
+    segments
+}
+
+/// One bar per player across the match, colored by whether they were up, knocked or out, so
+/// team wipes line up as columns of grey and revives show as short knocked stretches.
+#[derive(IntoElement, Clone)]
+pub struct ParticipationChart {
+    duration: f64,
+    lanes: Vec<Lane>,
+}
+
+impl ParticipationChart {
+    /// `rows` in the order the bars are stacked.
+    pub fn from_rows(rows: &[&Value]) -> Option<Self> {
+        let duration = rows
+            .iter()
+            .filter_map(|row| row["survival_duration"].as_f64())
+            .fold(0.0, f64::max);
+        if duration <= 0.0 {
+            return None;
+        }
+        let lanes = rows
+            .iter()
+            .map(|row| Lane {
+                player: row["player"]["unique_display_name"].as_str().unwrap_or("Unknown").to_string(),
+                team_id: row["team_id"].as_i64().unwrap_or(0),
+                segments: segments(row, duration),
+            })
+            .collect();
+        Some(Self { duration, lanes })
+    }
+}
+
+impl RenderOnce for ParticipationChart {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let duration = self.duration;
+        let legend = |color: Rgba, label: &'static str| {
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(div().size_2().rounded_sm().bg(color))
+                .child(label)
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(2.0))
+            .children(self.lanes.into_iter().map(move |lane| {
+                let color = team_color(lane.team_id);
+                let segments = lane.segments;
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .h(px(14.0))
+                    .child(
+                        div()
+                            .w(px(140.0))
+                            .flex_shrink_0()
+                            .min_w_0()
+                            .truncate()
+                            .text_xs()
+                            .child(lane.player),
+                    )
+                    .child(
+                        canvas(
+                            |_, _, _| {},
+                            move |bounds, _, window, _| {
+                                for segment in &segments {
+                                    let left = bounds.size.width * (segment.start / duration) as f32;
+                                    let width = bounds.size.width * ((segment.end - segment.start) / duration) as f32;
+                                    let fill_color = match segment.state {
+                                        State::Alive => color,
+                                        State::Knocked => rgb(KNOCKED),
+                                        State::Dead => rgb(DEAD),
+                                    };
+                                    let bar = Bounds::new(point(bounds.origin.x + left, bounds.origin.y), size(width, bounds.size.height));
+                                    window.paint_quad(fill(bar, fill_color));
+                                }
+                            },
+                        )
+                        .flex_1()
+                        .h(px(10.0)),
+                    )
+            }))
+            .child(
+                div()
+                    .flex()
+                    .gap_4()
+                    .pt_1()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child(legend(rgb(0xa6e3a1), "Alive (team color)"))
+                    .child(legend(rgb(KNOCKED), "Knocked"))
+                    .child(legend(rgb(DEAD), "Out"))
+                    .child(div().flex_1())
+                    .child(format!("0:00 – {}", format::duration(duration as i64))),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{segments, Segment, State};
+    use serde_json::json;
+
+    fn states(segments: &[Segment]) -> Vec<(State, f64, f64)> {
+        segments.iter().map(|segment| (segment.state, segment.start, segment.end)).collect()
+    }
+
+    #[test]
+    fn knocks_and_respawns_split_the_lane() {
+        let row = json!({
+            "placement": 4,
+            "survival_duration": 600.0,
+            "knocks": [{ "start": 100.0, "end": 110.0 }, { "start": 590.0 }],
+            "deaths": [{ "time": 300.0, "respawn": 330.0 }, { "time": 600.0 }]
+        });
+        assert_eq!(
+            states(&segments(&row, 900.0)),
+            vec![
+                (State::Alive, 0.0, 100.0),
+                (State::Knocked, 100.0, 110.0),
+                (State::Alive, 110.0, 300.0),
+                (State::Dead, 300.0, 330.0),
+                (State::Alive, 330.0, 590.0),
+                (State::Knocked, 590.0, 600.0),
+                (State::Dead, 600.0, 900.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn winners_stay_up_and_rows_without_timelines_are_alive_then_out() {
+        let winner = json!({ "placement": 1, "survival_duration": 400.0 });
+        assert_eq!(states(&segments(&winner, 900.0)), vec![(State::Alive, 0.0, 900.0)]);
+        let plain = json!({ "placement": 6, "survival_duration": 400.0 });
+        assert_eq!(
+            states(&segments(&plain, 900.0)),
+            vec![(State::Alive, 0.0, 400.0), (State::Dead, 400.0, 900.0)]
+        );
+    }
+}
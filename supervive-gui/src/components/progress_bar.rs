@@ -0,0 +1,47 @@
+use crate::tasks::TaskProgress;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Determinate progress bar with a "12 / 40 players loaded" caption.
+#[derive(IntoElement)]
+pub struct ProgressBar {
+    progress: TaskProgress,
+}
+
+impl ProgressBar {
+    pub fn new(progress: TaskProgress) -> Self {
+        Self { progress }
+    }
+}
+
+impl RenderOnce for ProgressBar {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(rgb(0x9399b2))
+                    .child(format!(
+                        "{} / {} {}",
+                        self.progress.completed, self.progress.total, self.progress.label
+                    )),
+            )
+            .child(
+                div()
+                    .w_full()
+                    .h_1()
+                    .rounded_full()
+                    .bg(rgb(0x313244))
+                    .child(
+                        div()
+                            .h_full()
+                            .rounded_full()
+                            .bg(rgb(0x89b4fa))
+                            .w(relative(self.progress.fraction())),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,93 @@
+use gpui::prelude::*;
+use gpui::*;
+use std::time::{Duration, Instant};
+
+/// How often the countdown re-renders.
+const TICK: Duration = Duration::from_secs(1);
+
+/// A view's request hit the rate limit; the view retries once `until` passes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    until: Instant,
+}
+
+impl RateLimit {
+    /// Starts the countdown and returns the state for the view to keep in `slot`. Every second
+    /// the view re-renders; when time is up `slot` is cleared and `retry` runs. A newer limit
+    /// stored in `slot` takes over the countdown.
+    pub fn start<V: 'static>(
+        retry_after: Duration,
+        slot: fn(&mut V) -> &mut Option<RateLimit>,
+        retry: fn(&mut V, &mut Context<V>),
+        cx: &mut Context<V>,
+    ) -> Self {
+        let limit = Self {
+            until: Instant::now() + retry_after,
+        };
+        cx.spawn(async move |view, cx| loop {
+            cx.background_executor().timer(limit.remaining().min(TICK)).await;
+            let waiting = view.update(cx, |this, cx| {
+                if *slot(this) != Some(limit) {
+                    return false;
+                }
+                if limit.remaining().is_zero() {
+                    *slot(this) = None;
+                    retry(this, cx);
+                    return false;
+                }
+                cx.notify();
+                true
+            });
+            if !matches!(waiting, Ok(true)) {
+                break;
+            }
+        })
+        .detach();
+        limit
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.until.saturating_duration_since(Instant::now())
+    }
+}
+
+/// "Retrying in 12s" banner shown in place of data while a view waits out the rate limit.
+#[derive(IntoElement)]
+pub struct RateLimitBanner {
+    limit: RateLimit,
+}
+
+impl RateLimitBanner {
+    pub fn new(limit: RateLimit) -> Self {
+        Self { limit }
+    }
+}
+
+impl RenderOnce for RateLimitBanner {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        // Round up so the banner never reads "0s" while still waiting
+        let seconds = self.limit.remaining().as_secs_f32().ceil() as u64;
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_3()
+            .rounded_md()
+            .bg(rgb(0x3b3322))
+            .border_1()
+            .border_color(rgb(0xf9e2af))
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(rgb(0xf9e2af))
+                    .child("op.gg is rate limiting requests"),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(rgb(0xbac2de))
+                    .child(format!("Retrying automatically in {}s…", seconds)),
+            )
+    }
+}
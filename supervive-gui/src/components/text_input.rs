@@ -0,0 +1,502 @@
+use gpui::prelude::*;
+use gpui::*;
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// What a key press did to a [`TextInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputChange {
+    /// The text changed, so anything derived from it should be refreshed
+    Edited,
+    /// Only the cursor moved
+    Moved,
+    /// The key isn't an editing key; the caller may handle it
+    Ignored,
+}
+
+/// Single-line text being edited, with a cursor that moves by grapheme cluster, so accented and
+/// other multi-byte names edit like they read. The cursor is a byte offset that always sits on a
+/// grapheme boundary.
+///
+/// Typed text doesn't come from key presses but from the platform's input handler, which the
+/// owning view implements by forwarding to the `*_utf16` methods here. That is what lets an IME
+/// compose Hangul or kana: the text being composed is kept in the input as marked text and
+/// replaced once the IME commits it.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    text: String,
+    cursor: usize,
+    /// Byte range of the IME's uncommitted composition, if one is in progress
+    marked: Option<Range<usize>>,
+}
+
+impl TextInput {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the text and puts the cursor at its end.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.cursor = self.text.len();
+        self.marked = None;
+    }
+
+    /// Start of the grapheme before the cursor.
+    fn previous_boundary(&self) -> Option<usize> {
+        self.text[..self.cursor].grapheme_indices(true).next_back().map(|(idx, _)| idx)
+    }
+
+    /// End of the grapheme after the cursor.
+    fn next_boundary(&self) -> Option<usize> {
+        self.text[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map(|grapheme| self.cursor + grapheme.len())
+    }
+
+    /// Start of the word before the cursor, past any spaces in between.
+    fn previous_word_start(&self) -> Option<usize> {
+        if self.cursor == 0 {
+            return None;
+        }
+        let word = self.text[..self.cursor]
+            .split_word_bound_indices()
+            .rev()
+            .find(|(_, segment)| !segment.trim().is_empty());
+        Some(word.map_or(0, |(idx, _)| idx))
+    }
+
+    /// End of the word after the cursor, past any spaces in between.
+    fn next_word_end(&self) -> Option<usize> {
+        if self.cursor == self.text.len() {
+            return None;
+        }
+        let word = self.text[self.cursor..]
+            .split_word_bound_indices()
+            .find(|(_, segment)| !segment.trim().is_empty());
+        Some(word.map_or(self.text.len(), |(idx, segment)| self.cursor + idx + segment.len()))
+    }
+
+    pub fn clear(&mut self) -> bool {
+        let had_text = !self.text.is_empty();
+        self.set_text(String::new());
+        had_text
+    }
+
+    pub fn backspace(&mut self) -> bool {
+        let Some(start) = self.previous_boundary() else {
+            return false;
+        };
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+        true
+    }
+
+    pub fn delete(&mut self) -> bool {
+        let Some(end) = self.next_boundary() else {
+            return false;
+        };
+        self.text.replace_range(self.cursor..end, "");
+        true
+    }
+
+    pub fn delete_word_back(&mut self) -> bool {
+        let Some(start) = self.previous_word_start() else {
+            return false;
+        };
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+        true
+    }
+
+    pub fn delete_word_forward(&mut self) -> bool {
+        let Some(end) = self.next_word_end() else {
+            return false;
+        };
+        self.text.replace_range(self.cursor..end, "");
+        true
+    }
+
+    pub fn move_left(&mut self) -> bool {
+        self.previous_boundary().map(|start| self.cursor = start).is_some()
+    }
+
+    pub fn move_right(&mut self) -> bool {
+        self.next_boundary().map(|end| self.cursor = end).is_some()
+    }
+
+    /// Applies an editing or cursor key. With Ctrl (Alt on macOS) held, backspace, delete and
+    /// the arrows work a word at a time. Keys are left to the IME while it is composing.
+    pub fn handle_key(&mut self, keystroke: &Keystroke) -> InputChange {
+        if self.marked.is_some() {
+            return InputChange::Ignored;
+        }
+        let edited = |changed: bool| if changed { InputChange::Edited } else { InputChange::Ignored };
+        let moved = |changed: bool| if changed { InputChange::Moved } else { InputChange::Ignored };
+        let by_word = keystroke.modifiers.control || keystroke.modifiers.alt;
+        match keystroke.key.as_str() {
+            "backspace" if by_word => edited(self.delete_word_back()),
+            "delete" if by_word => edited(self.delete_word_forward()),
+            "left" if by_word => moved(self.previous_word_start().map(|start| self.cursor = start).is_some()),
+            "right" if by_word => moved(self.next_word_end().map(|end| self.cursor = end).is_some()),
+            "backspace" => edited(self.backspace()),
+            "delete" => edited(self.delete()),
+            "left" => moved(self.move_left()),
+            "right" => moved(self.move_right()),
+            "home" => moved(std::mem::replace(&mut self.cursor, 0) != 0),
+            "end" => {
+                let end = self.text.len();
+                moved(std::mem::replace(&mut self.cursor, end) != end)
+            }
+            _ => InputChange::Ignored,
+        }
+    }
+
+    fn byte_offset(&self, utf16_offset: usize) -> usize {
+        let mut utf16_count = 0;
+        for (idx, ch) in self.text.char_indices() {
+            if utf16_count >= utf16_offset {
+                return idx;
+            }
+            utf16_count += ch.len_utf16();
+        }
+        self.text.len()
+    }
+
+    fn utf16_offset(&self, byte_offset: usize) -> usize {
+        self.text[..byte_offset].encode_utf16().count()
+    }
+
+    fn byte_range(&self, range_utf16: Range<usize>) -> Range<usize> {
+        self.byte_offset(range_utf16.start)..self.byte_offset(range_utf16.end)
+    }
+
+    fn utf16_range(&self, range: Range<usize>) -> Range<usize> {
+        self.utf16_offset(range.start)..self.utf16_offset(range.end)
+    }
+
+    /// The range an input handler edit applies to when the platform doesn't name one: the
+    /// composition if there is one, otherwise the cursor.
+    fn edit_range(&self, range_utf16: Option<Range<usize>>) -> Range<usize> {
+        range_utf16
+            .map(|range| self.byte_range(range))
+            .or_else(|| self.marked.clone())
+            .unwrap_or(self.cursor..self.cursor)
+    }
+
+    pub fn text_for_range_utf16(&self, range_utf16: Range<usize>, adjusted: &mut Option<Range<usize>>) -> String {
+        let range = self.byte_range(range_utf16);
+        adjusted.replace(self.utf16_range(range.clone()));
+        self.text[range].to_string()
+    }
+
+    pub fn selection_utf16(&self) -> UTF16Selection {
+        let cursor = self.utf16_offset(self.cursor);
+        UTF16Selection {
+            range: cursor..cursor,
+            reversed: false,
+        }
+    }
+
+    pub fn marked_range_utf16(&self) -> Option<Range<usize>> {
+        self.marked.clone().map(|range| self.utf16_range(range))
+    }
+
+    /// Keeps the composed text as typed and ends the composition.
+    pub fn unmark(&mut self) {
+        self.marked = None;
+    }
+
+    /// Inserts committed text, replacing the composition if there is one. Control characters
+    /// (the platform's rendering of enter or tab) are dropped.
+    pub fn replace_utf16(&mut self, range_utf16: Option<Range<usize>>, text: &str) -> bool {
+        let range = self.edit_range(range_utf16);
+        let text: String = text.chars().filter(|ch| !ch.is_control()).collect();
+        if text.is_empty() && range.is_empty() {
+            return false;
+        }
+        self.text.replace_range(range.clone(), &text);
+        self.cursor = range.start + text.len();
+        self.marked = None;
+        true
+    }
+
+    /// Replaces the composition with the IME's latest preedit text and keeps it marked.
+    /// `selected_utf16` is the IME's cursor within the new text.
+    pub fn replace_and_mark_utf16(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        selected_utf16: Option<Range<usize>>,
+    ) {
+        let range = self.edit_range(range_utf16);
+        self.text.replace_range(range.clone(), text);
+        self.marked = (!text.is_empty()).then(|| range.start..range.start + text.len());
+        self.cursor = match selected_utf16 {
+            Some(selected) => self.byte_offset(self.utf16_offset(range.start) + selected.end),
+            None => range.start + text.len(),
+        };
+    }
+
+    /// The text with a `|` caret at the cursor while focused, or `placeholder` when empty and
+    /// unfocused.
+    pub fn display_text(&self, focused: bool, placeholder: &str) -> String {
+        if !focused {
+            return if self.text.is_empty() { placeholder.to_string() } else { self.text.clone() };
+        }
+        let mut display = self.text.clone();
+        display.insert(self.cursor, '|');
+        display
+    }
+
+    /// The displayed text, with an in-progress composition underlined the way IMEs expect.
+    pub fn render_text(&self, focused: bool, placeholder: &str) -> AnyElement {
+        let Some(marked) = self.marked.clone().filter(|_| focused) else {
+            return self.display_text(focused, placeholder).into_any_element();
+        };
+        div()
+            .flex()
+            .child(self.text[..marked.start].to_string())
+            .child(div().underline().child(self.text[marked.clone()].to_string()))
+            .child(self.text[marked.end..].to_string())
+            .into_any_element()
+    }
+}
+
+/// Registers `view` as the platform input handler over its parent while `focus` is focused, so
+/// typed and IME-composed text reach the view's [`TextInput`]. Place it inside a `relative()`
+/// input box.
+pub fn input_handler<V: EntityInputHandler>(view: Entity<V>, focus: FocusHandle) -> impl IntoElement {
+    canvas(
+        |_, _, _| {},
+        move |bounds, _, window, cx| window.handle_input(&focus, ElementInputHandler::new(bounds, view), cx),
+    )
+    .absolute()
+    .size_full()
+}
+
+/// Implements `EntityInputHandler` for a view by forwarding to its `TextInput` field.
+/// `$edited` runs with the view and its context after committed text changed the input.
+macro_rules! impl_text_input_handler {
+    ($view:ty, $field:ident, $edited:expr) => {
+        impl gpui::EntityInputHandler for $view {
+            fn text_for_range(
+                &mut self,
+                range_utf16: std::ops::Range<usize>,
+                adjusted_range: &mut Option<std::ops::Range<usize>>,
+                _window: &mut gpui::Window,
+                _cx: &mut gpui::Context<Self>,
+            ) -> Option<String> {
+                Some(self.$field.text_for_range_utf16(range_utf16, adjusted_range))
+            }
+
+            fn selected_text_range(
+                &mut self,
+                _ignore_disabled_input: bool,
+                _window: &mut gpui::Window,
+                _cx: &mut gpui::Context<Self>,
+            ) -> Option<gpui::UTF16Selection> {
+                Some(self.$field.selection_utf16())
+            }
+
+            fn marked_text_range(
+                &self,
+                _window: &mut gpui::Window,
+                _cx: &mut gpui::Context<Self>,
+            ) -> Option<std::ops::Range<usize>> {
+                self.$field.marked_range_utf16()
+            }
+
+            fn unmark_text(&mut self, _window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+                self.$field.unmark();
+                cx.notify();
+            }
+
+            fn replace_text_in_range(
+                &mut self,
+                range_utf16: Option<std::ops::Range<usize>>,
+                text: &str,
+                _window: &mut gpui::Window,
+                cx: &mut gpui::Context<Self>,
+            ) {
+                if self.$field.replace_utf16(range_utf16, text) {
+                    ($edited)(self, cx);
+                    cx.notify();
+                }
+            }
+
+            fn replace_and_mark_text_in_range(
+                &mut self,
+                range_utf16: Option<std::ops::Range<usize>>,
+                new_text: &str,
+                new_selected_range_utf16: Option<std::ops::Range<usize>>,
+                _window: &mut gpui::Window,
+                cx: &mut gpui::Context<Self>,
+            ) {
+                self.$field
+                    .replace_and_mark_utf16(range_utf16, new_text, new_selected_range_utf16);
+                cx.notify();
+            }
+
+            /// The IME places its candidate window under the whole box; the text isn't laid out
+            /// per character.
+            fn bounds_for_range(
+                &mut self,
+                _range_utf16: std::ops::Range<usize>,
+                element_bounds: gpui::Bounds<gpui::Pixels>,
+                _window: &mut gpui::Window,
+                _cx: &mut gpui::Context<Self>,
+            ) -> Option<gpui::Bounds<gpui::Pixels>> {
+                Some(element_bounds)
+            }
+
+            fn character_index_for_point(
+                &mut self,
+                _point: gpui::Point<gpui::Pixels>,
+                _window: &mut gpui::Window,
+                _cx: &mut gpui::Context<Self>,
+            ) -> Option<usize> {
+                None
+            }
+        }
+    };
+}
+
+pub(crate) use impl_text_input_handler;
+
+#[cfg(test)]
+mod tests {
+    use super::{InputChange, TextInput};
+    use gpui::Keystroke;
+
+    fn input(text: &str) -> TextInput {
+        let mut input = TextInput::default();
+        input.set_text(text);
+        input
+    }
+
+    fn press(input: &mut TextInput, key: &str) -> InputChange {
+        input.handle_key(&Keystroke::parse(key).unwrap())
+    }
+
+    #[test]
+    fn cursor_steps_over_combining_marks() {
+        // "e" + combining acute accent, then "a"
+        let mut text = input("e\u{301}a");
+        assert!(text.move_left());
+        assert_eq!(text.cursor, 3);
+        assert!(text.move_left());
+        assert_eq!(text.cursor, 0);
+        assert!(!text.move_left());
+        assert!(text.move_right());
+        assert_eq!(text.cursor, 3);
+
+        let mut text = input("e\u{301}a");
+        assert!(text.backspace());
+        assert!(text.backspace());
+        assert_eq!(text.text(), "");
+    }
+
+    #[test]
+    fn cursor_steps_over_emoji_with_modifiers() {
+        // Thumbs up with a skin tone modifier is one grapheme of two scalars
+        let mut text = input("a👍🏽b");
+        assert!(text.move_left());
+        assert!(text.move_left());
+        assert_eq!(text.cursor, 1);
+        assert!(text.delete());
+        assert_eq!(text.text(), "ab");
+
+        let mut family = input("👨‍👩‍👧x");
+        assert_eq!(press(&mut family, "left"), InputChange::Moved);
+        assert_eq!(press(&mut family, "backspace"), InputChange::Edited);
+        assert_eq!(family.text(), "x");
+    }
+
+    #[test]
+    fn words_skip_the_spaces_between_them() {
+        let mut text = input("hello wörld foo");
+        assert_eq!(press(&mut text, "ctrl-left"), InputChange::Moved);
+        assert_eq!(text.cursor, 13);
+        press(&mut text, "ctrl-left");
+        assert_eq!(text.cursor, 6);
+        press(&mut text, "ctrl-right");
+        assert_eq!(text.cursor, 12);
+        press(&mut text, "ctrl-right");
+        assert_eq!(text.cursor, text.text().len());
+        assert_eq!(press(&mut text, "ctrl-right"), InputChange::Ignored);
+
+        let mut text = input("hello wörld ");
+        assert_eq!(press(&mut text, "ctrl-backspace"), InputChange::Edited);
+        assert_eq!(text.text(), "hello ");
+        press(&mut text, "home");
+        assert_eq!(press(&mut text, "home"), InputChange::Ignored);
+        assert_eq!(press(&mut text, "alt-delete"), InputChange::Edited);
+        assert_eq!(text.text(), " ");
+        assert_eq!(press(&mut text, "end"), InputChange::Moved);
+    }
+
+    #[test]
+    fn utf16_offsets_map_to_char_boundaries() {
+        // 'a' is one UTF-16 unit, the emoji a surrogate pair, 'é' one unit in two bytes
+        let text = input("a😀é");
+        assert_eq!(text.byte_offset(0), 0);
+        assert_eq!(text.byte_offset(1), 1);
+        // Halfway through the surrogate pair rounds up to the next char
+        assert_eq!(text.byte_offset(2), 5);
+        assert_eq!(text.byte_offset(3), 5);
+        assert_eq!(text.byte_offset(4), 7);
+        assert_eq!(text.byte_offset(10), 7);
+        assert_eq!(text.utf16_offset(5), 3);
+        assert_eq!(text.utf16_offset(7), 4);
+
+        let mut adjusted = None;
+        assert_eq!(text.text_for_range_utf16(1..3, &mut adjusted), "😀");
+        assert_eq!(adjusted, Some(1..3));
+        assert_eq!(text.text_for_range_utf16(2..4, &mut adjusted), "é");
+        assert_eq!(adjusted, Some(3..4));
+        assert_eq!(text.selection_utf16().range, 4..4);
+    }
+
+    #[test]
+    fn ime_composition_is_marked_until_committed() {
+        let mut text = input("hi ");
+        text.replace_and_mark_utf16(None, "ㅎ", None);
+        assert_eq!(text.marked_range_utf16(), Some(3..4));
+        // Keys belong to the IME while it composes
+        assert_eq!(press(&mut text, "backspace"), InputChange::Ignored);
+        text.replace_and_mark_utf16(None, "하", None);
+        text.replace_and_mark_utf16(None, "한", Some(0..0));
+        assert_eq!(text.text(), "hi 한");
+        assert_eq!(text.cursor, 3);
+        assert!(text.replace_utf16(None, "한"));
+        assert_eq!(text.text(), "hi 한");
+        assert_eq!(text.marked_range_utf16(), None);
+        assert_eq!(text.cursor, text.text().len());
+    }
+
+    #[test]
+    fn composition_after_a_surrogate_pair_uses_utf16_positions() {
+        let mut text = input("😀");
+        text.replace_and_mark_utf16(None, "ab", Some(1..1));
+        assert_eq!(text.marked_range_utf16(), Some(2..4));
+        assert_eq!(text.cursor, 5);
+        assert_eq!(text.selection_utf16().range, 3..3);
+
+        // An explicit range replaces exactly those units
+        text.replace_and_mark_utf16(Some(2..4), "か", None);
+        assert_eq!(text.text(), "😀か");
+        text.unmark();
+        assert_eq!(text.marked_range_utf16(), None);
+        assert_eq!(text.text(), "😀か");
+    }
+
+    #[test]
+    fn committed_control_characters_are_dropped() {
+        let mut text = input("name");
+        assert!(!text.replace_utf16(None, "\n"));
+        assert!(text.replace_utf16(None, "\t!"));
+        assert_eq!(text.text(), "name!");
+    }
+}
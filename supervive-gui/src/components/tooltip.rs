@@ -0,0 +1,61 @@
+use gpui::prelude::*;
+use gpui::*;
+
+/// Hover tooltip with a title and optional explanatory line, shared across the app.
+pub struct Tooltip {
+    title: SharedString,
+    description: Option<SharedString>,
+}
+
+impl Tooltip {
+    /// Builder for `.tooltip(...)` showing a single line of text.
+    pub fn text(title: impl Into<SharedString>) -> impl Fn(&mut Window, &mut App) -> AnyView {
+        let title = title.into();
+        move |_window, cx| {
+            cx.new(|_| Self {
+                title: title.clone(),
+                description: None,
+            })
+            .into()
+        }
+    }
+
+    /// Builder for `.tooltip(...)` showing a title plus a dimmer explanation.
+    pub fn with_description(
+        title: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+    ) -> impl Fn(&mut Window, &mut App) -> AnyView {
+        let title = title.into();
+        let description = description.into();
+        move |_window, cx| {
+            cx.new(|_| Self {
+                title: title.clone(),
+                description: Some(description.clone()),
+            })
+            .into()
+        }
+    }
+}
+
+impl Render for Tooltip {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .max_w(px(280.0))
+            .px_2()
+            .py_1()
+            .bg(rgb(0x11111b))
+            .text_color(rgb(0xcdd6f4))
+            .text_sm()
+            .rounded_md()
+            .border_1()
+            .border_color(rgb(0x45475a))
+            .shadow_md()
+            .child(self.title.clone())
+            .when_some(self.description.clone(), |parent, description| {
+                parent.child(div().text_xs().text_color(rgb(0x9399b2)).child(description))
+            })
+    }
+}
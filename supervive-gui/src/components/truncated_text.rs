@@ -0,0 +1,37 @@
+use crate::components::tooltip::Tooltip;
+use gpui::prelude::*;
+use gpui::*;
+
+/// A single line of text that ends in "…" when it doesn't fit, instead of wrapping or pushing its
+/// row wider, with the full text in a tooltip. Give it room with `flex_1` or a width; it can
+/// shrink below its text inside flex rows.
+#[derive(IntoElement)]
+pub struct TruncatedText {
+    base: Stateful<Div>,
+    text: SharedString,
+}
+
+impl TruncatedText {
+    pub fn new(id: impl Into<ElementId>, text: impl Into<SharedString>) -> Self {
+        Self {
+            base: div().id(id),
+            text: text.into(),
+        }
+    }
+}
+
+impl Styled for TruncatedText {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for TruncatedText {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        self.base
+            .min_w_0()
+            .truncate()
+            .tooltip(Tooltip::text(self.text.clone()))
+            .child(self.text)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::heroes::HeroRole;
+use crate::matchups::own_row;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Results with one particular mix of ally roles.
+#[derive(Debug, Clone)]
+pub struct Composition {
+    /// Ally roles, sorted, e.g. "Frontliner + Protector"; heroes without a known role are "Unknown"
+    pub allies: String,
+    pub games: usize,
+    pub wins: usize,
+}
+
+impl Composition {
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.games as f64
+    }
+}
+
+/// Ally role compositions across analyzed matches with at least `min_games` games, best win
+/// rate first.
+pub fn ally_compositions<'a>(
+    player_id: &str,
+    matches: impl Iterator<Item = &'a Vec<Value>>,
+    min_games: usize,
+) -> Vec<Composition> {
+    let mut by_comp: HashMap<String, Composition> = HashMap::new();
+    for rows in matches {
+        let Some(own) = own_row(player_id, rows) else {
+            continue;
+        };
+        let mut roles: Vec<&str> = rows
+            .iter()
+            .filter(|row| row["team_id"] == own["team_id"] && !std::ptr::eq(*row, own))
+            .map(|row| {
+                row["hero"]["name"]
+                    .as_str()
+                    .and_then(HeroRole::for_hero)
+                    .map_or("Unknown", |role| role.label())
+            })
+            .collect();
+        if roles.is_empty() {
+            continue;
+        }
+        roles.sort();
+        let allies = roles.join(" + ");
+        let comp = by_comp.entry(allies.clone()).or_insert_with(|| Composition { allies, games: 0, wins: 0 });
+        comp.games += 1;
+        comp.wins += usize::from(own["placement"].as_i64() == Some(1));
+    }
+    let mut comps: Vec<Composition> = by_comp.into_values().filter(|comp| comp.games >= min_games).collect();
+    comps.sort_by(|a, b| b.win_rate().total_cmp(&a.win_rate()).then(b.games.cmp(&a.games)));
+    comps
+}
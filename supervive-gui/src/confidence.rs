@@ -0,0 +1,72 @@
+use crate::format;
+
+/// z-score of a two-sided 95% interval.
+const Z_95: f64 = 1.96;
+
+/// Wilson score interval around `successes / trials` at 95% confidence, as fractions. Unlike
+/// the plain ± margin it stays within 0–100% and stays wide for tiny samples.
+pub fn wilson_interval(successes: usize, trials: usize) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = Z_95 * Z_95;
+    let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let margin = Z_95 * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / (1.0 + z2 / n);
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+/// A percentage with its interval, e.g. "40% (17–69%)".
+pub fn rate_with_interval(successes: usize, trials: usize) -> String {
+    let (low, high) = wilson_interval(successes, trials);
+    let percent = |fraction: f64| format::decimal(fraction * 100.0, 0);
+    format!(
+        "{}% ({}–{}%)",
+        percent(successes as f64 / trials.max(1) as f64),
+        percent(low),
+        percent(high)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-4, "{} is not close to {}", actual, expected);
+    }
+
+    #[test]
+    fn no_trials_is_the_whole_range() {
+        assert_eq!(wilson_interval(0, 0), (0.0, 1.0));
+    }
+
+    #[test]
+    fn all_or_nothing_stays_within_bounds() {
+        let (low, high) = wilson_interval(0, 10);
+        assert_eq!(low, 0.0);
+        assert_close(high, 0.2775);
+
+        let (low, high) = wilson_interval(10, 10);
+        assert_close(low, 0.7225);
+        assert_eq!(high, 1.0);
+    }
+
+    #[test]
+    fn matches_the_reference_interval() {
+        // 4 of 10 at 95%: 16.8%–68.7%
+        let (low, high) = wilson_interval(4, 10);
+        assert_close(low, 0.1682);
+        assert_close(high, 0.6873);
+        assert_eq!(rate_with_interval(4, 10), "40% (17–69%)");
+    }
+
+    #[test]
+    fn interval_narrows_with_more_trials() {
+        let (small_low, small_high) = wilson_interval(5, 10);
+        let (large_low, large_high) = wilson_interval(500, 1000);
+        assert!(large_high - large_low < small_high - small_low);
+        assert!(large_low < 0.5 && large_high > 0.5);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::demo;
+use gpui::App;
+use reqwest::blocking::Client;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Probed to tell whether op.gg can be reached at all.
+const PROBE_URL: &str = "https://op.gg/supervive/";
+/// Time between probes while online.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+/// Time between probes while offline, so the app recovers soon after the network returns.
+const OFFLINE_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Set while op.gg is unreachable; the service then answers from its cache only.
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// A HEAD request with a short timeout. Any HTTP answer, even an error status, means online.
+fn probe(client: &Client) -> bool {
+    client.head(PROBE_URL).send().is_ok()
+}
+
+/// Records the probe result; returns whether the state changed.
+fn set_offline(offline: bool) -> bool {
+    let was_offline = OFFLINE.swap(offline, Ordering::Relaxed);
+    if was_offline != offline {
+        if offline {
+            tracing::warn!("op.gg is unreachable, switching to cached data");
+        } else {
+            tracing::info!("Connection to op.gg restored");
+        }
+    }
+    was_offline != offline
+}
+
+/// Probes once on a background thread and re-renders the windows if the state flipped. Demo
+/// mode never probes.
+pub fn check_now(cx: &mut App) {
+    if demo::is_enabled() {
+        return;
+    }
+    cx.spawn(async move |cx| {
+        let changed = cx
+            .background_executor()
+            .spawn(async move {
+                let Ok(client) = Client::builder().timeout(PROBE_TIMEOUT).build() else {
+                    return false;
+                };
+                set_offline(!probe(&client))
+            })
+            .await;
+        if changed {
+            cx.update(|cx| cx.refresh_windows()).ok();
+        }
+    })
+    .detach();
+}
+
+/// Starts the periodic reachability check.
+pub fn start(cx: &mut App) {
+    cx.spawn(async move |cx| loop {
+        if cx.update(check_now).is_err() {
+            break;
+        }
+        let interval = if is_offline() { OFFLINE_CHECK_INTERVAL } else { CHECK_INTERVAL };
+        cx.background_executor().timer(interval).await;
+    })
+    .detach();
+}
@@ -0,0 +1,42 @@
+/// Quotes a field when it contains a separator, quote or line break.
+pub fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One CSV line from the given fields, without the line break.
+pub fn row<S: AsRef<str>>(fields: impl IntoIterator<Item = S>) -> String {
+    fields.into_iter().map(|field| escape(field.as_ref())).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_table() {
+        let cases = [
+            ("plain", "plain"),
+            ("", ""),
+            ("with space", "with space"),
+            ("a,b", "\"a,b\""),
+            ("say \"hi\"", "\"say \"\"hi\"\"\""),
+            ("two\nlines", "\"two\nlines\""),
+            ("carriage\rreturn", "\"carriage\rreturn\""),
+            ("Ünïcødé#1", "Ünïcødé#1"),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(escape(field), expected, "{:?}", field);
+        }
+    }
+
+    #[test]
+    fn row_joins_escaped_fields() {
+        assert_eq!(row(["a", "b,c", "d\"e"]), "a,\"b,c\",\"d\"\"e\"");
+        assert_eq!(row(Vec::<String>::new()), "");
+        assert_eq!(row([String::new(), String::new()]), ",");
+    }
+}
@@ -0,0 +1,154 @@
+use serde_json::Value;
+
+/// Damage before a death that its recap lists.
+pub const RECAP_SECONDS: f64 = 5.0;
+
+#[derive(Debug, Clone)]
+pub struct DamageHit {
+    /// Seconds before the death
+    pub before: f64,
+    pub attacker: Option<String>,
+    pub source: Option<String>,
+    pub amount: f64,
+}
+
+/// How one death came about.
+#[derive(Debug, Clone)]
+pub struct DeathRecap {
+    /// Seconds into the match
+    pub time: f64,
+    pub killer: Option<String>,
+    pub killer_hero: Option<String>,
+    /// Ability or weapon of the final blow
+    pub source: Option<String>,
+    /// Damage taken in the last `RECAP_SECONDS`, oldest first
+    pub hits: Vec<DamageHit>,
+}
+
+impl DeathRecap {
+    pub fn total_damage(&self) -> f64 {
+        self.hits.iter().map(|hit| hit.amount).sum()
+    }
+}
+
+/// Scoreboard name of the player with `player_id` among `rows`.
+fn player_name(rows: &[Value], player_id: &str) -> Option<String> {
+    let player_id = player_id.replace('-', "");
+    rows.iter()
+        .find(|row| row["player_id"].as_str().is_some_and(|id| id.replace('-', "").eq_ignore_ascii_case(&player_id)))
+        .and_then(|row| row["player"]["unique_display_name"].as_str())
+        .map(str::to_string)
+}
+
+/// A name given directly, or a `player_id` resolved against the match's other rows.
+fn attacker_name(value: &Value, rows: &[Value]) -> Option<String> {
+    value["name"]
+        .as_str()
+        .map(str::to_string)
+        .or_else(|| player_name(rows, value["player_id"].as_str()?))
+}
+
+/// Death recaps from a match row's optional `deaths` timeline
+/// (`[{time, killer: {player_id | name, hero}, source, damage: [{time, attacker, source, amount}]}]`).
+/// Empty when the payload doesn't include it.
+pub fn death_recaps(row: &Value, rows: &[Value]) -> Vec<DeathRecap> {
+    let mut recaps: Vec<DeathRecap> = row["deaths"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|death| {
+            let time = death["time"].as_f64()?;
+            let mut hits: Vec<DamageHit> = death["damage"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|hit| {
+                    let before = time - hit["time"].as_f64()?;
+                    (0.0..=RECAP_SECONDS).contains(&before).then(|| DamageHit {
+                        before,
+                        attacker: attacker_name(&hit["attacker"], rows),
+                        source: hit["source"].as_str().map(str::to_string),
+                        amount: hit["amount"].as_f64().unwrap_or(0.0),
+                    })
+                })
+                .collect();
+            hits.sort_by(|a, b| b.before.total_cmp(&a.before));
+            Some(DeathRecap {
+                time,
+                killer: attacker_name(&death["killer"], rows),
+                killer_hero: death["killer"]["hero"].as_str().map(str::to_string),
+                source: death["source"].as_str().map(str::to_string),
+                hits,
+            })
+        })
+        .collect();
+    recaps.sort_by(|a, b| a.time.total_cmp(&b.time));
+    recaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::demo::DemoData;
+    use serde_json::json;
+
+    #[test]
+    fn recaps_resolve_attackers_and_keep_recent_hits() {
+        let rows = vec![
+            json!({ "player_id": "aaaa-1111", "player": { "unique_display_name": "Victim#1" } }),
+            json!({ "player_id": "bbbb2222", "player": { "unique_display_name": "Killer#2" } }),
+        ];
+        let row = json!({
+            "deaths": [
+                {
+                    "time": 300.0,
+                    "killer": { "player_id": "BBBB-2222", "hero": "Joule" },
+                    "source": "Ability",
+                    "damage": [
+                        { "time": 290.0, "attacker": { "name": "Storm" }, "amount": 50.0 },
+                        { "time": 297.0, "attacker": { "name": "Storm" }, "amount": 40.0 },
+                        { "time": 299.5, "attacker": { "player_id": "bbbb2222" }, "source": "Weapon", "amount": 200.0 }
+                    ]
+                },
+                { "time": 120.0, "killer": { "name": "Creep" } },
+                { "killer": { "name": "No time" } }
+            ]
+        });
+
+        let recaps = death_recaps(&row, &rows);
+        assert_eq!(recaps.len(), 2);
+        assert_eq!(recaps[0].time, 120.0);
+        assert_eq!(recaps[0].killer.as_deref(), Some("Creep"));
+        assert!(recaps[0].hits.is_empty());
+
+        let last = &recaps[1];
+        assert_eq!(last.killer.as_deref(), Some("Killer#2"));
+        assert_eq!(last.killer_hero.as_deref(), Some("Joule"));
+        assert_eq!(last.source.as_deref(), Some("Ability"));
+        // The hit 10 s out falls outside the recap; the rest are listed oldest first
+        assert_eq!(last.hits.len(), 2);
+        assert_eq!(last.hits[0].attacker.as_deref(), Some("Storm"));
+        assert_eq!(last.hits[1].attacker.as_deref(), Some("Killer#2"));
+        assert_eq!(last.total_damage(), 240.0);
+    }
+
+    #[test]
+    fn rows_without_a_timeline_have_no_recaps() {
+        assert!(death_recaps(&json!({ "player_id": "aaaa" }), &[]).is_empty());
+        assert!(death_recaps(&json!({ "deaths": "soon" }), &[]).is_empty());
+    }
+
+    #[test]
+    fn demo_fixture_deaths_parse() {
+        let Some(Value::Array(rows)) = DemoData::load().get_match("4c9f92e9b8c828bcd59658637e6d5d9d") else {
+            panic!("demo match with a death timeline is missing");
+        };
+        let eliminated: Vec<&Value> = rows.iter().filter(|row| row["placement"].as_i64() != Some(1)).collect();
+        assert!(!eliminated.is_empty());
+        for row in eliminated {
+            let recaps = death_recaps(row, &rows);
+            assert!(!recaps.is_empty());
+            assert!(recaps.iter().all(|recap| recap.killer.is_some() && !recap.hits.is_empty()));
+        }
+    }
+}
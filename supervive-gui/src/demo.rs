@@ -0,0 +1,120 @@
+use crate::api::SuperviveService;
+use crate::state::AppState;
+use crate::views::{GoHome, SearchPlayer};
+use gpui::{App, Window};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The sample account with a full match history; searched for when demo mode starts.
+pub const DEMO_PLAYER: &str = "DemoPlayer#0001";
+/// History rows per page, small enough that the sample history spans several pages.
+const PAGE_SIZE: usize = 10;
+
+/// Set while the shared service answers from the sample data. Kept outside the service mutex
+/// so views can check it while a background request holds the lock.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records whether the app started in or switched to demo mode.
+pub fn mark_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Sample matches bundled with the app, served instead of op.gg responses in demo mode.
+#[derive(Deserialize)]
+pub struct DemoData {
+    /// Participant rows keyed by match id
+    matches: HashMap<String, Vec<Value>>,
+}
+
+impl DemoData {
+    pub fn load() -> Self {
+        serde_json::from_str(include_str!("../assets/demo/fixtures.json")).expect("Bundled demo data is valid JSON")
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &Value> {
+        self.matches.values().flatten()
+    }
+
+    /// Search results in the shape of `/api/players/search`: every sample player whose name
+    /// contains `query`.
+    pub fn search(&self, query: &str) -> Value {
+        let query = query.trim().to_lowercase();
+        let mut players: HashMap<&str, Value> = HashMap::new();
+        for row in self.rows() {
+            let name = row["player"]["unique_display_name"].as_str().unwrap_or("");
+            let Some(id) = row["player_id"].as_str() else {
+                continue;
+            };
+            if name.to_lowercase().contains(&query) {
+                players.entry(id).or_insert_with(|| {
+                    json!({
+                        "userId": id,
+                        "platform": "steam",
+                        "uniqueDisplayName": name,
+                        "displayName": row["player"]["display_name"],
+                    })
+                });
+            }
+        }
+        let mut players: Vec<Value> = players.into_values().collect();
+        players.sort_by(|a, b| a["uniqueDisplayName"].as_str().cmp(&b["uniqueDisplayName"].as_str()));
+        Value::Array(players)
+    }
+
+    /// One page of a player's history, most recent first, in the shape of `/api/players/{id}/matches`.
+    pub fn player_matches(&self, player_id: &str, page: i32) -> Value {
+        let mut rows: Vec<&Value> = self
+            .rows()
+            .filter(|row| row["player_id"].as_str() == Some(player_id))
+            .collect();
+        rows.sort_by(|a, b| b["match_start"].as_str().cmp(&a["match_start"].as_str()));
+        let last_page = rows.len().div_ceil(PAGE_SIZE).max(1);
+        let page_rows: Vec<&Value> = rows
+            .into_iter()
+            .skip((page.max(1) as usize - 1) * PAGE_SIZE)
+            .take(PAGE_SIZE)
+            .collect();
+        json!({
+            "data": page_rows,
+            "meta": { "current_page": page, "last_page": last_page },
+        })
+    }
+
+    pub fn get_match(&self, match_id: &str) -> Option<Value> {
+        self.matches.get(match_id).map(|rows| Value::Array(rows.clone()))
+    }
+}
+
+/// Swaps the shared service and the user's stores for the sample data or back, then shows the
+/// sample account or the home page.
+pub fn set_enabled(enabled: bool, window: &mut Window, cx: &mut App) {
+    let service = if enabled {
+        SuperviveService::demo()
+    } else {
+        SuperviveService::new()
+    };
+    match service {
+        Ok(service) => {
+            let app_state = cx.global::<AppState>();
+            *app_state.service.lock().unwrap() = service;
+            app_state.switch_stores(enabled);
+            mark_enabled(enabled);
+        }
+        Err(err) => {
+            tracing::error!(error = %err, "Failed to switch demo mode");
+            return;
+        }
+    }
+    if enabled {
+        window.dispatch_action(Box::new(SearchPlayer { query: DEMO_PLAYER.to_string() }), cx);
+    } else {
+        window.dispatch_action(Box::new(GoHome), cx);
+    }
+    cx.refresh_windows();
+}
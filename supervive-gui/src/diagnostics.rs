@@ -0,0 +1,181 @@
+use crate::logging;
+use crate::settings::Settings;
+use anyhow::Result;
+use chrono::Local;
+use serde_json::Value;
+use std::backtrace::Backtrace;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use zip::write::FileOptions;
+
+/// Parts of settings keys whose values may carry credentials (webhook URLs embed their token)
+/// or the OS user name (file paths). Matched anywhere in the key, so `telemetry_endpoint` and
+/// `backup_dir` are caught too.
+const SENSITIVE_KEYS: [&str; 9] = [
+    "url", "endpoint", "token", "secret", "password", "api_key", "user", "path", "dir",
+];
+
+fn crash_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("supervive_gui")
+        .join("crashes")
+}
+
+fn environment_summary() -> String {
+    format!(
+        "supervive-gui {}\nOS: {} ({})\nTime: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        Local::now().to_rfc3339(),
+    )
+}
+
+/// Writes a crash report (panic message, backtrace, version, OS and recent log lines) to the
+/// data dir before the default hook prints the panic.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = format!(
+            "{}\nPanic: {}\n\nBacktrace:\n{}\n\nRecent log:\n{}\n",
+            environment_summary(),
+            info,
+            Backtrace::force_capture(),
+            logging::recent_text(),
+        );
+        let dir = crash_dir();
+        let path = dir.join(format!("crash-{}.txt", Local::now().format("%Y%m%d-%H%M%S")));
+        if fs::create_dir_all(&dir).and_then(|_| fs::write(&path, report)).is_ok() {
+            eprintln!("Crash report written to {}", path.display());
+        }
+        default_hook(info);
+    }));
+}
+
+fn scrub(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_ascii_lowercase();
+                if value.is_string() && SENSITIVE_KEYS.iter().any(|part| key.contains(part)) {
+                    *value = Value::String("[redacted]".to_string());
+                } else {
+                    scrub(value);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(scrub),
+        _ => {}
+    }
+}
+
+/// Replaces the path and query of every http(s) URL in `text`, keeping the host so a failing
+/// service can still be told apart. Webhook and sync URLs carry tokens and account names.
+fn redact_urls(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("http://").into_iter().chain(rest.find("https://")).min() {
+        let (before, url) = rest.split_at(start);
+        out.push_str(before);
+        let end = url
+            .find(|ch: char| ch.is_whitespace() || matches!(ch, '"' | '\'' | ')' | '>' | ']'))
+            .unwrap_or(url.len());
+        let scheme_end = url.find("://").unwrap_or(0) + 3;
+        let host_end = url[scheme_end..end]
+            .find(['/', '?', '#'])
+            .map_or(end, |idx| scheme_end + idx);
+        out.push_str(&url[..host_end]);
+        if host_end < end {
+            out.push_str("/[redacted]");
+        }
+        rest = &url[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Zips the environment summary, log files, scrubbed settings and any crash reports (URLs
+/// redacted in both) into
+/// the downloads folder for attaching to bug reports. Returns the path of the archive.
+pub fn export_bundle(settings: &Settings) -> Result<PathBuf> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let path = dir.join(format!(
+        "supervive-diagnostics-{}.zip",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let mut settings = serde_json::to_value(settings)?;
+    scrub(&mut settings);
+
+    let mut zip = zip::ZipWriter::new(fs::File::create(&path)?);
+    let options = FileOptions::default();
+    zip.start_file("environment.txt", options)?;
+    zip.write_all(environment_summary().as_bytes())?;
+    zip.start_file("settings.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(&settings)?.as_bytes())?;
+
+    for (folder, dir) in [("logs", logging::log_dir()), ("crashes", crash_dir())] {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            zip.start_file(format!("{}/{}", folder, name), options)?;
+            let contents = fs::read(entry.path())?;
+            zip.write_all(redact_urls(&String::from_utf8_lossy(&contents)).as_bytes())?;
+        }
+    }
+    zip.finish()?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn redact_urls_keeps_only_the_host() {
+        let cases = [
+            (
+                "WARN Webhook request failed error=error sending request for url (https://discord.com/api/webhooks/123/abc-token)",
+                "WARN Webhook request failed error=error sending request for url (https://discord.com/[redacted])",
+            ),
+            ("GET http://example.com?key=secret done", "GET http://example.com/[redacted] done"),
+            ("probe https://op.gg/ ok", "probe https://op.gg/[redacted] ok"),
+            ("host only https://op.gg", "host only https://op.gg"),
+            ("no links here", "no links here"),
+            (
+                "two: https://a.example/x and http://b.example/y",
+                "two: https://a.example/[redacted] and http://b.example/[redacted]",
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(redact_urls(input), expected);
+        }
+    }
+
+    #[test]
+    fn scrub_matches_key_parts() {
+        let mut settings = json!({
+            "telemetry_endpoint": "https://stats.example",
+            "backup_dir": "/home/me/backups",
+            "sync": { "username": "me", "password": "hunter2" },
+            "webhooks": [{ "url": "https://discord.com/api/webhooks/1/t" }],
+            "font_size": 16,
+            "palette": "Standard",
+        });
+        scrub(&mut settings);
+        assert_eq!(settings["telemetry_endpoint"], "[redacted]");
+        assert_eq!(settings["backup_dir"], "[redacted]");
+        assert_eq!(settings["sync"]["username"], "[redacted]");
+        assert_eq!(settings["sync"]["password"], "[redacted]");
+        assert_eq!(settings["webhooks"][0]["url"], "[redacted]");
+        assert_eq!(settings["font_size"], 16);
+        assert_eq!(settings["palette"], "Standard");
+    }
+}
@@ -0,0 +1,214 @@
+//! Numbers, durations and dates as shown to the player, in the system locale's separators and
+//! date order. Machine-readable output (CSV and JSON exports, file names, logs) keeps plain
+//! formatting so it parses the same everywhere.
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy)]
+enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Locale {
+    thousands: char,
+    decimal: char,
+    date_order: DateOrder,
+    date_separator: char,
+}
+
+impl Default for Locale {
+    /// No locale set (C/POSIX): English separators and ISO dates.
+    fn default() -> Self {
+        Self {
+            thousands: ',',
+            decimal: '.',
+            date_order: DateOrder::YearMonthDay,
+            date_separator: '-',
+        }
+    }
+}
+
+impl Locale {
+    /// Conventions for a POSIX locale name such as `de_DE.UTF-8` or `en_US`.
+    fn from_tag(tag: &str) -> Self {
+        let tag = tag.split(['.', '@']).next().unwrap_or("");
+        let (language, region) = tag.split_once(['_', '-']).unwrap_or((tag, ""));
+        let (thousands, decimal) = match language {
+            "de" | "nl" | "da" | "it" | "es" | "pt" | "tr" | "id" | "el" => ('.', ','),
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "uk" | "hu" => ('\u{a0}', ','),
+            "en" | "ja" | "zh" | "ko" | "th" => (',', '.'),
+            _ => return Self::default(),
+        };
+        let (date_order, date_separator) = match (language, region) {
+            ("en", "US") | ("en", "PH") => (DateOrder::MonthDayYear, '/'),
+            ("ja", _) | ("zh", _) => (DateOrder::YearMonthDay, '/'),
+            ("ko", _) | ("hu", _) => (DateOrder::YearMonthDay, '.'),
+            ("sv", _) => (DateOrder::YearMonthDay, '-'),
+            ("en", _) | ("fr", _) | ("es", _) | ("pt", _) | ("it", _) | ("el", _) | ("id", _) | ("th", _) => {
+                (DateOrder::DayMonthYear, '/')
+            }
+            ("nl", _) => (DateOrder::DayMonthYear, '-'),
+            _ => (DateOrder::DayMonthYear, '.'),
+        };
+        Self {
+            thousands,
+            decimal,
+            date_order,
+            date_separator,
+        }
+    }
+
+    /// The user's regional settings, which Windows keeps apart from any locale variable.
+    #[cfg(windows)]
+    fn from_registry() -> Option<Self> {
+        use winreg::enums::HKEY_CURRENT_USER;
+        use winreg::RegKey;
+        let key = RegKey::predef(HKEY_CURRENT_USER).open_subkey("Control Panel\\International").ok()?;
+        let first_char = |name: &str| key.get_value::<String, _>(name).ok()?.chars().next();
+        let defaults = Self::default();
+        // e.g. "dd/MM/yyyy" or "yyyy-MM-dd"; the first field and separator decide the rest
+        let short_date: String = key.get_value("sShortDate").ok()?;
+        let date_order = match short_date.chars().find(|ch| matches!(ch, 'd' | 'M' | 'y')) {
+            Some('d') => DateOrder::DayMonthYear,
+            Some('M') => DateOrder::MonthDayYear,
+            _ => DateOrder::YearMonthDay,
+        };
+        Some(Self {
+            thousands: first_char("sThousand").unwrap_or(defaults.thousands),
+            decimal: first_char("sDecimal").unwrap_or(defaults.decimal),
+            date_order,
+            date_separator: short_date
+                .chars()
+                .find(|ch| !ch.is_alphanumeric())
+                .unwrap_or(defaults.date_separator),
+        })
+    }
+
+    fn detect() -> Self {
+        #[cfg(windows)]
+        if let Some(locale) = Self::from_registry() {
+            return locale;
+        }
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .map(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+}
+
+fn locale() -> &'static Locale {
+    static LOCALE: OnceLock<Locale> = OnceLock::new();
+    LOCALE.get_or_init(Locale::detect)
+}
+
+/// Inserts the locale's thousands separator into a run of ASCII digits.
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// A whole number with thousands separators, e.g. "12,345".
+pub fn integer(value: i64) -> String {
+    let digits = group_digits(&value.unsigned_abs().to_string(), locale().thousands);
+    if value < 0 {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
+
+/// A number with `places` decimals, thousands separators and the locale's decimal mark.
+pub fn decimal(value: f64, places: usize) -> String {
+    if !value.is_finite() {
+        return if value.is_nan() { "—".to_string() } else { "∞".to_string() };
+    }
+    let locale = locale();
+    let plain = format!("{:.*}", places, value.abs());
+    let (whole, fraction) = plain.split_once('.').unwrap_or((&plain, ""));
+    let mut text = group_digits(whole, locale.thousands);
+    if !fraction.is_empty() {
+        text.push(locale.decimal);
+        text.push_str(fraction);
+    }
+    // Rounding can leave "-0"; only show the sign when something non-zero remains
+    if value < 0.0 && plain.chars().any(|ch| ch.is_ascii_digit() && ch != '0') {
+        text.insert(0, '-');
+    }
+    text
+}
+
+/// A large number shortened for tight spots: "950", "12.4k", "128k", "1.3M".
+pub fn compact(value: f64) -> String {
+    let magnitude = value.abs();
+    let (scaled, suffix) = if magnitude >= 1_000_000.0 {
+        (value / 1_000_000.0, "M")
+    } else if magnitude >= 1_000.0 {
+        (value / 1_000.0, "k")
+    } else {
+        return integer(value.round() as i64);
+    };
+    let places = if scaled.abs() < 100.0 { 1 } else { 0 };
+    let mut text = decimal(scaled, places);
+    // "12.0k" reads better as "12k"
+    if let Some(trimmed) = text.strip_suffix(&format!("{}0", locale().decimal)) {
+        text = trimmed.to_string();
+    }
+    text + suffix
+}
+
+/// A time span in seconds as "m:ss", or "h:mm:ss" from an hour up.
+pub fn duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}{}:{:02}:{:02}", sign, hours, minutes, seconds)
+    } else {
+        format!("{}{}:{:02}", sign, minutes, seconds)
+    }
+}
+
+/// A calendar date in the locale's numeric order, e.g. "16/10/2026" or "2026-10-16".
+pub fn date(date: NaiveDate) -> String {
+    let locale = locale();
+    let (day, month, year) = (date.day(), date.month(), date.year());
+    let sep = locale.date_separator;
+    match locale.date_order {
+        DateOrder::DayMonthYear => format!("{:02}{sep}{:02}{sep}{}", day, month, year),
+        DateOrder::MonthDayYear => format!("{:02}{sep}{:02}{sep}{}", month, day, year),
+        DateOrder::YearMonthDay => format!("{}{sep}{:02}{sep}{:02}", year, month, day),
+    }
+}
+
+/// A point in time as a local date and 24-hour time.
+pub fn date_time<Tz: TimeZone>(time: &DateTime<Tz>) -> String {
+    let local = time.with_timezone(&Local);
+    format!("{} {}", date(local.date_naive()), local.format("%H:%M"))
+}
+
+/// A file size in binary units: "840 B", "12.4 KB", "1.3 GB".
+pub fn bytes(value: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if value < 1024 {
+        return format!("{} B", integer(value as i64));
+    }
+    let mut scaled = value as f64 / 1024.0;
+    let mut unit = 0;
+    while scaled >= 1024.0 && unit < UNITS.len() - 1 {
+        scaled /= 1024.0;
+        unit += 1;
+    }
+    format!("{} {}", decimal(scaled, if scaled < 100.0 { 1 } else { 0 }), UNITS[unit])
+}
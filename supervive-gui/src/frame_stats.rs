@@ -0,0 +1,43 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Frames kept for the averages on the frame-time overlay.
+const SAMPLES: usize = 120;
+
+/// Times between the window's recent frames, fed by the root view while the overlay is shown.
+#[derive(Default)]
+pub struct FrameStats {
+    last_frame: Option<Instant>,
+    intervals: VecDeque<Duration>,
+}
+
+impl FrameStats {
+    pub fn record_frame(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.last_frame.replace(now) {
+            if self.intervals.len() == SAMPLES {
+                self.intervals.pop_front();
+            }
+            self.intervals.push_back(now - last);
+        }
+    }
+
+    /// Forgets the samples, so the idle time while the overlay was hidden isn't counted.
+    pub fn reset(&mut self) {
+        self.last_frame = None;
+        self.intervals.clear();
+    }
+
+    pub fn last(&self) -> Option<Duration> {
+        self.intervals.back().copied()
+    }
+
+    pub fn average(&self) -> Option<Duration> {
+        let count = self.intervals.len() as u32;
+        (count > 0).then(|| self.intervals.iter().sum::<Duration>() / count)
+    }
+
+    pub fn worst(&self) -> Option<Duration> {
+        self.intervals.iter().max().copied()
+    }
+}
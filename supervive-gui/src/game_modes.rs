@@ -0,0 +1,87 @@
+use crate::history_totals::HistoryTotals;
+use serde_json::Value;
+
+/// Stat keys only arena matches report.
+const ROUND_STATS: [&str; 2] = ["RoundsWon", "RoundsPlayed"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameMode {
+    BattleRoyale,
+    Arena,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 2] = [GameMode::BattleRoyale, GameMode::Arena];
+
+    /// The mode a match row was played in. Rows name it under `game_mode` or `queue_id` when
+    /// op.gg sends it; otherwise round stats give arena away and anything else is battle royale.
+    pub fn of(row: &Value) -> Self {
+        let named = ["game_mode", "queue_id", "mode"]
+            .iter()
+            .filter_map(|key| row[*key].as_str())
+            .any(|mode| mode.to_ascii_lowercase().contains("arena"));
+        if named || ROUND_STATS.iter().any(|key| !row["stats"][*key].is_null()) {
+            GameMode::Arena
+        } else {
+            GameMode::BattleRoyale
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::BattleRoyale => "Battle royale",
+            GameMode::Arena => "Arena",
+        }
+    }
+
+    /// The summary cards worth showing for the mode, in order.
+    pub fn preset(&self) -> &'static [SummaryStat] {
+        match self {
+            GameMode::BattleRoyale => &[SummaryStat::KdRatio, SummaryStat::AvgPlacement, SummaryStat::Games],
+            GameMode::Arena => &[SummaryStat::KdRatio, SummaryStat::RoundWins, SummaryStat::MatchWins, SummaryStat::Games],
+        }
+    }
+}
+
+/// A card of the profile summary panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryStat {
+    KdRatio,
+    AvgPlacement,
+    Games,
+    RoundWins,
+    MatchWins,
+}
+
+/// Totals over the games of one mode.
+#[derive(Debug, Clone, Default)]
+pub struct ModeTotals {
+    pub totals: HistoryTotals,
+    pub round_wins: i64,
+    pub rounds_played: i64,
+    pub match_wins: usize,
+}
+
+impl ModeTotals {
+    pub fn from_rows(mode: GameMode, rows: &[Value]) -> Self {
+        let rows: Vec<Value> = rows.iter().filter(|row| GameMode::of(row) == mode).cloned().collect();
+        let stat = |key: &str| -> i64 { rows.iter().filter_map(|row| row["stats"][key].as_i64()).sum() };
+        Self {
+            totals: HistoryTotals::from_rows(&rows),
+            round_wins: stat("RoundsWon"),
+            rounds_played: stat("RoundsPlayed"),
+            match_wins: rows.iter().filter(|row| row["placement"].as_i64() == Some(1)).count(),
+        }
+    }
+}
+
+/// Games per mode, most played first, leaving out modes with no games.
+pub fn mode_counts(rows: &[Value]) -> Vec<(GameMode, usize)> {
+    let mut counts: Vec<(GameMode, usize)> = GameMode::ALL
+        .into_iter()
+        .map(|mode| (mode, rows.iter().filter(|row| GameMode::of(row) == mode).count()))
+        .filter(|(_, games)| *games > 0)
+        .collect();
+    counts.sort_by_key(|(_, games)| std::cmp::Reverse(*games));
+    counts
+}
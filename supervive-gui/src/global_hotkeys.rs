@@ -0,0 +1,144 @@
+use crate::overlay;
+use crate::state::AppState;
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use gpui::App;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How often pressed hotkeys are picked up from the OS event channel.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A command reachable through an OS-wide hotkey, even when no app window is focused.
+/// Hotkeys use the `global-hotkey` syntax, e.g. `"ctrl+alt+o"`.
+pub struct GlobalCommand {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub default: &'static str,
+}
+
+pub const GLOBAL_COMMANDS: &[GlobalCommand] = &[
+    GlobalCommand {
+        id: "toggle_overlay",
+        label: "Show / hide session overlay",
+        default: "ctrl+alt+o",
+    },
+    GlobalCommand {
+        id: "open_dashboard",
+        label: "Open dashboard",
+        default: "ctrl+alt+d",
+    },
+    GlobalCommand {
+        id: "refresh_session",
+        label: "Refresh my session",
+        default: "ctrl+alt+r",
+    },
+];
+
+pub fn is_valid_hotkey(hotkey: &str) -> bool {
+    HotKey::from_str(hotkey).is_ok()
+}
+
+/// Converts a recorded gpui keystroke (`"ctrl-shift-o"`) into hotkey syntax. Chords can't be
+/// registered with the OS, so only single keystrokes convert.
+pub fn from_keystroke(keystroke: &str) -> Option<String> {
+    if keystroke.contains(' ') {
+        return None;
+    }
+    let hotkey = keystroke.split('-').collect::<Vec<_>>().join("+");
+    is_valid_hotkey(&hotkey).then_some(hotkey)
+}
+
+pub fn hotkey_for<'a>(command: &'a GlobalCommand, overrides: &'a HashMap<String, String>) -> &'a str {
+    overrides
+        .get(command.id)
+        .map(|hotkey| hotkey.as_str())
+        .filter(|hotkey| is_valid_hotkey(hotkey))
+        .unwrap_or(command.default)
+}
+
+/// Registers the configured hotkeys and handles their presses. Registrations follow settings
+/// changes; a hotkey already taken by another application is logged and skipped.
+pub fn start(cx: &mut App) {
+    let manager = match GlobalHotKeyManager::new() {
+        Ok(manager) => manager,
+        Err(err) => {
+            tracing::warn!(error = %err, "Global hotkeys are unavailable");
+            return;
+        }
+    };
+
+    cx.spawn(async move |cx| {
+        let mut registered: Vec<(HotKey, &'static str)> = Vec::new();
+        let mut active_config = None;
+        let mut seen_revision = None;
+        loop {
+            // Only a settings change can change the registrations, so the hotkeys are reread then
+            let Ok(changed) = cx.update(|cx| {
+                let store = cx.global::<AppState>().settings.lock().unwrap();
+                let changed = seen_revision != Some(store.revision());
+                seen_revision = Some(store.revision());
+                let settings = store.settings();
+                changed.then(|| (settings.global_hotkeys_enabled, settings.global_hotkeys.clone()))
+            }) else {
+                break;
+            };
+            if let Some(config) = changed.filter(|config| active_config.as_ref() != Some(config)) {
+                let hotkeys: Vec<HotKey> = registered.drain(..).map(|(hotkey, _)| hotkey).collect();
+                let _ = manager.unregister_all(&hotkeys);
+                let (enabled, overrides) = &config;
+                if *enabled {
+                    for command in GLOBAL_COMMANDS {
+                        let hotkey_str = hotkey_for(command, overrides);
+                        let Ok(hotkey) = HotKey::from_str(hotkey_str) else {
+                            continue;
+                        };
+                        match manager.register(hotkey) {
+                            Ok(()) => registered.push((hotkey, command.id)),
+                            Err(err) => tracing::warn!(
+                                command = command.id,
+                                hotkey = hotkey_str,
+                                error = %err,
+                                "Couldn't register global hotkey"
+                            ),
+                        }
+                    }
+                }
+                active_config = Some(config);
+            }
+
+            while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+                if event.state != HotKeyState::Pressed {
+                    continue;
+                }
+                if let Some((_, id)) = registered.iter().find(|(hotkey, _)| hotkey.id() == event.id) {
+                    let id = *id;
+                    cx.update(|cx| run(id, cx)).ok();
+                }
+            }
+            cx.background_executor().timer(POLL_INTERVAL).await;
+        }
+    })
+    .detach();
+}
+
+fn run(id: &str, cx: &mut App) {
+    match id {
+        "toggle_overlay" => overlay::toggle(cx),
+        "open_dashboard" => {
+            cx.activate(true);
+            let overlay = *cx.global::<AppState>().overlay_window.lock().unwrap();
+            let dashboard = cx.windows().into_iter().find(|window| Some(*window) != overlay);
+            if let Some(window) = dashboard {
+                window.update(cx, |_, window, _| window.activate_window()).ok();
+            }
+        }
+        "refresh_session"
+            // Opening the overlay fetches fresh session data
+            if !overlay::refresh(cx) => {
+                overlay::toggle(cx);
+            }
+        _ => {}
+    }
+}
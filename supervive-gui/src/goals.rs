@@ -0,0 +1,142 @@
+use crate::api::rank_of;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Placement and K/D goals are measured over this many of the most recent matches.
+pub const GOAL_WINDOW: usize = 20;
+
+/// Ranked tiers from lowest to highest. Rank names on match rows carry a division suffix
+/// ("Diamond1"), which is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RankTier {
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+    Master,
+    Grandmaster,
+    Legend,
+}
+
+impl RankTier {
+    pub const ALL: [RankTier; 8] = [
+        RankTier::Bronze,
+        RankTier::Silver,
+        RankTier::Gold,
+        RankTier::Platinum,
+        RankTier::Diamond,
+        RankTier::Master,
+        RankTier::Grandmaster,
+        RankTier::Legend,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RankTier::Bronze => "Bronze",
+            RankTier::Silver => "Silver",
+            RankTier::Gold => "Gold",
+            RankTier::Platinum => "Platinum",
+            RankTier::Diamond => "Diamond",
+            RankTier::Master => "Master",
+            RankTier::Grandmaster => "Grandmaster",
+            RankTier::Legend => "Legend",
+        }
+    }
+
+    pub fn parse(rank: &str) -> Option<Self> {
+        let name = rank.trim_end_matches(|c: char| c.is_ascii_digit() || c.is_whitespace());
+        Self::ALL.into_iter().find(|tier| name.eq_ignore_ascii_case(tier.label()))
+    }
+
+    /// Position from the bottom tier, 0 for Bronze.
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|tier| tier == self).unwrap_or(0)
+    }
+}
+
+/// Something the user is working towards, shown with a progress bar on the home page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Goal {
+    Rank { tier: RankTier },
+    /// Average placement at or below `target`
+    AvgPlacement { target: f64 },
+    /// K/D at or above `target`, on one hero or across all of them
+    KdRatio { target: f64, hero: Option<String> },
+}
+
+pub struct GoalProgress {
+    pub current: String,
+    pub fraction: f32,
+}
+
+impl Goal {
+    pub fn label(&self) -> String {
+        match self {
+            Goal::Rank { tier } => format!("Reach {}", tier.label()),
+            Goal::AvgPlacement { target } => format!("Average placement under #{:.1}", target),
+            Goal::KdRatio { target, hero: Some(hero) } => format!("{:.1} K/D on {}", target, hero),
+            Goal::KdRatio { target, hero: None } => format!("{:.1} K/D", target),
+        }
+    }
+
+    /// Moves the target one step up (`direction` 1.0) or down (-1.0).
+    pub fn step(&mut self, direction: f64) {
+        match self {
+            Goal::Rank { tier } => {
+                let index = (tier.index() as i64 + direction as i64).clamp(0, RankTier::ALL.len() as i64 - 1);
+                *tier = RankTier::ALL[index as usize];
+            }
+            Goal::AvgPlacement { target } => *target = (*target + direction * 0.5).max(1.0),
+            // Round to the step so repeated 0.1 increments don't drift
+            Goal::KdRatio { target, .. } => *target = ((*target + direction * 0.1) * 10.0).round().max(1.0) / 10.0,
+        }
+    }
+
+    /// Progress from match rows ordered most recent first; `None` when they hold no relevant data.
+    pub fn progress(&self, rows: &[Value]) -> Option<GoalProgress> {
+        let recent = &rows[..rows.len().min(GOAL_WINDOW)];
+        match self {
+            Goal::Rank { tier } => {
+                let current = rows.iter().find_map(rank_of)?;
+                let current_tier = RankTier::parse(current)?;
+                Some(GoalProgress {
+                    current: current.to_string(),
+                    fraction: ((current_tier.index() + 1) as f32 / (tier.index() + 1) as f32).min(1.0),
+                })
+            }
+            Goal::AvgPlacement { target } => {
+                let placements: Vec<i64> = recent.iter().filter_map(|row| row["placement"].as_i64()).collect();
+                if placements.is_empty() {
+                    return None;
+                }
+                let average = placements.iter().sum::<i64>() as f64 / placements.len() as f64;
+                Some(GoalProgress {
+                    current: format!("#{:.1} over the last {} games", average, placements.len()),
+                    fraction: (target / average).min(1.0) as f32,
+                })
+            }
+            Goal::KdRatio { target, hero } => {
+                let games: Vec<&Value> = recent
+                    .iter()
+                    .filter(|row| {
+                        hero.as_ref().is_none_or(|hero| {
+                            row["hero"]["name"].as_str().is_some_and(|name| name.eq_ignore_ascii_case(hero))
+                        })
+                    })
+                    .collect();
+                if games.is_empty() {
+                    return None;
+                }
+                let stat = |key: &str| -> i64 { games.iter().filter_map(|row| row["stats"][key].as_i64()).sum() };
+                let (kills, deaths) = (stat("Kills"), stat("Deaths"));
+                let kd = if deaths > 0 { kills as f64 / deaths as f64 } else { kills as f64 };
+                Some(GoalProgress {
+                    current: format!("{:.2} over the last {} games", kd, games.len()),
+                    fraction: (kd / target).min(1.0) as f32,
+                })
+            }
+        }
+    }
+}
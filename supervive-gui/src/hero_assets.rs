@@ -0,0 +1,111 @@
+use anyhow::Result;
+use reqwest::blocking::Client;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Local store of hero portraits. Each portrait is downloaded once the first time its remote URL
+/// is seen and served from disk afterwards, so hero art renders instantly and offline.
+pub struct HeroAssets {
+    dir: PathBuf,
+    client: Client,
+    pending: HashSet<String>,
+    /// Downloads that failed; not retried until the next launch
+    failed: HashSet<String>,
+    stats: PortraitStats,
+}
+
+/// Portrait lookups since startup, shown on the frame-time overlay.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PortraitStats {
+    /// Renders served from the local store
+    pub local_hits: usize,
+    /// Renders that fell back to the remote URL
+    pub remote_loads: usize,
+    pub downloading: usize,
+    pub failed_downloads: usize,
+}
+
+impl HeroAssets {
+    pub fn new(dir: PathBuf) -> Self {
+        let _ = fs::create_dir_all(&dir);
+        let client = Client::builder()
+            .timeout(Duration::from_secs(15))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            dir,
+            client,
+            pending: HashSet::new(),
+            failed: HashSet::new(),
+            stats: PortraitStats::default(),
+        }
+    }
+
+    fn file_name(hero_name: &str, url: &str) -> String {
+        let stem: String = hero_name
+            .to_ascii_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let extension = url
+            .rsplit('.')
+            .next()
+            .map(|ext| ext.split(['?', '#']).next().unwrap_or(""))
+            .filter(|ext| matches!(*ext, "png" | "jpg" | "jpeg" | "webp"))
+            .unwrap_or("png");
+        format!("{}.{}", stem, extension)
+    }
+
+    /// Returns the bundled portrait for a hero if it has already been downloaded.
+    pub fn local_path(&mut self, hero_name: &str, url: &str) -> Option<PathBuf> {
+        let path = self.dir.join(Self::file_name(hero_name, url));
+        let found = !hero_name.is_empty() && path.exists();
+        if found {
+            self.stats.local_hits += 1;
+        } else {
+            self.stats.remote_loads += 1;
+        }
+        found.then_some(path)
+    }
+
+    pub fn stats(&self) -> PortraitStats {
+        PortraitStats {
+            downloading: self.pending.len(),
+            failed_downloads: self.failed.len(),
+            ..self.stats
+        }
+    }
+
+    /// Marks a portrait as being fetched; returns false if a download is already in flight or
+    /// failed earlier.
+    pub fn begin_download(&mut self, hero_name: &str) -> bool {
+        let key = hero_name.to_ascii_lowercase();
+        !hero_name.is_empty() && !self.failed.contains(&key) && self.pending.insert(key)
+    }
+
+    /// Prepares a blocking download job to run on the background executor.
+    pub fn download_job(&self, hero_name: &str, url: &str) -> impl FnOnce() -> Result<()> + Send + 'static {
+        let client = self.client.clone();
+        let url = url.to_string();
+        let path = self.dir.join(Self::file_name(hero_name, url.as_str()));
+        move || {
+            let bytes = client.get(&url).send()?.error_for_status()?.bytes()?;
+            // Write to a temp file first so a partial download is never picked up
+            let tmp = path.with_extension("part");
+            fs::write(&tmp, &bytes)?;
+            fs::rename(&tmp, &path)?;
+            Ok(())
+        }
+    }
+
+    pub fn finish_download(&mut self, hero_name: &str, succeeded: bool) {
+        let key = hero_name.to_ascii_lowercase();
+        self.pending.remove(&key);
+        if !succeeded {
+            self.failed.insert(key);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use gpui::{rgb, Rgba};
+
+/// In-game hero classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HeroRole {
+    Fighter,
+    Frontliner,
+    Initiator,
+    Protector,
+    Controller,
+}
+
+impl HeroRole {
+    /// Looks up the role of a hero by display name (case-insensitive).
+    pub fn for_hero(name: &str) -> Option<Self> {
+        let role = match name.to_ascii_lowercase().as_str() {
+            "brall" | "carbine" | "ghost" | "hudson" | "jin" | "myth" | "shiv" | "shrike" => {
+                HeroRole::Fighter
+            }
+            "bishop" | "kingpin" | "oath" | "crysta" => HeroRole::Frontliner,
+            "felix" | "saros" | "zeph" => HeroRole::Initiator,
+            "elluna" | "eva" | "joule" => HeroRole::Protector,
+            "beebo" | "celeste" | "void" => HeroRole::Controller,
+            _ => return None,
+        };
+        Some(role)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HeroRole::Fighter => "Fighter",
+            HeroRole::Frontliner => "Frontliner",
+            HeroRole::Initiator => "Initiator",
+            HeroRole::Protector => "Protector",
+            HeroRole::Controller => "Controller",
+        }
+    }
+
+    /// The broad role the class plays in a squad.
+    pub fn group(&self) -> RoleGroup {
+        match self {
+            HeroRole::Fighter => RoleGroup::Damage,
+            HeroRole::Frontliner | HeroRole::Initiator => RoleGroup::Frontline,
+            HeroRole::Protector | HeroRole::Controller => RoleGroup::Support,
+        }
+    }
+
+    pub fn color(&self) -> Rgba {
+        match self {
+            HeroRole::Fighter => rgb(0xf38ba8),
+            HeroRole::Frontliner => rgb(0xfab387),
+            HeroRole::Initiator => rgb(0xcba6f7),
+            HeroRole::Protector => rgb(0xa6e3a1),
+            HeroRole::Controller => rgb(0x89b4fa),
+        }
+    }
+}
+
+/// Coarser split of the hero classes, for comparing how a player does in each kind of role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoleGroup {
+    Frontline,
+    Support,
+    Damage,
+}
+
+impl RoleGroup {
+    pub const ALL: [RoleGroup; 3] = [RoleGroup::Frontline, RoleGroup::Support, RoleGroup::Damage];
+
+    pub fn for_hero(name: &str) -> Option<Self> {
+        HeroRole::for_hero(name).map(|role| role.group())
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RoleGroup::Frontline => "Frontline",
+            RoleGroup::Support => "Support",
+            RoleGroup::Damage => "Damage",
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Games, wins and placements on one hero.
+#[derive(Debug, Clone, Default)]
+pub struct HeroTotals {
+    pub games: usize,
+    pub wins: usize,
+    placement_sum: i64,
+}
+
+impl HeroTotals {
+    pub fn avg_placement(&self) -> f64 {
+        self.placement_sum as f64 / self.games as f64
+    }
+}
+
+/// Running totals over a player's loaded history. Pages are added as they arrive, so loading
+/// more history never rescans what is already counted.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryTotals {
+    pub kills: i64,
+    pub deaths: i64,
+    /// Matches with a placement; only these count towards the average
+    pub games: usize,
+    placement_sum: i64,
+    pub heroes: HashMap<String, HeroTotals>,
+}
+
+impl HistoryTotals {
+    pub fn from_rows(rows: &[Value]) -> Self {
+        let mut totals = Self::default();
+        totals.add_rows(rows);
+        totals
+    }
+
+    pub fn add_rows(&mut self, rows: &[Value]) {
+        for row in rows {
+            self.kills += row["stats"]["Kills"].as_i64().unwrap_or(0);
+            self.deaths += row["stats"]["Deaths"].as_i64().unwrap_or(0);
+            let Some(placement) = row["placement"].as_i64() else {
+                continue;
+            };
+            self.games += 1;
+            self.placement_sum += placement;
+            if let Some(hero) = row["hero"]["name"].as_str() {
+                let hero = self.heroes.entry(hero.to_string()).or_default();
+                hero.games += 1;
+                hero.placement_sum += placement;
+                if placement == 1 {
+                    hero.wins += 1;
+                }
+            }
+        }
+    }
+
+    pub fn avg_placement(&self) -> f64 {
+        if self.games > 0 {
+            self.placement_sum as f64 / self.games as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Infinite when the player never died but has kills.
+    pub fn kd_ratio(&self) -> f64 {
+        if self.deaths > 0 {
+            self.kills as f64 / self.deaths as f64
+        } else if self.kills > 0 {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+}
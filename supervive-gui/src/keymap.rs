@@ -0,0 +1,96 @@
+use crate::views::{FocusNext, FocusPrevious, GoBack, GoHome, OpenQuickSearch, OpenSettings, Refresh, ToggleOverlay};
+use gpui::{App, KeyBinding, Keystroke};
+use std::collections::HashMap;
+
+/// A rebindable command: stable id (used as the settings key), label, and default keystrokes.
+/// Keystrokes may be chords, e.g. `"ctrl-k ctrl-s"`.
+pub struct Command {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub default: &'static str,
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        id: "go_back",
+        label: "Back",
+        default: "alt-left",
+    },
+    Command {
+        id: "go_home",
+        label: "Home / search",
+        default: "alt-home",
+    },
+    Command {
+        id: "quick_search",
+        label: "Search for a player",
+        default: "ctrl-f",
+    },
+    Command {
+        id: "refresh",
+        label: "Refresh current view",
+        default: "f5",
+    },
+    Command {
+        id: "open_settings",
+        label: "Open settings",
+        default: "ctrl-,",
+    },
+    Command {
+        id: "toggle_overlay",
+        label: "Show / hide session overlay",
+        default: "ctrl-shift-o",
+    },
+    Command {
+        id: "focus_next",
+        label: "Focus next element",
+        default: "tab",
+    },
+    Command {
+        id: "focus_previous",
+        label: "Focus previous element",
+        default: "shift-tab",
+    },
+];
+
+/// Whether every keystroke in a (possibly chorded) binding parses.
+pub fn is_valid_binding(keystrokes: &str) -> bool {
+    !keystrokes.trim().is_empty()
+        && keystrokes
+            .split_whitespace()
+            .all(|keystroke| Keystroke::parse(keystroke).is_ok())
+}
+
+/// The effective keystrokes for a command, preferring a valid user override.
+pub fn binding_for<'a>(command: &'a Command, overrides: &'a HashMap<String, String>) -> &'a str {
+    overrides
+        .get(command.id)
+        .map(|keystrokes| keystrokes.as_str())
+        .filter(|keystrokes| is_valid_binding(keystrokes))
+        .unwrap_or(command.default)
+}
+
+fn key_binding(id: &str, keystrokes: &str) -> Option<KeyBinding> {
+    let binding = match id {
+        "go_back" => KeyBinding::new(keystrokes, GoBack, None),
+        "go_home" => KeyBinding::new(keystrokes, GoHome, None),
+        "quick_search" => KeyBinding::new(keystrokes, OpenQuickSearch, None),
+        "refresh" => KeyBinding::new(keystrokes, Refresh, None),
+        "open_settings" => KeyBinding::new(keystrokes, OpenSettings, None),
+        "toggle_overlay" => KeyBinding::new(keystrokes, ToggleOverlay, None),
+        "focus_next" => KeyBinding::new(keystrokes, FocusNext, None),
+        "focus_previous" => KeyBinding::new(keystrokes, FocusPrevious, None),
+        _ => return None,
+    };
+    Some(binding)
+}
+
+/// Replaces all key bindings with the defaults merged with the user's overrides.
+pub fn apply(overrides: &HashMap<String, String>, cx: &mut App) {
+    cx.clear_key_bindings();
+    cx.bind_keys(
+        COMMANDS
+            .iter()
+            .filter_map(|command| key_binding(command.id, binding_for(command, overrides))),
+    );
+}
@@ -0,0 +1,248 @@
+use crate::api::{PlayerSummary, SuperviveService};
+use crate::player_index::{IndexedPlayer, PlayerIndex};
+use crate::settings::LiveMatchSettings;
+use crate::state::AppState;
+use crate::views::OpenLobby;
+use chrono::{DateTime, Local};
+use gpui::{App, AppContext};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How often the game log is checked for new lines.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Default location of the client log written by the game (Unreal's `Saved/Logs`).
+pub fn default_log_path() -> Option<PathBuf> {
+    Some(
+        dirs::data_local_dir()?
+            .join("SUPERVIVE")
+            .join("Saved")
+            .join("Logs")
+            .join("SUPERVIVE.log"),
+    )
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum LogEvent {
+    MatchStarted,
+    MatchEnded,
+    PlayerSeen(String),
+}
+
+/// Classifies a log line using the markers from settings. The defaults follow Unreal's map
+/// loading lines; they can be adjusted in settings.json if a game update changes the format.
+fn parse_line(line: &str, markers: &LiveMatchSettings) -> Option<LogEvent> {
+    if line.contains(&markers.load_marker) {
+        return Some(if line.contains(&markers.menu_marker) {
+            LogEvent::MatchEnded
+        } else {
+            LogEvent::MatchStarted
+        });
+    }
+    let start = line.find(&markers.player_marker)? + markers.player_marker.len();
+    let name: String = line[start..]
+        .trim_start_matches('"')
+        .chars()
+        .take_while(|c| !matches!(c, '"' | ',' | ')' | ' ') && !c.is_control())
+        .collect();
+    (!name.is_empty()).then_some(LogEvent::PlayerSeen(name))
+}
+
+/// Follows a log file from its current end, returning complete lines as they are appended.
+struct LogTailer {
+    path: PathBuf,
+    offset: u64,
+    partial: String,
+}
+
+impl LogTailer {
+    fn new(path: PathBuf) -> Self {
+        // Start at the end so lines from earlier sessions are not replayed
+        let offset = std::fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+        Self {
+            path,
+            offset,
+            partial: String::new(),
+        }
+    }
+
+    fn read_new_lines(&mut self) -> Vec<String> {
+        let Ok(mut file) = File::open(&self.path) else {
+            return Vec::new();
+        };
+        let len = file.metadata().map(|meta| meta.len()).unwrap_or(0);
+        if len < self.offset {
+            // The game truncated or rotated the log on startup
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return Vec::new();
+        }
+
+        let mut bytes = Vec::new();
+        if file.read_to_end(&mut bytes).is_err() {
+            return Vec::new();
+        }
+        self.offset += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+
+        let mut lines: Vec<String> = self.partial.split('\n').map(|line| line.trim_end().to_string()).collect();
+        // The last piece has no newline yet; keep it for the next read
+        self.partial = lines.pop().unwrap_or_default();
+        lines
+    }
+}
+
+/// A player name seen in the current match and what could be resolved for it.
+#[derive(Debug, Clone)]
+pub struct LobbyPlayer {
+    pub name: String,
+    pub profile: Option<IndexedPlayer>,
+    pub summary: Option<PlayerSummary>,
+    pub resolving: bool,
+}
+
+/// The match currently detected from the game log.
+#[derive(Debug, Clone, Default)]
+pub struct LiveLobby {
+    pub started_at: Option<DateTime<Local>>,
+    pub in_match: bool,
+    pub players: Vec<LobbyPlayer>,
+}
+
+/// Starts following the game log. The loop idles while the integration is disabled in settings.
+pub fn start(cx: &mut App) {
+    cx.spawn(async move |cx| {
+        let mut tailer: Option<LogTailer> = None;
+        loop {
+            cx.background_executor().timer(POLL_INTERVAL).await;
+            let Ok(settings) = cx.update(|cx| cx.global::<AppState>().settings().live_match) else {
+                break;
+            };
+            if !settings.enabled {
+                tailer = None;
+                continue;
+            }
+            let Some(path) = settings.log_path.clone().map(PathBuf::from).or_else(default_log_path) else {
+                continue;
+            };
+
+            // File access stays off the UI thread; only the parsed events come back to it
+            let current = tailer.take().filter(|tailer| tailer.path == path);
+            let (next, events) = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut tailer = current.unwrap_or_else(|| LogTailer::new(path));
+                    let events: Vec<LogEvent> = tailer
+                        .read_new_lines()
+                        .iter()
+                        .filter_map(|line| parse_line(line, &settings))
+                        .collect();
+                    (tailer, events)
+                })
+                .await;
+            tailer = Some(next);
+            if !events.is_empty() {
+                cx.update(|cx| apply_events(events, cx)).ok();
+            }
+        }
+    })
+    .detach();
+}
+
+fn apply_events(events: Vec<LogEvent>, cx: &mut App) {
+    let app_state = cx.global::<AppState>();
+    let lobby = app_state.live_lobby.clone();
+    let mut new_players = Vec::new();
+    let mut match_started = false;
+    {
+        let mut lobby = lobby.lock().unwrap();
+        for event in events {
+            match event {
+                LogEvent::MatchStarted => {
+                    *lobby = LiveLobby {
+                        started_at: Some(Local::now()),
+                        in_match: true,
+                        players: Vec::new(),
+                    };
+                    new_players.clear();
+                    match_started = true;
+                }
+                LogEvent::MatchEnded => lobby.in_match = false,
+                LogEvent::PlayerSeen(name) => {
+                    if lobby.in_match && !lobby.players.iter().any(|p| p.name.eq_ignore_ascii_case(&name)) {
+                        lobby.players.push(LobbyPlayer {
+                            name: name.clone(),
+                            profile: None,
+                            summary: None,
+                            resolving: true,
+                        });
+                        new_players.push(name);
+                    }
+                }
+            }
+        }
+    }
+
+    let service = app_state.service.clone();
+    let index = app_state.player_index.clone();
+    for name in new_players {
+        let (service, index, lobby) = (service.clone(), index.clone(), lobby.clone());
+        cx.spawn(async move |cx| {
+            cx.background_executor()
+                .spawn(async move { resolve_player(&name, &service, &index, &lobby) })
+                .await;
+            cx.update(|cx| cx.refresh_windows()).ok();
+        })
+        .detach();
+    }
+
+    if match_started {
+        if let Some(window) = cx.windows().first().copied() {
+            cx.update_window(window, |_, window, cx| window.dispatch_action(Box::new(OpenLobby), cx))
+                .ok();
+        }
+    }
+    cx.refresh_windows();
+}
+
+/// Looks the name up in the local index first (instant, offline), then the search API. Results
+/// of the search are recorded in the index on the way.
+pub fn find_profile(name: &str, service: &Mutex<SuperviveService>, index: &Mutex<PlayerIndex>) -> Option<IndexedPlayer> {
+    let profile = index.lock().unwrap().find_by_name(name);
+    if profile.is_some() {
+        return profile;
+    }
+    let data = service.lock().unwrap().search_players(name).ok()?;
+    let mut index = index.lock().unwrap();
+    index.record_search_results(data.as_array()?);
+    index.find_by_name(name)
+}
+
+/// Finds the profile for a name seen in the log and loads the cached summary for it.
+fn resolve_player(
+    name: &str,
+    service: &Mutex<SuperviveService>,
+    index: &Mutex<PlayerIndex>,
+    lobby: &Mutex<LiveLobby>,
+) {
+    let profile = find_profile(name, service, index);
+    let summary = profile.as_ref().and_then(|profile| {
+        service
+            .lock()
+            .unwrap()
+            .get_player_summary(&profile.platform, &profile.player_id)
+            .ok()
+    });
+
+    let mut lobby = lobby.lock().unwrap();
+    if let Some(player) = lobby.players.iter_mut().find(|player| player.name == name) {
+        player.profile = profile;
+        player.summary = summary;
+        player.resolving = false;
+    }
+}
@@ -0,0 +1,48 @@
+use crate::api::rank_of;
+use crate::goals::RankTier;
+use serde_json::Value;
+
+/// A lobby averaging this many tiers above or below the player reads as hard or soft.
+const DIFFICULTY_MARGIN: f64 = 0.5;
+
+/// Average rank tier of a match's participants (0 for Bronze), from the match detail rows.
+/// `None` when no participant has a recorded rank.
+pub fn lobby_rating(rows: &[Value]) -> Option<f64> {
+    let tiers: Vec<f64> = rows
+        .iter()
+        .filter_map(|row| RankTier::parse(rank_of(row)?))
+        .map(|tier| tier.index() as f64)
+        .collect();
+    (!tiers.is_empty()).then(|| tiers.iter().sum::<f64>() / tiers.len() as f64)
+}
+
+/// Tier the rating rounds to, for display.
+pub fn nearest_tier(rating: f64) -> RankTier {
+    RankTier::ALL[(rating.round().max(0.0) as usize).min(RankTier::ALL.len() - 1)]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LobbyDifficulty {
+    Soft,
+    Even,
+    Hard,
+}
+
+impl LobbyDifficulty {
+    /// Compares the lobby to the player's own tier; without one every lobby reads as even.
+    pub fn new(lobby: f64, own: Option<f64>) -> Self {
+        match own {
+            Some(own) if lobby > own + DIFFICULTY_MARGIN => LobbyDifficulty::Hard,
+            Some(own) if lobby < own - DIFFICULTY_MARGIN => LobbyDifficulty::Soft,
+            _ => LobbyDifficulty::Even,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LobbyDifficulty::Soft => "Soft lobby",
+            LobbyDifficulty::Even => "Even lobby",
+            LobbyDifficulty::Hard => "Stacked lobby",
+        }
+    }
+}
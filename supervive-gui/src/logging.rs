@@ -0,0 +1,144 @@
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Number of recent events kept in memory for the log viewer and crash reports.
+const LOG_HISTORY: usize = 500;
+/// Daily log files kept on disk before the oldest is deleted.
+const MAX_LOG_FILES: usize = 7;
+
+/// An event captured for the in-app log viewer.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+impl LogEntry {
+    pub fn format_line(&self) -> String {
+        format!(
+            "{} {:<5} {}: {}",
+            self.time.format("%H:%M:%S%.3f"),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+static RECENT: Mutex<VecDeque<LogEntry>> = Mutex::new(VecDeque::new());
+
+/// Snapshot of the most recent events, oldest first.
+pub fn recent() -> Vec<LogEntry> {
+    let entries = RECENT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    entries.iter().cloned().collect()
+}
+
+pub fn recent_text() -> String {
+    recent().iter().map(LogEntry::format_line).collect::<Vec<_>>().join("\n")
+}
+
+pub fn log_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("supervive_gui")
+        .join("logs")
+}
+
+/// Collects an event's message and structured fields into one line.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "message" => {
+                let _ = write!(self.message, "{:?}", value);
+            }
+            // Records bridged from the `log` crate carry their metadata as fields
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.fields, " {}={:?}", name, value);
+            }
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message.push_str(value),
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.fields, " {}={}", name, value);
+            }
+        }
+    }
+}
+
+/// Layer that keeps the last events in memory for the log viewer.
+struct MemoryLayer;
+
+impl<S: Subscriber> Layer<S> for MemoryLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let entry = LogEntry {
+            time: Local::now(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.message + &visitor.fields,
+        };
+
+        let mut entries = RECENT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if entries.len() == LOG_HISTORY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+}
+
+/// Installs the global subscriber: stderr, daily-rotated files in the data dir and the
+/// in-memory buffer, filtered by `RUST_LOG` (default `info`). `log` records from
+/// dependencies are forwarded too. Keep the returned guard alive to flush the file writer.
+pub fn init() -> Option<WorkerGuard> {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+
+    let (file_layer, guard) = match RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("supervive-gui")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(log_dir())
+    {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = tracing_subscriber::fmt::layer().with_ansi(false).with_writer(writer);
+            (Some(layer), Some(guard))
+        }
+        Err(err) => {
+            eprintln!("Couldn't open log directory: {}", err);
+            (None, None)
+        }
+    };
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .with(file_layer)
+        .with(MemoryLayer)
+        .init();
+    guard
+}
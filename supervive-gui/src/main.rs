@@ -1,23 +1,118 @@
+mod abilities;
 mod api;
+mod archive_query;
+mod backup;
+mod cli;
+mod clipboard_watch;
+mod components;
+mod compositions;
+mod confidence;
+mod connectivity;
+mod csv;
+mod death_recap;
+mod demo;
+mod diagnostics;
+mod format;
+mod frame_stats;
+mod game_modes;
+mod global_hotkeys;
+mod goals;
+mod hero_assets;
+mod heroes;
+mod history_totals;
+mod keymap;
+mod live_match;
+mod lobby_strength;
+mod logging;
+mod markdown;
+mod match_archive;
+mod match_export;
+mod match_notes;
+mod matchups;
+mod meta;
+mod notifier;
+mod opponent_report;
+mod overlay;
+mod phases;
+mod player_index;
+mod plugins;
+mod rank_points;
+mod records;
+mod reports;
+mod scheduler;
+mod scout;
+mod series;
+mod session;
+mod share_image;
+mod settings;
 mod state;
+mod steam;
+mod suggestions;
+mod sync;
+mod tasks;
+mod teamplay;
+mod telemetry;
+mod theme;
+mod tiles;
+mod trends;
+mod updates;
 mod views;
 
 use api::SuperviveService;
+use cli::Command;
 use gpui::prelude::*;
 use gpui::*;
 use state::AppState;
 use views::root::RootView;
-use views::search::SearchView;
 
 fn main() {
-    env_logger::init();
+    let _log_guard = logging::init();
+    diagnostics::install_panic_hook();
 
-    Application::new().run(|cx| {
-        let service = SuperviveService::new().expect("Failed to initialize service");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let demo = args == ["--demo"];
+    if let Some(command) = Command::parse(&args).filter(|_| !demo) {
+        if let Err(err) = command.and_then(Command::run) {
+            eprintln!("error: {:#}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    Application::new().run(move |cx| {
+        let service = if demo {
+            SuperviveService::demo()
+        } else {
+            SuperviveService::new()
+        }
+        .expect("Failed to initialize service");
+        demo::mark_enabled(demo);
         let app_state = AppState::new(service);
+        let keybindings = app_state.settings().keybindings;
         cx.set_global(app_state);
+        keymap::apply(&keybindings, cx);
+        scheduler::start(cx);
+        cx.on_app_quit(|cx| {
+            let app_state = cx.global::<AppState>();
+            app_state.service.lock().unwrap().flush_cache();
+            app_state.player_index.lock().unwrap().flush();
+            async {}
+        })
+        .detach();
+        live_match::start(cx);
+        global_hotkeys::start(cx);
+        clipboard_watch::start(cx);
+        connectivity::start(cx);
+
+        let options = WindowOptions {
+            titlebar: Some(TitlebarOptions {
+                title: Some("Supervive Dashboard".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
 
-        cx.open_window(WindowOptions::default(), |window, cx| {
+        cx.open_window(options, |window, cx| {
             cx.new(|cx| RootView::new(cx, window))
         })
         .unwrap();
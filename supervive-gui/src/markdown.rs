@@ -0,0 +1,40 @@
+/// Escapes the characters that would end a table cell or start formatting.
+pub fn escape(cell: &str) -> String {
+    cell.replace('\\', "\\\\").replace('|', "\\|").replace('*', "\\*").replace('_', "\\_")
+}
+
+/// A pipe table with every column padded to its widest cell, so it renders on Reddit and still
+/// reads as a grid where tables aren't rendered, like Discord.
+pub fn table<S: AsRef<str>>(header: &[S], rows: &[Vec<String>]) -> String {
+    let header: Vec<String> = header.iter().map(|cell| escape(cell.as_ref())).collect();
+    let rows: Vec<Vec<String>> = rows.iter().map(|row| row.iter().map(|cell| escape(cell)).collect()).collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|idx| {
+            rows.iter()
+                .filter_map(|row| row.get(idx))
+                .chain(std::iter::once(&header[idx]))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(3)
+        })
+        .collect();
+    let line = |cells: &[String]| {
+        let padded: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(idx, width)| {
+                let cell = cells.get(idx).map(String::as_str).unwrap_or("");
+                format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+            })
+            .collect();
+        format!("| {} |\n", padded.join(" | "))
+    };
+    let mut text = line(&header);
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    text.push_str(&format!("|-{}-|\n", separator.join("-|-")));
+    for row in &rows {
+        text.push_str(&line(row));
+    }
+    text
+}
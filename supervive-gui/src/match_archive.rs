@@ -0,0 +1,473 @@
+use crate::session::parse_time;
+use crate::settings::ArchiveRetention;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Platforms a match ID may be prefixed with, as in op.gg's `steam-<id>` match URLs.
+const PLATFORMS: [&str; 2] = ["steam", "epic"];
+
+/// What the archive index records about a stored match, so listing the archive never reads the
+/// scoreboards themselves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveEntry {
+    pub match_id: String,
+    pub platform: String,
+    pub match_start: Option<DateTime<Utc>>,
+    pub players: usize,
+    pub imported_at: DateTime<Utc>,
+    /// Size of the stored scoreboard file
+    #[serde(default)]
+    pub bytes: u64,
+}
+
+impl ArchiveEntry {
+    /// When the match was played, or imported if the scoreboard had no start time.
+    pub fn played_at(&self) -> DateTime<Utc> {
+        self.match_start.unwrap_or(self.imported_at)
+    }
+}
+
+/// What an integrity check of the archive found and repaired.
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityReport {
+    pub checked: usize,
+    /// Repeated player rows dropped from scoreboards
+    pub duplicate_rows: usize,
+    /// Index entries whose scoreboard file was gone, removed from the index
+    pub missing: usize,
+    /// Truncated or unrecognised files moved to the quarantine folder
+    pub quarantined: usize,
+    /// Scoreboards on disk the index had lost track of, added back
+    pub reindexed: usize,
+}
+
+impl IntegrityReport {
+    pub fn summary(&self) -> String {
+        let mut repairs = Vec::new();
+        if self.duplicate_rows > 0 {
+            repairs.push(format!("removed {} duplicate player rows", self.duplicate_rows));
+        }
+        if self.missing > 0 {
+            repairs.push(format!("dropped {} entries with no scoreboard", self.missing));
+        }
+        if self.quarantined > 0 {
+            repairs.push(format!("quarantined {} unreadable files", self.quarantined));
+        }
+        if self.reindexed > 0 {
+            repairs.push(format!("re-indexed {} matches", self.reindexed));
+        }
+        if repairs.is_empty() {
+            format!("Checked {} matches, no problems found", self.checked)
+        } else {
+            format!("Checked {} matches: {}", self.checked, repairs.join(", "))
+        }
+    }
+}
+
+/// Matches kept on this machine for good (unlike the API cache, which expires them), e.g. a
+/// tournament's games imported by ID. One file per scoreboard plus an index of entries.
+pub struct MatchArchive {
+    dir: PathBuf,
+    entries: HashMap<String, ArchiveEntry>,
+}
+
+fn key(platform: &str, match_id: &str) -> String {
+    format!("{}:{}", platform, match_id)
+}
+
+/// Match ids are hex, optionally dashed like a UUID. Checked before an id becomes part of a
+/// file path, since ids also arrive from backups and pasted text.
+fn is_match_id(match_id: &str) -> bool {
+    match_id.len() >= 8 && match_id.chars().all(|ch| ch.is_ascii_hexdigit() || ch == '-')
+}
+
+/// `(platform, match_id)` from a scoreboard file name as the archive writes it.
+pub fn parse_file_name(name: &str) -> Option<(String, String)> {
+    let (platform, match_id) = name.strip_suffix(".json")?.split_once('-')?;
+    (PLATFORMS.contains(&platform) && is_match_id(match_id)).then(|| (platform.to_string(), match_id.to_string()))
+}
+
+/// Writes next to `path` and renames over it, so a crash mid-write never leaves half a file.
+/// Returns the size written.
+fn write_json(path: &Path, data: &impl Serialize) -> std::io::Result<u64> {
+    let tmp = path.with_extension("json.tmp");
+    let file = fs::File::create(&tmp)?;
+    let mut writer = std::io::BufWriter::new(file);
+    serde_json::to_writer(&mut writer, data)?;
+    writer.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+    fs::rename(&tmp, path)?;
+    Ok(fs::metadata(path)?.len())
+}
+
+/// The rows of a stored scoreboard, or `None` when the file is truncated or isn't a list of
+/// player rows from one match.
+fn read_scoreboard(path: &Path) -> Option<Vec<Value>> {
+    let file = fs::File::open(path).ok()?;
+    let Value::Array(rows) = serde_json::from_reader(std::io::BufReader::new(file)).ok()? else {
+        return None;
+    };
+    let match_id = rows.first()?["match_id"].as_str()?.to_string();
+    rows.iter()
+        .all(|row| row["match_id"].as_str() == Some(match_id.as_str()) && row["player_id"].is_string())
+        .then_some(rows)
+}
+
+/// Drops repeated rows of the same player, keeping the first. Returns how many were dropped.
+fn dedupe_rows(rows: &mut Vec<Value>) -> usize {
+    let before = rows.len();
+    let mut seen = HashSet::new();
+    rows.retain(|row| seen.insert(row["player_id"].as_str().unwrap_or_default().to_string()));
+    before - rows.len()
+}
+
+fn new_entry(platform: &str, match_id: &str, rows: &[Value], bytes: u64, imported_at: DateTime<Utc>) -> ArchiveEntry {
+    ArchiveEntry {
+        match_id: match_id.to_string(),
+        platform: platform.to_string(),
+        match_start: rows.iter().find_map(|row| parse_time(&row["match_start"])),
+        players: rows.len(),
+        imported_at,
+        bytes,
+    }
+}
+
+impl MatchArchive {
+    pub fn default_dir() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui")
+            .join("match_archive")
+    }
+
+    pub fn new(dir: PathBuf) -> Self {
+        let mut archive = Self {
+            dir,
+            entries: HashMap::new(),
+        };
+        archive.load();
+        archive
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.dir.join("index.json")
+    }
+
+    fn match_path(&self, platform: &str, match_id: &str) -> PathBuf {
+        self.dir.join(format!("{}-{}.json", platform, match_id))
+    }
+
+    fn load(&mut self) {
+        if let Ok(file) = fs::File::open(self.index_path()) {
+            if let Ok(entries) = serde_json::from_reader(file) {
+                self.entries = entries;
+            }
+        }
+        // Indexes written before sizes were recorded
+        for entry in self.entries.values_mut().filter(|entry| entry.bytes == 0) {
+            entry.bytes = fs::metadata(self.dir.join(format!("{}-{}.json", entry.platform, entry.match_id)))
+                .map(|meta| meta.len())
+                .unwrap_or(0);
+        }
+    }
+
+    fn save(&self) {
+        let _ = fs::create_dir_all(&self.dir);
+        let _ = write_json(&self.index_path(), &self.entries);
+    }
+
+    /// Where files the integrity check couldn't read are moved.
+    pub fn quarantine_dir(&self) -> PathBuf {
+        self.dir.join("quarantine")
+    }
+
+    pub fn contains(&self, platform: &str, match_id: &str) -> bool {
+        self.entries.contains_key(&key(platform, match_id))
+    }
+
+    /// The stored scoreboard rows, as `get_match` returns them.
+    pub fn get(&self, platform: &str, match_id: &str) -> Option<Value> {
+        if !self.contains(platform, match_id) {
+            return None;
+        }
+        let file = fs::File::open(self.match_path(platform, match_id)).ok()?;
+        serde_json::from_reader(std::io::BufReader::new(file)).ok()
+    }
+
+    /// Stores a match's scoreboard, replacing any earlier copy.
+    pub fn insert(&mut self, platform: &str, match_id: &str, data: &Value) -> std::io::Result<()> {
+        if !PLATFORMS.contains(&platform) || !is_match_id(match_id) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Not a match id: {}-{}", platform, match_id),
+            ));
+        }
+        fs::create_dir_all(&self.dir)?;
+        let bytes = write_json(&self.match_path(platform, match_id), data)?;
+        let rows = data.as_array().map(Vec::as_slice).unwrap_or_default();
+        self.entries
+            .insert(key(platform, match_id), new_entry(platform, match_id, rows, bytes, Utc::now()));
+        self.save();
+        Ok(())
+    }
+
+    pub fn remove(&mut self, platform: &str, match_id: &str) {
+        if self.entries.remove(&key(platform, match_id)).is_some() {
+            let _ = fs::remove_file(self.match_path(platform, match_id));
+            self.save();
+        }
+    }
+
+    /// Removes several matches, writing the index once. Returns how many were archived.
+    pub fn remove_all(&mut self, entries: &[ArchiveEntry]) -> usize {
+        let mut removed = 0;
+        for entry in entries {
+            if self.entries.remove(&key(&entry.platform, &entry.match_id)).is_some() {
+                let _ = fs::remove_file(self.match_path(&entry.platform, &entry.match_id));
+                removed += 1;
+            }
+        }
+        if removed > 0 {
+            self.save();
+        }
+        removed
+    }
+
+    /// The scoreboard files of every archived match, for backups.
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.entries
+            .values()
+            .map(|entry| self.match_path(&entry.platform, &entry.match_id))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.entries.values().map(|entry| entry.bytes).sum()
+    }
+
+    /// The matches `retention` would delete, oldest first. A size cap deletes from the oldest
+    /// match until the rest fits.
+    pub fn expired(&self, retention: ArchiveRetention) -> Vec<ArchiveEntry> {
+        let mut entries: Vec<ArchiveEntry> = self.entries.values().cloned().collect();
+        entries.sort_by_key(|entry| (entry.played_at(), entry.imported_at));
+        match retention {
+            ArchiveRetention::Forever => Vec::new(),
+            ArchiveRetention::Days(days) => {
+                let cutoff = Utc::now() - Duration::days(days as i64);
+                entries.retain(|entry| entry.played_at() < cutoff);
+                entries
+            }
+            ArchiveRetention::SizeMb(mb) => {
+                let cap = mb.saturating_mul(1024 * 1024);
+                let mut total = self.total_bytes();
+                entries
+                    .into_iter()
+                    .take_while(|entry| {
+                        let over = total > cap;
+                        total = total.saturating_sub(entry.bytes);
+                        over
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Moves an unreadable file aside instead of deleting it, in case it's worth recovering by hand.
+    fn quarantine(&self, path: &Path) {
+        let Some(name) = path.file_name() else {
+            return;
+        };
+        let dir = self.quarantine_dir();
+        if fs::create_dir_all(&dir).is_err() || fs::rename(path, dir.join(name)).is_err() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    /// Checks every scoreboard against the index: drops duplicate player rows, forgets entries
+    /// whose file is gone, quarantines files that are truncated or not a scoreboard, and adds back
+    /// scoreboards the index lost (e.g. after a crash while the index was written).
+    pub fn check_integrity(&mut self) -> IntegrityReport {
+        let mut report = IntegrityReport::default();
+        let keys: Vec<String> = self.entries.keys().cloned().collect();
+        for key in keys {
+            let entry = self.entries[&key].clone();
+            let path = self.match_path(&entry.platform, &entry.match_id);
+            report.checked += 1;
+            if !path.exists() {
+                self.entries.remove(&key);
+                report.missing += 1;
+                continue;
+            }
+            let Some(mut rows) = read_scoreboard(&path) else {
+                self.quarantine(&path);
+                self.entries.remove(&key);
+                report.quarantined += 1;
+                continue;
+            };
+            let duplicates = dedupe_rows(&mut rows);
+            if duplicates > 0 {
+                report.duplicate_rows += duplicates;
+                if let Ok(bytes) = write_json(&path, &rows) {
+                    let repaired = new_entry(&entry.platform, &entry.match_id, &rows, bytes, entry.imported_at);
+                    self.entries.insert(key, repaired);
+                }
+            }
+        }
+
+        let files: Vec<PathBuf> = fs::read_dir(&self.dir)
+            .map(|dir| dir.flatten().map(|file| file.path()).filter(|path| path.is_file()).collect())
+            .unwrap_or_default();
+        for path in files {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+            // Left behind by a write that never finished
+            if name.ends_with(".tmp") {
+                let _ = fs::remove_file(&path);
+                continue;
+            }
+            if !name.ends_with(".json") || name == "index.json" {
+                continue;
+            }
+            let reference = parse_file_name(&name);
+            if let Some((platform, match_id)) = &reference {
+                if self.contains(platform, match_id) {
+                    continue;
+                }
+            }
+            report.checked += 1;
+            let rows = read_scoreboard(&path);
+            let (Some((platform, match_id)), Some(mut rows)) = (reference, rows) else {
+                self.quarantine(&path);
+                report.quarantined += 1;
+                continue;
+            };
+            let duplicates = dedupe_rows(&mut rows);
+            report.duplicate_rows += duplicates;
+            let bytes = if duplicates > 0 {
+                write_json(&path, &rows).unwrap_or(0)
+            } else {
+                fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0)
+            };
+            let imported_at = fs::metadata(&path)
+                .and_then(|meta| meta.modified())
+                .map(DateTime::<Utc>::from)
+                .unwrap_or_else(|_| Utc::now());
+            self.entries
+                .insert(key(&platform, &match_id), new_entry(&platform, &match_id, &rows, bytes, imported_at));
+            report.reindexed += 1;
+        }
+        self.save();
+        report
+    }
+
+    /// Every archived match, most recently played first.
+    pub fn entries(&self) -> Vec<ArchiveEntry> {
+        let mut entries: Vec<ArchiveEntry> = self.entries.values().cloned().collect();
+        entries.sort_by(|a, b| b.match_start.cmp(&a.match_start).then(b.imported_at.cmp(&a.imported_at)));
+        entries
+    }
+}
+
+/// `(platform, match_id)` from an op.gg match URL, `steam-<id>` or a bare ID (assumed Steam).
+pub fn parse_match_reference(input: &str) -> Option<(String, String)> {
+    let input = input.trim().split(['?', '#']).next()?.trim_end_matches('/');
+    let last = input.rsplit('/').next()?;
+    let (platform, match_id) = match last.split_once('-') {
+        Some((platform, rest)) if PLATFORMS.contains(&platform.to_ascii_lowercase().as_str()) => {
+            (platform.to_ascii_lowercase(), rest)
+        }
+        _ => ("steam".to_string(), last),
+    };
+    is_match_id(match_id).then(|| (platform, match_id.to_ascii_lowercase()))
+}
+
+/// Match references in pasted text, separated by whitespace or commas, without repeats. The
+/// second value lists the pieces that couldn't be read as a match.
+pub fn parse_match_list(text: &str) -> (Vec<(String, String)>, Vec<String>) {
+    let mut matches: Vec<(String, String)> = Vec::new();
+    let mut invalid = Vec::new();
+    for token in text.split(|ch: char| ch.is_whitespace() || ch == ',' || ch == ';').filter(|token| !token.is_empty()) {
+        match parse_match_reference(token) {
+            Some(reference) if !matches.contains(&reference) => matches.push(reference),
+            Some(_) => {}
+            None => invalid.push(token.to_string()),
+        }
+    }
+    (matches, invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_file_name_reads_archive_names() {
+        assert_eq!(
+            parse_file_name("steam-0d75985d99c94309570dc1951c2442f9.json"),
+            Some(("steam".to_string(), "0d75985d99c94309570dc1951c2442f9".to_string()))
+        );
+        assert_eq!(parse_file_name("index.json"), None);
+        assert_eq!(parse_file_name("xbox-0d75985d99c94309.json"), None);
+    }
+
+    #[test]
+    fn parse_file_name_rejects_paths() {
+        for name in ["steam-../../../x.json", "steam-0d75985d/../x.json", "steam-0d75985d\\x.json", "epic-.json"] {
+            assert_eq!(parse_file_name(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn insert_rejects_ids_that_are_not_hex() {
+        let dir = std::env::temp_dir().join(format!("supervive-archive-test-{}", std::process::id()));
+        let mut archive = MatchArchive::new(dir.clone());
+        assert!(archive.insert("steam", "../../escape", &Value::Array(Vec::new())).is_err());
+        assert!(!dir.parent().unwrap().join("escape.json").exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn parse_match_reference_table() {
+        const ID: &str = "0d75985d99c94309570dc1951c2442f9";
+        let cases: &[(&str, Option<(&str, &str)>)] = &[
+            (ID, Some(("steam", ID))),
+            ("0D75985D99C94309570DC1951C2442F9", Some(("steam", ID))),
+            ("steam-0d75985d99c94309570dc1951c2442f9", Some(("steam", ID))),
+            ("Epic-0d75985d99c94309570dc1951c2442f9", Some(("epic", ID))),
+            ("https://op.gg/supervive/matches/steam-0d75985d99c94309570dc1951c2442f9", Some(("steam", ID))),
+            ("https://op.gg/supervive/matches/epic-0d75985d99c94309570dc1951c2442f9/?tab=map#top", Some(("epic", ID))),
+            ("  0d75985d99c94309570dc1951c2442f9  ", Some(("steam", ID))),
+            ("0d75985d-99c9-4309-570d-c1951c2442f9", Some(("steam", "0d75985d-99c9-4309-570d-c1951c2442f9"))),
+            ("", None),
+            ("0d75985", None),
+            ("xbox-0d75985d99c94309570dc1951c2442f9", None),
+            ("steam-", None),
+            ("https://op.gg/supervive/matches/", None),
+            ("steam-../../etc/passwd", None),
+        ];
+        for (input, expected) in cases {
+            let expected = expected.map(|(platform, id)| (platform.to_string(), id.to_string()));
+            assert_eq!(parse_match_reference(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn parse_match_list_splits_and_dedups() {
+        let text = "steam-0d75985d99c94309570dc1951c2442f9, 0d75985d99c94309570dc1951c2442f9\nnope;epic-4c9f92e9b8c828bc";
+        let (matches, invalid) = parse_match_list(text);
+        assert_eq!(
+            matches,
+            vec![
+                ("steam".to_string(), "0d75985d99c94309570dc1951c2442f9".to_string()),
+                ("epic".to_string(), "4c9f92e9b8c828bc".to_string()),
+            ]
+        );
+        assert_eq!(invalid, vec!["nope".to_string()]);
+    }
+}
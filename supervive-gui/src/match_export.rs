@@ -0,0 +1,216 @@
+use crate::csv;
+use crate::format;
+use crate::session::parse_time;
+use crate::share_image::{self, FONT_FAMILY};
+use crate::theme::team_color_hex;
+use anyhow::Result;
+use chrono::Local;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// Columns every player row starts with, as `(header, field)`, ahead of the match's stats.
+const PLAYER_COLUMNS: [(&str, &str); 6] = [
+    ("team", "team_id"),
+    ("placement", "placement"),
+    ("player", "player_name"),
+    ("player_id", "player_id"),
+    ("hero", "hero"),
+    ("survival_duration", "survival_duration"),
+];
+
+/// Stats highlighted under "Top performers" on the summary image, as `(label, stat)`.
+const TOP_STATS: [(&str, &str); 4] = [
+    ("Most kills", "Kills"),
+    ("Most damage", "HeroEffectiveDamageDone"),
+    ("Most healing", "HealingGiven"),
+    ("Most revives", "Revived"),
+];
+
+/// Width of the summary image; team lines longer than fit are cut short.
+const SUMMARY_WIDTH: usize = 720;
+const SUMMARY_LINE_CHARS: usize = 90;
+const SUMMARY_LINE_HEIGHT: usize = 24;
+
+pub fn downloads_dir() -> PathBuf {
+    dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn export_path(match_id: &str, extension: &str) -> PathBuf {
+    let short_id: String = match_id.chars().take(8).collect();
+    downloads_dir().join(format!(
+        "supervive-match-{}-{}.{}",
+        short_id,
+        Local::now().format("%Y%m%d-%H%M%S"),
+        extension
+    ))
+}
+
+fn player_name(row: &Value) -> &str {
+    row["player"]["unique_display_name"].as_str().unwrap_or("Unknown")
+}
+
+fn player_field(row: &Value, field: &str) -> Value {
+    match field {
+        "player_name" => row["player"]["unique_display_name"].clone(),
+        "hero" => row["hero"]["name"].clone(),
+        field => row[field].clone(),
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        // Numbers and booleans as JSON writes them; nested values kept whole
+        value => value.to_string(),
+    }
+}
+
+/// The full scoreboard as CSV: one line per player in the given order, with every stat any
+/// player reported as its own column.
+pub fn scoreboard_csv(rows: &[&Value]) -> String {
+    let stats: BTreeSet<&str> = rows
+        .iter()
+        .filter_map(|row| row["stats"].as_object())
+        .flat_map(|stats| stats.keys().map(String::as_str))
+        .collect();
+    let header = PLAYER_COLUMNS.iter().map(|(header, _)| *header).chain(stats.iter().copied());
+    let mut text = csv::row(header);
+    text.push('\n');
+    for row in rows {
+        let fields = PLAYER_COLUMNS
+            .iter()
+            .map(|(_, field)| cell(&player_field(row, field)))
+            .chain(stats.iter().map(|stat| cell(&row["stats"][*stat])));
+        text.push_str(&csv::row(fields));
+        text.push('\n');
+    }
+    text
+}
+
+/// Writes the scoreboard as CSV to the downloads folder and returns its path.
+pub fn export_csv(match_id: &str, rows: &[&Value]) -> Result<PathBuf> {
+    let path = export_path(match_id, "csv");
+    fs::write(&path, scoreboard_csv(rows))?;
+    Ok(path)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(max_chars - 1).collect::<String>())
+    }
+}
+
+/// A compact card for sharing scrim results: each team on one line in placement order, then the
+/// best player for a few headline stats. `rows` must be in scoreboard order.
+pub fn summary_svg(match_id: &str, rows: &[&Value]) -> String {
+    let mut teams: Vec<(i64, Option<i64>, Vec<&Value>)> = Vec::new();
+    for &row in rows {
+        let team_id = row["team_id"].as_i64().unwrap_or(0);
+        match teams.last_mut() {
+            Some((id, _, players)) if *id == team_id => players.push(row),
+            _ => teams.push((team_id, row["placement"].as_i64(), vec![row])),
+        }
+    }
+    let top: Vec<(&str, &Value, f64)> = TOP_STATS
+        .iter()
+        .filter_map(|(label, stat)| {
+            rows.iter()
+                .filter_map(|row| row["stats"][*stat].as_f64().map(|value| (*row, value)))
+                .filter(|(_, value)| *value > 0.0)
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(row, value)| (*label, row, value))
+        })
+        .collect();
+
+    let teams_top = 96;
+    let top_heading = teams_top + teams.len() * SUMMARY_LINE_HEIGHT + 20;
+    let height = top_heading + 12 + top.len() * SUMMARY_LINE_HEIGHT + 24;
+    let mut svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="{font}"><rect width="100%" height="100%" rx="12" fill="#1e1e2e"/>"##,
+        width = SUMMARY_WIDTH,
+        height = height,
+        font = FONT_FAMILY,
+    );
+    let short_id: String = match_id.chars().take(8).collect();
+    svg.push_str(&format!(
+        r##"<text x="24" y="40" font-size="22" font-weight="bold" fill="#cdd6f4">Match {}</text>"##,
+        share_image::escape(&short_id)
+    ));
+    let start = rows.iter().find_map(|row| parse_time(&row["match_start"]));
+    let subtitle = match start {
+        Some(start) => format!("{} · {} players · {} teams", format::date_time(&start), rows.len(), teams.len()),
+        None => format!("{} players · {} teams", rows.len(), teams.len()),
+    };
+    svg.push_str(&format!(
+        r##"<text x="24" y="66" font-size="13" fill="#9399b2">{}</text>"##,
+        share_image::escape(&subtitle)
+    ));
+
+    for (idx, (team_id, placement, players)) in teams.iter().enumerate() {
+        let y = teams_top + idx * SUMMARY_LINE_HEIGHT;
+        let names: Vec<String> = players
+            .iter()
+            .map(|row| match row["hero"]["name"].as_str() {
+                Some(hero) => format!("{} ({})", player_name(row), hero),
+                None => player_name(row).to_string(),
+            })
+            .collect();
+        let kills: i64 = players.iter().map(|row| row["stats"]["Kills"].as_i64().unwrap_or(0)).sum();
+        svg.push_str(&format!(
+            r##"<circle cx="30" cy="{}" r="5" fill="#{:06x}"/>"##,
+            y - 5,
+            team_color_hex(*team_id)
+        ));
+        svg.push_str(&format!(
+            r##"<text x="44" y="{}" font-size="14" font-weight="bold" fill="#cdd6f4">{}</text>"##,
+            y,
+            placement.map(|placement| format!("#{}", placement)).unwrap_or_else(|| "—".to_string())
+        ));
+        svg.push_str(&format!(
+            r##"<text x="84" y="{}" font-size="13" fill="#bac2de">{}</text>"##,
+            y,
+            share_image::escape(&truncate(&names.join(", "), SUMMARY_LINE_CHARS))
+        ));
+        svg.push_str(&format!(
+            r##"<text x="{}" y="{}" font-size="13" text-anchor="end" fill="#9399b2">{} kills</text>"##,
+            SUMMARY_WIDTH - 24,
+            y,
+            format::integer(kills)
+        ));
+    }
+
+    svg.push_str(&format!(
+        r##"<text x="24" y="{}" font-size="15" font-weight="bold" fill="#cdd6f4">Top performers</text>"##,
+        top_heading
+    ));
+    for (idx, (label, row, value)) in top.iter().enumerate() {
+        let y = top_heading + 12 + (idx + 1) * SUMMARY_LINE_HEIGHT;
+        svg.push_str(&format!(
+            r##"<text x="24" y="{}" font-size="13" fill="#9399b2">{}</text><text x="160" y="{}" font-size="13" fill="#cdd6f4">{}</text><text x="{}" y="{}" font-size="13" text-anchor="end" font-weight="bold" fill="#{:06x}">{}</text>"##,
+            y,
+            label,
+            y,
+            share_image::escape(player_name(row)),
+            SUMMARY_WIDTH - 24,
+            y,
+            team_color_hex(row["team_id"].as_i64().unwrap_or(0)),
+            format::compact(*value)
+        ));
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Renders the summary card to a PNG in the downloads folder and returns its path.
+pub fn export_summary_png(match_id: &str, rows: &[&Value]) -> Result<PathBuf> {
+    let path = export_path(match_id, "png");
+    share_image::save_png(&summary_svg(match_id, rows), &path)?;
+    Ok(path)
+}
@@ -0,0 +1,249 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Matches with this tag wait in the review queue until cleared.
+pub const REVIEW_LATER: &str = "review later";
+/// Tags offered on every match; any other tag can be typed in.
+pub const PRESET_TAGS: [&str; 3] = ["threw", "great rotate", REVIEW_LATER];
+
+/// Steps of reviewing a queued match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChecklistItem {
+    WatchedVod,
+    NotedMistakes,
+}
+
+impl ChecklistItem {
+    pub const ALL: [ChecklistItem; 2] = [ChecklistItem::WatchedVod, ChecklistItem::NotedMistakes];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChecklistItem::WatchedVod => "Watched VOD",
+            ChecklistItem::NotedMistakes => "Noted mistakes",
+        }
+    }
+}
+
+/// The user's tags and review note on one match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchNote {
+    pub match_id: String,
+    pub platform: String,
+    pub tags: Vec<String>,
+    pub note: String,
+    /// Review steps done while the match is queued
+    #[serde(default)]
+    pub checked: Vec<ChecklistItem>,
+    /// When the match started, copied from the scoreboard so the list can be sorted offline
+    pub match_start: Option<DateTime<Utc>>,
+    /// Hero and placement of the user's own account, when it played in the match
+    pub hero: Option<String>,
+    pub placement: Option<i64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MatchNote {
+    pub fn in_review_queue(&self) -> bool {
+        self.tags.iter().any(|tag| tag == REVIEW_LATER)
+    }
+
+    pub fn is_checked(&self, item: ChecklistItem) -> bool {
+        self.checked.contains(&item)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.note.trim().is_empty()
+    }
+}
+
+/// Details of a match recorded alongside a new note.
+#[derive(Debug, Clone, Default)]
+pub struct MatchContext {
+    pub match_start: Option<DateTime<Utc>>,
+    pub hero: Option<String>,
+    pub placement: Option<i64>,
+}
+
+/// Tags and notes on matches, kept on this machine next to the player archive. A note that loses
+/// all its tags and text stays behind empty, so sync can carry the removal to other machines.
+pub struct MatchNotes {
+    path: PathBuf,
+    notes: HashMap<String, MatchNote>,
+}
+
+fn key(platform: &str, match_id: &str) -> String {
+    format!("{}:{}", platform, match_id)
+}
+
+impl MatchNotes {
+    pub fn default_path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui")
+            .join("match_notes.json")
+    }
+
+    pub fn new(path: PathBuf) -> Self {
+        let mut notes = Self {
+            path,
+            notes: HashMap::new(),
+        };
+        notes.load();
+        notes
+    }
+
+    fn load(&mut self) {
+        if let Ok(file) = fs::File::open(&self.path) {
+            if let Ok(notes) = serde_json::from_reader(file) {
+                self.notes = notes;
+            }
+        }
+    }
+
+    fn save(&self) {
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(file) = fs::File::create(&self.path) {
+            let _ = serde_json::to_writer_pretty(file, &self.notes);
+        }
+    }
+
+    pub fn get(&self, platform: &str, match_id: &str) -> Option<MatchNote> {
+        self.notes
+            .get(&key(platform, match_id))
+            .filter(|note| !note.is_empty())
+            .cloned()
+    }
+
+    /// Copy of every note, emptied ones included, keyed like the on-disk file.
+    pub fn snapshot(&self) -> HashMap<String, MatchNote> {
+        self.notes.clone()
+    }
+
+    /// Adds notes from another machine or an imported backup, keeping whichever copy was edited
+    /// more recently. Returns how many entries were added or updated.
+    pub fn merge(&mut self, notes: HashMap<String, MatchNote>) -> usize {
+        let mut changed = 0;
+        for (key, note) in notes {
+            let is_newer = self
+                .notes
+                .get(&key)
+                .is_none_or(|existing| existing.updated_at < note.updated_at);
+            if is_newer {
+                self.notes.insert(key, note);
+                changed += 1;
+            }
+        }
+        if changed > 0 {
+            self.save();
+        }
+        changed
+    }
+
+    /// Applies `f` to the match's note, creating it from `context` if needed. Notes left without
+    /// tags or text are hidden from then on.
+    fn edit(&mut self, platform: &str, match_id: &str, context: MatchContext, f: impl FnOnce(&mut MatchNote)) {
+        let key = key(platform, match_id);
+        let note = self.notes.entry(key.clone()).or_insert_with(|| MatchNote {
+            match_id: match_id.to_string(),
+            platform: platform.to_string(),
+            tags: Vec::new(),
+            note: String::new(),
+            checked: Vec::new(),
+            match_start: context.match_start,
+            hero: context.hero,
+            placement: context.placement,
+            updated_at: Utc::now(),
+        });
+        f(note);
+        note.updated_at = Utc::now();
+        self.save();
+    }
+
+    /// Adds the tag to the match, or removes it if the match already has it.
+    pub fn toggle_tag(&mut self, platform: &str, match_id: &str, tag: &str, context: MatchContext) {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            return;
+        }
+        self.edit(platform, match_id, context, |note| {
+            if note.tags.contains(&tag) {
+                note.tags.retain(|existing| *existing != tag);
+                if tag == REVIEW_LATER {
+                    note.checked.clear();
+                }
+            } else {
+                note.tags.push(tag);
+            }
+        });
+    }
+
+    /// Ticks or unticks a review step on a match that already has a note.
+    pub fn toggle_checklist(&mut self, platform: &str, match_id: &str, item: ChecklistItem) {
+        let Some(note) = self.notes.get_mut(&key(platform, match_id)) else {
+            return;
+        };
+        if note.is_checked(item) {
+            note.checked.retain(|checked| *checked != item);
+        } else {
+            note.checked.push(item);
+        }
+        note.updated_at = Utc::now();
+        self.save();
+    }
+
+    /// Takes the match out of the review queue, keeping its other tags and note.
+    pub fn clear_review(&mut self, platform: &str, match_id: &str) {
+        if self.notes.contains_key(&key(platform, match_id)) {
+            self.edit(platform, match_id, MatchContext::default(), |note| {
+                note.tags.retain(|tag| tag != REVIEW_LATER);
+                note.checked.clear();
+            });
+        }
+    }
+
+    /// Matches waiting for review, oldest match first.
+    pub fn review_queue(&self) -> Vec<MatchNote> {
+        let mut queue = self.list(Some(REVIEW_LATER));
+        queue.reverse();
+        queue
+    }
+
+    pub fn set_note(&mut self, platform: &str, match_id: &str, text: &str, context: MatchContext) {
+        self.edit(platform, match_id, context, |note| note.note = text.trim().to_string());
+    }
+
+    /// Every tag in use, presets first, then the rest alphabetically.
+    pub fn all_tags(&self) -> Vec<String> {
+        let mut custom: Vec<String> = self
+            .notes
+            .values()
+            .flat_map(|note| note.tags.iter().cloned())
+            .filter(|tag| !PRESET_TAGS.contains(&tag.as_str()))
+            .collect();
+        custom.sort();
+        custom.dedup();
+        PRESET_TAGS.iter().map(|tag| tag.to_string()).chain(custom).collect()
+    }
+
+    /// Notes on matches with `tag` (or with any tag or note when `None`), most recent match first.
+    pub fn list(&self, tag: Option<&str>) -> Vec<MatchNote> {
+        let mut notes: Vec<MatchNote> = self
+            .notes
+            .values()
+            .filter(|note| !note.is_empty())
+            .filter(|note| tag.is_none_or(|tag| note.tags.iter().any(|existing| existing == tag)))
+            .cloned()
+            .collect();
+        notes.sort_by(|a, b| {
+            b.match_start
+                .unwrap_or(b.updated_at)
+                .cmp(&a.match_start.unwrap_or(a.updated_at))
+        });
+        notes
+    }
+}
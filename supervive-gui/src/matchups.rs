@@ -0,0 +1,65 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Average placement this much worse than usual marks a problem matchup.
+const PROBLEM_PLACEMENT_DELTA: f64 = 2.0;
+
+/// How the player fares in lobbies where an enemy plays `hero`.
+#[derive(Debug, Clone)]
+pub struct HeroMatchup {
+    pub hero: String,
+    pub games: usize,
+    pub wins: usize,
+    pub avg_placement: f64,
+}
+
+impl HeroMatchup {
+    /// At least `min_games` games and a clearly worse average placement than `baseline`.
+    pub fn is_problem(&self, baseline: f64, min_games: usize) -> bool {
+        self.games >= min_games && self.avg_placement - baseline >= PROBLEM_PLACEMENT_DELTA
+    }
+}
+
+/// The player's own row in a match's participant rows. Ids are compared without dashes since
+/// profile URLs and match rows don't agree on the format.
+pub fn own_row<'a>(player_id: &str, rows: &'a [Value]) -> Option<&'a Value> {
+    let player_id = player_id.replace('-', "");
+    rows.iter()
+        .find(|row| row["player_id"].as_str().is_some_and(|id| id.replace('-', "") == player_id))
+}
+
+/// Placement and result per enemy hero across analyzed matches, worst average placement first.
+/// Each hero counts once per match even if several enemies picked it.
+pub fn enemy_matchups<'a>(player_id: &str, matches: impl Iterator<Item = &'a Vec<Value>>) -> Vec<HeroMatchup> {
+    let mut by_hero: HashMap<String, HeroMatchup> = HashMap::new();
+    for rows in matches {
+        let Some(own) = own_row(player_id, rows) else {
+            continue;
+        };
+        let Some(placement) = own["placement"].as_i64() else {
+            continue;
+        };
+        let mut enemies: Vec<&str> = rows
+            .iter()
+            .filter(|row| row["team_id"] != own["team_id"])
+            .filter_map(|row| row["hero"]["name"].as_str())
+            .collect();
+        enemies.sort();
+        enemies.dedup();
+        for hero in enemies {
+            let matchup = by_hero.entry(hero.to_string()).or_insert_with(|| HeroMatchup {
+                hero: hero.to_string(),
+                games: 0,
+                wins: 0,
+                avg_placement: 0.0,
+            });
+            // Running mean so the struct holds the final average when the loop ends
+            matchup.games += 1;
+            matchup.wins += usize::from(placement == 1);
+            matchup.avg_placement += (placement as f64 - matchup.avg_placement) / matchup.games as f64;
+        }
+    }
+    let mut matchups: Vec<HeroMatchup> = by_hero.into_values().collect();
+    matchups.sort_by(|a, b| b.avg_placement.total_cmp(&a.avg_placement));
+    matchups
+}
@@ -0,0 +1,235 @@
+use crate::history_totals::HistoryTotals;
+use crate::match_archive::MatchArchive;
+use crate::session::parse_time;
+use crate::settings::PlayerRef;
+use crate::tiles::in_region;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Weeks the trend chart covers, ending with the current one.
+pub const TREND_WEEKS: i64 = 8;
+
+/// The archived scoreboard rows of the followed players played in `region`.
+pub fn followed_rows(archive: &MatchArchive, followed: &[PlayerRef], region: Option<&str>) -> Vec<Value> {
+    let ids: HashSet<String> = followed
+        .iter()
+        .map(|player| player.player_id.replace('-', "").to_ascii_lowercase())
+        .collect();
+    let mut rows = Vec::new();
+    for entry in archive.entries() {
+        let Some(Value::Array(scoreboard)) = archive.get(&entry.platform, &entry.match_id) else {
+            continue;
+        };
+        rows.extend(scoreboard.into_iter().filter(|row| {
+            row["player_id"]
+                .as_str()
+                .is_some_and(|id| ids.contains(&id.replace('-', "").to_ascii_lowercase()))
+                && in_region(row, region)
+        }));
+    }
+    rows
+}
+
+#[derive(Debug, Clone)]
+pub struct HeroMeta {
+    pub hero: String,
+    pub games: usize,
+    pub wins: usize,
+    pub avg_placement: f64,
+}
+
+/// What the followed players pick, over their archived games.
+#[derive(Debug, Clone, Default)]
+pub struct MetaSnapshot {
+    /// Placed games across every followed player; one match counts once per followed player in it
+    pub games: usize,
+    /// Distinct matches
+    pub matches: usize,
+    /// Most picked first
+    pub heroes: Vec<HeroMeta>,
+    /// The last `TREND_WEEKS` weeks that had games, oldest first
+    pub weeks: Vec<WeekTotals>,
+    /// Items per hero, most taken first; empty while payloads carry no loadouts
+    pub items: HashMap<String, Vec<ItemMeta>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemMeta {
+    pub item: String,
+    /// Games on the hero that ended with the item
+    pub games: usize,
+    pub wins: usize,
+}
+
+/// Item names from a match row's optional `items` loadout (`["name"]` or `[{name}]`), each once.
+/// Empty when the payload doesn't include it.
+pub fn row_items(row: &Value) -> Vec<String> {
+    let mut items: Vec<String> = row["items"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|item| item.as_str().or_else(|| item["name"].as_str()))
+        .map(str::to_string)
+        .collect();
+    items.sort();
+    items.dedup();
+    items
+}
+
+fn item_totals(rows: &[Value]) -> HashMap<String, Vec<ItemMeta>> {
+    let mut totals: HashMap<String, HashMap<String, (usize, usize)>> = HashMap::new();
+    for row in rows {
+        let (Some(hero), Some(placement)) = (row["hero"]["name"].as_str(), row["placement"].as_i64()) else {
+            continue;
+        };
+        for item in row_items(row) {
+            let entry = totals.entry(hero.to_string()).or_default().entry(item).or_default();
+            entry.0 += 1;
+            if placement == 1 {
+                entry.1 += 1;
+            }
+        }
+    }
+    totals
+        .into_iter()
+        .map(|(hero, items)| {
+            let mut items: Vec<ItemMeta> = items
+                .into_iter()
+                .map(|(item, (games, wins))| ItemMeta { item, games, wins })
+                .collect();
+            items.sort_by(|a, b| b.games.cmp(&a.games).then_with(|| a.item.cmp(&b.item)));
+            (hero, items)
+        })
+        .collect()
+}
+
+/// Placed games in one week, overall and per hero.
+#[derive(Debug, Clone)]
+pub struct WeekTotals {
+    /// The Monday the week starts on
+    pub start: NaiveDate,
+    pub games: usize,
+    /// Games and wins per hero
+    pub heroes: HashMap<String, (usize, usize)>,
+}
+
+/// One hero's share of a week.
+#[derive(Debug, Clone)]
+pub struct HeroWeek {
+    pub start: NaiveDate,
+    pub games: usize,
+    pub pick_rate: f64,
+    pub win_rate: f64,
+}
+
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+fn weekly_totals(rows: &[Value]) -> Vec<WeekTotals> {
+    let first_week = week_start(Local::now().date_naive()) - Duration::weeks(TREND_WEEKS - 1);
+    let mut weeks: BTreeMap<NaiveDate, WeekTotals> = BTreeMap::new();
+    for row in rows {
+        let (Some(time), Some(placement)) = (parse_time(&row["match_start"]), row["placement"].as_i64()) else {
+            continue;
+        };
+        let start = week_start(time.with_timezone(&Local).date_naive());
+        if start < first_week {
+            continue;
+        }
+        let week = weeks.entry(start).or_insert_with(|| WeekTotals {
+            start,
+            games: 0,
+            heroes: HashMap::new(),
+        });
+        week.games += 1;
+        if let Some(hero) = row["hero"]["name"].as_str() {
+            let hero = week.heroes.entry(hero.to_string()).or_default();
+            hero.0 += 1;
+            if placement == 1 {
+                hero.1 += 1;
+            }
+        }
+    }
+    weeks.into_values().collect()
+}
+
+impl MetaSnapshot {
+    pub fn from_rows(rows: &[Value]) -> Self {
+        let totals = HistoryTotals::from_rows(rows);
+        let mut heroes: Vec<HeroMeta> = totals
+            .heroes
+            .iter()
+            .map(|(hero, totals)| HeroMeta {
+                hero: hero.clone(),
+                games: totals.games,
+                wins: totals.wins,
+                avg_placement: totals.avg_placement(),
+            })
+            .collect();
+        heroes.sort_by(|a, b| b.games.cmp(&a.games).then(a.avg_placement.total_cmp(&b.avg_placement)));
+        Self {
+            games: totals.games,
+            matches: rows
+                .iter()
+                .filter_map(|row| row["match_id"].as_str())
+                .collect::<HashSet<_>>()
+                .len(),
+            heroes,
+            weeks: weekly_totals(rows),
+            items: item_totals(rows),
+        }
+    }
+
+    /// The hero's pick and win rate in each week of `weeks`.
+    pub fn hero_trend(&self, hero: &str) -> Vec<HeroWeek> {
+        self.weeks
+            .iter()
+            .map(|week| {
+                let (games, wins) = week.heroes.get(hero).copied().unwrap_or_default();
+                HeroWeek {
+                    start: week.start,
+                    games,
+                    pick_rate: games as f64 / week.games.max(1) as f64,
+                    win_rate: wins as f64 / games.max(1) as f64,
+                }
+            })
+            .collect()
+    }
+
+    /// Share of the followed players' games played on the hero.
+    pub fn pick_rate(&self, hero: &HeroMeta) -> f64 {
+        hero.games as f64 / self.games.max(1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn row_items_accepts_names_and_objects_once_each() {
+        let row = json!({ "items": ["Winged Boots", { "name": "Aegis Shard" }, "Winged Boots", { "slot": 3 }, 7] });
+        assert_eq!(row_items(&row), vec!["Aegis Shard".to_string(), "Winged Boots".to_string()]);
+        assert!(row_items(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn item_totals_count_games_and_wins_per_hero() {
+        let rows = vec![
+            json!({ "hero": { "name": "Ghost" }, "placement": 1, "items": ["Scope", "Boots"] }),
+            json!({ "hero": { "name": "Ghost" }, "placement": 4, "items": ["Boots"] }),
+            json!({ "hero": { "name": "Joule" }, "placement": 2, "items": [] }),
+            json!({ "placement": 1, "items": ["Boots"] }),
+        ];
+        let totals = item_totals(&rows);
+        assert!(!totals.contains_key("Joule"));
+        let ghost = &totals["Ghost"];
+        let boots = ghost.iter().find(|item| item.item == "Boots").unwrap();
+        assert_eq!((boots.games, boots.wins), (2, 1));
+        let scope = ghost.iter().find(|item| item.item == "Scope").unwrap();
+        assert_eq!((scope.games, scope.wins), (1, 1));
+    }
+}
@@ -0,0 +1,139 @@
+use anyhow::Result;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Payload shape sent to a webhook URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WebhookFormat {
+    /// The raw `{ player, match }` object, for custom bots and overlays
+    #[default]
+    Json,
+    /// A Discord webhook message with a single embed
+    Discord,
+}
+
+/// A URL notified whenever a watched player finishes a match that passes its filters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Webhook {
+    pub url: String,
+    pub format: WebhookFormat,
+    pub enabled: bool,
+    /// Skip unranked matches
+    pub ranked_only: bool,
+    /// Only notify for placements at or above this (e.g. 4 for top-4 finishes)
+    pub max_placement: Option<i64>,
+}
+
+impl Default for Webhook {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            format: WebhookFormat::default(),
+            enabled: true,
+            ranked_only: false,
+            max_placement: None,
+        }
+    }
+}
+
+impl Webhook {
+    pub fn accepts(&self, row: &Value) -> bool {
+        if !self.enabled || self.url.is_empty() {
+            return false;
+        }
+        if self.ranked_only && !row["is_ranked"].as_bool().unwrap_or(false) {
+            return false;
+        }
+        match (self.max_placement, row["placement"].as_i64()) {
+            (Some(max), Some(placement)) => placement <= max,
+            (Some(_), None) => false,
+            (None, _) => true,
+        }
+    }
+
+    fn payload(&self, player_name: &str, row: &Value) -> Value {
+        match self.format {
+            WebhookFormat::Json => json!({ "player": player_name, "match": row }),
+            WebhookFormat::Discord => discord_embed(player_name, row),
+        }
+    }
+}
+
+fn discord_embed(player_name: &str, row: &Value) -> Value {
+    let placement = row["placement"].as_i64().unwrap_or(0);
+    let stat = |key: &str| row["stats"][key].as_i64().unwrap_or(0);
+    // Gold for wins, green for top-4, grey otherwise (Discord colors are decimal RGB)
+    let color = match placement {
+        1 => 0xf9e2af,
+        2..=4 => 0xa6e3a1,
+        _ => 0x6c7086,
+    };
+
+    json!({
+        "embeds": [{
+            "title": format!("{} finished #{}", player_name, placement),
+            "description": format!(
+                "{} — {}/{}/{}",
+                row["hero"]["name"].as_str().unwrap_or("Unknown hero"),
+                stat("Kills"),
+                stat("Deaths"),
+                stat("Assists"),
+            ),
+            "color": color,
+            "fields": [
+                { "name": "Damage", "value": stat("HeroEffectiveDamageDone").to_string(), "inline": true },
+                { "name": "Healing", "value": stat("HealingGiven").to_string(), "inline": true },
+                {
+                    "name": "Queue",
+                    "value": if row["is_ranked"].as_bool().unwrap_or(false) { "Ranked" } else { "Unranked" },
+                    "inline": true,
+                },
+            ],
+            "timestamp": row["match_end"],
+        }]
+    })
+}
+
+/// Posts finished matches of watched players to the configured webhooks.
+pub struct Notifier {
+    client: Client,
+    webhooks: Vec<Webhook>,
+}
+
+impl Notifier {
+    pub fn new(webhooks: Vec<Webhook>) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self { client, webhooks }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.webhooks.iter().all(|webhook| !webhook.enabled)
+    }
+
+    /// Sends the match to every webhook whose filters accept it. Failures are logged per
+    /// webhook so one broken URL doesn't block the others.
+    pub fn notify(&self, player_name: &str, row: &Value) {
+        for webhook in self.webhooks.iter().filter(|webhook| webhook.accepts(row)) {
+            if let Err(err) = self.post(webhook, player_name, row) {
+                tracing::warn!(error = %err, "Webhook request failed");
+            }
+        }
+    }
+
+    /// Errors leave out the URL: webhook URLs carry their token and errors end up in the log.
+    fn post(&self, webhook: &Webhook, player_name: &str, row: &Value) -> Result<()> {
+        self.client
+            .post(&webhook.url)
+            .json(&webhook.payload(player_name, row))
+            .send()
+            .and_then(|resp| resp.error_for_status())
+            .map_err(reqwest::Error::without_url)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use crate::format;
+use crate::history_totals::HistoryTotals;
+use crate::markdown;
+use crate::match_archive::MatchArchive;
+use crate::match_export::downloads_dir;
+use crate::player_index::IndexedPlayer;
+use crate::session::parse_time;
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// Largest roster a report covers: one enemy squad.
+pub const MAX_ROSTER: usize = 4;
+/// History pages fetched per player, on top of what the match archive holds.
+pub const HISTORY_PAGES: i32 = 3;
+/// Heroes listed per player.
+const TOP_HEROES: usize = 3;
+/// Placements shown as recent form.
+const RECENT_FORM_GAMES: usize = 10;
+
+fn same_player(a: &str, b: &str) -> bool {
+    a.replace('-', "").eq_ignore_ascii_case(&b.replace('-', ""))
+}
+
+/// The archived scoreboard rows of every roster player, in one pass over the archive so each
+/// match file is read once.
+pub fn archive_rows(archive: &MatchArchive, roster: &[IndexedPlayer]) -> Vec<Vec<Value>> {
+    let mut rows = vec![Vec::new(); roster.len()];
+    for entry in archive.entries() {
+        let Some(Value::Array(scoreboard)) = archive.get(&entry.platform, &entry.match_id) else {
+            continue;
+        };
+        for row in scoreboard {
+            let Some(id) = row["player_id"].as_str() else {
+                continue;
+            };
+            if let Some(idx) = roster.iter().position(|player| same_player(&player.player_id, id)) {
+                rows[idx].push(row);
+            }
+        }
+    }
+    rows
+}
+
+#[derive(Debug, Clone)]
+pub struct HeroLine {
+    pub hero: String,
+    pub games: usize,
+    pub avg_placement: f64,
+}
+
+/// One opponent's section of the report.
+#[derive(Debug, Clone)]
+pub struct PlayerReport {
+    pub profile: IndexedPlayer,
+    pub rank: Option<String>,
+    pub games: usize,
+    pub avg_placement: f64,
+    pub kd_ratio: f64,
+    /// Most played first
+    pub heroes: Vec<HeroLine>,
+    /// Placements of the latest games, most recent first
+    pub recent: Vec<i64>,
+}
+
+impl PlayerReport {
+    /// Builds a player's section from their history and archived rows; a match found in both
+    /// counts once.
+    pub fn new(profile: IndexedPlayer, rank: Option<String>, rows: &[Value]) -> Self {
+        let mut seen = HashSet::new();
+        let mut rows: Vec<Value> = rows
+            .iter().filter(|&row| seen.insert(row["match_id"].as_str().unwrap_or_default().to_string())).cloned()
+            .collect();
+        rows.sort_by_key(|row| std::cmp::Reverse(parse_time(&row["match_start"])));
+        let totals = HistoryTotals::from_rows(&rows);
+        let mut heroes: Vec<HeroLine> = totals
+            .heroes
+            .iter()
+            .map(|(hero, totals)| HeroLine {
+                hero: hero.clone(),
+                games: totals.games,
+                avg_placement: totals.avg_placement(),
+            })
+            .collect();
+        heroes.sort_by(|a, b| b.games.cmp(&a.games).then(a.avg_placement.total_cmp(&b.avg_placement)));
+        heroes.truncate(TOP_HEROES);
+        Self {
+            profile,
+            rank,
+            games: totals.games,
+            avg_placement: totals.avg_placement(),
+            kd_ratio: totals.kd_ratio(),
+            heroes,
+            recent: rows.iter().filter_map(|row| row["placement"].as_i64()).take(RECENT_FORM_GAMES).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OpponentReport {
+    pub generated_at: DateTime<Utc>,
+    pub players: Vec<PlayerReport>,
+    /// Matches where at least two of the roster were on the same team
+    pub games_together: usize,
+    /// Average placement in those matches
+    pub together_avg_placement: Option<f64>,
+}
+
+impl OpponentReport {
+    /// `rows` holds each player's matches, in roster order.
+    pub fn new(players: Vec<PlayerReport>, rows: &[Vec<Value>]) -> Self {
+        // (match, team) -> roster players on it, and the team's placement
+        let mut teams: HashMap<(String, i64), (HashSet<usize>, Option<i64>)> = HashMap::new();
+        for (idx, player_rows) in rows.iter().enumerate() {
+            for row in player_rows {
+                let (Some(match_id), Some(team)) = (row["match_id"].as_str(), row["team_id"].as_i64()) else {
+                    continue;
+                };
+                let entry = teams.entry((match_id.to_string(), team)).or_default();
+                entry.0.insert(idx);
+                entry.1 = entry.1.or(row["placement"].as_i64());
+            }
+        }
+        let together: Vec<Option<i64>> = teams
+            .into_values()
+            .filter(|(players, _)| players.len() >= 2)
+            .map(|(_, placement)| placement)
+            .collect();
+        let placements: Vec<i64> = together.iter().flatten().copied().collect();
+        Self {
+            generated_at: Utc::now(),
+            players,
+            games_together: together.len(),
+            together_avg_placement: (!placements.is_empty())
+                .then(|| placements.iter().sum::<i64>() as f64 / placements.len() as f64),
+        }
+    }
+
+    /// The report as Markdown, readable as plain text and printable from any Markdown viewer.
+    pub fn to_markdown(&self) -> String {
+        let names: Vec<&str> = self.players.iter().map(|player| player.profile.display_name.as_str()).collect();
+        let mut text = format!("# Scouting report: {}\n\n", names.join(", "));
+        text.push_str(&format!(
+            "Generated {}.\n\n",
+            format::date_time(&self.generated_at.with_timezone(&Local))
+        ));
+
+        let overview: Vec<Vec<String>> = self
+            .players
+            .iter()
+            .map(|player| {
+                vec![
+                    player.profile.unique_display_name.clone(),
+                    player.rank.clone().unwrap_or_else(|| "Unranked".to_string()),
+                    format::integer(player.games as i64),
+                    format!("#{}", format::decimal(player.avg_placement, 1)),
+                    format::decimal(player.kd_ratio, 2),
+                    player.heroes.first().map(|hero| hero.hero.clone()).unwrap_or_default(),
+                ]
+            })
+            .collect();
+        text.push_str("## Overview\n\n");
+        text.push_str(&markdown::table(&["Player", "Rank", "Games", "Avg place", "K/D", "Main"], &overview));
+
+        text.push_str("\n## Together\n\n");
+        match self.together_avg_placement {
+            Some(avg) if self.games_together > 0 => text.push_str(&format!(
+                "{} games with two or more of them on one team, averaging #{}.\n",
+                self.games_together,
+                format::decimal(avg, 1)
+            )),
+            _ => text.push_str("No games found with two or more of them on one team.\n"),
+        }
+
+        for player in &self.players {
+            text.push_str(&format!("\n## {}\n\n", markdown::escape(&player.profile.unique_display_name)));
+            let heroes: Vec<Vec<String>> = player
+                .heroes
+                .iter()
+                .map(|hero| {
+                    vec![
+                        hero.hero.clone(),
+                        format::integer(hero.games as i64),
+                        format!("#{}", format::decimal(hero.avg_placement, 1)),
+                    ]
+                })
+                .collect();
+            if heroes.is_empty() {
+                text.push_str("No matches found.\n");
+                continue;
+            }
+            text.push_str(&markdown::table(&["Hero", "Games", "Avg place"], &heroes));
+            let recent: Vec<String> = player.recent.iter().map(|placement| format!("#{}", placement)).collect();
+            text.push_str(&format!("\nRecent form, latest first: {}\n", recent.join(" ")));
+        }
+        text
+    }
+
+    /// Writes the report to the downloads folder and returns its path.
+    pub fn export(&self) -> Result<PathBuf> {
+        let path = downloads_dir().join(format!(
+            "supervive-scouting-{}.md",
+            Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        fs::write(&path, self.to_markdown())?;
+        Ok(path)
+    }
+}
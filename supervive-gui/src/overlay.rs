@@ -0,0 +1,49 @@
+use crate::state::AppState;
+use crate::views::overlay::OverlayView;
+use gpui::*;
+
+const OVERLAY_SIZE: Size<Pixels> = Size {
+    width: px(320.0),
+    height: px(120.0),
+};
+
+/// Opens the session overlay, or closes it if it is already open.
+pub fn toggle(cx: &mut App) {
+    let overlay = cx.global::<AppState>().overlay_window.clone();
+    let existing = overlay.lock().unwrap().take();
+    if let Some(handle) = existing {
+        // Fails if the user already closed the window, in which case we open a new one
+        if handle.update(cx, |_, window, _| window.remove_window()).is_ok() {
+            return;
+        }
+    }
+
+    let options = WindowOptions {
+        titlebar: None,
+        // Pop-up windows stay above normal windows, including the game in borderless mode
+        kind: WindowKind::PopUp,
+        focus: false,
+        is_movable: true,
+        window_bounds: Some(WindowBounds::Windowed(Bounds::new(point(px(40.0), px(40.0)), OVERLAY_SIZE))),
+        ..Default::default()
+    };
+    match cx.open_window(options, |_, cx| cx.new(OverlayView::new)) {
+        Ok(handle) => *overlay.lock().unwrap() = Some(handle.into()),
+        Err(err) => tracing::warn!(error = %err, "Failed to open overlay window"),
+    }
+}
+
+/// Re-fetches the overlay's session stats if it is open. Returns false if it isn't.
+pub fn refresh(cx: &mut App) -> bool {
+    let overlay = *cx.global::<AppState>().overlay_window.lock().unwrap();
+    match overlay.and_then(|window| window.downcast::<OverlayView>()) {
+        Some(window) => window.update(cx, |view, _, cx| view.refresh(cx)).is_ok(),
+        None => false,
+    }
+}
+
+/// Closes the overlay from inside its own window.
+pub fn close(window: &mut Window, cx: &mut App) {
+    cx.global::<AppState>().overlay_window.lock().unwrap().take();
+    window.remove_window();
+}
@@ -0,0 +1,73 @@
+use serde_json::Value;
+
+/// Start of a storm phase, in seconds from the match start.
+#[derive(Debug, Clone, Copy)]
+pub struct Phase {
+    pub number: u32,
+    pub start: f64,
+}
+
+/// Phase timings from a match row's optional `phases` list (`[{phase, start}]`), ordered by start.
+/// Empty when the API didn't include the timeline.
+pub fn phases(row: &Value) -> Vec<Phase> {
+    let mut phases: Vec<Phase> = row["phases"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|phase| {
+            Some(Phase {
+                number: phase["phase"].as_u64()? as u32,
+                start: phase["start"].as_f64()?,
+            })
+        })
+        .collect();
+    phases.sort_by(|a, b| a.start.total_cmp(&b.start));
+    phases
+}
+
+/// Phase the player was eliminated in, from their survival time. `None` for winners and for
+/// matches without phase timings.
+pub fn death_phase(row: &Value) -> Option<u32> {
+    if row["placement"].as_i64() == Some(1) {
+        return None;
+    }
+    let survived = row["survival_duration"].as_f64()?;
+    phases(row)
+        .iter()
+        .rev()
+        .find(|phase| phase.start <= survived)
+        .map(|phase| phase.number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn phases_are_sorted_and_skip_incomplete_entries() {
+        let row = json!({
+            "phases": [
+                { "phase": 2, "start": 180.0 },
+                { "phase": 1, "start": 0.0 },
+                { "phase": 3 },
+                { "start": 400.0 }
+            ]
+        });
+        let parsed = phases(&row);
+        assert_eq!(parsed.iter().map(|phase| phase.number).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(phases(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn death_phase_uses_survival_time() {
+        let timeline = json!([{ "phase": 1, "start": 0.0 }, { "phase": 2, "start": 180.0 }, { "phase": 3, "start": 360.0 }]);
+        let row = |placement: i64, survived: f64| {
+            json!({ "placement": placement, "survival_duration": survived, "phases": timeline })
+        };
+        assert_eq!(death_phase(&row(5, 200.0)), Some(2));
+        assert_eq!(death_phase(&row(5, 360.0)), Some(3));
+        assert_eq!(death_phase(&row(1, 900.0)), None);
+        assert_eq!(death_phase(&json!({ "placement": 4, "survival_duration": 200.0 })), None);
+    }
+}
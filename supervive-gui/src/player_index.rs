@@ -0,0 +1,319 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Players kept on disk; the least recently seen are forgotten past this.
+const MAX_PLAYERS: usize = 20_000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexedPlayer {
+    pub player_id: String,
+    pub platform: String,
+    pub unique_display_name: String,
+    pub display_name: String,
+    pub last_seen: f64,
+}
+
+/// Every player the app has come across (search results, match lists, scoreboards),
+/// persisted to disk so suggestions are available offline and across sessions.
+/// Changes stay in memory until `flush`, which the scheduler calls periodically.
+pub struct PlayerIndex {
+    path: PathBuf,
+    players: HashMap<String, IndexedPlayer>,
+    dirty: bool,
+}
+
+impl PlayerIndex {
+    /// Location shared by the window and the command-line tools.
+    pub fn default_path() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui_players.json")
+    }
+
+    pub fn new(path: PathBuf) -> Self {
+        let mut index = Self {
+            path,
+            players: HashMap::new(),
+            dirty: false,
+        };
+        index.load();
+        index
+    }
+
+    fn load(&mut self) {
+        if let Ok(file) = fs::File::open(&self.path) {
+            if let Ok(players) = serde_json::from_reader(file) {
+                self.players = players;
+            }
+        }
+    }
+
+    /// Writes the index if it changed, trimmed to `MAX_PLAYERS`, through a temporary file so an
+    /// interrupted write never leaves half an index.
+    pub fn flush(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        self.prune();
+        let tmp = self.path.with_extension("json.tmp");
+        let written = fs::File::create(&tmp)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| {
+                let mut writer = std::io::BufWriter::new(file);
+                serde_json::to_writer(&mut writer, &self.players)?;
+                writer.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+                Ok(fs::rename(&tmp, &self.path)?)
+            });
+        if let Err(err) = written {
+            tracing::warn!(error = %err, "Failed to write the player index");
+        }
+    }
+
+    /// Forgets the least recently seen players beyond `MAX_PLAYERS`.
+    fn prune(&mut self) {
+        if self.players.len() <= MAX_PLAYERS {
+            return;
+        }
+        let mut last_seen: Vec<f64> = self.players.values().map(|player| player.last_seen).collect();
+        last_seen.sort_by(|a, b| b.total_cmp(a));
+        let cutoff = last_seen[MAX_PLAYERS - 1];
+        self.players.retain(|_, player| player.last_seen >= cutoff);
+        // Ties at the cutoff can leave a few extra
+        while self.players.len() > MAX_PLAYERS {
+            let Some(key) = self
+                .players
+                .iter()
+                .min_by(|a, b| a.1.last_seen.total_cmp(&b.1.last_seen))
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.players.remove(&key);
+        }
+    }
+
+    fn now() -> f64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64()
+    }
+
+    fn insert(&mut self, platform: &str, player_id: &str, unique_display_name: &str, display_name: &str) {
+        if player_id.is_empty() || unique_display_name.is_empty() {
+            return;
+        }
+        let player_id = player_id.replace('-', "");
+        self.players.insert(
+            format!("{}:{}", platform, player_id),
+            IndexedPlayer {
+                player_id,
+                platform: platform.to_string(),
+                unique_display_name: unique_display_name.to_string(),
+                display_name: display_name.to_string(),
+                last_seen: Self::now(),
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Records players from a `search_players` response.
+    pub fn record_search_results(&mut self, results: &[Value]) {
+        for player in results {
+            self.insert(
+                player["platform"].as_str().unwrap_or("steam"),
+                player["userId"].as_str().unwrap_or(""),
+                player["uniqueDisplayName"].as_str().unwrap_or(""),
+                player["displayName"].as_str().unwrap_or(""),
+            );
+        }
+    }
+
+    /// Records a single player whose id is known from context (e.g. an opened profile).
+    pub fn record_player(&mut self, platform: &str, player_id: &str, unique_display_name: &str, display_name: &str) {
+        self.insert(platform, player_id, unique_display_name, display_name);
+    }
+
+    /// Records players from match scoreboard rows, which carry their own `player_id`.
+    pub fn record_match_rows(&mut self, platform: &str, rows: &[Value]) {
+        for row in rows {
+            let platform = row["platform"]["code"].as_str().unwrap_or(platform);
+            self.insert(
+                platform,
+                row["player_id"].as_str().unwrap_or(""),
+                row["player"]["unique_display_name"].as_str().unwrap_or(""),
+                row["player"]["display_name"].as_str().unwrap_or(""),
+            );
+        }
+    }
+
+    /// Copy of every indexed player, keyed like the on-disk file.
+    pub fn snapshot(&self) -> HashMap<String, IndexedPlayer> {
+        self.players.clone()
+    }
+
+    /// Forgets every indexed player, returning how many were removed.
+    pub fn clear(&mut self) -> usize {
+        let removed = self.players.len();
+        self.players.clear();
+        self.dirty = true;
+        self.flush();
+        removed
+    }
+
+    /// Adds players from another index (e.g. an imported backup), keeping whichever copy was seen
+    /// more recently. Returns how many entries were added or updated.
+    pub fn merge(&mut self, players: HashMap<String, IndexedPlayer>) -> usize {
+        let mut changed = 0;
+        for (key, player) in players {
+            let is_newer = self
+                .players
+                .get(&key)
+                .is_none_or(|existing| existing.last_seen < player.last_seen);
+            if is_newer {
+                self.players.insert(key, player);
+                changed += 1;
+            }
+        }
+        if changed > 0 {
+            self.dirty = true;
+        }
+        changed
+    }
+
+    /// The most recently seen player whose unique or display name equals `name` (case-insensitive).
+    pub fn find_by_name(&self, name: &str) -> Option<IndexedPlayer> {
+        self.players
+            .values()
+            .filter(|player| {
+                player.unique_display_name.eq_ignore_ascii_case(name)
+                    || player.display_name.eq_ignore_ascii_case(name)
+            })
+            .max_by(|a, b| a.last_seen.total_cmp(&b.last_seen))
+            .cloned()
+    }
+
+    /// Returns up to `limit` players whose unique display name fuzzy-matches `query`, best first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<IndexedPlayer> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(i64, &IndexedPlayer)> = self
+            .players
+            .values()
+            .filter_map(|player| {
+                fuzzy_score(query, &player.unique_display_name).map(|score| (score, player))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| b.1.last_seen.total_cmp(&a.1.last_seen))
+        });
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, player)| player.clone())
+            .collect()
+    }
+}
+
+impl Drop for PlayerIndex {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Case-insensitive subsequence match; consecutive runs and prefix matches score higher.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score = 0i64;
+    let mut query_idx = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for (idx, c) in candidate.iter().enumerate() {
+        if query_idx == query.len() {
+            break;
+        }
+        if *c != query[query_idx] {
+            continue;
+        }
+
+        score += 1;
+        if idx == 0 {
+            score += 8;
+        }
+        if previous_match == Some(idx.wrapping_sub(1)) {
+            score += 5;
+        }
+        previous_match = Some(idx);
+        query_idx += 1;
+    }
+
+    if query_idx < query.len() {
+        return None;
+    }
+    // Prefer shorter names when the match quality is otherwise equal
+    Some(score * 10 - candidate.len() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("supervive-{}-{}.json", name, std::process::id()))
+    }
+
+    fn player(id: usize, last_seen: f64) -> (String, IndexedPlayer) {
+        (
+            format!("steam:{}", id),
+            IndexedPlayer {
+                player_id: id.to_string(),
+                platform: "steam".to_string(),
+                unique_display_name: format!("player{}", id),
+                display_name: format!("player{}", id),
+                last_seen,
+            },
+        )
+    }
+
+    #[test]
+    fn index_writes_only_on_flush() {
+        let path = temp_path("index-flush");
+        let _ = fs::remove_file(&path);
+        let mut index = PlayerIndex::new(path.clone());
+        index.record_player("steam", "abc-123", "someone#1", "someone");
+        assert!(!path.exists());
+        index.flush();
+        assert!(path.exists());
+        drop(index);
+
+        let reloaded = PlayerIndex::new(path.clone());
+        assert_eq!(reloaded.find_by_name("someone#1").map(|player| player.player_id), Some("abc123".to_string()));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn flush_forgets_least_recently_seen_past_cap() {
+        let path = temp_path("index-prune");
+        let _ = fs::remove_file(&path);
+        let mut index = PlayerIndex::new(path.clone());
+        index.merge((0..MAX_PLAYERS + 10).map(|id| player(id, id as f64)).collect());
+        index.flush();
+        assert_eq!(index.snapshot().len(), MAX_PLAYERS);
+        assert!(index.find_by_name("player9").is_none());
+        assert!(index.find_by_name("player10").is_some());
+        drop(index);
+        let _ = fs::remove_file(&path);
+    }
+}
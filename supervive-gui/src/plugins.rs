@@ -0,0 +1,365 @@
+//! Community panels for the player profile, loaded from `plugins/*.json` in the data dir.
+//!
+//! A plugin is a declarative layout whose values are small expressions evaluated over the
+//! loaded matches, e.g. `(sum(Kills) + 0.5 * sum(Assists)) / count()`:
+//!
+//! ```json
+//! {
+//!   "name": "Aggression",
+//!   "panel": [
+//!     { "type": "stat", "label": "Aggression score", "value": "avg(Kills) + avg(Assists) / 2",
+//!       "decimals": 1, "good_at": 6, "bad_at": 3 },
+//!     { "type": "text", "text": "Kills plus half of assists per game." }
+//!   ]
+//! }
+//! ```
+//!
+//! Functions: `sum`, `avg`, `min`, `max` over a field, `count()` and `top(n)` (share of games
+//! finished at or above placement `n`). Fields are `placement`, `survival_duration`, `ranked`
+//! (1 or 0) and any key of the match `stats` object, such as `Kills` or `DamageDone`.
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// The per-match data a plugin sees, extracted from a match history row.
+#[derive(Debug, Clone, Default)]
+pub struct MatchData {
+    pub placement: Option<f64>,
+    pub survival_duration: Option<f64>,
+    pub ranked: bool,
+    pub stats: HashMap<String, f64>,
+}
+
+impl MatchData {
+    pub fn from_row(row: &Value) -> Self {
+        Self {
+            placement: row["placement"].as_f64(),
+            survival_duration: row["survival_duration"].as_f64(),
+            ranked: row["is_ranked"].as_bool().unwrap_or(false),
+            stats: row["stats"]
+                .as_object()
+                .map(|stats| {
+                    stats
+                        .iter()
+                        .filter_map(|(key, value)| Some((key.clone(), value.as_f64()?)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn field(&self, name: &str) -> Option<f64> {
+        match name {
+            "placement" => self.placement,
+            "survival_duration" => self.survival_duration,
+            "ranked" => Some(if self.ranked { 1.0 } else { 0.0 }),
+            _ => self.stats.get(name).copied(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PanelElement {
+    /// A labelled number. Colored good/bad when thresholds are given; `bad_at` above `good_at`
+    /// means lower is better.
+    Stat {
+        label: String,
+        value: String,
+        #[serde(default)]
+        decimals: usize,
+        good_at: Option<f64>,
+        bad_at: Option<f64>,
+    },
+    Text { text: String },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub panel: Vec<PanelElement>,
+}
+
+/// A loaded plugin with its stat expressions already parsed.
+#[derive(Debug, Clone)]
+pub struct Plugin {
+    pub manifest: PluginManifest,
+    expressions: Vec<Option<Expr>>,
+}
+
+pub fn plugin_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("supervive_gui")
+        .join("plugins")
+}
+
+impl Plugin {
+    pub fn parse(source: &str) -> Result<Self> {
+        let manifest: PluginManifest = serde_json::from_str(source)?;
+        let expressions = manifest
+            .panel
+            .iter()
+            .map(|element| match element {
+                PanelElement::Stat { label, value, .. } => Expr::parse(value)
+                    .with_context(|| format!("in stat \"{}\"", label))
+                    .map(Some),
+                PanelElement::Text { .. } => Ok(None),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { manifest, expressions })
+    }
+
+    /// Evaluates the stat at `index` in the panel; `None` when the matches lack the fields used.
+    pub fn evaluate(&self, index: usize, matches: &[MatchData]) -> Option<f64> {
+        self.expressions.get(index)?.as_ref()?.eval(matches).filter(|value| value.is_finite())
+    }
+}
+
+/// Loads every plugin in the plugin directory, sorted by name. Broken files are logged and skipped.
+pub fn load_all() -> Vec<Plugin> {
+    let Ok(entries) = fs::read_dir(plugin_dir()) else {
+        return Vec::new();
+    };
+    let mut plugins: Vec<Plugin> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let result = fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|source| Plugin::parse(&source));
+            match result {
+                Ok(plugin) => Some(plugin),
+                Err(err) => {
+                    tracing::warn!(path = %path.display(), error = %format!("{:#}", err), "Skipping plugin");
+                    None
+                }
+            }
+        })
+        .collect();
+    plugins.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+    plugins
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Number(f64),
+    Call(String, Option<String>),
+    Neg(Box<Expr>),
+    Binary(char, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn parse(source: &str) -> Result<Self> {
+        let chars: Vec<char> = source.chars().collect();
+        // Evaluating and dropping the tree recurse once per operator, so long chains are refused too
+        if chars.len() > MAX_EXPRESSION_LEN {
+            bail!("the expression is longer than {} characters", MAX_EXPRESSION_LEN);
+        }
+        let mut parser = Parser { chars, pos: 0, depth: 0 };
+        let expr = parser.expression()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.chars.len() {
+            bail!("unexpected '{}' at position {}", parser.chars[parser.pos], parser.pos);
+        }
+        Ok(expr)
+    }
+
+    fn eval(&self, matches: &[MatchData]) -> Option<f64> {
+        match self {
+            Expr::Number(value) => Some(*value),
+            Expr::Neg(inner) => inner.eval(matches).map(|value| -value),
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (left.eval(matches)?, right.eval(matches)?);
+                Some(match op {
+                    '+' => left + right,
+                    '-' => left - right,
+                    '*' => left * right,
+                    _ => left / right,
+                })
+            }
+            Expr::Call(function, argument) => {
+                let values = || -> Vec<f64> {
+                    let field = argument.as_deref().unwrap_or("");
+                    matches.iter().filter_map(|data| data.field(field)).collect()
+                };
+                match function.as_str() {
+                    "count" => Some(matches.len() as f64),
+                    "top" => {
+                        let n: f64 = argument.as_deref()?.parse().ok()?;
+                        let placements: Vec<f64> = matches.iter().filter_map(|data| data.placement).collect();
+                        let hits = placements.iter().filter(|placement| **placement <= n).count();
+                        (!placements.is_empty()).then(|| hits as f64 / placements.len() as f64)
+                    }
+                    "sum" => Some(values().iter().sum()),
+                    "avg" => {
+                        let values = values();
+                        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+                    }
+                    "min" => values().into_iter().reduce(f64::min),
+                    "max" => values().into_iter().reduce(f64::max),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+const FUNCTIONS: [&str; 6] = ["sum", "avg", "min", "max", "count", "top"];
+/// Longest expression a panel may use.
+const MAX_EXPRESSION_LEN: usize = 1000;
+/// Deepest nesting of parentheses and unary minus, well within the parser's stack.
+const MAX_NESTING: usize = 32;
+
+/// Recursive-descent parser for `+ - * /`, parentheses, numbers and function calls.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    /// Parentheses and unary minus currently open
+    depth: usize,
+}
+
+impl Parser {
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => bail!("expected '{}' but found '{}' at position {}", expected, c, self.pos),
+            None => bail!("expected '{}' but the expression ended", expected),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expr> {
+        let mut expr = self.term()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.term()?));
+        }
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<Expr> {
+        let mut expr = self.factor()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.pos += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.factor()?));
+        }
+        Ok(expr)
+    }
+
+    /// Parses one nested level with `parse`, refusing to go deeper than `MAX_NESTING`.
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Result<Expr>) -> Result<Expr> {
+        if self.depth >= MAX_NESTING {
+            bail!("nested deeper than {} levels at position {}", MAX_NESTING, self.pos);
+        }
+        self.depth += 1;
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+
+    fn factor(&mut self) -> Result<Expr> {
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                self.nested(|parser| Ok(Expr::Neg(Box::new(parser.factor()?))))
+            }
+            Some('(') => {
+                self.pos += 1;
+                self.nested(|parser| {
+                    let expr = parser.expression()?;
+                    parser.expect(')')?;
+                    Ok(expr)
+                })
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let number = self.take_while(|c| c.is_ascii_digit() || c == '.');
+                Ok(Expr::Number(number.parse().map_err(|_| anyhow!("invalid number '{}'", number))?))
+            }
+            Some(c) if c.is_alphabetic() => {
+                let function = self.take_while(|c| c.is_alphabetic());
+                if !FUNCTIONS.contains(&function.as_str()) {
+                    bail!("unknown function '{}'", function);
+                }
+                self.expect('(')?;
+                self.skip_whitespace();
+                let argument = self.take_while(|c| c.is_alphanumeric() || c == '_' || c == '.');
+                self.expect(')')?;
+                match (function.as_str(), argument.is_empty()) {
+                    ("count", false) => bail!("count() takes no field"),
+                    ("count", true) => {}
+                    (_, true) => bail!("{}() needs a field", function),
+                    _ => {}
+                }
+                Ok(Expr::Call(function, (!argument.is_empty()).then_some(argument)))
+            }
+            Some(c) => bail!("unexpected '{}' at position {}", c, self.pos),
+            None => bail!("the expression ended early"),
+        }
+    }
+
+    fn take_while(&mut self, keep: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.chars.get(self.pos).is_some_and(|c| keep(*c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_precedence_and_calls() {
+        let expr = Expr::parse("count() - 2 * (1 + -1) / 4").unwrap();
+        assert_eq!(expr.eval(&[]), Some(0.0));
+        assert!(Expr::parse("avg(kills) + max(damage)").is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for source in ["", "1 +", "(1", "1)", "foo(kills)", "count(kills)", "sum()", "1..2"] {
+            assert!(Expr::parse(source).is_err(), "{:?}", source);
+        }
+    }
+
+    #[test]
+    fn refuses_deep_nesting_instead_of_overflowing() {
+        let parens = format!("{}1{}", "(".repeat(5000), ")".repeat(5000));
+        assert!(Expr::parse(&parens).is_err());
+        let minus = "-".repeat(5000) + "1";
+        assert!(Expr::parse(&minus).is_err());
+        let nested = format!("{}1{}", "(".repeat(MAX_NESTING), ")".repeat(MAX_NESTING));
+        assert!(Expr::parse(&nested).is_ok());
+        let too_nested = format!("{}1{}", "(".repeat(MAX_NESTING + 1), ")".repeat(MAX_NESTING + 1));
+        assert!(Expr::parse(&too_nested).is_err());
+    }
+
+    #[test]
+    fn refuses_long_chains() {
+        let chain = vec!["1"; 100_000].join("+");
+        assert!(Expr::parse(&chain).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::api::rank_rating;
+use serde_json::Value;
+
+/// RP gained or lost in each match, aligned with `rows` (most recent first). Uses the row's own
+/// `rating_delta` when present, otherwise the difference to the previous ranked match's rating.
+pub fn rating_deltas(rows: &[Value]) -> Vec<Option<i64>> {
+    rows.iter()
+        .enumerate()
+        .map(|(idx, row)| {
+            if !row["is_ranked"].as_bool().unwrap_or(false) {
+                return None;
+            }
+            if let Some(delta) = row["rating_delta"].as_i64() {
+                return Some(delta);
+            }
+            let previous = rows[idx + 1..]
+                .iter()
+                .find(|older| older["is_ranked"].as_bool().unwrap_or(false))?;
+            Some(rank_rating(row)? - rank_rating(previous)?)
+        })
+        .collect()
+}
+
+/// Rank points after each ranked match, oldest first, for charting.
+pub fn rating_history(rows: &[Value]) -> Vec<f64> {
+    rows.iter()
+        .rev()
+        .filter(|row| row["is_ranked"].as_bool().unwrap_or(false))
+        .filter_map(|row| rank_rating(row).map(|rating| rating as f64))
+        .collect()
+}
@@ -0,0 +1,78 @@
+use crate::format;
+use crate::session::parse_time;
+use serde_json::Value;
+
+/// A personal best and the match it was set in.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub label: &'static str,
+    pub value: String,
+    pub match_id: Option<String>,
+}
+
+fn match_id(row: &Value) -> Option<String> {
+    row["match_id"].as_str().map(|id| id.to_string())
+}
+
+/// Highest value of a match stat, with the match it came from.
+fn best_stat(rows: &[Value], key: &str, label: &'static str) -> Option<Record> {
+    rows.iter()
+        .filter_map(|row| Some((row, row["stats"][key].as_i64()?)))
+        .max_by_key(|(_, value)| *value)
+        .map(|(row, value)| Record {
+            label,
+            value: format::integer(value),
+            match_id: match_id(row),
+        })
+}
+
+/// Records over match rows ordered most recent first. Records the rows hold no data for are left out.
+pub fn records(rows: &[Value]) -> Vec<Record> {
+    let mut records: Vec<Record> = [
+        best_stat(rows, "Kills", "Most kills"),
+        best_stat(rows, "HeroEffectiveDamageDone", "Highest damage"),
+        best_stat(rows, "HealingGiven", "Most healing"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    // Walk oldest to newest; the link points at the win that completed the streak
+    let mut streak = 0;
+    let mut longest: Option<(usize, &Value)> = None;
+    for row in rows.iter().rev() {
+        if row["placement"].as_i64() == Some(1) {
+            streak += 1;
+            if longest.is_none_or(|(best, _)| streak > best) {
+                longest = Some((streak, row));
+            }
+        } else {
+            streak = 0;
+        }
+    }
+    if let Some((streak, row)) = longest {
+        records.push(Record {
+            label: "Longest win streak",
+            value: format!("{} {}", streak, if streak == 1 { "win" } else { "wins" }),
+            match_id: match_id(row),
+        });
+    }
+
+    let fastest = rows
+        .iter()
+        .filter(|row| row["placement"].as_i64() == Some(1))
+        .filter_map(|row| {
+            let duration = parse_time(&row["match_end"])? - parse_time(&row["match_start"])?;
+            Some((row, duration))
+        })
+        .min_by_key(|(_, duration)| *duration);
+    if let Some((row, duration)) = fastest {
+        records.push(Record {
+            label: "Fastest win",
+            value: format::duration(duration.num_seconds()),
+            match_id: match_id(row),
+        });
+    }
+
+    records
+}
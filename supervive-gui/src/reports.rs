@@ -0,0 +1,152 @@
+use crate::format;
+use crate::session::parse_time;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A hero needs this many games in the period to be picked as the best one.
+const MIN_HERO_GAMES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportPeriod {
+    Week,
+    Month,
+}
+
+impl ReportPeriod {
+    pub const ALL: [ReportPeriod; 2] = [ReportPeriod::Week, ReportPeriod::Month];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportPeriod::Week => "This week",
+            ReportPeriod::Month => "This month",
+        }
+    }
+
+    /// Local midnight starting the current week (Monday) or month.
+    pub fn start(&self) -> DateTime<Utc> {
+        let today = Local::now().date_naive();
+        let first_day = match self {
+            ReportPeriod::Week => today - Duration::days(today.weekday().num_days_from_monday() as i64),
+            ReportPeriod::Month => NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap_or(today),
+        };
+        Local
+            .from_local_datetime(&first_day.and_hms_opt(0, 0, 0).unwrap_or_default())
+            .earliest()
+            .map(|start| start.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now)
+    }
+}
+
+/// Totals for the linked account over the current week or month.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub period: ReportPeriod,
+    pub games: usize,
+    pub wins: usize,
+    pub kills: i64,
+    pub deaths: i64,
+    pub avg_placement: f64,
+    /// Lowest average placement among heroes with enough games, falling back to the most played
+    pub best_hero: Option<String>,
+}
+
+impl Report {
+    /// Builds the report from match rows, ignoring matches that started before the period.
+    pub fn build(period: ReportPeriod, rows: &[Value]) -> Self {
+        let start = period.start();
+        let rows: Vec<&Value> = rows
+            .iter()
+            .filter(|row| parse_time(&row["match_start"]).is_some_and(|time| time >= start))
+            .collect();
+
+        let placements: Vec<i64> = rows.iter().filter_map(|row| row["placement"].as_i64()).collect();
+        let stat = |key: &str| -> i64 { rows.iter().filter_map(|row| row["stats"][key].as_i64()).sum() };
+
+        let mut heroes: HashMap<&str, Vec<i64>> = HashMap::new();
+        for row in &rows {
+            if let (Some(hero), Some(placement)) = (row["hero"]["name"].as_str(), row["placement"].as_i64()) {
+                heroes.entry(hero).or_default().push(placement);
+            }
+        }
+        let average = |placements: &[i64]| placements.iter().sum::<i64>() as f64 / placements.len() as f64;
+        let best_hero = heroes
+            .iter()
+            .filter(|(_, placements)| placements.len() >= MIN_HERO_GAMES)
+            .min_by(|a, b| average(a.1).total_cmp(&average(b.1)))
+            .or_else(|| heroes.iter().max_by_key(|(_, placements)| placements.len()))
+            .map(|(hero, _)| hero.to_string());
+
+        Self {
+            period,
+            games: rows.len(),
+            wins: placements.iter().filter(|placement| **placement == 1).count(),
+            kills: stat("Kills"),
+            deaths: stat("Deaths"),
+            avg_placement: if placements.is_empty() { 0.0 } else { average(&placements) },
+            best_hero,
+        }
+    }
+
+    pub fn kd_ratio(&self) -> f64 {
+        if self.deaths > 0 {
+            self.kills as f64 / self.deaths as f64
+        } else {
+            self.kills as f64
+        }
+    }
+
+    /// One-line summary, e.g. "This week: 23 games, 2 wins, best hero: Ghost".
+    pub fn headline(&self) -> String {
+        let mut line = format!(
+            "{}: {} games, {} {}",
+            self.period.label(),
+            self.games,
+            self.wins,
+            if self.wins == 1 { "win" } else { "wins" }
+        );
+        if let Some(hero) = &self.best_hero {
+            line.push_str(&format!(", best hero: {}", hero));
+        }
+        line
+    }
+
+    pub fn to_markdown(&self, player_name: &str) -> String {
+        let mut markdown = format!("# {} — {}\n\n{}\n\n", player_name, self.period.label(), self.headline());
+        markdown.push_str("| Stat | Value |\n|---|---|\n");
+        markdown.push_str(&format!("| Games | {} |\n", format::integer(self.games as i64)));
+        markdown.push_str(&format!("| Wins | {} |\n", format::integer(self.wins as i64)));
+        markdown.push_str(&format!(
+            "| K/D | {} ({} K / {} D) |\n",
+            format::decimal(self.kd_ratio(), 2),
+            format::integer(self.kills),
+            format::integer(self.deaths)
+        ));
+        markdown.push_str(&format!("| Avg placement | #{} |\n", format::decimal(self.avg_placement, 1)));
+        if let Some(hero) = &self.best_hero {
+            markdown.push_str(&format!("| Best hero | {} |\n", hero));
+        }
+        markdown.push_str(&format!("\nSince {}\n", format::date(self.period.start().with_timezone(&Local).date_naive())));
+        markdown
+    }
+}
+
+/// Writes the report as markdown to the downloads folder and returns its path.
+pub fn export_markdown(report: &Report, player_name: &str) -> Result<PathBuf> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let path = dir.join(format!(
+        "supervive-report-{}-{}.md",
+        match report.period {
+            ReportPeriod::Week => "week",
+            ReportPeriod::Month => "month",
+        },
+        Local::now().format("%Y%m%d")
+    ));
+    fs::write(&path, report.to_markdown(player_name))?;
+    Ok(path)
+}
@@ -0,0 +1,605 @@
+use crate::api::{rate_limit_of, take_rows, MatchRows, SuperviveService, MAX_HISTORY_PAGES};
+use crate::demo;
+use crate::keymap;
+use crate::match_archive::MatchArchive;
+use crate::notifier::Notifier;
+use crate::player_index::PlayerIndex;
+use crate::settings::PlayerRef;
+use crate::state::AppState;
+use crate::sync::{self, SyncOutcome};
+use crate::telemetry;
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use gpui::App;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How often the scheduler checks for due jobs.
+const TICK: Duration = Duration::from_secs(5);
+/// Time between writes of the response cache and player index, which change with nearly every request.
+const CACHE_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+/// Pause between the requests of the cache-warming and archiving jobs.
+const WARM_REQUEST_GAP: Duration = Duration::from_secs(2);
+
+/// Interval presets offered on the Background jobs page, in minutes (0 = off).
+pub const INTERVAL_PRESETS: [u64; 6] = [0, 5, 15, 60, 360, 1440];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JobKind {
+    /// Checks watched players for newly finished matches and notifies webhooks, and refreshes
+    /// pinned home tiles
+    PollWatched,
+    /// Pulls the full history of watched players into the player index
+    BackfillWatched,
+    /// Removes expired entries from the API cache
+    PruneCache,
+    /// Sends the aggregated usage counters, when the user opted in
+    UploadTelemetry,
+    /// Exchanges settings with the configured sync endpoint
+    SyncSettings,
+    /// Caches the first history page and latest match of watched players so they open instantly
+    WarmWatched,
+    /// Deletes archived matches the retention setting no longer keeps
+    PruneArchive,
+    /// Repairs duplicate rows, lost index entries and unreadable files in the match archive
+    CheckArchive,
+    /// Archives new matches of the followed players for the meta page
+    ArchiveFollowed,
+}
+
+impl JobKind {
+    pub const ALL: [JobKind; 9] = [
+        JobKind::PollWatched,
+        JobKind::BackfillWatched,
+        JobKind::PruneCache,
+        JobKind::UploadTelemetry,
+        JobKind::SyncSettings,
+        JobKind::WarmWatched,
+        JobKind::PruneArchive,
+        JobKind::CheckArchive,
+        JobKind::ArchiveFollowed,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            JobKind::PollWatched => "Poll watched players",
+            JobKind::BackfillWatched => "Backfill watched history",
+            JobKind::PruneCache => "Prune API cache",
+            JobKind::UploadTelemetry => "Upload usage statistics",
+            JobKind::SyncSettings => "Sync settings",
+            JobKind::WarmWatched => "Warm watched players",
+            JobKind::PruneArchive => "Prune match archive",
+            JobKind::CheckArchive => "Check match archive",
+            JobKind::ArchiveFollowed => "Archive followed players",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            JobKind::PollWatched => "Looks for newly finished matches, posts them to your webhooks and refreshes pinned home tiles.",
+            JobKind::BackfillWatched => "Fetches every history page of watched players so their teammates show up in search.",
+            JobKind::PruneCache => "Drops expired cached responses to keep the cache file small.",
+            JobKind::UploadTelemetry => "Sends the anonymous usage counts shown in Settings. Does nothing unless you opted in.",
+            JobKind::SyncSettings => "Exchanges settings, watched players, linked accounts and match notes with your sync folder. Does nothing until sync is set up.",
+            JobKind::WarmWatched => "Runs on launch and caches each watched player's latest matches, so opening them is instant.",
+            JobKind::PruneArchive => "Deletes imported matches older or beyond the size set under Match archive in Settings. Does nothing while they are kept forever.",
+            JobKind::CheckArchive => "Removes duplicate player rows, forgets matches whose file is gone, re-indexes stray scoreboards and quarantines unreadable files.",
+            JobKind::ArchiveFollowed => "Keeps the latest matches of the players followed on the Meta page in the match archive. Does nothing until you follow someone.",
+        }
+    }
+
+    /// Default minutes between runs.
+    pub fn default_interval(&self) -> u64 {
+        match self {
+            JobKind::PollWatched => 5,
+            JobKind::BackfillWatched => 360,
+            JobKind::PruneCache => 1440,
+            JobKind::UploadTelemetry => 1440,
+            JobKind::SyncSettings => 15,
+            JobKind::WarmWatched => 1440,
+            JobKind::PruneArchive => 1440,
+            JobKind::CheckArchive => 1440,
+            JobKind::ArchiveFollowed => 60,
+        }
+    }
+
+    /// Runs on the first tick after startup instead of waiting one interval.
+    fn runs_on_launch(&self) -> bool {
+        matches!(self, JobKind::WarmWatched)
+    }
+
+    /// Can be stopped from the Background jobs page while running.
+    pub fn is_cancellable(&self) -> bool {
+        matches!(self, JobKind::WarmWatched | JobKind::BackfillWatched | JobKind::ArchiveFollowed)
+    }
+}
+
+pub fn format_interval(minutes: u64) -> String {
+    match minutes {
+        0 => "Off".to_string(),
+        m if m % 1440 == 0 => format!("{}d", m / 1440),
+        m if m % 60 == 0 => format!("{}h", m / 60),
+        m => format!("{}m", m),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct JobStatus {
+    pub last_run: Option<DateTime<Utc>>,
+    pub next_run: Option<DateTime<Utc>>,
+    /// Summary of the last run, or its error message
+    pub last_result: Option<Result<String, String>>,
+    pub running: bool,
+}
+
+/// The most recent history page the poller fetched for a player.
+#[derive(Debug, Clone)]
+pub struct PolledRows {
+    pub fetched_at: DateTime<Utc>,
+    pub rows: MatchRows,
+}
+
+/// Run times and results of the background jobs, plus the matches already seen per watched
+/// player so polls only report new ones.
+#[derive(Default)]
+pub struct Scheduler {
+    statuses: HashMap<JobKind, JobStatus>,
+    seen_matches: HashMap<String, HashSet<String>>,
+    /// Latest page of each watched or pinned player, keyed like `seen_matches`
+    polled_rows: HashMap<String, PolledRows>,
+    /// Running jobs asked to stop at their next request
+    cancelled: HashSet<JobKind>,
+}
+
+fn player_key(platform: &str, player_id: &str) -> String {
+    format!("{}:{}", platform, player_id)
+}
+
+impl Scheduler {
+    /// What the last poll fetched for a player, if it has been polled since startup.
+    pub fn polled_rows(&self, platform: &str, player_id: &str) -> Option<PolledRows> {
+        self.polled_rows.get(&player_key(platform, player_id)).cloned()
+    }
+
+    /// Asks a running job to stop before its next request.
+    pub fn cancel(&mut self, job: JobKind) {
+        if self.status(job).running {
+            self.cancelled.insert(job);
+        }
+    }
+
+    fn is_cancelled(&self, job: JobKind) -> bool {
+        self.cancelled.contains(&job)
+    }
+
+    pub fn status(&self, job: JobKind) -> JobStatus {
+        self.statuses.get(&job).cloned().unwrap_or_default()
+    }
+
+    /// Recomputes the next run after the interval changed; `0` disables the job.
+    pub fn reschedule(&mut self, job: JobKind, interval_minutes: u64) {
+        let status = self.statuses.entry(job).or_default();
+        status.next_run = (interval_minutes > 0).then(|| {
+            status.last_run.unwrap_or_else(Utc::now) + chrono::Duration::minutes(interval_minutes as i64)
+        });
+    }
+
+    fn take_due(&mut self, intervals: &HashMap<JobKind, u64>) -> Vec<JobKind> {
+        let now = Utc::now();
+        let mut due = Vec::new();
+        for job in JobKind::ALL {
+            let interval = intervals.get(&job).copied().unwrap_or(0);
+            let status = self.statuses.entry(job).or_default();
+            if interval == 0 || status.running {
+                continue;
+            }
+            match status.next_run {
+                None if job.runs_on_launch() => due.push(job),
+                // First tick after startup: wait one interval before the first run
+                None => status.next_run = Some(now + chrono::Duration::minutes(interval as i64)),
+                Some(next) if next <= now => due.push(job),
+                Some(_) => {}
+            }
+        }
+        due
+    }
+
+    fn begin(&mut self, job: JobKind) -> bool {
+        let status = self.statuses.entry(job).or_default();
+        if status.running {
+            return false;
+        }
+        status.running = true;
+        true
+    }
+
+    fn finish(&mut self, job: JobKind, result: Result<String>, interval_minutes: u64) {
+        self.cancelled.remove(&job);
+        let status = self.statuses.entry(job).or_default();
+        status.running = false;
+        status.last_run = Some(Utc::now());
+        status.last_result = Some(result.map_err(|err| err.to_string()));
+        self.reschedule(job, interval_minutes);
+    }
+}
+
+/// Starts the scheduler loop on the foreground executor; each due job runs on a background thread.
+pub fn start(cx: &mut App) {
+    cx.spawn(async move |cx| loop {
+        let due = cx
+            .update(|cx| {
+                let app_state = cx.global::<AppState>();
+                let settings = app_state.settings();
+                let intervals = JobKind::ALL
+                    .iter()
+                    .map(|job| (*job, settings.job_interval(*job)))
+                    .collect();
+                app_state.scheduler.lock().unwrap().take_due(&intervals)
+            })
+            .unwrap_or_default();
+        for job in due {
+            cx.update(|cx| run_now(job, cx)).ok();
+        }
+        cx.update(invalidate_for_new_patch).ok();
+        cx.background_executor().timer(TICK).await;
+    })
+    .detach();
+
+    let app_state = cx.global::<AppState>();
+    let (service, index) = (app_state.service.clone(), app_state.player_index.clone());
+    cx.spawn(async move |cx| loop {
+        cx.background_executor().timer(CACHE_FLUSH_INTERVAL).await;
+        let (service, index) = (service.clone(), index.clone());
+        cx.background_executor()
+            .spawn(async move {
+                service.lock().unwrap().flush_cache();
+                index.lock().unwrap().flush();
+            })
+            .await;
+    })
+    .detach();
+}
+
+/// Once the latest marked game update has landed, drops the stats cached before it and archives
+/// the followed players' newest matches so the meta page catches up.
+fn invalidate_for_new_patch(cx: &mut App) {
+    if demo::is_enabled() {
+        return;
+    }
+    let app_state = cx.global::<AppState>();
+    let settings = app_state.settings();
+    let today = Local::now().date_naive();
+    let Some(latest) = settings.patches.iter().map(|patch| patch.date).filter(|date| *date <= today).max() else {
+        return;
+    };
+    if settings.stats_patch.is_some_and(|handled| handled >= latest) {
+        return;
+    }
+    app_state.settings.lock().unwrap().update(|s| s.stats_patch = Some(latest));
+    let cutoff = latest
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map(|midnight| midnight.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+    let service = app_state.service.clone();
+    // The service lock may be held by a slow request
+    cx.background_executor()
+        .spawn(async move {
+            let removed = service.lock().unwrap().invalidate_stats_before(cutoff);
+            tracing::info!(patch = %latest, removed, "Dropped stats cached before the game update");
+        })
+        .detach();
+    if !settings.followed.players.is_empty() {
+        run_now(JobKind::ArchiveFollowed, cx);
+    }
+}
+
+/// Runs a job immediately unless it is already running.
+pub fn run_now(job: JobKind, cx: &mut App) {
+    let app_state = cx.global::<AppState>();
+    if !app_state.scheduler.lock().unwrap().begin(job) {
+        return;
+    }
+    let settings = app_state.settings();
+    let interval = settings.job_interval(job);
+    // Demo mode answers from the sample data and must not reach anything else on the network
+    let demo = demo::is_enabled();
+    let service = app_state.service.clone();
+    let index = app_state.player_index.clone();
+    let scheduler = app_state.scheduler.clone();
+    let watched = settings.watched_players.clone();
+    let pinned = settings.pinned_players();
+    let webhooks = if demo { Vec::new() } else { settings.webhooks.clone() };
+    let telemetry = app_state.telemetry.clone();
+    let telemetry_endpoint = settings.telemetry_enabled.then(|| settings.telemetry_endpoint.clone());
+    let settings_store = app_state.settings.clone();
+    let match_notes = app_state.match_notes.clone();
+    let sync_config = settings.sync.clone();
+    let archive = app_state.match_archive.clone();
+    let retention = settings.archive_retention;
+    let followed = settings.followed.players.clone();
+    cx.refresh_windows();
+
+    cx.spawn(async move |cx| {
+        let job_scheduler = scheduler.clone();
+        let result = cx
+            .background_executor()
+            .spawn(async move {
+                match job {
+                    JobKind::PollWatched => {
+                        poll_watched(&service, &index, &job_scheduler, &watched, &pinned, &Notifier::new(webhooks))
+                    }
+                    JobKind::BackfillWatched => backfill_watched(&service, &index, &job_scheduler, &watched),
+                    JobKind::WarmWatched => warm_watched(&service, &index, &job_scheduler, &watched),
+                    JobKind::PruneCache => {
+                        let removed = service.lock().unwrap().prune_cache();
+                        Ok(format!("Removed {} expired entries", removed))
+                    }
+                    JobKind::PruneArchive => {
+                        let mut archive = archive.lock().unwrap();
+                        let expired = archive.expired(retention);
+                        let removed = archive.remove_all(&expired);
+                        Ok(format!("Removed {} archived matches", removed))
+                    }
+                    JobKind::CheckArchive => Ok(archive.lock().unwrap().check_integrity().summary()),
+                    JobKind::ArchiveFollowed => archive_followed(&service, &index, &archive, &job_scheduler, &followed),
+                    JobKind::UploadTelemetry if demo => Ok("Skipped in demo mode".to_string()),
+                    JobKind::UploadTelemetry => {
+                        let Some(endpoint) = telemetry_endpoint else {
+                            return Ok("Usage statistics are off".to_string());
+                        };
+                        let batch = {
+                            let telemetry = telemetry.lock().unwrap();
+                            if telemetry.is_empty() {
+                                return Ok("Nothing to send".to_string());
+                            }
+                            telemetry.pending_batch()
+                        };
+                        telemetry::upload(&endpoint, &batch)?;
+                        telemetry.lock().unwrap().clear();
+                        Ok("Sent usage statistics".to_string())
+                    }
+                    JobKind::SyncSettings if demo => Ok("Skipped in demo mode".to_string()),
+                    JobKind::SyncSettings => {
+                        let Some(provider) = sync::provider(&sync_config)? else {
+                            return Ok("Sync is off".to_string());
+                        };
+                        let outcome = sync::sync(provider.as_ref(), &settings_store, &match_notes)?;
+                        Ok(match outcome {
+                            SyncOutcome::Pulled => "Applied newer changes from the sync folder",
+                            SyncOutcome::Pushed => "Uploaded local changes",
+                            SyncOutcome::UpToDate => "Already up to date",
+                        }
+                        .to_string())
+                    }
+                }
+            })
+            .await;
+        if let Err(err) = &result {
+            tracing::warn!(job = job.label(), error = %err, "Background job failed");
+        }
+        // A pull may have brought in different shortcuts
+        let reapply_keymap = job == JobKind::SyncSettings && result.is_ok();
+        scheduler.lock().unwrap().finish(job, result, interval);
+        cx.update(|cx| {
+            if reapply_keymap {
+                let keybindings = cx.global::<AppState>().settings().keybindings;
+                keymap::apply(&keybindings, cx);
+            }
+            cx.refresh_windows();
+        })
+        .ok();
+    })
+    .detach();
+}
+
+/// Players a job couldn't reach. One private, renamed or deleted player must not hold up the
+/// rest, so their errors are logged and counted; only the rate limit ends a run, since every
+/// later request would hit it too.
+#[derive(Default)]
+struct Failures {
+    players: HashSet<String>,
+}
+
+impl Failures {
+    fn skip<T>(&mut self, player: &PlayerRef, result: Result<T>) -> Result<Option<T>> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if rate_limit_of(&err).is_some() => Err(err),
+            Err(err) => {
+                tracing::warn!(player = %player.name, error = %err, "Request for player failed");
+                self.players.insert(player_key(&player.platform, &player.player_id));
+                Ok(None)
+            }
+        }
+    }
+
+    /// `summary`, followed by how many of `total` players failed if any did.
+    fn summarize(&self, mut summary: String, total: usize) -> String {
+        if !self.players.is_empty() {
+            summary.push_str(&format!(", {} of {} failed", self.players.len(), total));
+        }
+        summary
+    }
+}
+
+fn poll_watched(
+    service: &Mutex<SuperviveService>,
+    index: &Mutex<PlayerIndex>,
+    scheduler: &Mutex<Scheduler>,
+    watched: &[PlayerRef],
+    pinned: &[PlayerRef],
+    notifier: &Notifier,
+) -> Result<String> {
+    let fetch = |player: &PlayerRef| -> Result<MatchRows> {
+        let data = service
+            .lock()
+            .unwrap()
+            .get_player_matches(&player.platform, &player.player_id, 1)?;
+        let rows = MatchRows::new(take_rows(data));
+        index.lock().unwrap().record_match_rows(&player.platform, &rows);
+        scheduler.lock().unwrap().polled_rows.insert(
+            player_key(&player.platform, &player.player_id),
+            PolledRows {
+                fetched_at: Utc::now(),
+                rows: rows.clone(),
+            },
+        );
+        Ok(rows)
+    };
+
+    let mut failures = Failures::default();
+    let mut new_matches = 0;
+    for player in watched {
+        let Some(rows) = failures.skip(player, fetch(player))? else {
+            continue;
+        };
+        let key = player_key(&player.platform, &player.player_id);
+        let fresh: Vec<&Value> = {
+            let mut scheduler = scheduler.lock().unwrap();
+            // The first poll of a player only records what is already there
+            let first_poll = !scheduler.seen_matches.contains_key(&key);
+            let seen = scheduler.seen_matches.entry(key).or_default();
+            rows.iter()
+                .rev()
+                .filter(|row| seen.insert(row["match_id"].as_str().unwrap_or("").to_string()) && !first_poll)
+                .collect()
+        };
+        // Webhook posts block; the views read the scheduler while rendering
+        for row in fresh {
+            new_matches += 1;
+            notifier.notify(&player.name, row);
+        }
+    }
+    // Pinned players that aren't watched only feed their home tiles
+    for player in pinned {
+        failures.skip(player, fetch(player))?;
+    }
+    Ok(failures.summarize(
+        format!("{} new matches across {} players", new_matches, watched.len()),
+        watched.len() + pinned.len(),
+    ))
+}
+
+fn backfill_watched(
+    service: &Mutex<SuperviveService>,
+    index: &Mutex<PlayerIndex>,
+    scheduler: &Mutex<Scheduler>,
+    watched: &[PlayerRef],
+) -> Result<String> {
+    let mut failures = Failures::default();
+    let mut total = 0;
+    for player in watched {
+        let mut page = 1;
+        loop {
+            if scheduler.lock().unwrap().is_cancelled(JobKind::BackfillWatched) {
+                return Ok(format!("Cancelled after {} matches", total));
+            }
+            let result = service
+                .lock()
+                .unwrap()
+                .get_player_matches(&player.platform, &player.player_id, page as i32);
+            let Some(data) = failures.skip(player, result)? else {
+                break;
+            };
+            let last_page = data["meta"]["last_page"].as_i64().unwrap_or(1);
+            let rows = take_rows(data);
+            total += rows.len();
+            index.lock().unwrap().record_match_rows(&player.platform, &rows);
+
+            if page >= last_page.min(MAX_HISTORY_PAGES) {
+                break;
+            }
+            page += 1;
+            std::thread::sleep(WARM_REQUEST_GAP);
+        }
+    }
+    Ok(failures.summarize(format!("{} matches across {} players", total, watched.len()), watched.len()))
+}
+
+/// Fetches page 1 and the latest match of each watched player, pausing between requests so a
+/// launch with many watched players doesn't trip the rate limit.
+fn warm_watched(
+    service: &Mutex<SuperviveService>,
+    index: &Mutex<PlayerIndex>,
+    scheduler: &Mutex<Scheduler>,
+    watched: &[PlayerRef],
+) -> Result<String> {
+    let mut failures = Failures::default();
+    let mut warmed = 0;
+    for player in watched {
+        if scheduler.lock().unwrap().is_cancelled(JobKind::WarmWatched) {
+            return Ok(format!("Cancelled after {} of {} players", warmed, watched.len()));
+        }
+        let result = service
+            .lock()
+            .unwrap()
+            .get_player_matches(&player.platform, &player.player_id, 1);
+        let Some(data) = failures.skip(player, result)? else {
+            std::thread::sleep(WARM_REQUEST_GAP);
+            continue;
+        };
+        let rows = take_rows(data);
+        index.lock().unwrap().record_match_rows(&player.platform, &rows);
+        if let Some(match_id) = rows.first().and_then(|row| row["match_id"].as_str()) {
+            std::thread::sleep(WARM_REQUEST_GAP);
+            let result = service.lock().unwrap().get_match(&player.platform, match_id);
+            failures.skip(player, result)?;
+        }
+        warmed += 1;
+        std::thread::sleep(WARM_REQUEST_GAP);
+    }
+    Ok(failures.summarize(format!("Cached {} players", warmed), watched.len()))
+}
+
+/// Archives the matches on each followed player's first history page that the archive doesn't
+/// hold yet. Teammates share a scoreboard, so a match is fetched once however many of them
+/// played it. Scoreboards go straight to the archive without a copy in the response cache.
+fn archive_followed(
+    service: &Mutex<SuperviveService>,
+    index: &Mutex<PlayerIndex>,
+    archive: &Mutex<MatchArchive>,
+    scheduler: &Mutex<Scheduler>,
+    followed: &[PlayerRef],
+) -> Result<String> {
+    if followed.is_empty() {
+        return Ok("Nobody is followed".to_string());
+    }
+    let mut failures = Failures::default();
+    let mut archived = 0;
+    for player in followed {
+        let result = service
+            .lock()
+            .unwrap()
+            .get_player_matches(&player.platform, &player.player_id, 1);
+        let Some(data) = failures.skip(player, result)? else {
+            std::thread::sleep(WARM_REQUEST_GAP);
+            continue;
+        };
+        let rows = take_rows(data);
+        index.lock().unwrap().record_match_rows(&player.platform, &rows);
+        for match_id in rows.iter().filter_map(|row| row["match_id"].as_str()) {
+            if scheduler.lock().unwrap().is_cancelled(JobKind::ArchiveFollowed) {
+                return Ok(format!("Cancelled after archiving {} matches", archived));
+            }
+            if archive.lock().unwrap().contains(&player.platform, match_id) {
+                continue;
+            }
+            std::thread::sleep(WARM_REQUEST_GAP);
+            let result = service.lock().unwrap().get_match_for_archive(&player.platform, match_id);
+            let Some(data) = failures.skip(player, result)? else {
+                continue;
+            };
+            archive.lock().unwrap().insert(&player.platform, match_id, &data)?;
+            archived += 1;
+        }
+        std::thread::sleep(WARM_REQUEST_GAP);
+    }
+    Ok(failures.summarize(
+        format!("Archived {} matches of {} players", archived, followed.len()),
+        followed.len(),
+    ))
+}
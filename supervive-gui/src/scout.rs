@@ -0,0 +1,61 @@
+use crate::api::{take_rows, PlayerSummary};
+use crate::history_totals::HistoryTotals;
+use crate::player_index::IndexedPlayer;
+use serde_json::Value;
+
+/// Most names a scout runs at once: a full custom lobby.
+pub const MAX_SCOUT_NAMES: usize = 40;
+
+/// Player names from pasted text, one per line. Blank lines and repeats (ignoring case) are
+/// dropped; the second value counts names beyond [`MAX_SCOUT_NAMES`] that were left out.
+pub fn parse_names(text: &str) -> (Vec<String>, usize) {
+    let mut names: Vec<String> = Vec::new();
+    for name in text.lines().map(str::trim).filter(|name| !name.is_empty()) {
+        if !names.iter().any(|seen| seen.eq_ignore_ascii_case(name)) {
+            names.push(name.to_string());
+        }
+    }
+    let skipped = names.len().saturating_sub(MAX_SCOUT_NAMES);
+    names.truncate(MAX_SCOUT_NAMES);
+    (names, skipped)
+}
+
+/// What a scout card shows for a resolved player, from the first page of their matches.
+#[derive(Debug, Clone)]
+pub struct ScoutReport {
+    pub profile: IndexedPlayer,
+    pub rank: Option<String>,
+    /// `None` without any recent matches
+    pub kd_ratio: Option<f64>,
+    pub games: usize,
+    pub main_hero: Option<String>,
+    pub main_hero_image: Option<String>,
+}
+
+impl ScoutReport {
+    pub fn new(profile: IndexedPlayer, matches: Value) -> Self {
+        let rank = PlayerSummary::from_matches(&matches).rank;
+        let rows = take_rows(matches);
+        let totals = HistoryTotals::from_rows(&rows);
+        // Most games first, better average placement breaking ties
+        let main_hero = totals
+            .heroes
+            .iter()
+            .max_by(|(_, a), (_, b)| a.games.cmp(&b.games).then(b.avg_placement().total_cmp(&a.avg_placement())))
+            .map(|(name, _)| name.clone());
+        let main_hero_image = main_hero.as_ref().and_then(|hero| {
+            rows.iter()
+                .find(|row| row["hero"]["name"].as_str() == Some(hero.as_str()))
+                .and_then(|row| row["hero"]["head_image_url"].as_str().or(row["hero"]["image_url"].as_str()))
+                .map(|url| url.to_string())
+        });
+        Self {
+            profile,
+            rank,
+            kd_ratio: (!rows.is_empty()).then(|| totals.kd_ratio()),
+            games: rows.len(),
+            main_hero,
+            main_hero_image,
+        }
+    }
+}
@@ -0,0 +1,565 @@
+use crate::session::parse_time;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// Default points per placement, first place first; lower placements score nothing.
+pub const PLACEMENT_POINTS: [u32; 8] = [12, 9, 7, 5, 4, 3, 2, 1];
+/// Default points per kill, on top of the placement points.
+pub const KILL_POINTS: u32 = 1;
+/// Most placements a point system can score; lobbies don't hold more teams than this.
+pub const MAX_SCORED_PLACEMENTS: usize = 20;
+/// Longest break between two customs that still counts as the same session.
+const SESSION_GAP_MINUTES: i64 = 60;
+
+/// One match of a series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeriesGame {
+    pub platform: String,
+    pub match_id: String,
+    /// Map or other label given by the user; games sharing a label are totalled together
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// How a series turns placements and kills into points.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PointSystem {
+    /// Points per placement, first place first; lower placements score nothing
+    pub placement: Vec<u32>,
+    pub per_kill: u32,
+}
+
+impl Default for PointSystem {
+    fn default() -> Self {
+        Self {
+            placement: PLACEMENT_POINTS.to_vec(),
+            per_kill: KILL_POINTS,
+        }
+    }
+}
+
+impl PointSystem {
+    pub fn placement_points(&self, placement: Option<i64>) -> u32 {
+        placement
+            .and_then(|placement| usize::try_from(placement - 1).ok())
+            .and_then(|idx| self.placement.get(idx).copied())
+            .unwrap_or(0)
+    }
+
+    pub fn game_points(&self, placement: Option<i64>, kills: i64) -> u32 {
+        self.placement_points(placement) + kills.max(0) as u32 * self.per_kill
+    }
+
+    /// E.g. "12 points for a win down to 1 for #8, plus 1 per kill".
+    pub fn describe(&self) -> String {
+        let placement = match (self.placement.first(), self.placement.iter().rposition(|points| *points > 0)) {
+            (Some(first), Some(last)) if last > 0 => {
+                format!("{} points for a win down to {} for #{}", first, self.placement[last], last + 1)
+            }
+            (Some(first), Some(_)) => format!("{} points for a win", first),
+            _ => "no placement points".to_string(),
+        };
+        format!("{}, plus {} per kill", placement, self.per_kill)
+    }
+}
+
+/// Custom matches grouped under a name, e.g. "Tuesday scrims vs TeamX".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Series {
+    pub id: u64,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub games: Vec<SeriesGame>,
+    #[serde(default)]
+    pub points: PointSystem,
+}
+
+/// Named series, kept on this machine next to the match archive, which stores their scoreboards.
+pub struct SeriesStore {
+    path: PathBuf,
+    series: Vec<Series>,
+}
+
+impl SeriesStore {
+    pub fn default_path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui")
+            .join("series.json")
+    }
+
+    pub fn new(path: PathBuf) -> Self {
+        let mut store = Self {
+            path,
+            series: Vec::new(),
+        };
+        store.load();
+        store
+    }
+
+    fn load(&mut self) {
+        if let Ok(file) = fs::File::open(&self.path) {
+            if let Ok(series) = serde_json::from_reader(file) {
+                self.series = series;
+            }
+        }
+    }
+
+    fn save(&self) {
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(file) = fs::File::create(&self.path) {
+            let _ = serde_json::to_writer_pretty(file, &self.series);
+        }
+    }
+
+    /// Every series, most recently created first.
+    pub fn list(&self) -> Vec<Series> {
+        let mut series = self.series.clone();
+        series.sort_by_key(|series| std::cmp::Reverse(series.created_at));
+        series
+    }
+
+    /// Copy of every series for a backup.
+    pub fn snapshot(&self) -> Vec<Series> {
+        self.series.clone()
+    }
+
+    /// Adds series from an imported backup that aren't here yet, matched by name and creation
+    /// time. Imported series get fresh ids. Returns how many were added.
+    pub fn merge(&mut self, series: Vec<Series>) -> usize {
+        let mut added = 0;
+        for mut imported in series {
+            let exists = self
+                .series
+                .iter()
+                .any(|existing| existing.name == imported.name && existing.created_at == imported.created_at);
+            if exists {
+                continue;
+            }
+            imported.id = self.series.iter().map(|series| series.id).max().unwrap_or(0) + 1;
+            self.series.push(imported);
+            added += 1;
+        }
+        if added > 0 {
+            self.save();
+        }
+        added
+    }
+
+    pub fn get(&self, id: u64) -> Option<Series> {
+        self.series.iter().find(|series| series.id == id).cloned()
+    }
+
+    /// Starts an empty series and returns its id.
+    pub fn create(&mut self, name: &str) -> u64 {
+        let id = self.series.iter().map(|series| series.id).max().unwrap_or(0) + 1;
+        self.series.push(Series {
+            id,
+            name: name.trim().to_string(),
+            created_at: Utc::now(),
+            games: Vec::new(),
+            points: PointSystem::default(),
+        });
+        self.save();
+        id
+    }
+
+    fn edit(&mut self, id: u64, f: impl FnOnce(&mut Series)) {
+        if let Some(series) = self.series.iter_mut().find(|series| series.id == id) {
+            f(series);
+            self.save();
+        }
+    }
+
+    pub fn rename(&mut self, id: u64, name: &str) {
+        let name = name.trim().to_string();
+        if !name.is_empty() {
+            self.edit(id, |series| series.name = name);
+        }
+    }
+
+    pub fn delete(&mut self, id: u64) {
+        self.series.retain(|series| series.id != id);
+        self.save();
+    }
+
+    /// Adds matches the series doesn't have yet and returns how many were new.
+    pub fn add_games(&mut self, id: u64, games: Vec<(String, String)>) -> usize {
+        let mut added = 0;
+        self.edit(id, |series| {
+            for (platform, match_id) in games {
+                if series.games.iter().any(|game| game.platform == platform && game.match_id == match_id) {
+                    continue;
+                }
+                series.games.push(SeriesGame {
+                    platform,
+                    match_id,
+                    label: None,
+                });
+                added += 1;
+            }
+        });
+        added
+    }
+
+    pub fn set_points(&mut self, id: u64, points: PointSystem) {
+        self.edit(id, |series| series.points = points);
+    }
+
+    pub fn remove_game(&mut self, id: u64, match_id: &str) {
+        self.edit(id, |series| series.games.retain(|game| game.match_id != match_id));
+    }
+
+    /// Labels a game, e.g. with its map; an empty label clears it.
+    pub fn set_label(&mut self, id: u64, match_id: &str, label: &str) {
+        let label = label.trim();
+        self.edit(id, |series| {
+            if let Some(game) = series.games.iter_mut().find(|game| game.match_id == match_id) {
+                game.label = (!label.is_empty()).then(|| label.to_string());
+            }
+        });
+    }
+}
+
+/// The most recent run of back-to-back unranked matches in a history page (most recent first),
+/// oldest first. Op.gg doesn't mark customs as such, so any unranked match counts; the run ends
+/// at a ranked match or a break longer than an hour.
+pub fn latest_custom_run(platform: &str, rows: &[Value]) -> Vec<(String, String)> {
+    let mut run = Vec::new();
+    let mut previous_start: Option<DateTime<Utc>> = None;
+    for row in rows.iter().skip_while(|row| row["is_ranked"].as_bool().unwrap_or(false)) {
+        if row["is_ranked"].as_bool().unwrap_or(false) {
+            break;
+        }
+        let start = parse_time(&row["match_start"]);
+        if let (Some(previous), Some(start)) = (previous_start, start) {
+            if previous - start > Duration::minutes(SESSION_GAP_MINUTES) {
+                break;
+            }
+        }
+        let Some(match_id) = row["match_id"].as_str() else {
+            continue;
+        };
+        previous_start = start.or(previous_start);
+        run.push((platform.to_string(), match_id.to_string()));
+    }
+    run.reverse();
+    run
+}
+
+/// A team's totals across the series. The same squad can get a different team number each
+/// game, so teams are told apart by their players.
+#[derive(Debug, Clone, Default)]
+pub struct TeamStanding {
+    pub player_ids: HashSet<String>,
+    pub names: Vec<String>,
+    pub points: u32,
+    pub kills: i64,
+    pub games: usize,
+    pub wins: usize,
+    placement_sum: i64,
+}
+
+impl TeamStanding {
+    pub fn name(&self) -> String {
+        self.names.join(" / ")
+    }
+
+    pub fn avg_placement(&self) -> f64 {
+        if self.games > 0 {
+            self.placement_sum as f64 / self.games as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// One team's result in one game.
+#[derive(Debug, Clone)]
+pub struct GameTeam {
+    /// Index into `Standings::teams`
+    pub team: usize,
+    pub placement: Option<i64>,
+    pub kills: i64,
+    pub points: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct GameResult {
+    pub game: SeriesGame,
+    pub match_start: Option<DateTime<Utc>>,
+    /// Best placement first
+    pub teams: Vec<GameTeam>,
+}
+
+/// Points per team over the games sharing one label.
+#[derive(Debug, Clone)]
+pub struct LabelTotals {
+    pub label: String,
+    pub games: usize,
+    /// `(team, points)`, most points first
+    pub points: Vec<(usize, u32)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Standings {
+    pub teams: Vec<TeamStanding>,
+    /// Indices into `teams`, most points first
+    pub ranking: Vec<usize>,
+    /// Oldest game first
+    pub games: Vec<GameResult>,
+    pub labels: Vec<LabelTotals>,
+}
+
+/// Scores the loaded games of a series with `points`. A squad in a game joins the series team it
+/// shares at least half its players with, so a substitute doesn't split a team in two. Teams are
+/// matched the same way whatever the point system, so `teams` indices line up between two
+/// scorings of the same games.
+pub fn standings(games: &[(SeriesGame, Vec<Value>)], points: &PointSystem) -> Standings {
+    let mut order: Vec<&(SeriesGame, Vec<Value>)> = games.iter().collect();
+    order.sort_by_key(|(_, rows)| rows.iter().find_map(|row| parse_time(&row["match_start"])));
+
+    let mut result = Standings::default();
+    for (game, rows) in order {
+        let mut squads: BTreeMap<i64, Vec<&Value>> = BTreeMap::new();
+        for row in rows {
+            squads.entry(row["team_id"].as_i64().unwrap_or(0)).or_default().push(row);
+        }
+        let mut matched: HashSet<usize> = HashSet::new();
+        let mut game_teams = Vec::new();
+        for players in squads.values() {
+            let ids: Vec<&str> = players.iter().filter_map(|row| row["player_id"].as_str()).collect();
+            let best = result
+                .teams
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !matched.contains(idx))
+                .map(|(idx, team)| (idx, ids.iter().filter(|id| team.player_ids.contains(**id)).count()))
+                .max_by_key(|(_, overlap)| *overlap)
+                .filter(|(_, overlap)| *overlap > 0 && overlap * 2 >= ids.len());
+            let team_idx = match best {
+                Some((idx, _)) => idx,
+                None => {
+                    result.teams.push(TeamStanding::default());
+                    result.teams.len() - 1
+                }
+            };
+            matched.insert(team_idx);
+
+            let team = &mut result.teams[team_idx];
+            for row in players {
+                let Some(id) = row["player_id"].as_str() else {
+                    continue;
+                };
+                if team.player_ids.insert(id.to_string()) {
+                    let name = row["player"]["unique_display_name"].as_str().unwrap_or(id);
+                    team.names.push(name.split('#').next().unwrap_or(name).to_string());
+                }
+            }
+            let placement = players.iter().find_map(|row| row["placement"].as_i64());
+            let kills: i64 = players.iter().map(|row| row["stats"]["Kills"].as_i64().unwrap_or(0)).sum();
+            let game_points = points.game_points(placement, kills);
+            team.points += game_points;
+            team.kills += kills;
+            team.games += 1;
+            if let Some(placement) = placement {
+                team.placement_sum += placement;
+                if placement == 1 {
+                    team.wins += 1;
+                }
+            }
+            game_teams.push(GameTeam {
+                team: team_idx,
+                placement,
+                kills,
+                points: game_points,
+            });
+        }
+        game_teams.sort_by_key(|team| team.placement.unwrap_or(i64::MAX));
+        result.games.push(GameResult {
+            game: game.clone(),
+            match_start: rows.iter().find_map(|row| parse_time(&row["match_start"])),
+            teams: game_teams,
+        });
+    }
+
+    result.ranking = (0..result.teams.len()).collect();
+    result.ranking.sort_by(|a, b| {
+        let (a, b) = (&result.teams[*a], &result.teams[*b]);
+        b.points.cmp(&a.points).then(b.wins.cmp(&a.wins)).then(b.kills.cmp(&a.kills))
+    });
+
+    let mut labels: BTreeMap<String, (usize, Vec<u32>)> = BTreeMap::new();
+    for game in &result.games {
+        let Some(label) = &game.game.label else {
+            continue;
+        };
+        let (count, points) = labels.entry(label.clone()).or_insert_with(|| (0, vec![0; result.teams.len()]));
+        *count += 1;
+        for team in &game.teams {
+            points[team.team] += team.points;
+        }
+    }
+    result.labels = labels
+        .into_iter()
+        .map(|(label, (games, points))| {
+            let mut points: Vec<(usize, u32)> = points.into_iter().enumerate().filter(|(_, points)| *points > 0).collect();
+            points.sort_by_key(|(_, points)| std::cmp::Reverse(*points));
+            LabelTotals { label, games, points }
+        })
+        .collect();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(match_id: &str, start: &str, team_id: i64, player_id: &str, placement: i64, kills: i64) -> Value {
+        json!({
+            "match_id": match_id,
+            "match_start": start,
+            "team_id": team_id,
+            "player_id": player_id,
+            "player": { "unique_display_name": format!("{}#1", player_id) },
+            "placement": placement,
+            "stats": { "Kills": kills },
+        })
+    }
+
+    fn game(match_id: &str, label: Option<&str>) -> SeriesGame {
+        SeriesGame {
+            platform: "steam".to_string(),
+            match_id: match_id.to_string(),
+            label: label.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn describe_point_systems() {
+        assert_eq!(PointSystem::default().describe(), "12 points for a win down to 1 for #8, plus 1 per kill");
+        let win_only = PointSystem { placement: vec![10, 0, 0], per_kill: 2 };
+        assert_eq!(win_only.describe(), "10 points for a win, plus 2 per kill");
+        let nothing = PointSystem { placement: Vec::new(), per_kill: 1 };
+        assert_eq!(nothing.describe(), "no placement points, plus 1 per kill");
+        let zeroes = PointSystem { placement: vec![0, 0], per_kill: 0 };
+        assert_eq!(zeroes.describe(), "no placement points, plus 0 per kill");
+    }
+
+    #[test]
+    fn teams_follow_their_players_across_team_numbers() {
+        let games = vec![
+            (
+                game("b", Some("Map B")),
+                vec![
+                    // Same squads with swapped team numbers and a substitute on the first team
+                    row("b", "2026-09-01T19:00:00Z", 2, "alice", 2, 1),
+                    row("b", "2026-09-01T19:00:00Z", 2, "sub", 2, 0),
+                    row("b", "2026-09-01T19:00:00Z", 1, "carol", 1, 4),
+                    row("b", "2026-09-01T19:00:00Z", 1, "dave", 1, 0),
+                ],
+            ),
+            (
+                game("a", Some("Map A")),
+                vec![
+                    row("a", "2026-09-01T18:00:00Z", 1, "alice", 1, 3),
+                    row("a", "2026-09-01T18:00:00Z", 1, "bob", 1, 2),
+                    row("a", "2026-09-01T18:00:00Z", 2, "carol", 2, 0),
+                    row("a", "2026-09-01T18:00:00Z", 2, "dave", 2, 1),
+                ],
+            ),
+        ];
+        let standings = standings(&games, &PointSystem::default());
+
+        assert_eq!(standings.teams.len(), 2);
+        // Oldest game first, so the first team is alice's from game "a"
+        assert_eq!(standings.games[0].game.match_id, "a");
+        let alice = &standings.teams[0];
+        assert_eq!(alice.names, vec!["alice", "bob", "sub"]);
+        assert_eq!((alice.points, alice.kills, alice.games, alice.wins), (12 + 5 + 9 + 1, 6, 2, 1));
+        assert_eq!(alice.avg_placement(), 1.5);
+        let carol = &standings.teams[1];
+        assert_eq!((carol.points, carol.kills, carol.wins), (9 + 1 + 12 + 4, 5, 1));
+        assert_eq!(standings.ranking, vec![0, 1]);
+
+        assert_eq!(standings.labels.len(), 2);
+        assert_eq!(standings.labels[0].label, "Map A");
+        assert_eq!(standings.labels[0].points, vec![(0, 17), (1, 10)]);
+        assert_eq!(standings.labels[1].label, "Map B");
+        assert_eq!(standings.labels[1].points, vec![(1, 16), (0, 10)]);
+    }
+
+    #[test]
+    fn squads_sharing_under_half_their_players_are_new_teams() {
+        let games = vec![
+            (
+                game("a", None),
+                vec![row("a", "2026-09-01T18:00:00Z", 1, "alice", 1, 0), row("a", "2026-09-01T18:00:00Z", 1, "bob", 1, 0)],
+            ),
+            (
+                game("b", None),
+                vec![
+                    row("b", "2026-09-01T19:00:00Z", 1, "alice", 1, 0),
+                    row("b", "2026-09-01T19:00:00Z", 1, "x", 1, 0),
+                    row("b", "2026-09-01T19:00:00Z", 1, "y", 1, 0),
+                ],
+            ),
+        ];
+        let standings = standings(&games, &PointSystem::default());
+        assert_eq!(standings.teams.len(), 2);
+        assert!(standings.labels.is_empty());
+    }
+
+    #[test]
+    fn ranking_breaks_ties_on_wins_then_kills_then_first_seen() {
+        let points = PointSystem { placement: vec![3, 3, 0], per_kill: 0 };
+        let games = vec![(
+            game("a", None),
+            vec![
+                row("a", "2026-09-01T18:00:00Z", 1, "p1", 2, 5),
+                row("a", "2026-09-01T18:00:00Z", 2, "p2", 1, 0),
+                row("a", "2026-09-01T18:00:00Z", 3, "p3", 3, 9),
+                row("a", "2026-09-01T18:00:00Z", 4, "p4", 3, 9),
+            ],
+        )];
+        let standings = standings(&games, &points);
+        // Teams 0 and 1 tie on points; the win puts team 1 first. Teams 2 and 3 tie on
+        // everything and keep the order they were first seen in.
+        assert_eq!(standings.ranking, vec![1, 0, 2, 3]);
+        assert_eq!(standings.games[0].teams.iter().map(|team| team.team).collect::<Vec<_>>(), vec![1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn custom_run_stops_at_ranked_matches_and_long_breaks() {
+        let history = |ranked: &[(&str, &str, bool)]| -> Vec<Value> {
+            ranked
+                .iter()
+                .map(|(id, start, is_ranked)| json!({ "match_id": id, "match_start": start, "is_ranked": is_ranked }))
+                .collect()
+        };
+        let rows = history(&[
+            ("r1", "2026-09-01T22:00:00Z", true),
+            ("c3", "2026-09-01T21:00:00Z", false),
+            ("c2", "2026-09-01T20:30:00Z", false),
+            ("c1", "2026-09-01T20:00:00Z", false),
+            ("r0", "2026-09-01T19:30:00Z", true),
+            ("c0", "2026-09-01T19:00:00Z", false),
+        ]);
+        let ids: Vec<String> = latest_custom_run("steam", &rows).into_iter().map(|(_, id)| id).collect();
+        assert_eq!(ids, vec!["c1", "c2", "c3"]);
+
+        let rows = history(&[
+            ("c2", "2026-09-01T21:00:00Z", false),
+            ("c1", "2026-09-01T19:30:00Z", false),
+        ]);
+        assert_eq!(latest_custom_run("steam", &rows), vec![("steam".to_string(), "c2".to_string())]);
+        assert!(latest_custom_run("steam", &history(&[("r", "2026-09-01T21:00:00Z", true)])).is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+
+/// A break longer than this between two matches starts a new session.
+const SESSION_GAP: Duration = Duration::hours(1);
+
+/// Stats over the current play session: the run of recent matches without a long break.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStats {
+    pub games: usize,
+    pub wins: usize,
+    pub kills: i64,
+    pub deaths: i64,
+    pub avg_placement: f64,
+    /// Most recent first
+    pub placements: Vec<i64>,
+}
+
+pub fn parse_time(value: &Value) -> Option<DateTime<Utc>> {
+    value
+        .as_str()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|time| time.with_timezone(&Utc))
+}
+
+impl SessionStats {
+    /// Builds the session from match rows ordered most recent first. Returns an empty session
+    /// if the latest match ended more than `SESSION_GAP` ago.
+    pub fn from_matches(rows: &[Value]) -> Self {
+        let now = Utc::now();
+        let mut session = Vec::new();
+        let mut next_start = now;
+        for row in rows {
+            let end = parse_time(&row["match_end"]).or_else(|| parse_time(&row["match_start"]));
+            match end {
+                Some(end) if next_start - end <= SESSION_GAP => {
+                    next_start = parse_time(&row["match_start"]).unwrap_or(end);
+                    session.push(row);
+                }
+                _ => break,
+            }
+        }
+
+        let placements: Vec<i64> = session
+            .iter()
+            .filter_map(|row| row["placement"].as_i64())
+            .collect();
+        let stat = |key: &str| -> i64 {
+            session
+                .iter()
+                .filter_map(|row| row["stats"][key].as_i64())
+                .sum()
+        };
+
+        Self {
+            games: session.len(),
+            wins: placements.iter().filter(|placement| **placement == 1).count(),
+            kills: stat("Kills"),
+            deaths: stat("Deaths"),
+            avg_placement: if placements.is_empty() {
+                0.0
+            } else {
+                placements.iter().sum::<i64>() as f64 / placements.len() as f64
+            },
+            placements,
+        }
+    }
+
+    pub fn kd_ratio(&self) -> f64 {
+        if self.deaths > 0 {
+            self.kills as f64 / self.deaths as f64
+        } else {
+            self.kills as f64
+        }
+    }
+}
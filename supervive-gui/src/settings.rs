@@ -0,0 +1,598 @@
+use crate::goals::Goal;
+use crate::notifier::Webhook;
+use crate::scheduler::JobKind;
+use crate::theme::{Density, Palette, RatingThresholds};
+use crate::trends::{ChartRange, Smoothing};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A player the user has singled out: a watched player or their own linked account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRef {
+    pub platform: String,
+    pub player_id: String,
+    pub name: String,
+}
+
+/// Players followed for the meta page, e.g. the top 100 of one region's leaderboard. The archive
+/// job keeps their matches so the page works from local data.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FollowedSlice {
+    pub name: String,
+    /// Region code the meta page keeps matches from; `None` counts every region
+    pub region: Option<String>,
+    pub players: Vec<PlayerRef>,
+}
+
+impl FollowedSlice {
+    pub fn is_followed(&self, platform: &str, player_id: &str) -> bool {
+        self.players
+            .iter()
+            .any(|player| player.platform == platform && player.player_id == player_id)
+    }
+}
+
+/// A game update, marked on the meta trend chart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchMarker {
+    pub label: String,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum SyncProviderKind {
+    #[default]
+    WebDav,
+}
+
+/// A rearrangeable section of the player profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProfilePanel {
+    Summary,
+    Activity,
+    Records,
+    Phases,
+    Teamplay,
+    RankProgress,
+    Matchups,
+    Compositions,
+    Suggestions,
+    Plugins,
+    Matches,
+}
+
+impl ProfilePanel {
+    pub const ALL: [ProfilePanel; 11] = [
+        ProfilePanel::Summary,
+        ProfilePanel::Activity,
+        ProfilePanel::Records,
+        ProfilePanel::Phases,
+        ProfilePanel::Teamplay,
+        ProfilePanel::RankProgress,
+        ProfilePanel::Matchups,
+        ProfilePanel::Compositions,
+        ProfilePanel::Suggestions,
+        ProfilePanel::Plugins,
+        ProfilePanel::Matches,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProfilePanel::Summary => "Summary cards",
+            ProfilePanel::Activity => "Activity calendar",
+            ProfilePanel::Records => "Records",
+            ProfilePanel::Phases => "Deaths by phase",
+            ProfilePanel::Teamplay => "Teamplay",
+            ProfilePanel::RankProgress => "RP over time",
+            ProfilePanel::Matchups => "Enemy matchups",
+            ProfilePanel::Compositions => "Team compositions",
+            ProfilePanel::Suggestions => "Suggested heroes",
+            ProfilePanel::Plugins => "Plugin panels",
+            ProfilePanel::Matches => "Recent matches",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PanelWidth {
+    #[default]
+    Full,
+    /// Shares a row with a neighbouring half-width panel
+    Half,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PanelLayout {
+    pub panel: ProfilePanel,
+    pub visible: bool,
+    pub width: PanelWidth,
+}
+
+/// What a pinned home tile shows about its player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TileKind {
+    /// The current play session, as on the overlay
+    Session,
+    HeroWinRates,
+    /// The player's standing among watched players
+    Leaderboard,
+}
+
+impl TileKind {
+    pub const ALL: [TileKind; 3] = [TileKind::Session, TileKind::HeroWinRates, TileKind::Leaderboard];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TileKind::Session => "Session tracker",
+            TileKind::HeroWinRates => "Hero win rates",
+            TileKind::Leaderboard => "Watched leaderboard",
+        }
+    }
+
+    /// Whether the tile only counts matches from the preferred region.
+    pub fn uses_region(&self) -> bool {
+        matches!(self, TileKind::HeroWinRates | TileKind::Leaderboard)
+    }
+}
+
+/// A stat column the scoreboard can show next to each player's name and hero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoreboardColumn {
+    Kda,
+    Damage,
+    DamageTaken,
+    Healing,
+    Shielding,
+    Revives,
+    Accuracy,
+    Gold,
+}
+
+impl ScoreboardColumn {
+    pub const ALL: [ScoreboardColumn; 8] = [
+        ScoreboardColumn::Kda,
+        ScoreboardColumn::Damage,
+        ScoreboardColumn::DamageTaken,
+        ScoreboardColumn::Healing,
+        ScoreboardColumn::Shielding,
+        ScoreboardColumn::Revives,
+        ScoreboardColumn::Accuracy,
+        ScoreboardColumn::Gold,
+    ];
+
+    /// Shown until the user picks their own; few enough that rows stay readable.
+    pub const DEFAULT: [ScoreboardColumn; 3] =
+        [ScoreboardColumn::Kda, ScoreboardColumn::Damage, ScoreboardColumn::Accuracy];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ScoreboardColumn::Kda => "K/D/A",
+            ScoreboardColumn::Damage => "Damage",
+            ScoreboardColumn::DamageTaken => "Taken",
+            ScoreboardColumn::Healing => "Healing",
+            ScoreboardColumn::Shielding => "Shielding",
+            ScoreboardColumn::Revives => "Revives",
+            ScoreboardColumn::Accuracy => "Accuracy",
+            ScoreboardColumn::Gold => "Gold",
+        }
+    }
+}
+
+/// How long imported matches stay in the match archive before the "Prune match archive" job
+/// removes them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArchiveRetention {
+    #[default]
+    Forever,
+    /// Matches played more than this many days ago are removed
+    Days(u32),
+    /// The oldest matches are removed while the archive is larger than this many megabytes
+    SizeMb(u64),
+}
+
+impl ArchiveRetention {
+    pub const PRESETS: [ArchiveRetention; 6] = [
+        ArchiveRetention::Forever,
+        ArchiveRetention::Days(30),
+        ArchiveRetention::Days(90),
+        ArchiveRetention::Days(365),
+        ArchiveRetention::SizeMb(250),
+        ArchiveRetention::SizeMb(1024),
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            ArchiveRetention::Forever => "Forever".to_string(),
+            ArchiveRetention::Days(days) => format!("{} days", days),
+            ArchiveRetention::SizeMb(mb) if mb % 1024 == 0 => format!("Up to {} GB", mb / 1024),
+            ArchiveRetention::SizeMb(mb) => format!("Up to {} MB", mb),
+        }
+    }
+}
+
+/// A stats tile on the home page, refreshed by the "Poll watched players" job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PinnedTile {
+    pub kind: TileKind,
+    pub player: PlayerRef,
+}
+
+/// Endpoint for syncing settings across machines. Stays on this machine; never uploaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncSettings {
+    pub enabled: bool,
+    pub provider: SyncProviderKind,
+    /// Folder the sync document is stored in, e.g. `https://cloud.example.com/remote.php/dav/files/me/supervive`
+    pub url: String,
+    pub username: String,
+    pub password: String,
+}
+
+/// Game log integration used to detect matches and scout the lobby.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LiveMatchSettings {
+    pub enabled: bool,
+    /// Overrides the default game log location
+    pub log_path: Option<String>,
+    /// Marks a map load; a match starts unless the line also contains `menu_marker`
+    pub load_marker: String,
+    pub menu_marker: String,
+    /// Precedes a player name on lines that list match participants
+    pub player_marker: String,
+}
+
+impl Default for LiveMatchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            log_path: None,
+            load_marker: "LogLoad: LoadMap:".to_string(),
+            menu_marker: "Frontend".to_string(),
+            player_marker: "PlayerName=".to_string(),
+        }
+    }
+}
+
+/// Recent searches kept for the search page's empty state.
+const RECENT_SEARCHES: usize = 8;
+
+/// Choices offered for `Settings::min_sample_games`.
+pub const MIN_SAMPLE_PRESETS: [usize; 5] = [1, 3, 5, 10, 20];
+
+/// User preferences. Unknown or missing fields fall back to defaults so older files keep loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub palette: Palette,
+    /// Show ▲/▼ and W/L markers next to colored stats
+    pub show_indicators: bool,
+    /// Cut-offs for good / average / bad coloring per stat
+    pub rating_thresholds: RatingThresholds,
+    /// Keystroke overrides keyed by command id (see `keymap::COMMANDS`)
+    pub keybindings: HashMap<String, String>,
+    /// Base font size in pixels; all rem-based sizes scale from it
+    pub font_size: f32,
+    pub density: Density,
+    /// Notified when a watched player finishes a match
+    pub webhooks: Vec<Webhook>,
+    pub watched_players: Vec<PlayerRef>,
+    /// Players whose matches are archived for the meta page
+    pub followed: FollowedSlice,
+    /// Game updates the user marked, oldest first
+    pub patches: Vec<PatchMarker>,
+    /// Latest marked update the cached stats were dropped for; local to this machine's cache
+    pub stats_patch: Option<NaiveDate>,
+    /// Minutes between scheduled runs per job; 0 disables the job. Missing jobs use their default.
+    pub job_intervals: HashMap<JobKind, u64>,
+    /// Ask GitHub for a newer release on startup (opt-in)
+    pub check_for_updates: bool,
+    /// Count feature usage and error categories locally and upload them in batches (opt-in)
+    pub telemetry_enabled: bool,
+    pub telemetry_endpoint: String,
+    /// The active account, which powers the header shortcut and the session overlay
+    pub my_account: Option<PlayerRef>,
+    /// Every account linked as the user's own (alts, other platforms), including the active one
+    pub accounts: Vec<PlayerRef>,
+    /// Don't offer to link the detected Steam account again
+    pub steam_prompt_dismissed: bool,
+    pub live_match: LiveMatchSettings,
+    /// Register OS-wide hotkeys that work while the app is in the background (opt-in)
+    pub global_hotkeys_enabled: bool,
+    /// Hotkey overrides keyed by command id (see `global_hotkeys::GLOBAL_COMMANDS`)
+    pub global_hotkeys: HashMap<String, String>,
+    /// Offer to look up player names copied to the clipboard (opt-in)
+    pub clipboard_watch: bool,
+    pub sync: SyncSettings,
+    /// Order, visibility and width of the profile panels; see `Settings::profile_layout`
+    pub profile_layout: Vec<PanelLayout>,
+    /// Targets tracked on the home page
+    pub goals: Vec<Goal>,
+    /// Stats tiles on the home page, in display order
+    pub pinned_tiles: Vec<PinnedTile>,
+    /// Queries that found players, most recent first, offered before the next search
+    pub recent_searches: Vec<String>,
+    /// Stat columns on the match scoreboard, in display order
+    pub scoreboard_columns: Vec<ScoreboardColumn>,
+    /// How long imported matches are kept; see `MatchArchive::expired`
+    pub archive_retention: ArchiveRetention,
+    /// Folder backups are written to; the downloads folder when unset
+    pub backup_dir: Option<String>,
+    /// Deflate backups instead of storing their files as they are
+    pub backup_compress: bool,
+    /// Show the suggested heroes panel on profiles
+    pub show_hero_suggestions: bool,
+    /// Games a hero, matchup or composition needs before its win rate counts; smaller samples
+    /// are greyed out and left out of rankings
+    pub min_sample_games: usize,
+    /// Matches the profile charts cover
+    pub chart_range: ChartRange,
+    /// Rolling average applied to the profile's trend lines
+    pub chart_smoothing: Smoothing,
+    /// Draw frame times, live views and portrait cache counters over the window
+    pub show_frame_overlay: bool,
+    /// Set once the first-run wizard has been finished or skipped
+    pub onboarding_done: bool,
+    /// Platform whose players are listed first in search results
+    pub preferred_platform: String,
+    /// Region code (e.g. "eu") of the user's server; other regions are dimmed in search results
+    /// and left out of the hero win rate and leaderboard tiles. `None` merges every region.
+    pub preferred_region: Option<String>,
+    /// When these settings last changed; the newer copy wins during sync
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Settings {
+    pub fn is_watched(&self, platform: &str, player_id: &str) -> bool {
+        self.watched_players
+            .iter()
+            .any(|player| player.platform == platform && player.player_id == player_id)
+    }
+
+    pub fn is_my_account(&self, platform: &str, player_id: &str) -> bool {
+        self.accounts
+            .iter()
+            .any(|account| account.platform == platform && account.player_id == player_id)
+    }
+
+    pub fn is_pinned(&self, kind: TileKind, platform: &str, player_id: &str) -> bool {
+        self.pinned_tiles.iter().any(|tile| {
+            tile.kind == kind && tile.player.platform == platform && tile.player.player_id == player_id
+        })
+    }
+
+    /// Pins the tile, or unpins it if it is already on the home page.
+    pub fn toggle_pin(&mut self, tile: PinnedTile) {
+        if self.is_pinned(tile.kind, &tile.player.platform, &tile.player.player_id) {
+            self.pinned_tiles.retain(|pinned| {
+                pinned.kind != tile.kind
+                    || pinned.player.platform != tile.player.platform
+                    || pinned.player.player_id != tile.player.player_id
+            });
+        } else {
+            self.pinned_tiles.push(tile);
+        }
+    }
+
+    /// Players of pinned tiles, each once, that the poller fetches besides the watched ones.
+    pub fn pinned_players(&self) -> Vec<PlayerRef> {
+        let mut players: Vec<PlayerRef> = Vec::new();
+        for tile in &self.pinned_tiles {
+            let known = players.iter().any(|player| {
+                player.platform == tile.player.platform && player.player_id == tile.player.player_id
+            });
+            if !known && !self.is_watched(&tile.player.platform, &tile.player.player_id) {
+                players.push(tile.player.clone());
+            }
+        }
+        players
+    }
+
+    /// Adds `account` to the linked accounts if needed and makes it the active one.
+    pub fn link_account(&mut self, account: PlayerRef) {
+        if !self.is_my_account(&account.platform, &account.player_id) {
+            self.accounts.push(account.clone());
+        }
+        self.my_account = Some(account);
+    }
+
+    /// Removes a linked account; if it was active, the next remaining account takes over.
+    pub fn unlink_account(&mut self, platform: &str, player_id: &str) {
+        self.accounts
+            .retain(|account| account.platform != platform || account.player_id != player_id);
+        let was_active = self
+            .my_account
+            .as_ref()
+            .is_some_and(|account| account.platform == platform && account.player_id == player_id);
+        if was_active {
+            self.my_account = self.accounts.first().cloned();
+        }
+    }
+
+    /// The saved panel layout with duplicates dropped and panels added since it was saved
+    /// appended, visible and full width.
+    pub fn profile_layout(&self) -> Vec<PanelLayout> {
+        let mut layout: Vec<PanelLayout> = Vec::new();
+        for entry in &self.profile_layout {
+            if !layout.iter().any(|existing| existing.panel == entry.panel) {
+                layout.push(*entry);
+            }
+        }
+        for panel in ProfilePanel::ALL {
+            if !layout.iter().any(|existing| existing.panel == panel) {
+                layout.push(PanelLayout {
+                    panel,
+                    visible: true,
+                    width: PanelWidth::Full,
+                });
+            }
+        }
+        layout
+    }
+
+    /// Moves `query` to the front of the recent searches, dropping the oldest past the limit.
+    pub fn record_recent_search(&mut self, query: &str) {
+        let query = query.trim();
+        self.recent_searches.retain(|recent| !recent.eq_ignore_ascii_case(query));
+        self.recent_searches.insert(0, query.to_string());
+        self.recent_searches.truncate(RECENT_SEARCHES);
+    }
+
+    /// Shows or hides a scoreboard column, keeping the columns in their fixed order.
+    pub fn toggle_scoreboard_column(&mut self, column: ScoreboardColumn) {
+        let mut columns: Vec<ScoreboardColumn> = ScoreboardColumn::ALL
+            .into_iter()
+            .filter(|candidate| self.scoreboard_columns.contains(candidate))
+            .collect();
+        if let Some(idx) = columns.iter().position(|shown| *shown == column) {
+            columns.remove(idx);
+        } else {
+            columns.push(column);
+            columns.sort_by_key(|shown| ScoreboardColumn::ALL.iter().position(|candidate| candidate == shown));
+        }
+        self.scoreboard_columns = columns;
+    }
+
+    pub fn job_interval(&self, job: JobKind) -> u64 {
+        self.job_intervals
+            .get(&job)
+            .copied()
+            .unwrap_or_else(|| job.default_interval())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            palette: Palette::default(),
+            show_indicators: true,
+            rating_thresholds: RatingThresholds::default(),
+            keybindings: HashMap::new(),
+            font_size: 16.0,
+            density: Density::default(),
+            webhooks: Vec::new(),
+            watched_players: Vec::new(),
+            followed: FollowedSlice::default(),
+            patches: Vec::new(),
+            stats_patch: None,
+            job_intervals: HashMap::new(),
+            check_for_updates: false,
+            telemetry_enabled: false,
+            telemetry_endpoint: String::new(),
+            my_account: None,
+            accounts: Vec::new(),
+            steam_prompt_dismissed: false,
+            live_match: LiveMatchSettings::default(),
+            global_hotkeys_enabled: false,
+            global_hotkeys: HashMap::new(),
+            clipboard_watch: false,
+            sync: SyncSettings::default(),
+            profile_layout: Vec::new(),
+            goals: Vec::new(),
+            pinned_tiles: Vec::new(),
+            recent_searches: Vec::new(),
+            scoreboard_columns: ScoreboardColumn::DEFAULT.to_vec(),
+            archive_retention: ArchiveRetention::default(),
+            backup_dir: None,
+            backup_compress: true,
+            show_hero_suggestions: true,
+            min_sample_games: 5,
+            chart_range: ChartRange::default(),
+            chart_smoothing: Smoothing::default(),
+            show_frame_overlay: false,
+            onboarding_done: false,
+            preferred_platform: "steam".to_string(),
+            preferred_region: None,
+            updated_at: None,
+        }
+    }
+}
+
+pub struct SettingsStore {
+    path: PathBuf,
+    settings: Settings,
+    /// Bumped on every change so pollers can skip rereading unchanged settings
+    revision: u64,
+}
+
+impl SettingsStore {
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui")
+            .join("settings.json")
+    }
+
+    pub fn new(path: PathBuf) -> Self {
+        let mut store = Self {
+            path,
+            settings: Settings::default(),
+            revision: 0,
+        };
+        store.load();
+        store
+    }
+
+    fn load(&mut self) {
+        if let Ok(file) = fs::File::open(&self.path) {
+            if let Ok(settings) = serde_json::from_reader::<_, Settings>(file) {
+                self.settings = settings;
+                // Files from before multi-account support only have the single linked account
+                if let Some(account) = self.settings.my_account.clone() {
+                    self.settings.link_account(account);
+                }
+            }
+        }
+    }
+
+    fn save(&mut self) {
+        self.revision += 1;
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(file) = fs::File::create(&self.path) {
+            let _ = serde_json::to_writer_pretty(file, &self.settings);
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Changes whenever the settings do, including pulls from sync and restores.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Applies a change and persists it immediately.
+    pub fn update(&mut self, f: impl FnOnce(&mut Settings)) {
+        f(&mut self.settings);
+        self.settings.updated_at = Some(Utc::now());
+        self.save();
+    }
+
+    /// Replaces the settings with a newer copy from another machine, keeping the parts that
+    /// only make sense locally (sync credentials, game log location, cache state).
+    pub fn apply_synced(&mut self, mut settings: Settings, updated_at: DateTime<Utc>) {
+        settings.sync = self.settings.sync.clone();
+        settings.live_match = self.settings.live_match.clone();
+        settings.stats_patch = self.settings.stats_patch;
+        settings.updated_at = Some(updated_at);
+        self.settings = settings;
+        self.save();
+    }
+
+    /// Replaces the settings with those from a backup, keeping the same local-only parts as
+    /// `apply_synced`.
+    pub fn apply_restored(&mut self, mut settings: Settings) {
+        settings.sync = self.settings.sync.clone();
+        settings.live_match = self.settings.live_match.clone();
+        settings.stats_patch = self.settings.stats_patch;
+        self.update(|s| *s = settings);
+    }
+}
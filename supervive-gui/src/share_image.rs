@@ -0,0 +1,33 @@
+use anyhow::{Context, Result};
+use resvg::{tiny_skia, usvg};
+use std::path::Path;
+
+/// Pixels per SVG unit, so shared images stay sharp on high-density screens.
+const SCALE: f32 = 2.0;
+
+/// Fonts tried in order for text in shared images; the first one installed is used.
+pub const FONT_FAMILY: &str = "Inter, 'Segoe UI', 'Helvetica Neue', Arial, 'DejaVu Sans', sans-serif";
+
+/// Escapes text for use in SVG markup.
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders an SVG document off-screen with the system fonts and writes it to `path` as a PNG.
+pub fn save_png(svg: &str, path: &Path) -> Result<()> {
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+    let tree = usvg::Tree::from_str(svg, &options)?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(SCALE)
+        .context("Image is too large")?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).context("Image is too large")?;
+    resvg::render(&tree, tiny_skia::Transform::from_scale(SCALE, SCALE), &mut pixmap.as_mut());
+    pixmap.save_png(path)?;
+    Ok(())
+}
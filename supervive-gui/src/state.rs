@@ -1,18 +1,115 @@
 use crate::api::SuperviveService;
+use crate::demo;
+use crate::hero_assets::HeroAssets;
+use crate::live_match::LiveLobby;
+use crate::match_archive::MatchArchive;
+use crate::match_notes::MatchNotes;
+use crate::player_index::PlayerIndex;
+use crate::plugins::{self, Plugin};
+use crate::scheduler::Scheduler;
+use crate::series::SeriesStore;
+use crate::settings::{Settings, SettingsStore};
+use crate::telemetry::Telemetry;
 use gpui::*;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 pub struct AppState {
     pub service: Arc<Mutex<SuperviveService>>,
+    pub player_index: Arc<Mutex<PlayerIndex>>,
+    /// Tags and review notes on matches
+    pub match_notes: Arc<Mutex<MatchNotes>>,
+    /// Matches imported to keep for good
+    pub match_archive: Arc<Mutex<MatchArchive>>,
+    /// Named groups of custom matches, scored together
+    pub series: Arc<Mutex<SeriesStore>>,
+    pub hero_assets: Arc<Mutex<HeroAssets>>,
+    pub settings: Arc<Mutex<SettingsStore>>,
+    pub scheduler: Arc<Mutex<Scheduler>>,
+    pub telemetry: Arc<Mutex<Telemetry>>,
+    pub live_lobby: Arc<Mutex<LiveLobby>>,
+    /// The session overlay window while it is open
+    pub overlay_window: Arc<Mutex<Option<AnyWindowHandle>>>,
+    /// Player name picked up by the clipboard watcher, waiting for the user to look it up
+    pub clipboard_name: Arc<Mutex<Option<String>>>,
+    /// Custom profile panels from the plugin directory
+    pub plugins: Arc<Mutex<Vec<Plugin>>>,
 }
 
 impl Global for AppState {}
 
+/// The stores that record what the user browses. Demo mode gets a fresh set in a temporary
+/// folder, so sample players and anything noted about them never reach the real files.
+struct UserStores {
+    player_index: PlayerIndex,
+    match_notes: MatchNotes,
+    match_archive: MatchArchive,
+    series: SeriesStore,
+}
+
+impl UserStores {
+    fn open(demo: bool) -> Self {
+        if !demo {
+            return Self {
+                player_index: PlayerIndex::new(PlayerIndex::default_path()),
+                match_notes: MatchNotes::new(MatchNotes::default_path()),
+                match_archive: MatchArchive::new(MatchArchive::default_dir()),
+                series: SeriesStore::new(SeriesStore::default_path()),
+            };
+        }
+        let dir = std::env::temp_dir().join("supervive_gui_demo");
+        let _ = std::fs::remove_dir_all(&dir);
+        Self {
+            player_index: PlayerIndex::new(dir.join("players.json")),
+            match_notes: MatchNotes::new(dir.join("match_notes.json")),
+            match_archive: MatchArchive::new(dir.join("match_archive")),
+            series: SeriesStore::new(dir.join("series.json")),
+        }
+    }
+}
+
 impl AppState {
     pub fn new(service: SuperviveService) -> Self {
+        let hero_assets_dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui")
+            .join("heroes");
+
+        let telemetry_path = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("supervive_gui")
+            .join("telemetry.json");
+
+        let stores = UserStores::open(demo::is_enabled());
         Self {
             service: Arc::new(Mutex::new(service)),
+            player_index: Arc::new(Mutex::new(stores.player_index)),
+            match_notes: Arc::new(Mutex::new(stores.match_notes)),
+            match_archive: Arc::new(Mutex::new(stores.match_archive)),
+            series: Arc::new(Mutex::new(stores.series)),
+            hero_assets: Arc::new(Mutex::new(HeroAssets::new(hero_assets_dir))),
+            settings: Arc::new(Mutex::new(SettingsStore::new(SettingsStore::default_path()))),
+            scheduler: Arc::new(Mutex::new(Scheduler::default())),
+            telemetry: Arc::new(Mutex::new(Telemetry::new(telemetry_path))),
+            live_lobby: Arc::new(Mutex::new(LiveLobby::default())),
+            overlay_window: Arc::new(Mutex::new(None)),
+            clipboard_name: Arc::new(Mutex::new(None)),
+            plugins: Arc::new(Mutex::new(plugins::load_all())),
         }
     }
+
+    /// Swaps the player index, notes, archive and series for the demo set or back to the user's.
+    pub fn switch_stores(&self, demo: bool) {
+        let stores = UserStores::open(demo);
+        *self.player_index.lock().unwrap() = stores.player_index;
+        *self.match_notes.lock().unwrap() = stores.match_notes;
+        *self.match_archive.lock().unwrap() = stores.match_archive;
+        *self.series.lock().unwrap() = stores.series;
+    }
+
+    /// Snapshot of the current settings for rendering.
+    pub fn settings(&self) -> Settings {
+        self.settings.lock().unwrap().settings().clone()
+    }
 }
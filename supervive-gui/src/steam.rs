@@ -0,0 +1,120 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// Supervive's Steam app id, used to launch the game through the `steam://` protocol.
+const SUPERVIVE_APP_ID: u32 = 1283700;
+
+pub fn launch_url() -> String {
+    format!("steam://rungameid/{}", SUPERVIVE_APP_ID)
+}
+
+/// An account found in Steam's `config/loginusers.vdf`.
+#[derive(Debug, Clone, Default)]
+pub struct SteamLogin {
+    pub account_name: String,
+    pub persona_name: String,
+    pub most_recent: bool,
+}
+
+/// Steam install directory: the registry on Windows, the usual install locations elsewhere.
+fn steam_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        use winreg::enums::HKEY_CURRENT_USER;
+        use winreg::RegKey;
+        if let Ok(key) = RegKey::predef(HKEY_CURRENT_USER).open_subkey("Software\\Valve\\Steam") {
+            if let Ok(path) = key.get_value::<String, _>("SteamPath") {
+                return Some(PathBuf::from(path));
+            }
+        }
+        Some(PathBuf::from("C:\\Program Files (x86)\\Steam")).filter(|path| path.exists())
+    }
+
+    #[cfg(not(windows))]
+    {
+        let home = dirs::home_dir()?;
+        [
+            home.join(".steam").join("steam"),
+            home.join(".local").join("share").join("Steam"),
+            home.join("Library").join("Application Support").join("Steam"),
+        ]
+        .into_iter()
+        .find(|path| path.exists())
+    }
+}
+
+/// Splits a VDF file into its quoted tokens and braces.
+fn tokenize(vdf: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = vdf.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut token = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => token.extend(chars.next()),
+                        '"' => break,
+                        c => token.push(c),
+                    }
+                }
+                tokens.push(token);
+            }
+            '{' | '}' => tokens.push(c.to_string()),
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// Parses the `"users" { "<steamid64>" { "AccountName" ... } }` structure of loginusers.vdf.
+fn parse_login_users(vdf: &str) -> Vec<SteamLogin> {
+    let tokens = tokenize(vdf);
+    let mut logins = Vec::new();
+    let mut current: Option<SteamLogin> = None;
+    let mut depth = 0;
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        match tokens[idx].as_str() {
+            "{" => {
+                depth += 1;
+                if depth == 2 {
+                    current = Some(SteamLogin::default());
+                }
+            }
+            "}" => {
+                if depth == 2 {
+                    logins.extend(current.take());
+                }
+                depth -= 1;
+            }
+            key if depth == 2 && idx + 1 < tokens.len() && tokens[idx + 1] != "{" => {
+                if let Some(login) = current.as_mut() {
+                    let value = tokens[idx + 1].clone();
+                    match key.to_ascii_lowercase().as_str() {
+                        "accountname" => login.account_name = value,
+                        "personaname" => login.persona_name = value,
+                        "mostrecent" => login.most_recent = value == "1",
+                        _ => {}
+                    }
+                }
+                idx += 1;
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    logins
+}
+
+/// The account most recently signed in to the local Steam client, if Steam is installed.
+pub fn detect_login() -> Option<SteamLogin> {
+    let path = steam_dir()?.join("config").join("loginusers.vdf");
+    let logins = parse_login_users(&fs::read_to_string(path).ok()?);
+    logins
+        .iter()
+        .find(|login| login.most_recent)
+        .or_else(|| logins.first())
+        .cloned()
+}
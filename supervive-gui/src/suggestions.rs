@@ -0,0 +1,77 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The player needs this many games on a hero before it is ranked.
+const MIN_HERO_GAMES: usize = 3;
+/// A hero needs this many appearances across analyzed lobbies before its field results count.
+const MIN_FIELD_GAMES: usize = 5;
+/// How much the field's results on a hero count next to the player's own.
+pub const FIELD_WEIGHT: f64 = 0.5;
+
+/// One hero the player has enough games on, scored by how well they and the field place with it.
+#[derive(Debug, Clone)]
+pub struct HeroSuggestion {
+    pub hero: String,
+    pub games: usize,
+    pub avg_placement: f64,
+    /// Places better than the player's overall average by this much (positive is better)
+    pub personal: f64,
+    /// Places better than the average participant of analyzed lobbies by this much, when seen enough
+    pub field: Option<f64>,
+}
+
+impl HeroSuggestion {
+    pub fn score(&self) -> f64 {
+        self.personal + FIELD_WEIGHT * self.field.unwrap_or(0.0)
+    }
+}
+
+fn average(placements: &[i64]) -> f64 {
+    placements.iter().sum::<i64>() as f64 / placements.len() as f64
+}
+
+fn placements_by_hero<'a>(rows: impl Iterator<Item = &'a Value>) -> HashMap<String, Vec<i64>> {
+    let mut by_hero: HashMap<String, Vec<i64>> = HashMap::new();
+    for row in rows {
+        if let (Some(hero), Some(placement)) = (row["hero"]["name"].as_str(), row["placement"].as_i64()) {
+            by_hero.entry(hero.to_string()).or_default().push(placement);
+        }
+    }
+    by_hero
+}
+
+/// Ranks the heroes in the player's match rows, best score first. Every participant row of the
+/// analyzed lobbies in `lobbies` stands in for global meta stats, which the API doesn't offer.
+pub fn hero_suggestions<'a>(rows: &[Value], lobbies: impl Iterator<Item = &'a Vec<Value>>) -> Vec<HeroSuggestion> {
+    let own = placements_by_hero(rows.iter());
+    let all_own: Vec<i64> = own.values().flatten().copied().collect();
+    if all_own.is_empty() {
+        return Vec::new();
+    }
+    let baseline = average(&all_own);
+
+    let field = placements_by_hero(lobbies.flatten());
+    let all_field: Vec<i64> = field.values().flatten().copied().collect();
+    let field_baseline = (!all_field.is_empty()).then(|| average(&all_field));
+
+    let mut suggestions: Vec<HeroSuggestion> = own
+        .into_iter()
+        .filter(|(_, placements)| placements.len() >= MIN_HERO_GAMES)
+        .map(|(hero, placements)| {
+            let avg_placement = average(&placements);
+            let field = field_baseline.and_then(|field_baseline| {
+                let placements = field.get(&hero).filter(|placements| placements.len() >= MIN_FIELD_GAMES)?;
+                Some(field_baseline - average(placements))
+            });
+            HeroSuggestion {
+                hero,
+                games: placements.len(),
+                avg_placement,
+                personal: baseline - avg_placement,
+                field,
+            }
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.score().total_cmp(&a.score()));
+    suggestions
+}
@@ -0,0 +1,144 @@
+use crate::match_notes::{MatchNote, MatchNotes};
+use crate::settings::{Settings, SettingsStore, SyncProviderKind, SyncSettings};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use reqwest::blocking::Client;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Name of the document stored at the sync endpoint.
+const DOCUMENT_NAME: &str = "supervive-sync.json";
+
+/// What travels between machines. Sync credentials are stripped before upload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncDocument {
+    pub updated_at: DateTime<Utc>,
+    pub settings: Settings,
+    /// Missing from documents uploaded before notes were synced
+    #[serde(default)]
+    pub notes: HashMap<String, MatchNote>,
+}
+
+/// Remote storage for the sync document.
+pub trait SyncProvider: Send {
+    /// The stored document, or `None` if no machine has uploaded one yet.
+    fn pull(&self) -> Result<Option<SyncDocument>>;
+    fn push(&self, document: &SyncDocument) -> Result<()>;
+}
+
+/// Stores the document as a single file in a WebDAV folder (Nextcloud, ownCloud, NAS shares, ...).
+/// Errors leave out the folder URL, which usually names the account, since they end up in the log.
+pub struct WebDavProvider {
+    client: Client,
+    url: String,
+    username: String,
+    password: String,
+}
+
+impl WebDavProvider {
+    pub fn new(config: &SyncSettings) -> Result<Self> {
+        Ok(Self {
+            client: Client::builder().timeout(Duration::from_secs(15)).build()?,
+            url: format!("{}/{}", config.url.trim_end_matches('/'), DOCUMENT_NAME),
+            username: config.username.clone(),
+            password: config.password.clone(),
+        })
+    }
+}
+
+impl SyncProvider for WebDavProvider {
+    fn pull(&self) -> Result<Option<SyncDocument>> {
+        let resp = self
+            .client
+            .get(&self.url)
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .map_err(reqwest::Error::without_url)?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Ok(Some(
+            resp.error_for_status()
+                .and_then(|resp| resp.json())
+                .map_err(reqwest::Error::without_url)?,
+        ))
+    }
+
+    fn push(&self, document: &SyncDocument) -> Result<()> {
+        self.client
+            .put(&self.url)
+            .basic_auth(&self.username, Some(&self.password))
+            .json(document)
+            .send()
+            .and_then(|resp| resp.error_for_status())
+            .map_err(reqwest::Error::without_url)?;
+        Ok(())
+    }
+}
+
+/// Builds the configured provider, or `None` while sync is off or has no endpoint.
+pub fn provider(config: &SyncSettings) -> Result<Option<Box<dyn SyncProvider>>> {
+    if !config.enabled || config.url.trim().is_empty() {
+        return Ok(None);
+    }
+    match config.provider {
+        SyncProviderKind::WebDav => Ok(Some(Box::new(WebDavProvider::new(config)?))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncOutcome {
+    Pulled,
+    Pushed,
+    UpToDate,
+}
+
+/// Reconciles local settings and match notes with the remote copy. Settings go whole, to
+/// whichever side changed last; notes merge one by one, the more recently edited copy winning.
+pub fn sync(provider: &dyn SyncProvider, store: &Mutex<SettingsStore>, notes: &Mutex<MatchNotes>) -> Result<SyncOutcome> {
+    let local_updated = store
+        .lock()
+        .unwrap()
+        .settings()
+        .updated_at
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+    let remote = provider.pull()?;
+    let remote_notes = remote.as_ref().map(|remote| remote.notes.clone()).unwrap_or_default();
+    let pulled_notes = notes.lock().unwrap().merge(remote_notes.clone()) > 0;
+    let local_notes = notes.lock().unwrap().snapshot();
+    let notes_ahead = local_notes.iter().any(|(key, note)| {
+        remote_notes
+            .get(key)
+            .is_none_or(|remote| remote.updated_at < note.updated_at)
+    });
+
+    let (pulled_settings, push_settings) = match remote {
+        Some(remote) if remote.updated_at > local_updated => {
+            store.lock().unwrap().apply_synced(remote.settings, remote.updated_at);
+            (true, false)
+        }
+        Some(remote) if remote.updated_at == local_updated => (false, false),
+        _ => (false, true),
+    };
+
+    if push_settings || notes_ahead {
+        let mut settings = store.lock().unwrap().settings().clone();
+        let updated_at = settings.updated_at.unwrap_or_else(Utc::now);
+        settings.sync = SyncSettings::default();
+        provider.push(&SyncDocument {
+            updated_at,
+            settings,
+            notes: local_notes,
+        })?;
+        return Ok(SyncOutcome::Pushed);
+    }
+    Ok(if pulled_settings || pulled_notes {
+        SyncOutcome::Pulled
+    } else {
+        SyncOutcome::UpToDate
+    })
+}
@@ -0,0 +1,58 @@
+use gpui::SharedString;
+
+/// Progress of a view's multi-request job: enriching search results, backfilling history or
+/// loading the full scoreboard of every match in a history.
+#[derive(Clone, Debug)]
+pub struct TaskProgress {
+    pub label: SharedString,
+    pub completed: usize,
+    pub total: usize,
+}
+
+impl TaskProgress {
+    pub fn new(label: impl Into<SharedString>, total: usize) -> Self {
+        Self {
+            label: label.into(),
+            completed: 0,
+            total,
+        }
+    }
+
+    pub fn advance(&mut self) {
+        self.completed = (self.completed + 1).min(self.total);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.completed >= self.total
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.completed as f32 / self.total as f32
+        }
+    }
+}
+
+/// Counts the requests a view has started so a response can tell whether a newer request (a
+/// refresh, another search) has superseded it and should be dropped.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RequestGeneration(u64);
+
+impl RequestGeneration {
+    /// Supersedes every request in flight and returns the token of the new one.
+    pub fn start(&mut self) -> u64 {
+        self.0 += 1;
+        self.0
+    }
+
+    /// Token of the latest request, for follow-up work that belongs to it.
+    pub fn current(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_current(&self, token: u64) -> bool {
+        self.0 == token
+    }
+}
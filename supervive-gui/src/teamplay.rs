@@ -0,0 +1,39 @@
+use serde_json::Value;
+
+/// Per-game averages of the stats that reward playing for the team rather than for kills.
+#[derive(Debug, Clone, Default)]
+pub struct TeamplayStats {
+    pub assists: f64,
+    /// Teammates this player revived (`Revived`)
+    pub revives_given: f64,
+    /// Times this player was brought back by a teammate (`Resurrected`)
+    pub revives_received: f64,
+    pub healing: f64,
+    /// Damage absorbed by shields (`ShieldMitigatedDamage`)
+    pub shielding: f64,
+}
+
+impl TeamplayStats {
+    pub fn from_matches(rows: &[Value]) -> Self {
+        let games = rows.iter().filter(|row| row["stats"].is_object()).count();
+        if games == 0 {
+            return Self::default();
+        }
+        let average = |key: &str| -> f64 {
+            rows.iter().filter_map(|row| row["stats"][key].as_f64()).sum::<f64>() / games as f64
+        };
+        Self {
+            assists: average("Assists"),
+            revives_given: average("Revived"),
+            revives_received: average("Resurrected"),
+            healing: average("HealingGiven"),
+            shielding: average("ShieldMitigatedDamage"),
+        }
+    }
+
+    /// Single per-game number: an assist or a revive given is a point each (revives count
+    /// double), and every 1,000 healing or shielding is a point.
+    pub fn score(&self) -> f64 {
+        self.assists + 2.0 * self.revives_given + (self.healing + self.shielding) / 1000.0
+    }
+}
@@ -0,0 +1,127 @@
+use crate::state::AppState;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use gpui::App;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Usage counters collected since the last successful upload. Only feature names and error
+/// categories are counted — no player ids, names, queries or timestamps of individual actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Counters {
+    since: DateTime<Utc>,
+    features: BTreeMap<String, u64>,
+    errors: BTreeMap<String, u64>,
+}
+
+impl Default for Counters {
+    fn default() -> Self {
+        Self {
+            since: Utc::now(),
+            features: BTreeMap::new(),
+            errors: BTreeMap::new(),
+        }
+    }
+}
+
+/// Opt-in usage statistics, stored locally and uploaded as aggregated batches.
+pub struct Telemetry {
+    path: PathBuf,
+    counters: Counters,
+}
+
+impl Telemetry {
+    pub fn new(path: PathBuf) -> Self {
+        let mut telemetry = Self {
+            path,
+            counters: Counters::default(),
+        };
+        telemetry.load();
+        telemetry
+    }
+
+    fn load(&mut self) {
+        if let Ok(file) = fs::File::open(&self.path) {
+            if let Ok(counters) = serde_json::from_reader(file) {
+                self.counters = counters;
+            }
+        }
+    }
+
+    fn save(&self) {
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(file) = fs::File::create(&self.path) {
+            let _ = serde_json::to_writer(file, &self.counters);
+        }
+    }
+
+    fn record_feature(&mut self, feature: &str) {
+        *self.counters.features.entry(feature.to_string()).or_default() += 1;
+        self.save();
+    }
+
+    fn record_error(&mut self, category: &str) {
+        *self.counters.errors.entry(category.to_string()).or_default() += 1;
+        self.save();
+    }
+
+    /// Exactly the payload the next upload would send.
+    pub fn pending_batch(&self) -> Value {
+        json!({
+            "app_version": env!("CARGO_PKG_VERSION"),
+            "os": std::env::consts::OS,
+            "period_start": self.counters.since.format("%Y-%m-%d").to_string(),
+            "features": self.counters.features,
+            "errors": self.counters.errors,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counters.features.is_empty() && self.counters.errors.is_empty()
+    }
+
+    /// Discards everything collected so far.
+    pub fn clear(&mut self) {
+        self.counters = Counters::default();
+        self.save();
+    }
+}
+
+/// Posts a batch to the configured endpoint.
+pub fn upload(endpoint: &str, batch: &Value) -> Result<()> {
+    if endpoint.is_empty() {
+        bail!("No telemetry endpoint configured");
+    }
+    Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()?
+        .post(endpoint)
+        .json(batch)
+        .send()
+        .and_then(|resp| resp.error_for_status())
+        .map_err(reqwest::Error::without_url)?;
+    Ok(())
+}
+
+/// Counts one use of a feature if the user opted in.
+pub fn track(cx: &App, feature: &str) {
+    let app_state = cx.global::<AppState>();
+    if app_state.settings().telemetry_enabled {
+        app_state.telemetry.lock().unwrap().record_feature(feature);
+    }
+}
+
+/// Counts one error of the given category if the user opted in.
+pub fn track_error(cx: &App, category: &str) {
+    let app_state = cx.global::<AppState>();
+    if app_state.settings().telemetry_enabled {
+        app_state.telemetry.lock().unwrap().record_error(category);
+    }
+}
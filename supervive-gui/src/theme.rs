@@ -0,0 +1,273 @@
+use gpui::{rems, rgb, Rems, Rgba};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Color scheme used for good/average/bad stat coloring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Palette {
+    #[default]
+    Standard,
+    /// Blue/yellow/vermillion (Okabe-Ito), distinguishable with deuteranopia and protanopia
+    ColorblindSafe,
+    HighContrast,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [
+        Palette::Standard,
+        Palette::ColorblindSafe,
+        Palette::HighContrast,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Palette::Standard => "Standard",
+            Palette::ColorblindSafe => "Colorblind-safe",
+            Palette::HighContrast => "High contrast",
+        }
+    }
+
+    pub fn color(&self, rating: Rating) -> Rgba {
+        match (self, rating) {
+            (Palette::Standard, Rating::Good) => rgb(0x4daf4a),
+            (Palette::Standard, Rating::Average) => rgb(0xcdd6f4),
+            (Palette::Standard, Rating::Bad) => rgb(0xef4444),
+            (Palette::ColorblindSafe, Rating::Good) => rgb(0x56b4e9),
+            (Palette::ColorblindSafe, Rating::Average) => rgb(0xf0e442),
+            (Palette::ColorblindSafe, Rating::Bad) => rgb(0xd55e00),
+            (Palette::HighContrast, Rating::Good) => rgb(0x00e5ff),
+            (Palette::HighContrast, Rating::Average) => rgb(0xffffff),
+            (Palette::HighContrast, Rating::Bad) => rgb(0xff6e40),
+        }
+    }
+}
+
+/// How a stat compares to its thresholds; drives both color and the shape indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rating {
+    Good,
+    Average,
+    Bad,
+}
+
+impl Rating {
+    /// Shape marker so the rating doesn't depend on color alone.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Rating::Good => "▲",
+            Rating::Average => "●",
+            Rating::Bad => "▼",
+        }
+    }
+
+    /// Prefixes `text` with the shape marker when indicators are enabled.
+    pub fn decorate(&self, text: impl Into<String>, show_indicator: bool) -> String {
+        let text = text.into();
+        if show_indicator {
+            format!("{} {}", self.symbol(), text)
+        } else {
+            text
+        }
+    }
+}
+
+/// Fewest matches needed before thresholds are derived from the player's own history.
+const MIN_PERSONAL_SAMPLES: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThresholdMode {
+    /// Use the configured `good` and `bad` values
+    #[default]
+    Fixed,
+    /// Good is the player's best quarter of games, bad their worst quarter
+    Personal,
+}
+
+/// Cut-offs for one stat. When `good` is below `bad`, lower values are better (placement).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatThresholds {
+    pub mode: ThresholdMode,
+    /// At or beyond this value the stat is good
+    pub good: f64,
+    /// Past this value, on the wrong side, the stat is bad
+    pub bad: f64,
+}
+
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let rank = fraction * (sorted.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (rank - rank.floor())
+}
+
+impl StatThresholds {
+    pub fn rate(&self, value: f64) -> Rating {
+        let higher_is_better = self.good >= self.bad;
+        let at_least = |limit: f64| if higher_is_better { value >= limit } else { value <= limit };
+        if at_least(self.good) {
+            Rating::Good
+        } else if at_least(self.bad) {
+            Rating::Average
+        } else {
+            Rating::Bad
+        }
+    }
+
+    /// In personal mode, replaces the cut-offs with quartiles of `samples` (one value per match).
+    /// Falls back to the fixed values until there are enough matches.
+    pub fn personalized(&self, samples: &[f64]) -> Self {
+        if self.mode != ThresholdMode::Personal || samples.len() < MIN_PERSONAL_SAMPLES {
+            return *self;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let (upper, lower) = (percentile(&sorted, 0.75), percentile(&sorted, 0.25));
+        let (good, bad) = if self.good >= self.bad { (upper, lower) } else { (lower, upper) };
+        Self { good, bad, ..*self }
+    }
+}
+
+/// Stats with configurable coloring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RatedStat {
+    Kd,
+    Placement,
+}
+
+impl RatedStat {
+    pub const ALL: [RatedStat; 2] = [RatedStat::Kd, RatedStat::Placement];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RatedStat::Kd => "K/D",
+            RatedStat::Placement => "Placement",
+        }
+    }
+
+    /// Increment used by the settings steppers.
+    pub fn step(&self) -> f64 {
+        match self {
+            RatedStat::Kd => 0.1,
+            RatedStat::Placement => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RatingThresholds {
+    pub kd: StatThresholds,
+    pub placement: StatThresholds,
+}
+
+impl Default for RatingThresholds {
+    fn default() -> Self {
+        Self {
+            kd: StatThresholds {
+                mode: ThresholdMode::Fixed,
+                good: 2.0,
+                bad: 1.0,
+            },
+            placement: StatThresholds {
+                mode: ThresholdMode::Fixed,
+                good: 10.0,
+                bad: 20.0,
+            },
+        }
+    }
+}
+
+impl RatingThresholds {
+    pub fn get(&self, stat: RatedStat) -> &StatThresholds {
+        match stat {
+            RatedStat::Kd => &self.kd,
+            RatedStat::Placement => &self.placement,
+        }
+    }
+
+    pub fn get_mut(&mut self, stat: RatedStat) -> &mut StatThresholds {
+        match stat {
+            RatedStat::Kd => &mut self.kd,
+            RatedStat::Placement => &mut self.placement,
+        }
+    }
+
+    /// Thresholds for rating a player whose match history rows are `rows`.
+    pub fn for_history(&self, rows: &[Value]) -> Self {
+        let kd: Vec<f64> = rows
+            .iter()
+            .filter_map(|row| {
+                let kills = row["stats"]["Kills"].as_f64()?;
+                let deaths = row["stats"]["Deaths"].as_f64().unwrap_or(0.0);
+                Some(if deaths > 0.0 { kills / deaths } else { kills })
+            })
+            .collect();
+        let placements: Vec<f64> = rows.iter().filter_map(|row| row["placement"].as_f64()).collect();
+        Self {
+            kd: self.kd.personalized(&kd),
+            placement: self.placement.personalized(&placements),
+        }
+    }
+}
+
+/// Layout density; compact trims paddings and gaps for small screens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Density {
+    #[default]
+    Comfortable,
+    Compact,
+}
+
+impl Density {
+    pub const ALL: [Density; 2] = [Density::Comfortable, Density::Compact];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Density::Comfortable => "Comfortable",
+            Density::Compact => "Compact",
+        }
+    }
+
+    pub fn spacing(&self) -> Spacing {
+        match self {
+            Density::Comfortable => Spacing {
+                page: rems(2.0),
+                section_gap: rems(1.0),
+                card: rems(1.0),
+                row: rems(1.0),
+                row_gap: rems(0.75),
+            },
+            Density::Compact => Spacing {
+                page: rems(1.0),
+                section_gap: rems(0.5),
+                card: rems(0.5),
+                row: rems(0.5),
+                row_gap: rems(0.25),
+            },
+        }
+    }
+}
+
+/// Spacing tokens used by views instead of fixed paddings.
+#[derive(Debug, Clone, Copy)]
+pub struct Spacing {
+    /// Padding around a whole view
+    pub page: Rems,
+    /// Gap between top-level sections of a view
+    pub section_gap: Rems,
+    /// Padding inside panels and stat cards
+    pub card: Rems,
+    /// Padding inside list rows
+    pub row: Rems,
+    /// Gap between list rows
+    pub row_gap: Rems,
+}
+
+/// Distinct marker color per team as `0xRRGGBB`, cycling when a lobby has more teams than colors.
+pub fn team_color_hex(team_id: i64) -> u32 {
+    const TEAM_COLORS: [u32; 8] = [0xf38ba8, 0x89b4fa, 0xa6e3a1, 0xf9e2af, 0xcba6f7, 0xfab387, 0x94e2d5, 0xf5c2e7];
+    TEAM_COLORS[team_id.unsigned_abs() as usize % TEAM_COLORS.len()]
+}
+
+pub fn team_color(team_id: i64) -> Rgba {
+    rgb(team_color_hex(team_id))
+}
@@ -0,0 +1,77 @@
+use crate::api::MatchRows;
+use crate::history_totals::HistoryTotals;
+use crate::settings::PlayerRef;
+use serde_json::Value;
+
+/// Rows shown on a hero win rate or leaderboard tile.
+pub const TILE_ROWS: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct HeroWinRate {
+    pub hero: String,
+    pub games: usize,
+    pub wins: usize,
+}
+
+impl HeroWinRate {
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.games as f64
+    }
+}
+
+/// Whether a match row was played in `region`. `None` merges every region, and rows that don't
+/// say where they were played are always kept.
+pub fn in_region(row: &Value, region: Option<&str>) -> bool {
+    match (region, row["region"].as_str()) {
+        (Some(region), Some(played)) => played.eq_ignore_ascii_case(region),
+        _ => true,
+    }
+}
+
+/// Win rate per hero in the rows played in `region`, most played first.
+pub fn hero_win_rates(rows: &[Value], region: Option<&str>) -> Vec<HeroWinRate> {
+    let rows: Vec<Value> = rows.iter().filter(|row| in_region(row, region)).cloned().collect();
+    let mut heroes: Vec<HeroWinRate> = HistoryTotals::from_rows(&rows)
+        .heroes
+        .into_iter()
+        .map(|(hero, totals)| HeroWinRate {
+            hero,
+            games: totals.games,
+            wins: totals.wins,
+        })
+        .collect();
+    heroes.sort_by(|a, b| b.games.cmp(&a.games).then_with(|| b.win_rate().total_cmp(&a.win_rate())));
+    heroes
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardEntry {
+    pub player: PlayerRef,
+    pub games: usize,
+    pub avg_placement: f64,
+}
+
+/// Players ranked by average placement over the matches of their latest polled page played in
+/// `region`, best first. Players without placed matches there are left out.
+pub fn leaderboard(players: Vec<(PlayerRef, MatchRows)>, region: Option<&str>) -> Vec<LeaderboardEntry> {
+    let mut entries: Vec<LeaderboardEntry> = players
+        .into_iter()
+        .filter_map(|(player, rows)| {
+            let placements: Vec<i64> = rows
+                .iter()
+                .filter(|row| in_region(row, region))
+                .filter_map(|row| row["placement"].as_i64())
+                .collect();
+            if placements.is_empty() {
+                return None;
+            }
+            Some(LeaderboardEntry {
+                player,
+                games: placements.len(),
+                avg_placement: placements.iter().sum::<i64>() as f64 / placements.len() as f64,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.avg_placement.total_cmp(&b.avg_placement));
+    entries
+}
@@ -0,0 +1,92 @@
+use crate::session::parse_time;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Games in the short range.
+const RECENT_GAMES: usize = 20;
+/// Days in the time-based range.
+const RECENT_DAYS: i64 = 7;
+
+/// Rolling average applied to trend lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Smoothing {
+    #[default]
+    Raw,
+    Rolling5,
+    Rolling20,
+}
+
+impl Smoothing {
+    pub const ALL: [Smoothing; 3] = [Smoothing::Raw, Smoothing::Rolling5, Smoothing::Rolling20];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Smoothing::Raw => "Raw",
+            Smoothing::Rolling5 => "5-game avg",
+            Smoothing::Rolling20 => "20-game avg",
+        }
+    }
+
+    fn window(&self) -> usize {
+        match self {
+            Smoothing::Raw => 1,
+            Smoothing::Rolling5 => 5,
+            Smoothing::Rolling20 => 20,
+        }
+    }
+
+    /// Trailing average of each value and the ones before it. The first points average over
+    /// what is there, so the line starts at the first value instead of a gap.
+    pub fn apply(&self, values: &[f64]) -> Vec<f64> {
+        let window = self.window();
+        let mut sum = 0.0;
+        values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                sum += value;
+                if idx >= window {
+                    sum -= values[idx - window];
+                }
+                sum / (idx + 1).min(window) as f64
+            })
+            .collect()
+    }
+}
+
+/// Which matches every chart on a profile covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChartRange {
+    LastGames,
+    LastDays,
+    #[default]
+    AllLoaded,
+}
+
+impl ChartRange {
+    pub const ALL: [ChartRange; 3] = [ChartRange::LastGames, ChartRange::LastDays, ChartRange::AllLoaded];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartRange::LastGames => "Last 20 games",
+            ChartRange::LastDays => "Last 7 days",
+            ChartRange::AllLoaded => "All loaded",
+        }
+    }
+
+    /// The leading part of `rows` (most recent first) that falls in the range.
+    pub fn apply<'a>(&self, rows: &'a [Value]) -> &'a [Value] {
+        let len = match self {
+            ChartRange::LastGames => RECENT_GAMES.min(rows.len()),
+            ChartRange::LastDays => {
+                let start = Utc::now() - Duration::days(RECENT_DAYS);
+                rows.iter()
+                    .take_while(|row| parse_time(&row["match_start"]).is_some_and(|time| time >= start))
+                    .count()
+            }
+            ChartRange::AllLoaded => rows.len(),
+        };
+        &rows[..len]
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use reqwest::blocking::Client;
+use serde_json::Value;
+use std::time::Duration;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/lucannez64/ViveCore/releases/latest";
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A published release newer than the running build.
+#[derive(Debug, Clone)]
+pub struct ReleaseInfo {
+    pub version: String,
+    pub changelog: String,
+    pub page_url: String,
+    /// The attached build for this OS and CPU, if the release ships one
+    pub download_url: Option<String>,
+}
+
+/// Parses `v1.2.3` / `1.2.3-beta` into comparable numeric parts.
+fn version_parts(version: &str) -> Vec<u64> {
+    version
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()
+        .unwrap_or("")
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+fn is_newer(candidate: &str, current: &str) -> bool {
+    version_parts(candidate) > version_parts(current)
+}
+
+/// Asset name words that mark a build for an OS, keyed by `std::env::consts::OS`.
+const OS_WORDS: &[(&str, &[&str])] = &[
+    ("windows", &["windows", "win", "win64", "win32", "exe", "msi"]),
+    ("macos", &["macos", "mac", "darwin", "osx", "dmg"]),
+    ("linux", &["linux", "appimage", "deb", "rpm"]),
+];
+
+/// Asset name words that mark a build for a CPU, keyed by `std::env::consts::ARCH`.
+const ARCH_WORDS: &[(&str, &[&str])] = &[
+    ("x86_64", &["x86_64", "amd64", "x64", "win64"]),
+    ("aarch64", &["aarch64", "arm64"]),
+    ("x86", &["x86", "i686", "win32"]),
+];
+
+fn words_for<'a>(table: &[(&str, &'a [&'a str])], key: &str) -> &'a [&'a str] {
+    table.iter().find(|(name, _)| *name == key).map_or(&[], |(_, words)| words)
+}
+
+/// Download URL of the asset built for `os` and `arch`. Assets that name no CPU (or say
+/// "universal") count for any; one naming this CPU wins over those. `None` when no asset fits,
+/// so the banner only links the release page rather than offering another platform's build.
+fn asset_for(assets: &[Value], os: &str, arch: &str) -> Option<String> {
+    let os_words = words_for(OS_WORDS, os);
+    let arch_words = words_for(ARCH_WORDS, arch);
+    let all_arch_words: Vec<&str> = ARCH_WORDS.iter().flat_map(|(_, words)| words.iter().copied()).collect();
+    assets
+        .iter()
+        .filter_map(|asset| {
+            let name = asset["name"].as_str()?.to_ascii_lowercase();
+            let words: Vec<&str> = name.split(['-', '.', ' ']).collect();
+            if !words.iter().any(|word| os_words.contains(word)) {
+                return None;
+            }
+            let names_arch = words.iter().any(|word| arch_words.contains(word) || *word == "universal");
+            let names_other_arch = words.iter().any(|word| all_arch_words.contains(word));
+            let rank = match (names_arch, names_other_arch) {
+                (true, _) => 0,
+                (false, false) => 1,
+                (false, true) => return None,
+            };
+            Some((rank, asset["browser_download_url"].as_str()?.to_string()))
+        })
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, url)| url)
+}
+
+/// Asks GitHub for the latest release. Returns `None` when the running build is up to date.
+/// Only reads release metadata; nothing is downloaded or installed.
+pub fn check_latest() -> Result<Option<ReleaseInfo>> {
+    let client = Client::builder()
+        .user_agent(concat!("supervive-gui/", env!("CARGO_PKG_VERSION")))
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    let release: Value = client.get(RELEASES_URL).send()?.error_for_status()?.json()?;
+
+    let version = release["tag_name"].as_str().unwrap_or("").to_string();
+    if version.is_empty() || !is_newer(&version, CURRENT_VERSION) {
+        return Ok(None);
+    }
+
+    Ok(Some(ReleaseInfo {
+        version,
+        changelog: release["body"].as_str().unwrap_or("").trim().to_string(),
+        page_url: release["html_url"].as_str().unwrap_or("").to_string(),
+        download_url: release["assets"]
+            .as_array()
+            .and_then(|assets| asset_for(assets, std::env::consts::OS, std::env::consts::ARCH)),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn assets(names: &[&str]) -> Vec<Value> {
+        names
+            .iter()
+            .map(|name| json!({ "name": name, "browser_download_url": format!("https://example.com/{}", name) }))
+            .collect()
+    }
+
+    #[test]
+    fn picks_the_build_for_this_os_and_cpu() {
+        let release = assets(&[
+            "supervive-gui-0.3.0-linux-x86_64.tar.gz",
+            "supervive-gui-0.3.0-linux-aarch64.tar.gz",
+            "supervive-gui-0.3.0-windows-x64.msi",
+            "supervive-gui-0.3.0-macos-universal.dmg",
+            "checksums.txt",
+        ]);
+        let pick = |os, arch| asset_for(&release, os, arch).map(|url| url.trim_start_matches("https://example.com/").to_string());
+        assert_eq!(pick("linux", "x86_64").as_deref(), Some("supervive-gui-0.3.0-linux-x86_64.tar.gz"));
+        assert_eq!(pick("linux", "aarch64").as_deref(), Some("supervive-gui-0.3.0-linux-aarch64.tar.gz"));
+        assert_eq!(pick("windows", "x86_64").as_deref(), Some("supervive-gui-0.3.0-windows-x64.msi"));
+        assert_eq!(pick("macos", "aarch64").as_deref(), Some("supervive-gui-0.3.0-macos-universal.dmg"));
+        // Only an x64 Windows build: an ARM machine gets the release page instead
+        assert_eq!(pick("windows", "aarch64"), None);
+        assert_eq!(pick("freebsd", "x86_64"), None);
+    }
+
+    #[test]
+    fn builds_without_a_cpu_fit_any_but_rank_below_exact_ones() {
+        let release = assets(&["app-linux.AppImage", "app-linux-x86_64.AppImage"]);
+        assert_eq!(asset_for(&release, "linux", "x86_64").as_deref(), Some("https://example.com/app-linux-x86_64.AppImage"));
+        assert_eq!(asset_for(&release, "linux", "aarch64").as_deref(), Some("https://example.com/app-linux.AppImage"));
+        // "darwin" must not read as a Windows build
+        assert_eq!(asset_for(&assets(&["app-darwin.zip"]), "windows", "x86_64"), None);
+        assert_eq!(asset_for(&[], "linux", "x86_64"), None);
+    }
+
+    #[test]
+    fn newer_versions_compare_numerically() {
+        assert!(is_newer("v0.10.0", "0.9.3"));
+        assert!(!is_newer("v0.9.3", "0.9.3"));
+        assert!(!is_newer("0.9.3-beta", "0.9.3"));
+    }
+}
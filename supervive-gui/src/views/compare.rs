@@ -0,0 +1,259 @@
+use crate::api::{rate_limit_of, take_rows, MatchRows};
+use crate::components::rate_limit::{RateLimit, RateLimitBanner};
+use crate::components::truncated_text::TruncatedText;
+use crate::format;
+use crate::settings::PlayerRef;
+use crate::state::AppState;
+use crate::tasks::RequestGeneration;
+use crate::telemetry;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Totals over one player's most recent history page.
+struct PlayerTotals {
+    games: usize,
+    wins: usize,
+    avg_placement: f64,
+    kills: i64,
+    deaths: i64,
+    avg_damage: f64,
+    top_hero: Option<String>,
+}
+
+impl PlayerTotals {
+    fn from_rows(rows: &[Value]) -> Option<Self> {
+        let placements: Vec<i64> = rows.iter().filter_map(|row| row["placement"].as_i64()).collect();
+        if placements.is_empty() {
+            return None;
+        }
+        let stat = |key: &str| -> i64 { rows.iter().filter_map(|row| row["stats"][key].as_i64()).sum() };
+        let mut heroes: HashMap<&str, usize> = HashMap::new();
+        for hero in rows.iter().filter_map(|row| row["hero"]["name"].as_str()) {
+            *heroes.entry(hero).or_default() += 1;
+        }
+        Some(Self {
+            games: rows.len(),
+            wins: placements.iter().filter(|placement| **placement == 1).count(),
+            avg_placement: placements.iter().sum::<i64>() as f64 / placements.len() as f64,
+            kills: stat("Kills"),
+            deaths: stat("Deaths"),
+            avg_damage: stat("HeroEffectiveDamageDone") as f64 / rows.len() as f64,
+            top_hero: heroes.into_iter().max_by_key(|(_, games)| *games).map(|(hero, _)| hero.to_string()),
+        })
+    }
+
+    fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.games as f64
+    }
+
+    fn kd_ratio(&self) -> f64 {
+        if self.deaths > 0 {
+            self.kills as f64 / self.deaths as f64
+        } else {
+            self.kills as f64
+        }
+    }
+}
+
+/// One side of the comparison while its history loads.
+enum Side {
+    Loading,
+    Loaded(MatchRows),
+    Failed(String),
+}
+
+/// Two players' recent form side by side, opened by dropping one player onto another's profile.
+pub struct CompareView {
+    players: [PlayerRef; 2],
+    sides: [Side; 2],
+    requests: RequestGeneration,
+    /// Set while either side waits out op.gg's rate limit; both sides reload afterwards
+    rate_limit: Option<RateLimit>,
+    scroll_handle: ScrollHandle,
+}
+
+impl CompareView {
+    pub fn new(cx: &mut Context<Self>, left: PlayerRef, right: PlayerRef) -> Self {
+        let mut view = Self {
+            players: [left, right],
+            sides: [Side::Loading, Side::Loading],
+            requests: RequestGeneration::default(),
+            rate_limit: None,
+            scroll_handle: ScrollHandle::new(),
+        };
+        view.refresh(cx);
+        view
+    }
+
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "compare_players");
+        let request = self.requests.start();
+        self.rate_limit = None;
+        for idx in 0..2 {
+            self.sides[idx] = Side::Loading;
+            let service = cx.global::<AppState>().service.clone();
+            let player = self.players[idx].clone();
+            cx.spawn(async move |view, cx| {
+                let result = cx.background_executor().spawn(async move {
+                    service.lock().unwrap().get_player_matches(&player.platform, &player.player_id, 1)
+                }).await;
+                view.update(cx, |this, cx| {
+                    if !this.requests.is_current(request) {
+                        return;
+                    }
+                    this.sides[idx] = match result {
+                        Ok(data) => {
+                            let rows = take_rows(data);
+                            // Names from a drag may be placeholders; the history knows the real one
+                            if let Some(name) = rows.first().and_then(|row| row["player"]["unique_display_name"].as_str()) {
+                                this.players[idx].name = name.to_string();
+                            }
+                            Side::Loaded(MatchRows::new(rows))
+                        }
+                        Err(err) => {
+                            if let Some(retry_after) = rate_limit_of(&err) {
+                                this.rate_limit =
+                                    Some(RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::refresh, cx));
+                            }
+                            Side::Failed(err.to_string())
+                        }
+                    };
+                    cx.notify();
+                }).ok();
+            }).detach();
+        }
+        cx.notify();
+    }
+
+    pub fn title(&self) -> SharedString {
+        format!("{} vs {}", self.players[0].name, self.players[1].name).into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    /// A stat row; `better` picks the side to highlight from the two values, if any.
+    fn stat_row(label: &str, values: [String; 2], better: Option<usize>) -> Div {
+        div()
+            .flex()
+            .items_center()
+            .py_1()
+            .border_b_1()
+            .border_color(rgb(0x313244))
+            .child(div().w(px(160.0)).text_sm().text_color(rgb(0x9399b2)).child(label.to_string()))
+            .children(values.into_iter().enumerate().map(|(idx, value)| {
+                div()
+                    .flex_1()
+                    .text_color(if better == Some(idx) { rgb(0xa6e3a1) } else { rgb(0xcdd6f4) })
+                    .child(value)
+            }))
+    }
+}
+
+/// Index of the larger (or, with `lower_is_better`, smaller) value; `None` on a tie.
+fn better_of(values: [f64; 2], lower_is_better: bool) -> Option<usize> {
+    if (values[0] - values[1]).abs() < f64::EPSILON {
+        return None;
+    }
+    let first_wins = (values[0] > values[1]) != lower_is_better;
+    Some(if first_wins { 0 } else { 1 })
+}
+
+impl Render for CompareView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let spacing = cx.global::<AppState>().settings().density.spacing();
+        let totals: Vec<Option<PlayerTotals>> = self
+            .sides
+            .iter()
+            .map(|side| match side {
+                Side::Loaded(rows) => PlayerTotals::from_rows(rows),
+                _ => None,
+            })
+            .collect();
+        let statuses: Vec<Option<String>> = self
+            .sides
+            .iter()
+            .zip(&totals)
+            .map(|(side, total)| match side {
+                Side::Loading => Some("Loading…".to_string()),
+                Side::Failed(err) => Some(format!("Failed to load: {}", err)),
+                Side::Loaded(_) if total.is_none() => Some("No recent matches".to_string()),
+                Side::Loaded(_) => None,
+            })
+            .collect();
+
+        let table = match (&totals[0], &totals[1]) {
+            (Some(left), Some(right)) => {
+                let row = |label: &str, value: &dyn Fn(&PlayerTotals) -> f64, format: &dyn Fn(f64) -> String, lower_is_better: bool| {
+                    let values = [value(left), value(right)];
+                    Self::stat_row(label, [format(values[0]), format(values[1])], better_of(values, lower_is_better))
+                };
+                Some(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .child(Self::stat_row("Games", [left.games.to_string(), right.games.to_string()], None))
+                        .child(row("Win rate", &|t| t.win_rate(), &|v| format!("{:.0}%", v * 100.0), false))
+                        .child(row("Avg placement", &|t| t.avg_placement, &|v| format!("#{}", format::decimal(v, 1)), true))
+                        .child(row("K/D", &|t| t.kd_ratio(), &|v| format::decimal(v, 2), false))
+                        .child(row(
+                            "Kills per game",
+                            &|t| t.kills as f64 / t.games as f64,
+                            &|v| format::decimal(v, 1),
+                            false,
+                        ))
+                        .child(row("Damage per game", &|t| t.avg_damage, &|v| format::compact(v), false))
+                        .child(Self::stat_row(
+                            "Most played",
+                            [
+                                left.top_hero.clone().unwrap_or_else(|| "—".to_string()),
+                                right.top_hero.clone().unwrap_or_else(|| "—".to_string()),
+                            ],
+                            None,
+                        )),
+                )
+            }
+            _ => None,
+        };
+
+        div()
+            .id("compare-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .children(self.rate_limit.map(RateLimitBanner::new))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .child(div().w(px(160.0)))
+                    .children(self.players.iter().zip(statuses).enumerate().map(|(idx, (player, status))| {
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                TruncatedText::new(("compare-name", idx), player.name.clone())
+                                    .text_xl()
+                                    .font_weight(FontWeight::BOLD),
+                            )
+                            .children(status.map(|status| div().text_sm().text_color(rgb(0x9399b2)).child(status)))
+                    })),
+            )
+            .children(table)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child("Based on each player's most recent page of matches."),
+            )
+    }
+}
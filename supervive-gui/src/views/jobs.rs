@@ -0,0 +1,252 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::scheduler::{self, format_interval, JobKind, INTERVAL_PRESETS};
+use crate::state::AppState;
+use crate::views::{DraggedPlayer, OpenPlayer};
+use chrono::{DateTime, Local, Utc};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Focus slots per job: one per interval preset plus "Run now".
+const JOB_FOCUS_SLOTS: usize = INTERVAL_PRESETS.len() + 1;
+
+/// Background jobs page: schedule, next run and last result of each scheduled job.
+pub struct JobsView {
+    scroll_handle: ScrollHandle,
+    option_focus: Vec<FocusHandle>,
+    watched_focus: Vec<FocusHandle>,
+}
+
+impl JobsView {
+    pub fn new(_cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            option_focus: Vec::new(),
+            watched_focus: Vec::new(),
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Background jobs".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    fn set_interval(&mut self, job: JobKind, minutes: u64, cx: &mut Context<Self>) {
+        let app_state = cx.global::<AppState>();
+        app_state.settings.lock().unwrap().update(|s| {
+            s.job_intervals.insert(job, minutes);
+        });
+        app_state.scheduler.lock().unwrap().reschedule(job, minutes);
+        cx.notify();
+    }
+
+    /// Runs the job, or stops it if it is running and can be cancelled.
+    fn run_or_cancel(&mut self, job: JobKind, cx: &mut Context<Self>) {
+        let app_state = cx.global::<AppState>();
+        let mut job_scheduler = app_state.scheduler.lock().unwrap();
+        if job_scheduler.status(job).running {
+            job_scheduler.cancel(job);
+            drop(job_scheduler);
+            cx.notify();
+        } else {
+            drop(job_scheduler);
+            scheduler::run_now(job, cx);
+        }
+    }
+
+    fn chip(label: impl Into<SharedString>, selected: bool, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .text_sm()
+            .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+            .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label.into())
+    }
+}
+
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|time| time.with_timezone(&Local).format("%H:%M:%S").to_string())
+        .unwrap_or_else(|| "—".to_string())
+}
+
+impl Render for JobsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        sync_focus_handles(&mut self.option_focus, JobKind::ALL.len() * JOB_FOCUS_SLOTS, cx);
+        let app_state = cx.global::<AppState>();
+        let settings = app_state.settings();
+        let statuses: Vec<_> = {
+            let scheduler = app_state.scheduler.lock().unwrap();
+            JobKind::ALL.iter().map(|job| (*job, scheduler.status(*job))).collect()
+        };
+        let spacing = settings.density.spacing();
+        let focus = self.option_focus.clone();
+        sync_focus_handles(&mut self.watched_focus, settings.watched_players.len(), cx);
+
+        div()
+            .id("jobs-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(
+                div()
+                    .text_2xl()
+                    .font_weight(FontWeight::BOLD)
+                    .child("Background jobs"),
+            )
+            .children(statuses.into_iter().enumerate().map(|(job_idx, (job, status))| {
+                let interval = settings.job_interval(job);
+                let focus = &focus[job_idx * JOB_FOCUS_SLOTS..(job_idx + 1) * JOB_FOCUS_SLOTS];
+                let (result_text, result_color) = match &status.last_result {
+                    Some(Ok(summary)) => (summary.clone(), rgb(0xa6e3a1)),
+                    Some(Err(err)) => (format!("Failed: {}", err), rgb(0xf38ba8)),
+                    None => ("Not run yet".to_string(), rgb(0x9399b2)),
+                };
+
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(spacing.row_gap)
+                    .p(spacing.card)
+                    .bg(rgb(0x181825))
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(rgb(0x313244))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_lg()
+                                    .font_weight(FontWeight::SEMIBOLD)
+                                    .child(job.label()),
+                            )
+                            .child(
+                                Self::chip(
+                                    match (status.running, job.is_cancellable()) {
+                                        (true, true) => "Cancel",
+                                        (true, false) => "Running…",
+                                        (false, _) => "Run now",
+                                    },
+                                    false,
+                                    &focus[INTERVAL_PRESETS.len()],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.run_or_cancel(job, cx);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.run_or_cancel(job, cx);
+                                    }
+                                })),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(job.description()),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .children(INTERVAL_PRESETS.iter().enumerate().map(|(idx, minutes)| {
+                                let minutes = *minutes;
+                                let label = if minutes == 0 {
+                                    "Off".to_string()
+                                } else {
+                                    format!("Every {}", format_interval(minutes))
+                                };
+                                Self::chip(label, interval == minutes, &focus[idx])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                        this.set_interval(job, minutes, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.set_interval(job, minutes, cx);
+                                        }
+                                    }))
+                            })),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_4()
+                            .text_sm()
+                            .child(format!("Last run: {}", format_time(status.last_run)))
+                            .child(format!(
+                                "Next run: {}",
+                                if interval == 0 { "—".to_string() } else { format_time(status.next_run) }
+                            )),
+                    )
+                    .child(div().text_sm().text_color(result_color).child(result_text))
+            }))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(spacing.row_gap)
+                    .p(spacing.card)
+                    .bg(rgb(0x181825))
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(rgb(0x313244))
+                    .child(
+                        div()
+                            .text_lg()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .child("Watched players"),
+                    )
+                    .when(settings.watched_players.is_empty(), |parent| {
+                        parent.child(
+                            div()
+                                .text_sm()
+                                .text_color(rgb(0x9399b2))
+                                .child("Use Watch on a player's page to include them in polls and backfills."),
+                        )
+                    })
+                    .children(settings.watched_players.iter().zip(&self.watched_focus).enumerate().map(|(idx, (player, focus))| {
+                        let action = OpenPlayer {
+                            player_id: player.player_id.clone(),
+                            platform: player.platform.clone(),
+                        };
+                        let key_action = action.clone();
+                        div()
+                            .id(("watched", idx))
+                            .px_2()
+                            .py_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(focus)
+                            // Click rather than mouse-down so the player can be dragged onto a profile
+                            .on_click(move |_, window, cx| {
+                                window.dispatch_action(Box::new(action.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_action.clone()), cx);
+                                }
+                            })
+                            .on_drag(DraggedPlayer(player.clone()), |dragged, _offset, _window, cx| {
+                                cx.new(|_| dragged.clone())
+                            })
+                            .child(format!("{} ({})", player.name, player.platform))
+                    })),
+            )
+    }
+}
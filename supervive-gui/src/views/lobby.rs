@@ -0,0 +1,143 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::format;
+use crate::state::AppState;
+use crate::views::{OpenPlayer, OpenScout};
+use chrono::Local;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Live lobby scout: players seen in the game log for the current match, with whatever the
+/// cache and search API know about them.
+pub struct LobbyView {
+    scroll_handle: ScrollHandle,
+    player_focus: Vec<FocusHandle>,
+    scout_focus: FocusHandle,
+}
+
+impl LobbyView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            player_focus: Vec::new(),
+            scout_focus: cx.focus_handle().tab_stop(true),
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Live lobby".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+}
+
+impl Render for LobbyView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let app_state = cx.global::<AppState>();
+        let lobby = app_state.live_lobby.lock().unwrap().clone();
+        let settings = app_state.settings();
+        let spacing = settings.density.spacing();
+        sync_focus_handles(&mut self.player_focus, lobby.players.len(), cx);
+
+        let status = match (settings.live_match.enabled, lobby.started_at, lobby.in_match) {
+            (false, _, _) => "Live lobby scouting is off. Turn it on in Settings.".to_string(),
+            (true, None, _) => "Waiting for a match to start…".to_string(),
+            (true, Some(started), true) => format!("In match since {}", started.format("%H:%M")),
+            (true, Some(started), false) => format!("Last match started at {}", started.format("%H:%M")),
+        };
+
+        div()
+            .id("lobby-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Live lobby"))
+                    .child(
+                        div()
+                            .px_3()
+                            .py_1()
+                            .text_sm()
+                            .rounded_md()
+                            .bg(rgb(0x313244))
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(&self.scout_focus)
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                window.dispatch_action(Box::new(OpenScout), cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(OpenScout), cx);
+                                }
+                            })
+                            .child("Scout lobby"),
+                    ),
+            )
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child(status))
+            .when(lobby.started_at.is_some() && lobby.players.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child("No player names found in the game log yet."),
+                )
+            })
+            .children(lobby.players.into_iter().zip(self.player_focus.iter()).map(|(player, focus)| {
+                let details = match (&player.profile, &player.summary) {
+                    _ if player.resolving => "Looking up…".to_string(),
+                    (None, _) => "Not found".to_string(),
+                    (Some(_), None) => "No recent matches".to_string(),
+                    (Some(_), Some(summary)) => [
+                        summary.rank.clone(),
+                        summary.level.map(|level| format!("Lv {}", level)),
+                        summary.region.clone(),
+                        summary
+                            .last_seen
+                            .map(|time| format!("last played {}", format::date(time.with_timezone(&Local).date_naive()))),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" · "),
+                };
+                let action = player.profile.as_ref().map(|profile| OpenPlayer {
+                    player_id: profile.player_id.clone(),
+                    platform: profile.platform.clone(),
+                });
+
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .p(spacing.row)
+                    .bg(rgb(0x181825))
+                    .rounded_md()
+                    .child(div().font_weight(FontWeight::SEMIBOLD).child(player.name))
+                    .child(div().text_sm().text_color(rgb(0x9399b2)).child(details))
+                    .when_some(action, |row, action| {
+                        let key_action = action.clone();
+                        row.cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(focus)
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                window.dispatch_action(Box::new(action.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_action.clone()), cx);
+                                }
+                            })
+                    })
+            }))
+    }
+}
@@ -0,0 +1,181 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::logging::{self, LogEntry};
+use crate::state::AppState;
+use gpui::prelude::*;
+use gpui::*;
+use std::time::Duration;
+use tracing::Level;
+
+const LEVELS: [Level; 5] = [Level::ERROR, Level::WARN, Level::INFO, Level::DEBUG, Level::TRACE];
+
+/// How often the viewer picks up new events while it is open.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+fn level_color(level: Level) -> Rgba {
+    match level {
+        Level::ERROR => rgb(0xf38ba8),
+        Level::WARN => rgb(0xf9e2af),
+        Level::INFO => rgb(0x89b4fa),
+        Level::DEBUG => rgb(0x9399b2),
+        _ => rgb(0x6c7086),
+    }
+}
+
+/// Recent log events with a minimum-level filter, so API failures can be inspected in-app.
+pub struct LogsView {
+    scroll_handle: ScrollHandle,
+    min_level: Level,
+    entries: Vec<LogEntry>,
+    level_focus: Vec<FocusHandle>,
+}
+
+impl LogsView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        cx.spawn(async move |view, cx| loop {
+            cx.background_executor().timer(POLL_INTERVAL).await;
+            if view.update(cx, |this, cx| this.refresh(cx)).is_err() {
+                break;
+            }
+        })
+        .detach();
+
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            min_level: Level::INFO,
+            entries: logging::recent(),
+            level_focus: Vec::new(),
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Logs".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let entries = logging::recent();
+        let changed = entries.len() != self.entries.len()
+            || entries.last().map(|e| e.time) != self.entries.last().map(|e| e.time);
+        if changed {
+            self.entries = entries;
+            cx.notify();
+        }
+    }
+
+    fn set_level(&mut self, level: Level, cx: &mut Context<Self>) {
+        self.min_level = level;
+        cx.notify();
+    }
+}
+
+impl Render for LogsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        sync_focus_handles(&mut self.level_focus, LEVELS.len(), cx);
+        let spacing = cx.global::<AppState>().settings().density.spacing();
+        // More verbose levels compare greater, so "at least as severe" is `<=`
+        let visible: Vec<&LogEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.level <= self.min_level)
+            .collect();
+
+        div()
+            .id("logs-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Logs"))
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .children(LEVELS.iter().zip(&self.level_focus).map(|(level, focus)| {
+                                let level = *level;
+                                let selected = level == self.min_level;
+                                div()
+                                    .px_3()
+                                    .py_1()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .text_sm()
+                                    .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                                    .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                        this.set_level(level, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.set_level(level, cx);
+                                        }
+                                    }))
+                                    .child(level.to_string())
+                            })),
+                    ),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child(format!(
+                        "Showing {} of the last {} events. Full logs are in {}",
+                        visible.len(),
+                        self.entries.len(),
+                        logging::log_dir().display()
+                    )),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .p(spacing.card)
+                    .bg(rgb(0x181825))
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(rgb(0x313244))
+                    .text_sm()
+                    .when(visible.is_empty(), |parent| {
+                        parent.child(div().text_color(rgb(0x9399b2)).child("No events at this level yet."))
+                    })
+                    .children(visible.iter().rev().map(|entry| {
+                        div()
+                            .flex()
+                            .gap_2()
+                            .py(spacing.row_gap)
+                            .child(
+                                div()
+                                    .flex_none()
+                                    .text_color(rgb(0x6c7086))
+                                    .child(entry.time.format("%H:%M:%S").to_string()),
+                            )
+                            .child(
+                                div()
+                                    .flex_none()
+                                    .w(px(48.0))
+                                    .text_color(level_color(entry.level))
+                                    .child(entry.level.to_string()),
+                            )
+                            .child(
+                                div()
+                                    .flex_none()
+                                    .text_color(rgb(0x9399b2))
+                                    .child(entry.target.clone()),
+                            )
+                            .child(div().flex_1().child(entry.message.clone()))
+                    })),
+            )
+    }
+}
@@ -1,13 +1,127 @@
 use gpui::*;
 use gpui::prelude::*;
+use crate::abilities::{ability_usage, accuracy};
+use crate::api::rate_limit_of;
+use crate::components::damage_bar::DamageBar;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::match_map::MatchMap;
+use crate::components::match_timeline::MatchTimeline;
+use crate::components::participation::ParticipationChart;
+use crate::components::rate_limit::{RateLimit, RateLimitBanner};
+use crate::components::truncated_text::TruncatedText;
+use crate::death_recap::{death_recaps, DeathRecap, RECAP_SECONDS};
+use crate::format;
+use crate::markdown;
+use crate::match_export;
+use crate::match_notes::{ChecklistItem, MatchContext, MatchNote};
+use crate::matchups::own_row;
+use crate::session::parse_time;
+use crate::settings::ScoreboardColumn;
 use crate::state::AppState;
+use crate::tasks::RequestGeneration;
+use crate::telemetry;
+use crate::theme::{team_color, Spacing};
+use crate::views::modal::{self, Modal};
 use serde_json::Value;
+use std::sync::Arc;
+
+/// How long "Copied" replaces the copy button's label.
+const COPIED_FEEDBACK: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Tallest the scoreboard grows before its rows scroll under the frozen header.
+const SCOREBOARD_HEIGHT: f32 = 560.0;
+
+/// A squad's players on the scoreboard, under a header naming the team and its placement.
+struct TeamGroup {
+    team_id: i64,
+    placement: Option<i64>,
+    /// Indices into `LoadedMatch::rows`
+    rows: Vec<usize>,
+}
+
+/// A match prepared on the background thread, so rendering never sorts or scans the raw rows.
+struct LoadedMatch {
+    rows: Vec<Value>,
+    /// Scoreboard order: by placement, then team, so teammates sit together
+    groups: Vec<TeamGroup>,
+    /// Only shown when the payload reports shots
+    show_accuracy: bool,
+    timeline: Option<MatchTimeline>,
+    /// Stacked in scoreboard order
+    participation: Option<ParticipationChart>,
+    map: Option<MatchMap>,
+}
+
+impl LoadedMatch {
+    fn new(data: Value) -> Option<Self> {
+        let Value::Array(rows) = data else {
+            return None;
+        };
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by_key(|idx| {
+            let row = &rows[*idx];
+            (row["placement"].as_i64().unwrap_or(i64::MAX), row["team_id"].as_i64().unwrap_or(0))
+        });
+        let mut groups: Vec<TeamGroup> = Vec::new();
+        for idx in order {
+            let team_id = rows[idx]["team_id"].as_i64().unwrap_or(0);
+            match groups.last_mut() {
+                Some(group) if group.team_id == team_id => group.rows.push(idx),
+                _ => groups.push(TeamGroup {
+                    team_id,
+                    placement: rows[idx]["placement"].as_i64(),
+                    rows: vec![idx],
+                }),
+            }
+        }
+        let ordered: Vec<&Value> = groups.iter().flat_map(|group| group.rows.iter().map(|&idx| &rows[idx])).collect();
+        let participation = ParticipationChart::from_rows(&ordered);
+        Some(Self {
+            groups,
+            show_accuracy: rows.iter().any(|row| accuracy(&row["stats"]).is_some()),
+            timeline: MatchTimeline::from_rows(&rows),
+            participation,
+            map: MatchMap::from_rows(&rows),
+            rows,
+        })
+    }
+
+    /// Rows in scoreboard order.
+    fn ordered_rows(&self) -> Vec<&Value> {
+        self.groups
+            .iter()
+            .flat_map(|group| group.rows.iter().map(|&idx| &self.rows[idx]))
+            .collect()
+    }
+}
 
 pub struct MatchDetailView {
     match_id: String,
     platform: String,
-    details: Option<Value>,
+    details: Option<Arc<LoadedMatch>>,
     loading: bool,
+    requests: RequestGeneration,
+    rate_limit: Option<RateLimit>,
+    scroll_handle: ScrollHandle,
+    /// The scoreboard's own scroll area, below its frozen column header
+    scoreboard_scroll: ScrollHandle,
+    /// Scoreboard row showing its full stat breakdown
+    expanded: Option<usize>,
+    row_focus: Vec<FocusHandle>,
+    /// Whether the column picker above the scoreboard is open
+    columns_open: bool,
+    /// The "Columns" button, then one per `ScoreboardColumn::ALL`
+    column_focus: Vec<FocusHandle>,
+    /// One per tag chip, then "+ Tag", the note button, the review checklist and "Clear from queue"
+    review_focus: Vec<FocusHandle>,
+    export_focus: FocusHandle,
+    /// Where the last CSV export went, or why it failed
+    export_status: Option<Result<String, String>>,
+    image_focus: FocusHandle,
+    exporting_image: bool,
+    copy_focus: FocusHandle,
+    /// Set for a moment after the scoreboard was copied as Markdown
+    copied: bool,
 }
 
 impl MatchDetailView {
@@ -17,6 +131,21 @@ impl MatchDetailView {
             platform: platform.clone(),
             details: None,
             loading: true,
+            requests: RequestGeneration::default(),
+            rate_limit: None,
+            scroll_handle: ScrollHandle::new(),
+            scoreboard_scroll: ScrollHandle::new(),
+            expanded: None,
+            row_focus: Vec::new(),
+            columns_open: false,
+            column_focus: Vec::new(),
+            review_focus: Vec::new(),
+            export_focus: cx.focus_handle().tab_stop(true),
+            export_status: None,
+            image_focus: cx.focus_handle().tab_stop(true),
+            exporting_image: false,
+            copy_focus: cx.focus_handle().tab_stop(true),
+            copied: false,
         };
         cx.spawn(async move |view, cx| {
             view.update(cx, |this, cx| this.fetch_data(cx)).ok();
@@ -24,52 +153,870 @@ impl MatchDetailView {
         view
     }
 
+    pub fn title(&self) -> SharedString {
+        let short_id: String = self.match_id.chars().take(8).collect();
+        format!("Match {}", short_id).into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        self.loading = true;
+        cx.notify();
+        self.fetch_data(cx);
+    }
+
     fn fetch_data(&mut self, cx: &mut Context<Self>) {
         let app_state = cx.global::<AppState>();
         let service = app_state.service.clone();
         let match_id = self.match_id.clone();
         let platform = self.platform.clone();
+        let request = self.requests.start();
+
+        let index = app_state.player_index.clone();
+        let archive = app_state.match_archive.clone();
 
         cx.spawn(async move |view, cx| {
             let result = cx.background_executor().spawn(async move {
-                let mut service = service.lock().unwrap();
-                service.get_match(&platform, &match_id)
+                // Imported matches never expire, so they open offline and skip the API
+                let archived = archive.lock().unwrap().get(&platform, &match_id);
+                let data = match archived {
+                    Some(data) => data,
+                    None => service.lock().unwrap().get_match(&platform, &match_id)?,
+                };
+                let details = LoadedMatch::new(data);
+                if let Some(details) = &details {
+                    index.lock().unwrap().record_match_rows(&platform, &details.rows);
+                }
+                anyhow::Ok(details.map(Arc::new))
             }).await;
 
             view.update(cx, |this, cx| {
+                if !this.requests.is_current(request) {
+                    return;
+                }
                 this.loading = false;
-                if let Ok(data) = result {
-                    this.details = Some(data);
+                this.rate_limit = match &result {
+                    Err(err) => rate_limit_of(err)
+                        .map(|retry_after| RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::refresh, cx)),
+                    Ok(_) => None,
+                };
+                if let Ok(details) = result {
+                    this.details = details;
                 }
                 cx.notify();
             }).ok();
         }).detach();
     }
+
+    fn export_csv(&mut self, cx: &mut Context<Self>) {
+        let Some(details) = self.details.clone() else {
+            return;
+        };
+        telemetry::track(cx, "export_match_csv");
+        self.export_status = Some(
+            match_export::export_csv(&self.match_id, &details.ordered_rows())
+                .map(|path| format!("Saved to {}", path.display()))
+                .map_err(|err| format!("Export failed: {}", err)),
+        );
+        cx.notify();
+    }
+
+    /// Renders the shareable summary image on a background thread, since loading the system
+    /// fonts takes a moment.
+    fn export_image(&mut self, cx: &mut Context<Self>) {
+        let Some(details) = self.details.clone() else {
+            return;
+        };
+        if self.exporting_image {
+            return;
+        }
+        self.exporting_image = true;
+        telemetry::track(cx, "export_match_image");
+        cx.notify();
+        let match_id = self.match_id.clone();
+        cx.spawn(async move |view, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { match_export::export_summary_png(&match_id, &details.ordered_rows()) })
+                .await;
+            view.update(cx, |this, cx| {
+                this.exporting_image = false;
+                this.export_status = Some(
+                    result
+                        .map(|path| format!("Saved to {}", path.display()))
+                        .map_err(|err| format!("Export failed: {}", err)),
+                );
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    /// Puts the scoreboard, with the columns currently shown, on the clipboard as a Markdown table.
+    fn copy_markdown(&mut self, cx: &mut Context<Self>) {
+        let Some(details) = self.details.clone() else {
+            return;
+        };
+        telemetry::track(cx, "copy_match_markdown");
+        let columns = visible_columns(&cx.global::<AppState>().settings().scoreboard_columns, &details);
+        let rows: Vec<Vec<String>> = details
+            .ordered_rows()
+            .into_iter()
+            .map(|row| {
+                let mut cells = vec![
+                    row["placement"].as_i64().map(|placement| format!("#{}", placement)).unwrap_or_default(),
+                    row["team_id"].as_i64().map(|team| team.to_string()).unwrap_or_default(),
+                    row["player"]["unique_display_name"].as_str().unwrap_or("Unknown").to_string(),
+                    row["hero"]["name"].as_str().unwrap_or("").to_string(),
+                ];
+                cells.extend(columns.iter().map(|column| column_value(*column, &row["stats"])));
+                cells
+            })
+            .collect();
+        let mut header = vec!["Place", "Team", "Player", "Hero"];
+        header.extend(columns.iter().map(|column| column.label()));
+
+        let mut text = format!("**Match {}**", self.match_id);
+        if let Some(start) = details.rows.iter().find_map(|row| parse_time(&row["match_start"])) {
+            text.push_str(&format!(" · {}", format::date_time(&start)));
+        }
+        text.push_str("\n\n");
+        text.push_str(&markdown::table(&header, &rows));
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+
+        self.copied = true;
+        cx.notify();
+        cx.spawn(async move |view, cx| {
+            cx.background_executor().timer(COPIED_FEEDBACK).await;
+            view.update(cx, |this, cx| {
+                this.copied = false;
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    fn header_button(label: &'static str, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_sm()
+            .rounded_md()
+            .bg(rgb(0x313244))
+            .cursor_pointer()
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label)
+    }
+
+    /// Start time plus the user's own hero and placement, stored with a new note so the
+    /// review list doesn't need the match again.
+    fn note_context(&self, cx: &App) -> MatchContext {
+        let Some(rows) = self.details.as_ref().map(|details| &details.rows) else {
+            return MatchContext::default();
+        };
+        let accounts = cx.global::<AppState>().settings().accounts;
+        let own = accounts.iter().find_map(|account| own_row(&account.player_id, rows));
+        MatchContext {
+            match_start: rows.iter().find_map(|row| parse_time(&row["match_start"])),
+            hero: own.and_then(|row| row["hero"]["name"].as_str()).map(|hero| hero.to_string()),
+            placement: own.and_then(|row| row["placement"].as_i64()),
+        }
+    }
+
+    fn toggle_tag(&mut self, tag: &str, cx: &mut Context<Self>) {
+        telemetry::track(cx, "tag_match");
+        let context = self.note_context(cx);
+        cx.global::<AppState>()
+            .match_notes
+            .lock()
+            .unwrap()
+            .toggle_tag(&self.platform, &self.match_id, tag, context);
+        cx.notify();
+    }
+
+    fn toggle_checklist(&mut self, item: ChecklistItem, cx: &mut Context<Self>) {
+        cx.global::<AppState>()
+            .match_notes
+            .lock()
+            .unwrap()
+            .toggle_checklist(&self.platform, &self.match_id, item);
+        cx.notify();
+    }
+
+    fn clear_review(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "clear_review");
+        cx.global::<AppState>()
+            .match_notes
+            .lock()
+            .unwrap()
+            .clear_review(&self.platform, &self.match_id);
+        cx.notify();
+    }
+
+    fn prompt_tag(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.entity().downgrade();
+        modal::open(
+            Modal::prompt("Add tag", "Tag this match, e.g. \"bad drop\".", "Tag", move |tag, _window, cx| {
+                view.update(cx, |this, cx| this.toggle_tag(&tag, cx)).ok();
+            })
+            .confirm_label("Add"),
+            window,
+            cx,
+        );
+    }
+
+    fn prompt_note(&mut self, current: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.entity().downgrade();
+        modal::open(
+            Modal::prompt("Review note", "What to remember about this match.", "Note", move |text, _window, cx| {
+                view.update(cx, |this, cx| {
+                    let context = this.note_context(cx);
+                    cx.global::<AppState>()
+                        .match_notes
+                        .lock()
+                        .unwrap()
+                        .set_note(&this.platform, &this.match_id, &text, context);
+                    cx.notify();
+                })
+                .ok();
+            })
+            .initial_value(current),
+            window,
+            cx,
+        );
+    }
+
+    /// Tag chips (presets, tags used elsewhere and this match's own) and the review note.
+    fn render_review(&mut self, note: Option<MatchNote>, cx: &mut Context<Self>) -> Div {
+        let note_tags = note.as_ref().map(|note| note.tags.clone()).unwrap_or_default();
+        let mut tags = cx.global::<AppState>().match_notes.lock().unwrap().all_tags();
+        for tag in &note_tags {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        sync_focus_handles(&mut self.review_focus, tags.len() + 3 + ChecklistItem::ALL.len(), cx);
+        let queued = note.as_ref().is_some_and(|note| note.in_review_queue());
+        let checked = note.as_ref().map(|note| note.checked.clone()).unwrap_or_default();
+        let text = note.map(|note| note.note).unwrap_or_default();
+        let chip = |label: String, selected: bool, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+        let add_focus = &self.review_focus[tags.len()];
+        let note_focus = &self.review_focus[tags.len() + 1];
+        let checklist_focus = &self.review_focus[tags.len() + 2..];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_4()
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(div().font_weight(FontWeight::SEMIBOLD).child("Review"))
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap_2()
+                    .children(tags.iter().zip(&self.review_focus).map(|(tag, focus)| {
+                        let selected = note_tags.contains(tag);
+                        let (click_tag, key_tag) = (tag.clone(), tag.clone());
+                        chip(tag.clone(), selected, focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.toggle_tag(&click_tag, cx);
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.toggle_tag(&key_tag, cx);
+                                }
+                            }))
+                    }))
+                    .child(
+                        chip("+ Tag".to_string(), false, add_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.prompt_tag(window, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.prompt_tag(window, cx);
+                                }
+                            })),
+                    ),
+            )
+            .when(!text.is_empty(), |parent| {
+                parent.child(div().text_sm().text_color(rgb(0xbac2de)).child(text.clone()))
+            })
+            .child(
+                div().flex().child(
+                    chip(if text.is_empty() { "Add note" } else { "Edit note" }.to_string(), false, note_focus)
+                        .on_mouse_down(MouseButton::Left, cx.listener({
+                            let text = text.clone();
+                            move |this, _, window, cx| this.prompt_note(&text, window, cx)
+                        }))
+                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                            if is_activation_key(event) {
+                                this.prompt_note(&text, window, cx);
+                            }
+                        })),
+                ),
+            )
+            // Queued matches stay on the home page until cleared here or there
+            .when(queued, |parent| {
+                parent.child(
+                    div()
+                        .flex()
+                        .flex_wrap()
+                        .items_center()
+                        .gap_2()
+                        .child(div().text_sm().text_color(rgb(0x9399b2)).child("Review queue:"))
+                        .children(ChecklistItem::ALL.into_iter().zip(checklist_focus).map(|(item, focus)| {
+                            let done = checked.contains(&item);
+                            chip(format!("{} {}", if done { "☑" } else { "☐" }, item.label()), done, focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.toggle_checklist(item, cx);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.toggle_checklist(item, cx);
+                                    }
+                                }))
+                        }))
+                        .child(
+                            chip("Clear from queue".to_string(), false, &checklist_focus[ChecklistItem::ALL.len()])
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.clear_review(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.clear_review(cx);
+                                    }
+                                })),
+                        ),
+                )
+            })
+    }
+
+    fn toggle_row(&mut self, idx: usize, cx: &mut Context<Self>) {
+        self.expanded = if self.expanded == Some(idx) { None } else { Some(idx) };
+        cx.notify();
+    }
+
+    fn toggle_column(&mut self, column: ScoreboardColumn, cx: &mut Context<Self>) {
+        cx.global::<AppState>()
+            .settings
+            .lock()
+            .unwrap()
+            .update(|s| s.toggle_scoreboard_column(column));
+        cx.notify();
+    }
+
+    fn toggle_column_picker(&mut self, cx: &mut Context<Self>) {
+        self.columns_open = !self.columns_open;
+        cx.notify();
+    }
+
+    /// The "Columns" button and, while open, a chip per column to show or hide it.
+    fn render_column_picker(&self, shown: &[ScoreboardColumn], cx: &mut Context<Self>) -> Div {
+        let chip = |label: &'static str, selected: bool, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+
+        div()
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .gap_2()
+            .child(
+                chip(if self.columns_open { "Columns ▾" } else { "Columns ▸" }, false, &self.column_focus[0])
+                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.toggle_column_picker(cx)))
+                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.toggle_column_picker(cx);
+                        }
+                    })),
+            )
+            .when(self.columns_open, |parent| {
+                parent.children(ScoreboardColumn::ALL.into_iter().zip(&self.column_focus[1..]).map(|(column, focus)| {
+                    chip(column.label(), shown.contains(&column), focus)
+                        .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                            this.toggle_column(column, cx);
+                        }))
+                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.toggle_column(column, cx);
+                            }
+                        }))
+                }))
+            })
+    }
+
+    fn render_scoreboard(&mut self, details: &LoadedMatch, spacing: &Spacing, cx: &mut Context<Self>) -> Div {
+        let rows = &details.rows;
+        let settings = cx.global::<AppState>().settings();
+        let columns = visible_columns(&settings.scoreboard_columns, details);
+        sync_focus_handles(&mut self.row_focus, rows.len(), cx);
+        sync_focus_handles(&mut self.column_focus, ScoreboardColumn::ALL.len() + 1, cx);
+        // The group scrolled past the top keeps its header pinned there
+        let scrolled = self.scoreboard_scroll.offset().y < px(0.0);
+        let pinned_group = scrolled
+            .then(|| details.groups.get(self.scoreboard_scroll.logical_scroll_top().0))
+            .flatten();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(spacing.row_gap)
+            .child(self.render_column_picker(&settings.scoreboard_columns, cx))
+            .child(
+                div()
+                    .flex()
+                    .gap_3()
+                    .px(spacing.row)
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    // Dot, placement, name and hero, lined up with the rows below
+                    .child(div().size_2())
+                    .child(div().w(px(36.0)))
+                    .child(div().flex_1())
+                    .child(div().w(px(90.0)))
+                    .children(columns.iter().map(|column| div().w(column_width(*column)).child(column.label())))
+                    .child(div().w(px(8.0))),
+            )
+            .child(
+                div()
+                    .relative()
+                    .child(
+                        div()
+                            .id("scoreboard-rows")
+                            .flex()
+                            .flex_col()
+                            .gap(spacing.row_gap)
+                            .max_h(px(SCOREBOARD_HEIGHT))
+                            .overflow_y_scroll()
+                            .track_scroll(&self.scoreboard_scroll)
+                            // Re-render while scrolling so the pinned team header follows along
+                            .on_scroll_wheel(cx.listener(|_, _, _window, cx| cx.notify()))
+                            .children(details.groups.iter().map(|group| {
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .gap(spacing.row_gap)
+                                    .child(team_header(group, spacing))
+                                    .children(
+                                        group
+                                            .rows
+                                            .iter()
+                                            .map(|&idx| self.render_player_row(idx, &rows[idx], &columns, spacing, cx))
+                                            .collect::<Vec<_>>(),
+                                    )
+                            })),
+                    )
+                    .when_some(pinned_group, |parent, group| {
+                        parent.child(div().absolute().top_0().left_0().right_0().child(team_header(group, spacing)))
+                    }),
+            )
+    }
+
+    fn render_player_row(
+        &self,
+        idx: usize,
+        row: &Value,
+        columns: &[ScoreboardColumn],
+        spacing: &Spacing,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let stats = &row["stats"];
+        let expanded = self.expanded == Some(idx);
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p(spacing.row)
+            .bg(rgb(0x181825))
+            .rounded_md()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(&self.row_focus[idx])
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                        this.toggle_row(idx, cx);
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.toggle_row(idx, cx);
+                        }
+                    }))
+                    .child(div().size_2().rounded_full().bg(team_color(row["team_id"].as_i64().unwrap_or(0))))
+                    .child(
+                        div()
+                            .w(px(36.0))
+                            .text_color(rgb(0x9399b2))
+                            .child(format!("#{}", row["placement"].as_i64().unwrap_or(0)))
+                    )
+                    .child(
+                        TruncatedText::new(
+                            ("name", idx),
+                            row["player"]["unique_display_name"].as_str().unwrap_or("Unknown").to_string(),
+                        )
+                        .flex_1()
+                        .font_weight(FontWeight::SEMIBOLD)
+                    )
+                    .child(
+                        TruncatedText::new(("hero", idx), row["hero"]["name"].as_str().unwrap_or("").to_string())
+                            .w(px(90.0))
+                            .text_sm()
+                    )
+                    .children(columns.iter().map(|column| {
+                        div()
+                            .w(column_width(*column))
+                            .text_sm()
+                            .when(*column != ScoreboardColumn::Kda, |cell| cell.text_color(rgb(0x9399b2)))
+                            .child(column_value(*column, stats))
+                    }))
+                    .child(div().text_xs().text_color(rgb(0x6c7086)).child(if expanded { "▾" } else { "▸" }))
+            )
+            .when(expanded, |parent| {
+                let mut all_stats: Vec<(String, String)> = stats
+                    .as_object()
+                    .map(|stats| {
+                        stats
+                            .iter()
+                            .map(|(key, value)| {
+                                let value = match (value.as_i64(), value.as_f64()) {
+                                    (Some(value), _) => format::integer(value),
+                                    (None, Some(value)) => format::decimal(value, 1),
+                                    _ => value.to_string(),
+                                };
+                                (key.clone(), value)
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                all_stats.sort();
+                let abilities = ability_usage(row);
+                let recaps = self
+                    .details
+                    .as_ref()
+                    .map(|details| death_recaps(row, &details.rows))
+                    .unwrap_or_default();
+                parent
+                    .children(DamageBar::from_stats(stats))
+                    .when(!abilities.is_empty(), |parent| {
+                        parent.child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .gap_1()
+                                .text_xs()
+                                .children(abilities.into_iter().map(|ability| {
+                                    div()
+                                        .flex()
+                                        .gap_3()
+                                        .child(div().w(px(140.0)).child(ability.name))
+                                        .child(div().w(px(70.0)).text_color(rgb(0x9399b2)).child(format!("{} casts", ability.casts)))
+                                        .children(ability.damage.map(|damage| {
+                                            div().text_color(rgb(0x9399b2)).child(format!("{} dmg", format::compact(damage as f64)))
+                                        }))
+                                }))
+                        )
+                    })
+                    .when(!recaps.is_empty(), |parent| {
+                        parent.child(
+                            div()
+                                .flex()
+                                .flex_wrap()
+                                .gap_2()
+                                .children(recaps.iter().enumerate().map(|(idx, recap)| death_card(idx + 1, recap))),
+                        )
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .flex_wrap()
+                            .gap_x_4()
+                            .gap_y_1()
+                            .text_xs()
+                            .children(all_stats.into_iter().map(|(key, value)| {
+                                div()
+                                    .flex()
+                                    .gap_1()
+                                    .child(div().text_color(rgb(0x9399b2)).child(key))
+                                    .child(value)
+                            }))
+                    )
+            })
+    }
+}
+
+/// Who ended one of a player's lives and the damage they took in the seconds before.
+fn death_card(number: usize, recap: &DeathRecap) -> Div {
+    let mut killed_by = recap.killer.clone().unwrap_or_else(|| "Unknown".to_string());
+    if let Some(hero) = &recap.killer_hero {
+        killed_by.push_str(&format!(" ({})", hero));
+    }
+    if let Some(source) = &recap.source {
+        killed_by.push_str(&format!(" with {}", source));
+    }
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .w(px(260.0))
+        .p_2()
+        .rounded_md()
+        .bg(rgb(0x11111b))
+        .text_xs()
+        .child(
+            div()
+                .flex()
+                .justify_between()
+                .font_weight(FontWeight::SEMIBOLD)
+                .child(format!("Death {}", number))
+                .child(div().text_color(rgb(0x9399b2)).child(format::duration(recap.time as i64))),
+        )
+        .child(div().text_color(rgb(0xf38ba8)).child(format!("Killed by {}", killed_by)))
+        .when(!recap.hits.is_empty(), |card| {
+            card.child(div().text_color(rgb(0x6c7086)).child(format!(
+                "{} damage in the last {}s",
+                format::compact(recap.total_damage()),
+                RECAP_SECONDS
+            )))
+        })
+        .children(recap.hits.iter().map(|hit| {
+            let mut from = hit.attacker.clone().unwrap_or_else(|| "Unknown".to_string());
+            if let Some(source) = &hit.source {
+                from.push_str(&format!(" · {}", source));
+            }
+            div()
+                .flex()
+                .gap_2()
+                .child(div().w(px(40.0)).text_color(rgb(0x6c7086)).child(format!("-{}s", format::decimal(hit.before, 1))))
+                .child(div().flex_1().min_w_0().truncate().child(from))
+                .child(div().text_color(rgb(0x9399b2)).child(format::compact(hit.amount)))
+        }))
+}
+
+/// The squad's color, number and placement above its players.
+fn team_header(group: &TeamGroup, spacing: &Spacing) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .px(spacing.row)
+        .py_1()
+        .bg(rgb(0x11111b))
+        .text_xs()
+        .text_color(rgb(0xbac2de))
+        .child(div().size_2().rounded_full().bg(team_color(group.team_id)))
+        .child(format!("Team {}", group.team_id))
+        .children(group.placement.map(|placement| {
+            div().text_color(rgb(0x6c7086)).child(format!("#{}", placement))
+        }))
+}
+
+/// The chosen columns this match can fill; accuracy needs shot counts, which not every payload
+/// reports.
+fn visible_columns(chosen: &[ScoreboardColumn], details: &LoadedMatch) -> Vec<ScoreboardColumn> {
+    chosen
+        .iter()
+        .copied()
+        .filter(|column| *column != ScoreboardColumn::Accuracy || details.show_accuracy)
+        .collect()
+}
+
+/// Op.gg stat behind a numeric column; K/D/A and accuracy combine several.
+fn column_stat(column: ScoreboardColumn) -> Option<&'static str> {
+    match column {
+        ScoreboardColumn::Damage => Some("HeroEffectiveDamageDone"),
+        ScoreboardColumn::DamageTaken => Some("HeroEffectiveDamageTaken"),
+        ScoreboardColumn::Healing => Some("HealingGiven"),
+        ScoreboardColumn::Shielding => Some("ShieldMitigatedDamage"),
+        ScoreboardColumn::Revives => Some("Revived"),
+        ScoreboardColumn::Gold => Some("GoldEarned"),
+        ScoreboardColumn::Kda | ScoreboardColumn::Accuracy => None,
+    }
+}
+
+fn column_width(column: ScoreboardColumn) -> Pixels {
+    match column {
+        ScoreboardColumn::Kda | ScoreboardColumn::Damage | ScoreboardColumn::DamageTaken => px(80.0),
+        ScoreboardColumn::Healing | ScoreboardColumn::Shielding | ScoreboardColumn::Accuracy => px(70.0),
+        ScoreboardColumn::Revives | ScoreboardColumn::Gold => px(60.0),
+    }
+}
+
+/// A player's cell in `column`; "—" when the match didn't report the stat.
+fn column_value(column: ScoreboardColumn, stats: &Value) -> String {
+    let stat = |key: &str| stats[key].as_i64().unwrap_or(0);
+    match column {
+        ScoreboardColumn::Kda => format!("{}/{}/{}", stat("Kills"), stat("Deaths"), stat("Assists")),
+        ScoreboardColumn::Accuracy => match accuracy(stats) {
+            Some(accuracy) => format!("{:.0}%", accuracy * 100.0),
+            None => "—".to_string(),
+        },
+        column => match column_stat(column).and_then(|key| stats[key].as_f64()) {
+            Some(value) => format::compact(value),
+            None => "—".to_string(),
+        },
+    }
 }
 
 impl Render for MatchDetailView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let spacing = cx.global::<AppState>().settings().density.spacing();
+        let details = self.details.clone();
+        let timeline = details.as_ref().and_then(|details| details.timeline.clone());
+        let participation = details.as_ref().and_then(|details| details.participation.clone());
+        let map = details.as_ref().and_then(|details| details.map.clone());
+        let note = cx
+            .global::<AppState>()
+            .match_notes
+            .lock()
+            .unwrap()
+            .get(&self.platform, &self.match_id);
+        let review = self.render_review(note, cx);
+        let scoreboard = if let Some(limit) = self.rate_limit {
+            div().child(RateLimitBanner::new(limit))
+        } else if self.loading {
+            div().child("Loading...")
+        } else if let Some(details) = &details {
+            self.render_scoreboard(details, &spacing, cx)
+        } else {
+            div().child("Failed to load match details")
+        };
         div()
+            .id("match-detail-view")
             .flex()
             .flex_col()
             .size_full()
-            .p_8()
-            .gap_4()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
             .child(
                 div()
-                    .text_xl()
-                    .font_weight(FontWeight::BOLD)
-                    .child(format!("Match: {}", self.match_id))
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_xl()
+                            .font_weight(FontWeight::BOLD)
+                            .child(format!("Match: {}", self.match_id))
+                    )
+                    .when(details.is_some(), |row| {
+                        row.child(
+                            Self::header_button(if self.copied { "Copied" } else { "Copy as Markdown" }, &self.copy_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.copy_markdown(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.copy_markdown(cx);
+                                    }
+                                })),
+                        )
+                        .child(
+                            Self::header_button(
+                                if self.exporting_image { "Rendering…" } else { "Export match summary" },
+                                &self.image_focus,
+                            )
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.export_image(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.export_image(cx);
+                                }
+                            })),
+                        )
+                        .child(
+                            Self::header_button("Export CSV", &self.export_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.export_csv(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.export_csv(cx);
+                                    }
+                                })),
+                        )
+                    })
             )
-            .child(
-                if self.loading {
-                    div().child("Loading...")
-                } else if let Some(details) = &self.details {
+            .when_some(self.export_status.clone(), |parent, status| {
+                let (text, color) = match status {
+                    Ok(text) => (text, rgb(0xa6e3a1)),
+                    Err(text) => (text, rgb(0xf38ba8)),
+                };
+                parent.child(div().text_sm().text_color(color).child(text))
+            })
+            .child(review)
+            .child(scoreboard)
+            .when_some(timeline, |parent, timeline| {
+                parent.child(
                     div()
-                        .child(format!("Details: {:?}", details))
-                } else {
-                    div().child("Failed to load match details")
-                }
-            )
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child("Timeline")
+                        )
+                        .child(timeline)
+                )
+            })
+            .when_some(participation, |parent, participation| {
+                parent.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child("Participation")
+                        )
+                        .child(participation)
+                )
+            })
+            .when_some(map, |parent, map| {
+                parent.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child("Deaths")
+                        )
+                        .child(map)
+                )
+            })
     }
 }
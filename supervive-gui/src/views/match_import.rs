@@ -0,0 +1,454 @@
+use crate::api::rate_limit_of;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::progress_bar::ProgressBar;
+use crate::components::rate_limit::{RateLimit, RateLimitBanner};
+use crate::format;
+use crate::match_archive::{self, ArchiveEntry};
+use crate::state::AppState;
+use crate::tasks::{RequestGeneration, TaskProgress};
+use crate::telemetry;
+use crate::views::{OpenMatch, OpenQueryConsole};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Focus handles per archived match row: open, then remove.
+const ENTRY_CONTROLS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ImportStatus {
+    Queued,
+    Fetching,
+    Archived,
+    AlreadyArchived,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+struct ImportItem {
+    platform: String,
+    match_id: String,
+    status: ImportStatus,
+}
+
+/// Bulk import of matches by ID, e.g. a list from a tournament organizer. Each match is fetched
+/// once and kept in the local match archive, which also lists everything imported so far.
+pub struct MatchImportView {
+    scroll_handle: ScrollHandle,
+    items: Vec<ImportItem>,
+    /// Pasted pieces that weren't match IDs or URLs
+    invalid: Vec<String>,
+    progress: Option<TaskProgress>,
+    running: bool,
+    requests: RequestGeneration,
+    rate_limit: Option<RateLimit>,
+    paste_focus: FocusHandle,
+    retry_focus: FocusHandle,
+    query_focus: FocusHandle,
+    entry_focus: Vec<FocusHandle>,
+}
+
+impl MatchImportView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            items: Vec::new(),
+            invalid: Vec::new(),
+            progress: None,
+            running: false,
+            requests: RequestGeneration::default(),
+            rate_limit: None,
+            paste_focus: cx.focus_handle().tab_stop(true),
+            retry_focus: cx.focus_handle().tab_stop(true),
+            query_focus: cx.focus_handle().tab_stop(true),
+            entry_focus: Vec::new(),
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Import matches".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    fn paste_ids(&mut self, cx: &mut Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        let (matches, invalid) = match_archive::parse_match_list(&text);
+        if matches.is_empty() && invalid.is_empty() {
+            return;
+        }
+        telemetry::track(cx, "import_matches");
+        let archive = cx.global::<AppState>().match_archive.clone();
+        self.items = {
+            let archive = archive.lock().unwrap();
+            matches
+                .into_iter()
+                .map(|(platform, match_id)| ImportItem {
+                    status: if archive.contains(&platform, &match_id) {
+                        ImportStatus::AlreadyArchived
+                    } else {
+                        ImportStatus::Queued
+                    },
+                    platform,
+                    match_id,
+                })
+                .collect()
+        };
+        self.invalid = invalid;
+        self.requests.start();
+        self.rate_limit = None;
+        self.start_import(cx);
+    }
+
+    fn retry_failed(&mut self, cx: &mut Context<Self>) {
+        for item in &mut self.items {
+            if matches!(item.status, ImportStatus::Failed(_)) {
+                item.status = ImportStatus::Queued;
+            }
+        }
+        self.start_import(cx);
+    }
+
+    fn start_import(&mut self, cx: &mut Context<Self>) {
+        let queued = self.items.iter().filter(|item| item.status == ImportStatus::Queued).count();
+        self.progress = (queued > 0).then(|| TaskProgress::new("matches imported", queued));
+        self.run_import(cx);
+        cx.notify();
+    }
+
+    /// Fetches the queued matches one at a time, archiving each as it arrives. A rate limit
+    /// pauses the queue and resumes it when the limit lifts.
+    fn run_import(&mut self, cx: &mut Context<Self>) {
+        let queued: Vec<usize> = (0..self.items.len())
+            .filter(|&idx| self.items[idx].status == ImportStatus::Queued)
+            .collect();
+        self.running = !queued.is_empty();
+        if queued.is_empty() {
+            return;
+        }
+        let app_state = cx.global::<AppState>();
+        let service = app_state.service.clone();
+        let archive = app_state.match_archive.clone();
+        let index = app_state.player_index.clone();
+        let request = self.requests.current();
+
+        cx.spawn(async move |view, cx| {
+            for idx in queued {
+                let target = view.update(cx, |this, cx| {
+                    if !this.requests.is_current(request) {
+                        return None;
+                    }
+                    let item = this.items.get_mut(idx)?;
+                    item.status = ImportStatus::Fetching;
+                    cx.notify();
+                    Some((item.platform.clone(), item.match_id.clone()))
+                });
+                let Ok(Some((platform, match_id))) = target else {
+                    return;
+                };
+
+                let (service, archive, index) = (service.clone(), archive.clone(), index.clone());
+                let result = cx
+                    .background_executor()
+                    .spawn(async move {
+                        let data = service.lock().unwrap().get_match_for_archive(&platform, &match_id)?;
+                        if let Some(rows) = data.as_array() {
+                            index.lock().unwrap().record_match_rows(&platform, rows);
+                        }
+                        archive.lock().unwrap().insert(&platform, &match_id, &data)?;
+                        anyhow::Ok(())
+                    })
+                    .await;
+
+                let go_on = view.update(cx, |this, cx| {
+                    if !this.requests.is_current(request) {
+                        return false;
+                    }
+                    let retry_after = result.as_ref().err().and_then(rate_limit_of);
+                    let Some(item) = this.items.get_mut(idx) else {
+                        return false;
+                    };
+                    if let Some(retry_after) = retry_after {
+                        item.status = ImportStatus::Queued;
+                        this.rate_limit =
+                            Some(RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::run_import, cx));
+                        cx.notify();
+                        return false;
+                    }
+                    item.status = match result {
+                        Ok(()) => ImportStatus::Archived,
+                        Err(err) => ImportStatus::Failed(err.to_string()),
+                    };
+                    if let Some(progress) = this.progress.as_mut() {
+                        progress.advance();
+                    }
+                    cx.notify();
+                    true
+                });
+                if !matches!(go_on, Ok(true)) {
+                    return;
+                }
+            }
+            view.update(cx, |this, cx| {
+                if this.requests.is_current(request) {
+                    this.running = false;
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn remove_entry(&mut self, entry: &ArchiveEntry, cx: &mut Context<Self>) {
+        cx.global::<AppState>()
+            .match_archive
+            .lock()
+            .unwrap()
+            .remove(&entry.platform, &entry.match_id);
+        cx.notify();
+    }
+
+    fn button(label: &'static str, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_sm()
+            .rounded_md()
+            .bg(rgb(0x313244))
+            .cursor_pointer()
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label)
+    }
+
+    fn render_summary(&self) -> Div {
+        let count = |wanted: fn(&ImportStatus) -> bool| self.items.iter().filter(|item| wanted(&item.status)).count();
+        let parts = [
+            (count(|status| *status == ImportStatus::Archived), "imported"),
+            (count(|status| *status == ImportStatus::AlreadyArchived), "already archived"),
+            (count(|status| matches!(status, ImportStatus::Failed(_))), "failed"),
+            (self.invalid.len(), "not match IDs"),
+        ];
+        div().text_sm().text_color(rgb(0x9399b2)).child(
+            parts
+                .iter()
+                .filter(|(count, _)| *count > 0)
+                .map(|(count, label)| format!("{} {}", format::integer(*count as i64), label))
+                .collect::<Vec<_>>()
+                .join(" · "),
+        )
+    }
+
+    /// One line per match that didn't import, with the reason.
+    fn render_failures(&self) -> Option<Div> {
+        let failures: Vec<(String, String)> = self
+            .items
+            .iter()
+            .filter_map(|item| match &item.status {
+                ImportStatus::Failed(error) => Some((item.match_id.clone(), error.clone())),
+                _ => None,
+            })
+            .chain(self.invalid.iter().map(|token| (token.clone(), "Not a match ID or URL".to_string())))
+            .collect();
+        (!failures.is_empty()).then(|| {
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .p_3()
+                .rounded_md()
+                .bg(rgb(0x45273a))
+                .text_sm()
+                .children(failures.into_iter().map(|(reference, error)| {
+                    div()
+                        .flex()
+                        .gap_3()
+                        .child(div().font_weight(FontWeight::SEMIBOLD).child(reference))
+                        .child(div().text_color(rgb(0xf38ba8)).child(error))
+                }))
+        })
+    }
+
+    fn render_archive(&self, entries: Vec<ArchiveEntry>, cx: &mut Context<Self>) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(format!("Archived matches ({})", format::integer(entries.len() as i64))),
+            )
+            .when(entries.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child("Nothing imported yet."),
+                )
+            })
+            .children(entries.into_iter().enumerate().map(|(idx, entry)| {
+                let focus = &self.entry_focus[idx * ENTRY_CONTROLS..(idx + 1) * ENTRY_CONTROLS];
+                let action = OpenMatch {
+                    match_id: entry.match_id.clone(),
+                    platform: entry.platform.clone(),
+                };
+                let key_action = action.clone();
+                let started = entry
+                    .match_start
+                    .map(|start| format::date_time(&start))
+                    .unwrap_or_else(|| "Unknown date".to_string());
+                let short_id: String = entry.match_id.chars().take(8).collect();
+                let remove_entry = entry.clone();
+
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .px_3()
+                            .py_2()
+                            .bg(rgb(0x181825))
+                            .rounded_md()
+                            .text_sm()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(&focus[0])
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                window.dispatch_action(Box::new(action.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_action.clone()), cx);
+                                }
+                            })
+                            .child(div().flex_1().child(started))
+                            .child(
+                                div()
+                                    .text_color(rgb(0x9399b2))
+                                    .child(format!("{} players", format::integer(entry.players as i64))),
+                            )
+                            .child(
+                                div()
+                                    .text_color(rgb(0x6c7086))
+                                    .child(format!("{} · {}", entry.platform.to_uppercase(), short_id)),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .px_2()
+                            .py_1()
+                            .text_xs()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .text_color(rgb(0x9399b2))
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(&focus[1])
+                            .on_mouse_down(MouseButton::Left, cx.listener({
+                                let entry = remove_entry.clone();
+                                move |this, _, _window, cx| this.remove_entry(&entry, cx)
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.remove_entry(&remove_entry, cx);
+                                }
+                            }))
+                            .child("Remove"),
+                    )
+            }))
+    }
+}
+
+impl Render for MatchImportView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let app_state = cx.global::<AppState>();
+        let spacing = app_state.settings().density.spacing();
+        let entries = app_state.match_archive.lock().unwrap().entries();
+        sync_focus_handles(&mut self.entry_focus, entries.len() * ENTRY_CONTROLS, cx);
+        let has_failures = self.items.iter().any(|item| matches!(item.status, ImportStatus::Failed(_)));
+        let fetching = self
+            .items
+            .iter()
+            .find(|item| item.status == ImportStatus::Fetching)
+            .map(|item| item.match_id.chars().take(8).collect::<String>());
+        let archive = self.render_archive(entries, cx);
+
+        div()
+            .id("match-import-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Import matches"))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child("Copy a list of match IDs or op.gg match links (one per line, or separated by commas), then paste it here. Imported matches are kept on this machine and open without a connection."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .when(!self.running, |row| {
+                        row.child(
+                            Self::button("Paste match IDs", &self.paste_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.paste_ids(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.paste_ids(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .when(!self.running && has_failures, |row| {
+                        row.child(
+                            Self::button("Retry failed", &self.retry_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.retry_failed(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.retry_failed(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .child(
+                        Self::button("Query archive", &self.query_focus)
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                window.dispatch_action(Box::new(OpenQueryConsole), cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(OpenQueryConsole), cx);
+                                }
+                            }),
+                    ),
+            )
+            .children(self.rate_limit.map(RateLimitBanner::new))
+            .when_some(self.progress.clone(), |parent, progress| parent.child(ProgressBar::new(progress)))
+            .when_some(fetching, |parent, match_id| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child(format!("Fetching match {}…", match_id)),
+                )
+            })
+            .when(!self.items.is_empty() || !self.invalid.is_empty(), |parent| parent.child(self.render_summary()))
+            .children(self.render_failures())
+            .child(archive)
+    }
+}
@@ -0,0 +1,735 @@
+use crate::api::parse_profile_reference;
+use crate::components::chart::LineChart;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::confidence;
+use crate::format;
+use crate::meta::{self, MetaSnapshot, TREND_WEEKS};
+use crate::scheduler::{self, JobKind};
+use crate::settings::{PatchMarker, PlayerRef, Settings};
+use crate::state::AppState;
+use crate::tasks::RequestGeneration;
+use crate::telemetry;
+use crate::views::modal::{self, Modal};
+use crate::views::onboarding::REGIONS;
+use crate::views::OpenPlayer;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Heroes offered on the trend chart and items tab, most picked first.
+const TREND_HEROES: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetaTab {
+    Heroes,
+    Items,
+}
+
+impl MetaTab {
+    const ALL: [MetaTab; 2] = [MetaTab::Heroes, MetaTab::Items];
+
+    fn label(&self) -> &'static str {
+        match self {
+            MetaTab::Heroes => "Heroes",
+            MetaTab::Items => "Items",
+        }
+    }
+}
+
+/// What a followed group of players picks, e.g. the top 100 of a region, counted from their
+/// matches in the local archive. The archive job keeps those matches coming in the background.
+pub struct MetaView {
+    scroll_handle: ScrollHandle,
+    snapshot: MetaSnapshot,
+    loading: bool,
+    /// When the archive job last finished as of the loaded snapshot
+    archived_at: Option<DateTime<Utc>>,
+    status: Option<Result<String, String>>,
+    requests: RequestGeneration,
+    rename_focus: FocusHandle,
+    follow_focus: FocusHandle,
+    archive_focus: FocusHandle,
+    region_focus: Vec<FocusHandle>,
+    /// Name link and unfollow button of each followed player
+    player_focus: Vec<FocusHandle>,
+    /// Hero on the trend chart and items tab; the most picked one when unset
+    selected_hero: Option<String>,
+    tab: MetaTab,
+    tab_focus: Vec<FocusHandle>,
+    trend_focus: Vec<FocusHandle>,
+    patch_focus: FocusHandle,
+    patch_remove_focus: Vec<FocusHandle>,
+}
+
+impl MetaView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let mut view = Self {
+            scroll_handle: ScrollHandle::new(),
+            snapshot: MetaSnapshot::default(),
+            loading: false,
+            archived_at: cx.global::<AppState>().scheduler.lock().unwrap().status(JobKind::ArchiveFollowed).last_run,
+            status: None,
+            requests: RequestGeneration::default(),
+            rename_focus: cx.focus_handle().tab_stop(true),
+            follow_focus: cx.focus_handle().tab_stop(true),
+            archive_focus: cx.focus_handle().tab_stop(true),
+            region_focus: Vec::new(),
+            player_focus: Vec::new(),
+            selected_hero: None,
+            tab: MetaTab::Heroes,
+            tab_focus: Vec::new(),
+            trend_focus: Vec::new(),
+            patch_focus: cx.focus_handle().tab_stop(true),
+            patch_remove_focus: Vec::new(),
+        };
+        view.refresh(cx);
+        view
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Meta".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    /// Counts the followed players' archived matches again. Reading the archive touches every
+    /// match file, so it runs in the background.
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let app_state = cx.global::<AppState>();
+        let followed = app_state.settings().followed;
+        let archive = app_state.match_archive.clone();
+        let request = self.requests.start();
+        self.loading = true;
+        cx.notify();
+
+        cx.spawn(async move |view, cx| {
+            let snapshot = cx
+                .background_executor()
+                .spawn(async move {
+                    let rows = meta::followed_rows(&archive.lock().unwrap(), &followed.players, followed.region.as_deref());
+                    MetaSnapshot::from_rows(&rows)
+                })
+                .await;
+            view.update(cx, |this, cx| {
+                if this.requests.is_current(request) {
+                    this.snapshot = snapshot;
+                    this.loading = false;
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn update_followed(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Settings)) {
+        cx.global::<AppState>().settings.lock().unwrap().update(f);
+        self.refresh(cx);
+    }
+
+    fn set_region(&mut self, region: Option<String>, cx: &mut Context<Self>) {
+        self.update_followed(cx, |s| s.followed.region = region);
+    }
+
+    fn unfollow(&mut self, player: PlayerRef, cx: &mut Context<Self>) {
+        self.update_followed(cx, |s| s.followed.players.retain(|followed| *followed != player));
+    }
+
+    /// Resolves pasted op.gg links, player IDs and names to players. Names only resolve to
+    /// players the app has come across before.
+    fn resolve(text: &str, cx: &App) -> (Vec<PlayerRef>, Vec<String>) {
+        let index = cx.global::<AppState>().player_index.lock().unwrap();
+        let mut found = Vec::new();
+        let mut unknown = Vec::new();
+        for piece in text.split([',', '\n']).map(str::trim).filter(|piece| !piece.is_empty()) {
+            if let Some((platform, player_id)) = parse_profile_reference(piece) {
+                let name = index
+                    .snapshot()
+                    .get(&format!("{}:{}", platform, player_id))
+                    .map(|player| player.unique_display_name.clone())
+                    .unwrap_or_else(|| player_id.chars().take(8).collect());
+                found.push(PlayerRef { platform, player_id, name });
+            } else if let Some(player) = index.find_by_name(piece) {
+                found.push(PlayerRef {
+                    platform: player.platform,
+                    player_id: player.player_id,
+                    name: player.unique_display_name,
+                });
+            } else {
+                unknown.push(piece.to_string());
+            }
+        }
+        (found, unknown)
+    }
+
+    fn prompt_follow(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt(
+            "Follow players",
+            "Paste op.gg profile links, player IDs or names of players you've come across, separated by commas or new lines.",
+            "Links, IDs or names",
+            move |text, _window, cx| {
+                view.update(cx, |this, cx| {
+                    telemetry::track(cx, "follow_players");
+                    let (found, unknown) = Self::resolve(&text, cx);
+                    let mut added = 0;
+                    this.update_followed(cx, |s| {
+                        for player in found {
+                            if !s.followed.is_followed(&player.platform, &player.player_id) {
+                                s.followed.players.push(player);
+                                added += 1;
+                            }
+                        }
+                    });
+                    this.status = Some(if unknown.is_empty() {
+                        Ok(format!("Following {} more players", added))
+                    } else {
+                        Err(format!(
+                            "Following {} more players. Not found, open their profile once or paste a link: {}",
+                            added,
+                            unknown.join(", ")
+                        ))
+                    });
+                    if added > 0 {
+                        scheduler::run_now(JobKind::ArchiveFollowed, cx);
+                    }
+                })
+                .ok();
+            },
+        )
+        .confirm_label("Follow");
+        modal::open(dialog, window, cx);
+    }
+
+    fn prompt_rename(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt(
+            "Name the group",
+            "e.g. \"Top 100 EU\".",
+            "Group name",
+            move |name, _window, cx| {
+                view.update(cx, |this, cx| this.update_followed(cx, |s| s.followed.name = name.clone()))
+                    .ok();
+            },
+        )
+        .initial_value(cx.global::<AppState>().settings().followed.name);
+        modal::open(dialog, window, cx);
+    }
+
+    fn select_hero(&mut self, hero: String, cx: &mut Context<Self>) {
+        self.selected_hero = Some(hero);
+        cx.notify();
+    }
+
+    /// Reads "Patch 1.4 2026-10-01" as a label and date; without a date the patch is today's.
+    fn parse_patch(text: &str) -> Option<PatchMarker> {
+        let text = text.trim();
+        let (label, date) = match text.rsplit_once(' ') {
+            Some((label, date)) => match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                Ok(date) => (label.trim(), date),
+                Err(_) => (text, Local::now().date_naive()),
+            },
+            None => (text, Local::now().date_naive()),
+        };
+        (!label.is_empty()).then(|| PatchMarker {
+            label: label.to_string(),
+            date,
+        })
+    }
+
+    fn prompt_patch(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt(
+            "Mark a patch",
+            "Name the update and the day it landed, e.g. \"Patch 1.4 2026-10-01\". Leave out the date for today. Once it lands, ranks and stats cached before it are fetched again.",
+            "Patch name and date",
+            move |text, _window, cx| {
+                let Some(patch) = Self::parse_patch(&text) else {
+                    return;
+                };
+                view.update(cx, |_, cx| {
+                    cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+                        s.patches.push(patch);
+                        s.patches.sort_by_key(|patch| patch.date);
+                    });
+                    cx.notify();
+                })
+                .ok();
+            },
+        )
+        .confirm_label("Mark");
+        modal::open(dialog, window, cx);
+    }
+
+    fn remove_patch(&mut self, patch: PatchMarker, cx: &mut Context<Self>) {
+        cx.global::<AppState>()
+            .settings
+            .lock()
+            .unwrap()
+            .update(|s| s.patches.retain(|marked| *marked != patch));
+        cx.notify();
+    }
+
+    fn button(label: impl Into<SharedString>, selected: bool, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_sm()
+            .rounded_md()
+            .cursor_pointer()
+            .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+            .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label.into())
+    }
+
+    fn render_heroes(&self, min_games: usize) -> Div {
+        let cell = |width: f32| div().w(px(width)).flex_shrink_0();
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .text_sm()
+            .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child("Heroes"))
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child(format!(
+                "{} games by followed players across {} matches. Greyed out: fewer than {} games.",
+                format::integer(self.snapshot.games as i64),
+                format::integer(self.snapshot.matches as i64),
+                min_games
+            )))
+            .child(
+                div()
+                    .flex()
+                    .gap_3()
+                    .px_3()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child(div().flex_1().child("Hero"))
+                    .child(cell(60.0).child("Games"))
+                    .child(cell(70.0).child("Pick rate"))
+                    .child(cell(130.0).child("Win rate"))
+                    .child(cell(70.0).child("Avg place")),
+            )
+            .children(self.snapshot.heroes.iter().map(|hero| {
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .px_3()
+                    .py_2()
+                    .bg(rgb(0x181825))
+                    .rounded_md()
+                    .when(hero.games < min_games, |row| row.text_color(rgb(0x6c7086)))
+                    .child(div().flex_1().min_w_0().truncate().font_weight(FontWeight::MEDIUM).child(hero.hero.clone()))
+                    .child(cell(60.0).child(format::integer(hero.games as i64)))
+                    .child(cell(70.0).child(format!("{}%", format::decimal(self.snapshot.pick_rate(hero) * 100.0, 1))))
+                    .child(cell(130.0).child(confidence::rate_with_interval(hero.wins, hero.games)))
+                    .child(cell(70.0).child(format!("#{}", format::decimal(hero.avg_placement, 1))))
+            }))
+    }
+
+    /// The chosen hero while it still has games, else the most picked one.
+    fn current_hero(&self) -> String {
+        self.selected_hero
+            .clone()
+            .filter(|hero| self.snapshot.heroes.iter().any(|meta| meta.hero == *hero))
+            .or_else(|| self.snapshot.heroes.first().map(|meta| meta.hero.clone()))
+            .unwrap_or_default()
+    }
+
+    fn set_tab(&mut self, tab: MetaTab, cx: &mut Context<Self>) {
+        self.tab = tab;
+        cx.notify();
+    }
+
+    fn render_tabs(&mut self, cx: &mut Context<Self>) -> Div {
+        sync_focus_handles(&mut self.tab_focus, MetaTab::ALL.len(), cx);
+        div()
+            .flex()
+            .gap_2()
+            .children(MetaTab::ALL.into_iter().zip(&self.tab_focus).map(|(tab, focus)| {
+                Self::button(tab.label(), self.tab == tab, focus)
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.set_tab(tab, cx)))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.set_tab(tab, cx);
+                        }
+                    }))
+            }))
+    }
+
+    /// What the chosen hero's players end their games with.
+    fn render_items(&self, min_games: usize) -> Div {
+        let hero = self.current_hero();
+        let hero_games = self
+            .snapshot
+            .heroes
+            .iter()
+            .find(|meta| meta.hero == hero)
+            .map(|meta| meta.games)
+            .unwrap_or(0);
+        let items = self.snapshot.items.get(&hero).cloned().unwrap_or_default();
+        let cell = |width: f32| div().w(px(width)).flex_shrink_0();
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .text_sm()
+            .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child(format!("Items on {}", hero)))
+            .child(div().text_color(rgb(0x9399b2)).child(format!(
+                "Loadouts at the end of {} games; pick another hero under Trends. Greyed out: fewer than {} games.",
+                format::integer(hero_games as i64),
+                min_games
+            )))
+            .when(items.is_empty(), |parent| {
+                parent.child(div().text_color(rgb(0x9399b2)).child("No loadouts archived for this hero."))
+            })
+            .when(!items.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .flex()
+                        .gap_3()
+                        .px_3()
+                        .text_xs()
+                        .text_color(rgb(0x6c7086))
+                        .child(div().flex_1().child("Item"))
+                        .child(cell(60.0).child("Games"))
+                        .child(cell(70.0).child("Taken"))
+                        .child(cell(130.0).child("Win rate")),
+                )
+            })
+            .children(items.into_iter().map(|item| {
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .px_3()
+                    .py_2()
+                    .bg(rgb(0x181825))
+                    .rounded_md()
+                    .when(item.games < min_games, |row| row.text_color(rgb(0x6c7086)))
+                    .child(div().flex_1().min_w_0().truncate().child(item.item.clone()))
+                    .child(cell(60.0).child(format::integer(item.games as i64)))
+                    .child(cell(70.0).child(format!(
+                        "{}%",
+                        format::decimal(item.games as f64 / hero_games.max(1) as f64 * 100.0, 0)
+                    )))
+                    .child(cell(130.0).child(confidence::rate_with_interval(item.wins, item.games)))
+            }))
+    }
+
+    /// Weekly pick and win rate of one hero, with the marked patches that fall in the range.
+    fn render_trends(&mut self, patches: &[PatchMarker], cx: &mut Context<Self>) -> Div {
+        let heroes: Vec<String> = self.snapshot.heroes.iter().take(TREND_HEROES).map(|hero| hero.hero.clone()).collect();
+        sync_focus_handles(&mut self.trend_focus, heroes.len(), cx);
+        let hero = self.current_hero();
+        let trend = self.snapshot.hero_trend(&hero);
+        let weeks: Vec<NaiveDate> = trend.iter().map(|week| week.start).collect();
+        // A patch is marked on the first charted week on or after the week it landed in
+        let shown_patches: Vec<(usize, PatchMarker)> = patches
+            .iter()
+            .filter_map(|patch| {
+                let week = meta::week_start(patch.date);
+                weeks.iter().position(|start| *start >= week).map(|idx| (idx, patch.clone()))
+            })
+            .filter(|(_, patch)| weeks.first().is_some_and(|first| meta::week_start(patch.date) >= *first))
+            .collect();
+        sync_focus_handles(&mut self.patch_remove_focus, shown_patches.len(), cx);
+        let markers: Vec<usize> = shown_patches.iter().map(|(idx, _)| *idx).collect();
+
+        let chips = heroes.iter().zip(&self.trend_focus).map(|(name, focus)| {
+            let (click, key) = (name.clone(), name.clone());
+            Self::button(name.clone(), *name == hero, focus)
+                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.select_hero(click.clone(), cx)))
+                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                    if is_activation_key(event) {
+                        this.select_hero(key.clone(), cx);
+                    }
+                }))
+        });
+        let chart = |title: &'static str, values: Vec<f64>, color: Rgba| {
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .flex_1()
+                .child(div().text_sm().text_color(rgb(0x9399b2)).child(title))
+                .child(LineChart::new(values).color(color).markers(markers.clone()))
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .text_sm()
+            .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child("Trends"))
+            .child(div().text_color(rgb(0x9399b2)).child(format!(
+                "Week by week over the last {} weeks with games. Dashed lines mark patches.",
+                TREND_WEEKS
+            )))
+            .child(div().flex().flex_wrap().gap_2().children(chips))
+            .child(if trend.len() < 2 {
+                div()
+                    .text_color(rgb(0x9399b2))
+                    .child("Needs archived games from at least two weeks.")
+            } else {
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex()
+                            .gap_4()
+                            .child(chart("Pick rate %", trend.iter().map(|week| week.pick_rate * 100.0).collect(), rgb(0x89b4fa)))
+                            .child(chart("Win rate %", trend.iter().map(|week| week.win_rate * 100.0).collect(), rgb(0xa6e3a1))),
+                    )
+                    .child(div().text_xs().text_color(rgb(0x6c7086)).child(format!(
+                        "Weeks of {} to {} · {} games on {}",
+                        format::date(weeks[0]),
+                        format::date(weeks[weeks.len() - 1]),
+                        trend.iter().map(|week| week.games).sum::<usize>(),
+                        hero
+                    )))
+            })
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .items_center()
+                    .gap_2()
+                    .children(shown_patches.into_iter().zip(&self.patch_remove_focus).map(|((_, patch), focus)| {
+                        let (click, key) = (patch.clone(), patch.clone());
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .px_2()
+                            .py_1()
+                            .bg(rgb(0x181825))
+                            .rounded_md()
+                            .child(div().text_color(rgb(0xf9e2af)).child("┆"))
+                            .child(format!("{} · {}", patch.label, format::date(patch.date)))
+                            .child(
+                                div()
+                                    .px_1()
+                                    .rounded_sm()
+                                    .cursor_pointer()
+                                    .text_color(rgb(0x6c7086))
+                                    .hover(|s| s.bg(rgb(0x313244)))
+                                    .focus_ring(focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.remove_patch(click.clone(), cx)))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.remove_patch(key.clone(), cx);
+                                        }
+                                    }))
+                                    .child("×"),
+                            )
+                    }))
+                    .child(
+                        Self::button("Mark a patch", false, &self.patch_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.prompt_patch(window, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.prompt_patch(window, cx);
+                                }
+                            })),
+                    ),
+            )
+    }
+}
+
+impl Render for MetaView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let app_state = cx.global::<AppState>();
+        let settings = app_state.settings();
+        let spacing = settings.density.spacing();
+        let job = app_state.scheduler.lock().unwrap().status(JobKind::ArchiveFollowed);
+        // The archive job brought in matches since the snapshot was counted
+        if job.last_run != self.archived_at && !job.running {
+            self.archived_at = job.last_run;
+            self.refresh(cx);
+        }
+        let followed = settings.followed;
+        sync_focus_handles(&mut self.region_focus, REGIONS.len() + 1, cx);
+        sync_focus_handles(&mut self.player_focus, followed.players.len() * 2, cx);
+        let name = if followed.name.is_empty() { "Followed players".to_string() } else { followed.name.clone() };
+
+        let regions = std::iter::once((None, "All regions"))
+            .chain(REGIONS.iter().map(|(code, label)| (Some(code.to_string()), *label)))
+            .zip(&self.region_focus)
+            .map(|((code, label), focus)| {
+                let key_code = code.clone();
+                Self::button(label, followed.region == code, focus)
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.set_region(code.clone(), cx)))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.set_region(key_code.clone(), cx);
+                        }
+                    }))
+            })
+            .collect::<Vec<_>>();
+
+        let players = followed
+            .players
+            .iter()
+            .zip(self.player_focus.chunks(2))
+            .map(|(player, focus)| {
+                let action = OpenPlayer {
+                    platform: player.platform.clone(),
+                    player_id: player.player_id.clone(),
+                };
+                let key_action = action.clone();
+                let unfollow = player.clone();
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .bg(rgb(0x181825))
+                    .rounded_md()
+                    .child(
+                        div()
+                            .cursor_pointer()
+                            .hover(|s| s.text_color(rgb(0x89b4fa)))
+                            .focus_ring(&focus[0])
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                window.dispatch_action(Box::new(action.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_action.clone()), cx);
+                                }
+                            })
+                            .child(player.name.clone()),
+                    )
+                    .child(
+                        div()
+                            .px_1()
+                            .rounded_sm()
+                            .cursor_pointer()
+                            .text_color(rgb(0x6c7086))
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(&focus[1])
+                            .on_mouse_down(MouseButton::Left, {
+                                let unfollow = unfollow.clone();
+                                cx.listener(move |this, _, _window, cx| this.unfollow(unfollow.clone(), cx))
+                            })
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.unfollow(unfollow.clone(), cx);
+                                }
+                            }))
+                            .child("×"),
+                    )
+            })
+            .collect::<Vec<_>>();
+
+        let job_line = if job.running {
+            Some(Ok("Archiving their latest matches…".to_string()))
+        } else {
+            job.last_result.clone()
+        };
+
+        div()
+            .id("meta-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Meta"))
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child(
+                "Follow a group of players, e.g. a leaderboard's top 100 in your region, and see what they pick. Their matches are archived in the background, so everything here comes from local data.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_xl()
+                            .font_weight(FontWeight::BOLD)
+                            .child(format!("{} · {} players", name, followed.players.len())),
+                    )
+                    .child(
+                        Self::button("Follow players", false, &self.follow_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.prompt_follow(window, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.prompt_follow(window, cx);
+                                }
+                            })),
+                    )
+                    .child(
+                        Self::button(if job.running { "Archiving…" } else { "Archive now" }, false, &self.archive_focus)
+                            .on_mouse_down(MouseButton::Left, |_, _window, cx| scheduler::run_now(JobKind::ArchiveFollowed, cx))
+                            .on_key_down(|event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    scheduler::run_now(JobKind::ArchiveFollowed, cx);
+                                }
+                            }),
+                    )
+                    .child(
+                        Self::button("Rename", false, &self.rename_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.prompt_rename(window, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.prompt_rename(window, cx);
+                                }
+                            })),
+                    ),
+            )
+            .child(div().flex().flex_wrap().gap_2().children(regions))
+            .when_some(self.status.clone().or(job_line), |parent, status| {
+                let (text, color) = match status {
+                    Ok(text) => (text, rgb(0x9399b2)),
+                    Err(text) => (text, rgb(0xf38ba8)),
+                };
+                parent.child(div().text_sm().text_color(color).child(text))
+            })
+            .when(followed.players.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child("Nobody followed yet. Paste the profile links of the players you want to learn from."),
+                )
+            })
+            .when(!players.is_empty(), |parent| parent.child(div().flex().flex_wrap().gap_2().children(players)))
+            .when(self.loading, |parent| {
+                parent.child(div().text_sm().text_color(rgb(0x9399b2)).child("Counting archived matches…"))
+            })
+            .when(!self.loading && !followed.players.is_empty() && self.snapshot.heroes.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child("No archived matches of followed players yet. Run the archive job or wait for its next run."),
+                )
+            })
+            .when(!self.snapshot.heroes.is_empty(), |parent| {
+                let trends = self.render_trends(&settings.patches, cx);
+                // Tabs only once loadouts show up; until then the hero table is all there is
+                let has_items = !self.snapshot.items.is_empty();
+                let tabs = has_items.then(|| self.render_tabs(cx));
+                let table = if has_items && self.tab == MetaTab::Items {
+                    self.render_items(settings.min_sample_games)
+                } else {
+                    self.render_heroes(settings.min_sample_games)
+                };
+                parent.child(trends).children(tabs).child(table)
+            })
+    }
+}
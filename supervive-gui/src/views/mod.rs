@@ -1,9 +1,53 @@
+use crate::settings::PlayerRef;
+use gpui::prelude::*;
 use gpui::*;
 
+pub mod compare;
+pub mod jobs;
+pub mod lobby;
+pub mod logs;
 pub mod match_detail;
+pub mod match_import;
+pub mod meta;
+pub mod modal;
+pub mod onboarding;
+pub mod overlay;
 pub mod player;
+pub mod query_console;
+pub mod quick_search;
+pub mod reviews;
 pub mod root;
+pub mod scout;
 pub mod search;
+pub mod series;
+pub mod settings;
+
+/// Implements `Action` for a serde-backed action struct under the given name.
+macro_rules! impl_action {
+    ($ty:ident) => {
+        impl Action for $ty {
+            fn name(&self) -> &'static str {
+                stringify!($ty)
+            }
+            fn name_for_type() -> &'static str {
+                stringify!($ty)
+            }
+            fn build(value: serde_json::Value) -> anyhow::Result<Box<dyn Action>> {
+                let action: Self = serde_json::from_value(value)?;
+                Ok(Box::new(action))
+            }
+            fn boxed_clone(&self) -> Box<dyn Action> {
+                Box::new(self.clone())
+            }
+            fn partial_eq(&self, other: &dyn Action) -> bool {
+                other
+                    .as_any()
+                    .downcast_ref::<Self>()
+                    .map_or(false, |a| self == a)
+            }
+        }
+    };
+}
 
 #[derive(Clone, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
 pub struct OpenPlayer {
@@ -11,27 +55,7 @@ pub struct OpenPlayer {
     pub platform: String,
 }
 
-impl Action for OpenPlayer {
-    fn name(&self) -> &'static str {
-        "OpenPlayer"
-    }
-    fn name_for_type() -> &'static str {
-        "OpenPlayer"
-    }
-    fn build(value: serde_json::Value) -> anyhow::Result<Box<dyn Action>> {
-        let action: Self = serde_json::from_value(value)?;
-        Ok(Box::new(action))
-    }
-    fn boxed_clone(&self) -> Box<dyn Action> {
-        Box::new(self.clone())
-    }
-    fn partial_eq(&self, other: &dyn Action) -> bool {
-        other
-            .as_any()
-            .downcast_ref::<Self>()
-            .map_or(false, |a| self == a)
-    }
-}
+impl_action!(OpenPlayer);
 
 #[derive(Clone, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
 pub struct OpenMatch {
@@ -39,24 +63,125 @@ pub struct OpenMatch {
     pub platform: String,
 }
 
-impl Action for OpenMatch {
-    fn name(&self) -> &'static str {
-        "OpenMatch"
-    }
-    fn name_for_type() -> &'static str {
-        "OpenMatch"
-    }
-    fn build(value: serde_json::Value) -> anyhow::Result<Box<dyn Action>> {
-        let action: Self = serde_json::from_value(value)?;
-        Ok(Box::new(action))
-    }
-    fn boxed_clone(&self) -> Box<dyn Action> {
-        Box::new(self.clone())
-    }
-    fn partial_eq(&self, other: &dyn Action) -> bool {
-        other
-            .as_any()
-            .downcast_ref::<Self>()
-            .map_or(false, |a| self == a)
+impl_action!(OpenMatch);
+
+/// Returns to the search page and searches for `query`.
+#[derive(Clone, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SearchPlayer {
+    pub query: String,
+}
+
+impl_action!(SearchPlayer);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct GoBack;
+
+impl_action!(GoBack);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct FocusNext;
+
+impl_action!(FocusNext);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct FocusPrevious;
+
+impl_action!(FocusPrevious);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenSettings;
+
+impl_action!(OpenSettings);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct GoHome;
+
+impl_action!(GoHome);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct Refresh;
+
+impl_action!(Refresh);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenJobs;
+
+impl_action!(OpenJobs);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenLogs;
+
+impl_action!(OpenLogs);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenReviews;
+
+impl_action!(OpenReviews);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenLobby;
+
+impl_action!(OpenLobby);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenScout;
+
+impl_action!(OpenScout);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenMatchImport;
+
+impl_action!(OpenMatchImport);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenQueryConsole;
+
+impl_action!(OpenQueryConsole);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenSeries;
+
+impl_action!(OpenSeries);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenMeta;
+
+impl_action!(OpenMeta);
+
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ToggleOverlay;
+
+impl_action!(ToggleOverlay);
+
+/// Opens the header search box from any page.
+#[derive(Clone, PartialEq, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OpenQuickSearch;
+
+impl_action!(OpenQuickSearch);
+
+/// Opens the side-by-side comparison of two players.
+#[derive(Clone, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
+pub struct OpenCompare {
+    pub left: PlayerRef,
+    pub right: PlayerRef,
+}
+
+impl_action!(OpenCompare);
+
+/// Payload while a player (search result, watched player, linked account) is dragged; dropping it
+/// on an open profile compares the two.
+#[derive(Clone)]
+pub struct DraggedPlayer(pub PlayerRef);
+
+impl Render for DraggedPlayer {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .bg(rgb(0x89b4fa))
+            .text_color(rgb(0x1e1e2e))
+            .text_sm()
+            .child(format!("Compare with {}", self.0.name))
     }
 }
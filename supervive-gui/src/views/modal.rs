@@ -0,0 +1,248 @@
+use crate::components::focus::{is_activation_key, FocusRingExt};
+use crate::views::root::RootView;
+use gpui::prelude::*;
+use gpui::*;
+use std::rc::Rc;
+
+/// Focus slots of a dialog: the body (which takes typing), cancel and confirm.
+pub const MODAL_FOCUS_SLOTS: usize = 3;
+
+type SubmitHandler = Rc<dyn Fn(String, &mut Window, &mut App)>;
+
+enum ModalKind {
+    Confirm,
+    /// Asks for one line of text, e.g. a webhook URL
+    Prompt { value: String, placeholder: SharedString },
+}
+
+/// A dialog drawn over the whole window by `RootView`, opened with [`open`]. Only one is shown
+/// at a time; opening another replaces it.
+pub struct Modal {
+    title: SharedString,
+    message: SharedString,
+    confirm_label: SharedString,
+    /// Colors the confirm button red for actions that can't be undone
+    destructive: bool,
+    kind: ModalKind,
+    on_submit: SubmitHandler,
+}
+
+impl Modal {
+    pub fn confirm(
+        title: impl Into<SharedString>,
+        message: impl Into<SharedString>,
+        on_confirm: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "Confirm".into(),
+            destructive: false,
+            kind: ModalKind::Confirm,
+            on_submit: Rc::new(move |_, window, cx| on_confirm(window, cx)),
+        }
+    }
+
+    /// A text prompt; `on_submit` receives the trimmed, non-empty input.
+    pub fn prompt(
+        title: impl Into<SharedString>,
+        message: impl Into<SharedString>,
+        placeholder: impl Into<SharedString>,
+        on_submit: impl Fn(String, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "Save".into(),
+            destructive: false,
+            kind: ModalKind::Prompt {
+                value: String::new(),
+                placeholder: placeholder.into(),
+            },
+            on_submit: Rc::new(on_submit),
+        }
+    }
+
+    pub fn confirm_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    pub fn destructive(mut self) -> Self {
+        self.destructive = true;
+        self
+    }
+
+    /// Pre-fills a prompt, e.g. with the value being edited.
+    pub fn initial_value(mut self, initial: impl Into<String>) -> Self {
+        if let ModalKind::Prompt { value, .. } = &mut self.kind {
+            *value = initial.into();
+        }
+        self
+    }
+
+    /// The value passed to the submit handler, or `None` while a prompt is empty.
+    fn submission(&self) -> Option<String> {
+        match &self.kind {
+            ModalKind::Confirm => Some(String::new()),
+            ModalKind::Prompt { value, .. } => Some(value.trim().to_string()).filter(|value| !value.is_empty()),
+        }
+    }
+
+    /// Runs the submit handler; returns false when there is nothing to submit yet.
+    pub fn submit(&self, window: &mut Window, cx: &mut App) -> bool {
+        match self.submission() {
+            Some(value) => {
+                (self.on_submit)(value, window, cx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies a key press to the prompt's text; returns whether it changed.
+    pub fn edit(&mut self, key: &str) -> bool {
+        let ModalKind::Prompt { value, .. } = &mut self.kind else {
+            return false;
+        };
+        match key {
+            "backspace" => value.pop().is_some(),
+            "space" => {
+                value.push(' ');
+                true
+            }
+            key if key.chars().count() == 1 => {
+                value.push_str(key);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn render(&self, focus: &[FocusHandle], window: &Window, cx: &mut Context<RootView>) -> impl IntoElement {
+        let body_focused = focus[0].is_focused(window);
+        let can_submit = self.submission().is_some();
+
+        div()
+            .absolute()
+            .inset_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(hsla(0.0, 0.0, 0.0, 0.6))
+            .occlude()
+            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.close_modal(cx)))
+            .child(
+                div()
+                    .w(px(440.0))
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .p_5()
+                    .bg(rgb(0x181825))
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(rgb(0x45475a))
+                    .on_mouse_down(MouseButton::Left, |_, _window, cx| cx.stop_propagation())
+                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                        if event.keystroke.key == "escape" {
+                            this.close_modal(cx);
+                        }
+                    }))
+                    .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child(self.title.clone()))
+                    .child(
+                        div()
+                            .track_focus(&focus[0])
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if event.keystroke.key == "enter" {
+                                    this.submit_modal(window, cx);
+                                } else {
+                                    this.edit_modal(&event.keystroke.key, cx);
+                                }
+                            }))
+                            .child(div().text_sm().text_color(rgb(0xbac2de)).child(self.message.clone()))
+                            .when_some(
+                                match &self.kind {
+                                    ModalKind::Prompt { value, placeholder } => Some((value.clone(), placeholder.clone())),
+                                    ModalKind::Confirm => None,
+                                },
+                                |body, (value, placeholder)| {
+                                    body.child(
+                                        div()
+                                            .p_2()
+                                            .bg(rgb(0x313244))
+                                            .rounded_md()
+                                            .border_1()
+                                            .border_color(if body_focused { rgb(0xf9e2af) } else { rgb(0x45475a) })
+                                            .cursor_text()
+                                            .on_mouse_down(MouseButton::Left, {
+                                                let focus = focus[0].clone();
+                                                move |_, window, _cx| window.focus(&focus)
+                                            })
+                                            .map(|input| {
+                                                if value.is_empty() && !body_focused {
+                                                    input.text_color(rgb(0x6c7086)).child(placeholder)
+                                                } else if body_focused {
+                                                    input.child(format!("{}|", value))
+                                                } else {
+                                                    input.child(value)
+                                                }
+                                            }),
+                                    )
+                                },
+                            ),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .justify_end()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .px_3()
+                                    .py_1()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .bg(rgb(0x313244))
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(&focus[1])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.close_modal(cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.close_modal(cx);
+                                        }
+                                    }))
+                                    .child("Cancel"),
+                            )
+                            .child(
+                                div()
+                                    .px_3()
+                                    .py_1()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .bg(if self.destructive { rgb(0xf38ba8) } else { rgb(0x89b4fa) })
+                                    .text_color(rgb(0x1e1e2e))
+                                    .when(!can_submit, |button| button.opacity(0.5))
+                                    .focus_ring(&focus[2])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.submit_modal(window, cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.submit_modal(window, cx);
+                                        }
+                                    }))
+                                    .child(self.confirm_label.clone()),
+                            ),
+                    ),
+            )
+    }
+}
+
+/// Shows `modal` over the window's root view, replacing any open dialog.
+pub fn open(modal: Modal, window: &mut Window, cx: &mut App) {
+    if let Some(root) = window.root::<RootView>().flatten() {
+        root.update(cx, |root, cx| root.open_modal(modal, window, cx));
+    }
+}
@@ -0,0 +1,497 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::demo;
+use crate::scheduler::JobKind;
+use crate::settings::{PlayerRef, Settings};
+use crate::state::AppState;
+use crate::telemetry;
+use crate::theme::Palette;
+use crate::views::root::RootView;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+
+/// Platforms players can be searched on, as `(id, label)`.
+pub const PLATFORMS: [(&str, &str); 2] = [("steam", "Steam"), ("epic", "Epic Games")];
+/// Server regions as reported on match rows, as `(code, label)`.
+pub const REGIONS: [(&str, &str); 5] = [
+    ("na", "North America"),
+    ("eu", "Europe"),
+    ("asia", "Asia"),
+    ("oce", "Oceania"),
+    ("sa", "South America"),
+];
+/// Scheduled jobs that call the API in the background; the polling opt-in toggles them together.
+const POLLING_JOBS: [JobKind; 4] = [
+    JobKind::PollWatched,
+    JobKind::BackfillWatched,
+    JobKind::WarmWatched,
+    JobKind::ArchiveFollowed,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Step {
+    Region,
+    Account,
+    Theme,
+    Polling,
+}
+
+impl Step {
+    const ALL: [Step; 4] = [Step::Region, Step::Account, Step::Theme, Step::Polling];
+
+    fn title(&self) -> &'static str {
+        match self {
+            Step::Region => "Where do you play?",
+            Step::Account => "Link your account",
+            Step::Theme => "Pick a color theme",
+            Step::Polling => "Background updates",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0)
+    }
+}
+
+/// First-launch wizard shown in place of the home page until it is finished or skipped.
+pub struct OnboardingView {
+    root: WeakEntity<RootView>,
+    step: Step,
+    query: String,
+    results: Vec<Value>,
+    searching: bool,
+    search_error: Option<String>,
+    input_focus: FocusHandle,
+    // Step-specific choices
+    option_focus: Vec<FocusHandle>,
+    // Skip, demo, back, next
+    nav_focus: Vec<FocusHandle>,
+}
+
+impl OnboardingView {
+    pub fn new(root: WeakEntity<RootView>, cx: &mut Context<Self>) -> Self {
+        Self {
+            root,
+            step: Step::Region,
+            query: String::new(),
+            results: Vec::new(),
+            searching: false,
+            search_error: None,
+            input_focus: cx.focus_handle().tab_stop(true),
+            option_focus: Vec::new(),
+            nav_focus: Vec::new(),
+        }
+    }
+
+    fn update_settings(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Settings)) {
+        cx.global::<AppState>().settings.lock().unwrap().update(f);
+        cx.refresh_windows();
+    }
+
+    fn go_to(&mut self, step: Step, cx: &mut Context<Self>) {
+        self.step = step;
+        cx.notify();
+    }
+
+    fn next(&mut self, cx: &mut Context<Self>) {
+        match Step::ALL.get(self.step.index() + 1) {
+            Some(step) => self.go_to(*step, cx),
+            None => self.finish(cx),
+        }
+    }
+
+    fn back(&mut self, cx: &mut Context<Self>) {
+        if let Some(index) = self.step.index().checked_sub(1) {
+            self.go_to(Step::ALL[index], cx);
+        }
+    }
+
+    /// Marks onboarding done, whether finished or skipped; choices made so far are kept.
+    fn finish(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "onboarding_finished");
+        self.update_settings(cx, |s| s.onboarding_done = true);
+        self.root.update(cx, |root, cx| root.finish_onboarding(cx)).ok();
+    }
+
+    fn search(&mut self, cx: &mut Context<Self>) {
+        let query = self.query.trim().to_string();
+        if query.is_empty() {
+            return;
+        }
+        let service = cx.global::<AppState>().service.clone();
+        let platform = cx.global::<AppState>().settings().preferred_platform;
+        self.searching = true;
+        self.search_error = None;
+        cx.spawn(async move |view, cx| {
+            let result = cx.background_executor().spawn(async move {
+                service.lock().unwrap().search_players(&query)
+            }).await;
+            view.update(cx, |this, cx| {
+                this.searching = false;
+                match result {
+                    Ok(data) => {
+                        this.results = data
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter(|player| player["platform"].as_str().unwrap_or("steam") == platform)
+                            .cloned()
+                            .collect();
+                        if this.results.is_empty() {
+                            this.search_error = Some(format!("No players named '{}' on this platform", this.query.trim()));
+                        }
+                    }
+                    Err(err) => this.search_error = Some(format!("Search failed: {}", err)),
+                }
+                cx.notify();
+            }).ok();
+        }).detach();
+        cx.notify();
+    }
+
+    fn link(&mut self, player: &Value, cx: &mut Context<Self>) {
+        let Some(player_id) = player["userId"].as_str() else {
+            return;
+        };
+        let account = PlayerRef {
+            platform: player["platform"].as_str().unwrap_or("steam").to_string(),
+            player_id: player_id.to_string(),
+            name: player["uniqueDisplayName"]
+                .as_str()
+                .or_else(|| player["displayName"].as_str())
+                .unwrap_or(player_id)
+                .to_string(),
+        };
+        self.update_settings(cx, |s| s.link_account(account));
+        self.next(cx);
+    }
+
+    fn set_polling(&mut self, enabled: bool, cx: &mut Context<Self>) {
+        self.update_settings(cx, |s| {
+            for job in POLLING_JOBS {
+                s.job_intervals.insert(job, if enabled { job.default_interval() } else { 0 });
+            }
+        });
+    }
+
+    fn chip(label: impl Into<SharedString>, selected: bool, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+            .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label.into())
+    }
+
+    /// Number of step-specific focusable controls, so focus handles can be synced before rendering.
+    fn option_count(&self) -> usize {
+        match self.step {
+            Step::Region => PLATFORMS.len() + REGIONS.len(),
+            Step::Account => 1 + self.results.len(),
+            Step::Theme => Palette::ALL.len(),
+            Step::Polling => 2,
+        }
+    }
+
+    fn render_region(&self, settings: &Settings, cx: &mut Context<Self>) -> Div {
+        let focus = &self.option_focus;
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child("Search results on your platform are listed first."))
+            .child(div().flex().gap_2().children(PLATFORMS.iter().enumerate().map(|(idx, (id, label))| {
+                let id = *id;
+                Self::chip(*label, settings.preferred_platform == id, &focus[idx])
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                        this.update_settings(cx, |s| s.preferred_platform = id.to_string());
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.update_settings(cx, |s| s.preferred_platform = id.to_string());
+                        }
+                    }))
+            })))
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child("Players from other regions are dimmed in search results."))
+            .child(div().flex().flex_wrap().gap_2().children(REGIONS.iter().enumerate().map(|(idx, (code, label))| {
+                let code = *code;
+                let selected = settings.preferred_region.as_deref() == Some(code);
+                // Clicking the selected region again clears the preference
+                let toggle = move |s: &mut Settings| {
+                    s.preferred_region = if selected { None } else { Some(code.to_string()) };
+                };
+                Self::chip(*label, selected, &focus[PLATFORMS.len() + idx])
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                        this.update_settings(cx, toggle);
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.update_settings(cx, toggle);
+                        }
+                    }))
+            })))
+    }
+
+    fn render_account(&self, settings: &Settings, window: &mut Window, cx: &mut Context<Self>) -> Div {
+        let input_focused = self.input_focus.is_focused(window);
+        let focus = &self.option_focus;
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child("Your account gets a header shortcut, a weekly report and goal tracking on the home page."),
+            )
+            .when_some(settings.my_account.clone(), |parent, account| {
+                parent.child(div().text_sm().text_color(rgb(0xa6e3a1)).child(format!("Linked: {}", account.name)))
+            })
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .p_2()
+                            .bg(rgb(0x313244))
+                            .rounded_md()
+                            .border_1()
+                            .border_color(if input_focused { rgb(0xf9e2af) } else { rgb(0x45475a) })
+                            .cursor_text()
+                            .track_focus(&self.input_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                window.focus(&this.input_focus);
+                                cx.notify();
+                            }))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                match event.keystroke.key.as_str() {
+                                    "backspace" => {
+                                        this.query.pop();
+                                        cx.notify();
+                                    }
+                                    "enter" => this.search(cx),
+                                    key if key.len() == 1 => {
+                                        this.query.push_str(key);
+                                        cx.notify();
+                                    }
+                                    _ => {}
+                                }
+                            }))
+                            .child(if self.query.is_empty() && !input_focused {
+                                "Your in-game name...".to_string()
+                            } else if input_focused {
+                                format!("{}|", self.query)
+                            } else {
+                                self.query.clone()
+                            }),
+                    )
+                    .child(
+                        Self::chip(if self.searching { "Searching..." } else { "Search" }, false, &focus[0])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.search(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.search(cx);
+                                }
+                            })),
+                    ),
+            )
+            .when_some(self.search_error.clone(), |parent, error| {
+                parent.child(div().text_sm().text_color(rgb(0xf38ba8)).child(error))
+            })
+            .children(self.results.iter().enumerate().map(|(idx, player)| {
+                let name = player["uniqueDisplayName"]
+                    .as_str()
+                    .or_else(|| player["displayName"].as_str())
+                    .unwrap_or("Unknown")
+                    .to_string();
+                let (click_player, key_player) = (player.clone(), player.clone());
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .px_3()
+                    .py_2()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .bg(rgb(0x181825))
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(&focus[1 + idx])
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                        this.link(&click_player, cx);
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.link(&key_player, cx);
+                        }
+                    }))
+                    .child(div().flex_1().child(name))
+                    .child(div().text_sm().text_color(rgb(0x89b4fa)).child("This is me"))
+            }))
+    }
+
+    fn render_theme(&self, settings: &Settings, cx: &mut Context<Self>) -> Div {
+        let focus = &self.option_focus;
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child("Colors used to rate stats as good, average or bad. You can change this later in Settings."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .children(Palette::ALL.iter().enumerate().map(|(idx, palette)| {
+                        let palette = *palette;
+                        Self::chip(palette.label(), settings.palette == palette, &focus[idx])
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.update_settings(cx, |s| s.palette = palette);
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.update_settings(cx, |s| s.palette = palette);
+                                }
+                            }))
+                    })),
+            )
+    }
+
+    fn render_polling(&self, settings: &Settings, cx: &mut Context<Self>) -> Div {
+        let focus = &self.option_focus;
+        let enabled = POLLING_JOBS.iter().any(|job| settings.job_interval(*job) > 0);
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child("While the app is open it can check watched players for new matches and fetch their history. This makes regular requests to op.gg; intervals can be tuned on the Jobs page."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .child(
+                        Self::chip("Check in the background", enabled, &focus[0])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.set_polling(true, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.set_polling(true, cx);
+                                }
+                            })),
+                    )
+                    .child(
+                        Self::chip("Only when I ask", !enabled, &focus[1])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.set_polling(false, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.set_polling(false, cx);
+                                }
+                            })),
+                    ),
+            )
+    }
+}
+
+impl Render for OnboardingView {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let settings = cx.global::<AppState>().settings();
+        let option_count = self.option_count();
+        sync_focus_handles(&mut self.option_focus, option_count, cx);
+        sync_focus_handles(&mut self.nav_focus, 4, cx);
+        let nav_focus = self.nav_focus.clone();
+        let is_first = self.step.index() == 0;
+        let is_last = self.step.index() + 1 == Step::ALL.len();
+        let body = match self.step {
+            Step::Region => self.render_region(&settings, cx),
+            Step::Account => self.render_account(&settings, window, cx),
+            Step::Theme => self.render_theme(&settings, cx),
+            Step::Polling => self.render_polling(&settings, cx),
+        };
+
+        div()
+            .size_full()
+            .flex()
+            .justify_center()
+            .pt_12()
+            .child(
+                div()
+                    .w(px(560.0))
+                    .flex()
+                    .flex_col()
+                    .gap_4()
+                    .p_6()
+                    .bg(rgb(0x181825))
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(rgb(0x313244))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(rgb(0x6c7086))
+                            .child(format!("Welcome · step {} of {}", self.step.index() + 1, Step::ALL.len())),
+                    )
+                    .child(div().text_xl().font_weight(FontWeight::BOLD).child(self.step.title()))
+                    .child(body)
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .pt_2()
+                            .child(
+                                Self::chip("Skip setup", false, &nav_focus[0])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.finish(cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.finish(cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::chip("Try with sample data", false, &nav_focus[1])
+                                    .on_mouse_down(MouseButton::Left, |_, window, cx| demo::set_enabled(true, window, cx))
+                                    .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            demo::set_enabled(true, window, cx);
+                                        }
+                                    }),
+                            )
+                            .child(div().flex_1())
+                            .when(!is_first, |parent| {
+                                parent.child(
+                                    Self::chip("Back", false, &nav_focus[2])
+                                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.back(cx)))
+                                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                this.back(cx);
+                                            }
+                                        })),
+                                )
+                            })
+                            .child(
+                                Self::chip(if is_last { "Finish" } else { "Next" }, true, &nav_focus[3])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.next(cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.next(cx);
+                                        }
+                                    })),
+                            ),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,178 @@
+use crate::api::take_rows;
+use crate::components::focus::{is_activation_key, FocusRingExt};
+use crate::overlay;
+use crate::session::SessionStats;
+use crate::state::AppState;
+use crate::views::ToggleOverlay;
+use gpui::prelude::*;
+use gpui::*;
+use std::time::Duration;
+
+/// How often the overlay re-fetches the linked account's recent matches.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Compact always-on-top view of the linked account's current session.
+pub struct OverlayView {
+    stats: Option<SessionStats>,
+    /// Latest history page, for personal rating thresholds
+    rows: Vec<serde_json::Value>,
+    loading: bool,
+    error: Option<String>,
+    close_focus: FocusHandle,
+}
+
+impl OverlayView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        cx.spawn(async move |view, cx| loop {
+            if view.update(cx, |this, cx| this.refresh(cx)).is_err() {
+                break;
+            }
+            cx.background_executor().timer(REFRESH_INTERVAL).await;
+        })
+        .detach();
+
+        Self {
+            stats: None,
+            rows: Vec::new(),
+            loading: true,
+            error: None,
+            close_focus: cx.focus_handle().tab_stop(true),
+        }
+    }
+
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let app_state = cx.global::<AppState>();
+        let Some(account) = app_state.settings().my_account else {
+            self.loading = false;
+            cx.notify();
+            return;
+        };
+        let service = app_state.service.clone();
+
+        cx.spawn(async move |view, cx| {
+            let result = cx.background_executor().spawn(async move {
+                let mut service = service.lock().unwrap();
+                service.get_player_matches(&account.platform, &account.player_id, 1)
+            }).await;
+
+            view.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(data) => {
+                        let rows = take_rows(data);
+                        this.stats = Some(SessionStats::from_matches(&rows));
+                        this.rows = rows;
+                        this.error = None;
+                    }
+                    Err(err) => this.error = Some(err.to_string()),
+                }
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+}
+
+impl Render for OverlayView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let settings = cx.global::<AppState>().settings();
+        let palette = settings.palette;
+        let account_name = settings.my_account.as_ref().map(|account| account.name.clone());
+        let thresholds = settings.rating_thresholds.for_history(&self.rows);
+
+        let body = match (&account_name, &self.stats) {
+            (None, _) => div()
+                .text_sm()
+                .text_color(rgb(0x9399b2))
+                .child("Link your account from the search page to see session stats."),
+            (Some(_), None) if self.loading => div().text_sm().text_color(rgb(0x9399b2)).child("Loading…"),
+            (Some(_), None) => div()
+                .text_sm()
+                .text_color(rgb(0xf38ba8))
+                .child(self.error.clone().unwrap_or_else(|| "No data".to_string())),
+            (Some(_), Some(stats)) if stats.games == 0 => div()
+                .text_sm()
+                .text_color(rgb(0x9399b2))
+                .child("No matches this session yet."),
+            (Some(_), Some(stats)) => div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .child(
+                    div()
+                        .flex()
+                        .gap_3()
+                        .text_sm()
+                        .child(format!("{} games", stats.games))
+                        .child(
+                            div()
+                                .text_color(palette.color(thresholds.kd.rate(stats.kd_ratio())))
+                                .child(format!("K/D {:.2}", stats.kd_ratio())),
+                        )
+                        .child(
+                            div()
+                                .text_color(palette.color(thresholds.placement.rate(stats.avg_placement)))
+                                .child(format!("Avg #{:.1}", stats.avg_placement)),
+                        )
+                        .child(format!("{} wins", stats.wins)),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .gap_1()
+                        .children(stats.placements.iter().take(8).map(|placement| {
+                            div()
+                                .px_1()
+                                .rounded_sm()
+                                .bg(rgb(0x313244))
+                                .text_xs()
+                                .text_color(palette.color(thresholds.placement.rate(*placement as f64)))
+                                .child(format!("#{}", placement))
+                        })),
+                ),
+        };
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_3()
+            .bg(rgb(0x11111b))
+            .text_color(rgb(0xcdd6f4))
+            .border_1()
+            .border_color(rgb(0x313244))
+            .on_action(|_: &ToggleOverlay, window, cx| overlay::close(window, cx))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    // Frameless: the header doubles as the drag handle
+                    .on_mouse_down(MouseButton::Left, |_, window, _cx| window.start_window_move())
+                    .child(
+                        div()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .child(account_name.unwrap_or_else(|| "Session".to_string())),
+                    )
+                    .child(
+                        div()
+                            .px_1()
+                            .cursor_pointer()
+                            .text_color(rgb(0x9399b2))
+                            .hover(|s| s.text_color(rgb(0xcdd6f4)))
+                            .focus_ring(&self.close_focus)
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                cx.stop_propagation();
+                                overlay::close(window, cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    overlay::close(window, cx);
+                                }
+                            })
+                            .child("×"),
+                    ),
+            )
+            .child(body)
+    }
+}
@@ -1,19 +1,133 @@
 use gpui::*;
 use gpui::prelude::*;
+use crate::api::{is_not_found, is_private_profile, rank_of, rate_limit_of, take_rows, MatchRows, MAX_HISTORY_PAGES};
+use crate::components::activity_calendar::ActivityCalendar;
+use crate::compositions::{ally_compositions, Composition};
+use crate::confidence;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::hero_image::HeroImage;
+use crate::components::chart::{Bar, Histogram, LineChart};
+use crate::components::progress_bar::ProgressBar;
+use crate::components::rate_limit::{RateLimit, RateLimitBanner};
+use crate::components::tooltip::Tooltip;
+use crate::format;
+use crate::game_modes::{self, GameMode, ModeTotals, SummaryStat};
+use crate::goals::RankTier;
+use crate::heroes::RoleGroup;
+use crate::history_totals::HistoryTotals;
+use crate::lobby_strength::{lobby_rating, nearest_tier, LobbyDifficulty};
+use crate::markdown;
+use crate::matchups::{enemy_matchups, own_row};
+use crate::phases::death_phase;
+use crate::plugins::{MatchData, PanelElement, Plugin};
+use crate::rank_points;
+use crate::records::{self, Record};
+use crate::session::parse_time;
+use crate::scheduler::{self, JobKind};
+use crate::settings::{PanelLayout, PanelWidth, PinnedTile, PlayerRef, ProfilePanel, TileKind};
 use crate::state::AppState;
-use crate::views::OpenMatch;
+use crate::suggestions::{hero_suggestions, HeroSuggestion, FIELD_WEIGHT};
+use crate::tasks::{RequestGeneration, TaskProgress};
+use crate::teamplay::TeamplayStats;
+use crate::telemetry;
+use crate::theme::{Palette, RatingThresholds, Spacing, StatThresholds, ThresholdMode};
+use crate::trends::{ChartRange, Smoothing};
+use crate::views::{DraggedPlayer, OpenCompare, OpenMatch};
+use chrono::{Local, NaiveDate};
 use serde_json::Value;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// How long "Copied" replaces the copy button's label.
+const COPIED_FEEDBACK: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Number of focusable controls per panel in layout edit mode: up, down, width, visibility.
+const LAYOUT_CONTROLS: usize = 4;
+
+type LayoutHandler = Rc<dyn Fn(&mut PlayerView, &mut Context<PlayerView>)>;
+
+/// Payload while a profile panel is dragged in layout edit mode; also renders the drag preview.
+#[derive(Clone)]
+struct DraggedPanel(ProfilePanel);
+
+impl Render for DraggedPanel {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .bg(rgb(0x89b4fa))
+            .text_color(rgb(0x1e1e2e))
+            .text_sm()
+            .child(self.0.label())
+    }
+}
+
+/// Outcome of the first history page, used to pick between the match list and an empty state.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum HistoryState {
+    Loaded,
+    Empty,
+    Private,
+    /// op.gg answered 404: there is no such player on this platform
+    NotFound,
+    Failed,
+}
+
+/// Emitted when the first history page comes back 404, so navigation can drop the profile.
+pub struct PlayerNotFound;
 
 pub struct PlayerView {
     player_id: String,
     platform: String,
-    matches: Vec<Value>,
+    display_name: Option<String>,
+    matches: MatchRows,
     loading: bool,
-    // Statistics
-    total_kills: i64,
-    total_deaths: i64,
-    avg_placement: f64,
-    total_games: usize,
+    history_state: HistoryState,
+    pages_loaded: i64,
+    last_page: i64,
+    backfill: Option<TaskProgress>,
+    /// Bumped per first-page load so a slow response can't overwrite a newer refresh
+    history_requests: RequestGeneration,
+    /// Set while the first page waits out op.gg's rate limit
+    rate_limit: Option<RateLimit>,
+    /// Participant rows per match id, filled in by `analyze_lobbies`
+    match_details: HashMap<String, Vec<Value>>,
+    lobby_progress: Option<TaskProgress>,
+    lobby_focus: FocusHandle,
+    /// Kept up to date as pages arrive
+    totals: HistoryTotals,
+    scroll_handle: ScrollHandle,
+    action_focus: FocusHandle,
+    watch_focus: FocusHandle,
+    account_focus: FocusHandle,
+    match_focus: Vec<FocusHandle>,
+    record_focus: Vec<FocusHandle>,
+    /// Day picked on the activity calendar; narrows the match list to it
+    day_filter: Option<NaiveDate>,
+    /// Day under the pointer on the activity calendar
+    hovered_day: Option<NaiveDate>,
+    clear_filter_focus: FocusHandle,
+    /// Role picked above the match list; narrows it to heroes of that role
+    role_filter: Option<RoleGroup>,
+    /// "All", then one per `RoleGroup`
+    role_focus: Vec<FocusHandle>,
+    editing_layout: bool,
+    edit_layout_focus: FocusHandle,
+    reset_layout_focus: FocusHandle,
+    calendar_focus: FocusHandle,
+    layout_focus: Vec<FocusHandle>,
+    /// One per `TileKind`, shown in layout edit mode
+    pin_focus: Vec<FocusHandle>,
+    copy_focus: FocusHandle,
+    /// Mode the summary panel was switched to; `None` follows the most played mode
+    summary_mode: Option<GameMode>,
+    mode_focus: Vec<FocusHandle>,
+    /// Range chips of the RP chart, its smoothing chips, then the range chips of the phase chart
+    chart_focus: Vec<FocusHandle>,
+    /// Set for a moment after the summary was copied as Markdown
+    copied: bool,
 }
 
 impl PlayerView {
@@ -21,12 +135,41 @@ impl PlayerView {
         let view = Self {
             player_id: player_id.clone(),
             platform: platform.clone(),
-            matches: Vec::new(),
+            display_name: None,
+            matches: MatchRows::default(),
             loading: true,
-            total_kills: 0,
-            total_deaths: 0,
-            avg_placement: 0.0,
-            total_games: 0,
+            history_state: HistoryState::Loaded,
+            pages_loaded: 0,
+            last_page: 0,
+            backfill: None,
+            history_requests: RequestGeneration::default(),
+            rate_limit: None,
+            match_details: HashMap::new(),
+            lobby_progress: None,
+            lobby_focus: cx.focus_handle().tab_stop(true),
+            totals: HistoryTotals::default(),
+            scroll_handle: ScrollHandle::new(),
+            action_focus: cx.focus_handle().tab_stop(true),
+            watch_focus: cx.focus_handle().tab_stop(true),
+            account_focus: cx.focus_handle().tab_stop(true),
+            match_focus: Vec::new(),
+            record_focus: Vec::new(),
+            day_filter: None,
+            hovered_day: None,
+            clear_filter_focus: cx.focus_handle().tab_stop(true),
+            role_filter: None,
+            role_focus: Vec::new(),
+            editing_layout: false,
+            edit_layout_focus: cx.focus_handle().tab_stop(true),
+            reset_layout_focus: cx.focus_handle().tab_stop(true),
+            calendar_focus: cx.focus_handle().tab_stop(true),
+            layout_focus: Vec::new(),
+            pin_focus: Vec::new(),
+            copy_focus: cx.focus_handle().tab_stop(true),
+            summary_mode: None,
+            mode_focus: Vec::new(),
+            chart_focus: Vec::new(),
+            copied: false,
         };
         cx.spawn(async move |view, cx| {
             view.update(cx, |this, cx| this.fetch_data(cx)).ok();
@@ -34,24 +177,73 @@ impl PlayerView {
         view
     }
 
+    pub fn title(&self) -> SharedString {
+        match &self.display_name {
+            Some(name) => name.clone().into(),
+            None => self.player_id.chars().take(8).collect::<String>().into(),
+        }
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
     fn fetch_data(&mut self, cx: &mut Context<Self>) {
         let app_state = cx.global::<AppState>();
         let service = app_state.service.clone();
         let player_id = self.player_id.clone();
         let platform = self.platform.clone();
+        let request = self.history_requests.start();
 
         cx.spawn(async move |view, cx| {
+            // A warmed or previously fetched copy shows right away while the fresh page loads
+            let cached = cx.background_executor().spawn({
+                let (service, platform, player_id) = (service.clone(), platform.clone(), player_id.clone());
+                async move { service.lock().unwrap().cached_player_matches(&platform, &player_id, 1) }
+            }).await;
+            if let Some(data) = cached {
+                view.update(cx, |this, cx| {
+                    if this.history_requests.is_current(request) && this.loading {
+                        this.loading = false;
+                        this.apply_first_page(data, cx);
+                    }
+                }).ok();
+            }
+
             let result = cx.background_executor().spawn(async move {
-                let service = service.lock().unwrap();
+                let mut service = service.lock().unwrap();
                 service.get_player_matches(&platform, &player_id, 1)
             }).await;
             
             view.update(cx, |this, cx| {
+                if !this.history_requests.is_current(request) {
+                    return;
+                }
+                // Keep the cached copy on screen if the refresh fails
+                if result.is_err() && !this.loading && !this.matches.is_empty() {
+                    this.rate_limit = result.as_ref().err().and_then(rate_limit_of)
+                        .map(|retry_after| RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::refresh, cx));
+                    cx.notify();
+                    return;
+                }
                 this.loading = false;
-                if let Ok(data) = result {
-                    if let Some(items) = data["data"].as_array() {
-                        this.matches = items.clone();
-                        this.calculate_stats();
+                this.rate_limit = match &result {
+                    Err(err) => rate_limit_of(err)
+                        .map(|retry_after| RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::refresh, cx)),
+                    Ok(_) => None,
+                };
+                let is_private = is_private_profile(&result);
+                match result {
+                    _ if is_private => this.history_state = HistoryState::Private,
+                    Ok(data) => this.apply_first_page(data, cx),
+                    Err(err) if is_not_found(&err) => {
+                        this.history_state = HistoryState::NotFound;
+                        cx.emit(PlayerNotFound);
+                    }
+                    Err(err) => {
+                        tracing::warn!(player_id = %this.player_id, error = %err, "Failed to load matches");
+                        telemetry::track_error(cx, "match_history_failed");
+                        this.history_state = HistoryState::Failed;
                     }
                 }
                 cx.notify();
@@ -59,260 +251,1814 @@ impl PlayerView {
         }).detach();
     }
 
-    fn calculate_stats(&mut self) {
-        let mut total_kills = 0i64;
-        let mut total_deaths = 0i64;
-        let mut total_placement = 0i64;
-        let mut game_count = 0usize;
+    fn apply_first_page(&mut self, data: Value, cx: &mut Context<Self>) {
+        self.pages_loaded = 1;
+        self.last_page = data["meta"]["last_page"].as_i64().unwrap_or(1);
+        let items = take_rows(data);
+        self.history_state = if items.is_empty() {
+            HistoryState::Empty
+        } else {
+            HistoryState::Loaded
+        };
+        if let Some(player) = items.first().map(|item| &item["player"]) {
+            self.display_name = player["unique_display_name"]
+                .as_str()
+                .map(|name| name.to_string());
+            cx.global::<AppState>().player_index.lock().unwrap().record_player(
+                &self.platform,
+                &self.player_id,
+                player["unique_display_name"].as_str().unwrap_or(""),
+                player["display_name"].as_str().unwrap_or(""),
+            );
+        }
+        self.totals = HistoryTotals::from_rows(&items);
+        self.matches = Arc::new(items);
+        cx.notify();
+    }
+
+    /// Fetches the remaining history pages one by one, updating stats as each page arrives.
+    fn backfill_history(&mut self, cx: &mut Context<Self>) {
+        if self.backfill.is_some() {
+            return;
+        }
+        telemetry::track(cx, "load_full_history");
+        let first_page = self.pages_loaded + 1;
+        let last_page = self.last_page.min(MAX_HISTORY_PAGES);
+        if first_page > last_page {
+            return;
+        }
+        self.backfill = Some(TaskProgress::new(
+            "history pages loaded",
+            (last_page - first_page + 1) as usize,
+        ));
+        cx.notify();
+
+        let service = cx.global::<AppState>().service.clone();
+        let player_id = self.player_id.clone();
+        let platform = self.platform.clone();
+        // Pages only extend the history they were started from
+        let request = self.history_requests.current();
+
+        cx.spawn(async move |view, cx| {
+            for page in first_page..=last_page {
+                let service = service.clone();
+                let player_id = player_id.clone();
+                let platform = platform.clone();
+                let result = cx.background_executor().spawn(async move {
+                    let mut service = service.lock().unwrap();
+                    service.get_player_matches(&platform, &player_id, page as i32)
+                }).await;
 
-        for match_item in &self.matches {
-            // Get stats
-            if let Some(stats) = match_item.get("stats") {
-                if let Some(kills) = stats.get("Kills").and_then(|v| v.as_i64()) {
-                    total_kills += kills;
+                let keep_going = view.update(cx, |this, cx| {
+                    if !this.history_requests.is_current(request) {
+                        return false;
+                    }
+                    let mut has_items = false;
+                    if let Ok(data) = result {
+                        let items = take_rows(data);
+                        has_items = !items.is_empty();
+                        this.totals.add_rows(&items);
+                        Arc::make_mut(&mut this.matches).extend(items);
+                        this.pages_loaded = page;
+                    }
+                    if let Some(progress) = this.backfill.as_mut() {
+                        progress.advance();
+                    }
+                    cx.notify();
+                    has_items
+                });
+                if !matches!(keep_going, Ok(true)) {
+                    break;
                 }
-                if let Some(deaths) = stats.get("Deaths").and_then(|v| v.as_i64()) {
-                    total_deaths += deaths;
+            }
+
+            view.update(cx, |this, cx| {
+                this.backfill = None;
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    /// Fetches the full scoreboard of every loaded match not analyzed yet, for lobby ratings and
+    /// matchups, counting scoreboards in `lobby_progress`. Match details are cached for weeks, so
+    /// repeat runs mostly hit the disk cache.
+    fn analyze_lobbies(&mut self, cx: &mut Context<Self>) {
+        if self.lobby_progress.is_some() {
+            return;
+        }
+        let targets: Vec<String> = self
+            .matches
+            .iter()
+            .filter_map(|row| row["match_id"].as_str())
+            .filter(|match_id| !self.match_details.contains_key(*match_id))
+            .map(|match_id| match_id.to_string())
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+        telemetry::track(cx, "analyze_lobbies");
+        self.lobby_progress = Some(TaskProgress::new("scoreboards loaded", targets.len()));
+        cx.notify();
+
+        let service = cx.global::<AppState>().service.clone();
+        let platform = self.platform.clone();
+        cx.spawn(async move |view, cx| {
+            for match_id in targets {
+                let service = service.clone();
+                let platform = platform.clone();
+                let lookup_id = match_id.clone();
+                let details = cx.background_executor().spawn(async move {
+                    let mut service = service.lock().unwrap();
+                    service.get_match(&platform, &lookup_id).ok()?.as_array().cloned()
+                }).await;
+
+                let updated = view.update(cx, |this, cx| {
+                    if let Some(details) = details {
+                        this.match_details.insert(match_id, details);
+                    }
+                    if let Some(progress) = this.lobby_progress.as_mut() {
+                        progress.advance();
+                    }
+                    cx.notify();
+                });
+                if updated.is_err() {
+                    return;
                 }
             }
+            view.update(cx, |this, cx| {
+                this.lobby_progress = None;
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    /// Reloads the first page of history, discarding anything loaded so far.
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        if self.backfill.is_some() {
+            return;
+        }
+        self.loading = true;
+        self.history_state = HistoryState::Loaded;
+        cx.notify();
+        self.fetch_data(cx);
+    }
 
-            // Get placement
-            if let Some(placement) = match_item.get("placement").and_then(|v| v.as_i64()) {
-                total_placement += placement;
-                game_count += 1;
+    /// Adds or removes this player from the background scheduler's watch list.
+    fn toggle_watch(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "toggle_watch");
+        let player = PlayerRef {
+            platform: self.platform.clone(),
+            player_id: self.player_id.clone(),
+            name: self.title().to_string(),
+        };
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+            if s.is_watched(&player.platform, &player.player_id) {
+                s.watched_players
+                    .retain(|p| p.platform != player.platform || p.player_id != player.player_id);
+            } else {
+                s.watched_players.push(player);
             }
+        });
+        cx.notify();
+    }
+
+    /// Pins a tile for this player to the home page, or unpins it. A new tile is filled by an
+    /// immediate poll instead of waiting for the next scheduled one.
+    fn toggle_pin(&mut self, kind: TileKind, cx: &mut Context<Self>) {
+        telemetry::track(cx, "toggle_pin");
+        let tile = PinnedTile {
+            kind,
+            player: PlayerRef {
+                platform: self.platform.clone(),
+                player_id: self.player_id.clone(),
+                name: self.title().to_string(),
+            },
+        };
+        let pinning = !cx.global::<AppState>().settings().is_pinned(kind, &self.platform, &self.player_id);
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| s.toggle_pin(tile));
+        if pinning {
+            scheduler::run_now(JobKind::PollWatched, cx);
         }
+        cx.notify();
+    }
 
-        self.total_kills = total_kills;
-        self.total_deaths = total_deaths;
-        self.total_games = game_count;
-        self.avg_placement = if game_count > 0 {
-            total_placement as f64 / game_count as f64
-        } else {
-            0.0
+    /// Puts the summary stats on the clipboard as a Markdown table for Discord or Reddit.
+    fn copy_summary_markdown(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "copy_summary_markdown");
+        let totals = &self.totals;
+        let mut rows = vec![
+            vec![
+                "K/D".to_string(),
+                format!(
+                    "{} ({} K / {} D)",
+                    format::decimal(totals.kd_ratio(), 2),
+                    format::integer(totals.kills),
+                    format::integer(totals.deaths)
+                ),
+            ],
+            vec!["Avg placement".to_string(), format!("#{}", format::decimal(totals.avg_placement(), 1))],
+            vec!["Games".to_string(), format::integer(totals.games as i64)],
+        ];
+        if let Some((hero, hero_totals)) = totals.heroes.iter().max_by_key(|(_, hero_totals)| hero_totals.games) {
+            rows.push(vec![
+                "Most played".to_string(),
+                format!("{} ({} games)", hero, format::integer(hero_totals.games as i64)),
+            ]);
+        }
+        let text = format!(
+            "**{}** · last {} games\n\n{}",
+            self.title(),
+            format::integer(totals.games as i64),
+            markdown::table(&["Stat", "Value"], &rows)
+        );
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+
+        self.copied = true;
+        cx.notify();
+        cx.spawn(async move |view, cx| {
+            cx.background_executor().timer(COPIED_FEEDBACK).await;
+            view.update(cx, |this, cx| {
+                this.copied = false;
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    /// Links or unlinks this player as one of the user's own accounts.
+    fn toggle_my_account(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "toggle_my_account");
+        let account = PlayerRef {
+            platform: self.platform.clone(),
+            player_id: self.player_id.clone(),
+            name: self.title().to_string(),
         };
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+            if s.is_my_account(&account.platform, &account.player_id) {
+                s.unlink_account(&account.platform, &account.player_id);
+            } else {
+                s.link_account(account);
+            }
+        });
+        // The header switcher and overlay follow the active account
+        cx.refresh_windows();
     }
-}
 
-impl Render for PlayerView {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        let kd_ratio = if self.total_deaths > 0 {
-            self.total_kills as f64 / self.total_deaths as f64
-        } else if self.total_kills > 0 {
-            f64::INFINITY
-        } else {
-            0.0
+    fn render_empty_state(&self, cx: &mut Context<Self>) -> Div {
+        let (title, guidance) = match self.history_state {
+            HistoryState::Private => (
+                "Profile is private",
+                "This player has hidden their match history. Stats will appear here once they make it public again.",
+            ),
+            HistoryState::Empty => (
+                "No recent matches",
+                "No matches have been recorded for this account yet. Check that the platform is right, or come back after their next game.",
+            ),
+            HistoryState::NotFound => (
+                "Player not found",
+                "op.gg has no player with this id on this platform.",
+            ),
+            HistoryState::Failed | HistoryState::Loaded => (
+                "Couldn't load matches",
+                "The match history request failed. Check your connection and try again.",
+            ),
         };
 
         div()
             .flex()
             .flex_col()
-            .size_full()
+            .items_center()
+            .gap_2()
             .p_8()
-            .gap_4()
-            // Header
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
             .child(
                 div()
-                    .text_2xl()
-                    .font_weight(FontWeight::BOLD)
-                    .child(format!("Player: {}", self.player_id))
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(title)
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child(guidance)
             )
-            // Stats Summary Panel
-            .when(!self.loading && self.total_games > 0, |parent| {
+            .when(self.history_state == HistoryState::Failed, |parent| {
                 parent.child(
                     div()
-                        .flex()
-                        .gap_4()
-                        .p_4()
-                        .bg(rgb(0x181825))
-                        .rounded_lg()
-                        .border_1()
-                        .border_color(rgb(0x313244))
-                        .children(vec![
-                            // K/D Stat
+                        .mt_2()
+                        .px_3()
+                        .py_1()
+                        .bg(rgb(0x89b4fa))
+                        .text_color(rgb(0x1e1e2e))
+                        .rounded_md()
+                        .cursor_pointer()
+                        .focus_ring(&self.action_focus)
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.refresh(cx);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.refresh(cx);
+                            }
+                        }))
+                        .child("Retry")
+                )
+            })
+    }
+
+    fn update_layout(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Vec<PanelLayout>)) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+            let mut layout = s.profile_layout();
+            f(&mut layout);
+            s.profile_layout = layout;
+        });
+        // Every open profile shares the layout
+        cx.refresh_windows();
+    }
+
+    /// Moves `panel` to position `to` in the layout.
+    fn move_panel(&mut self, panel: ProfilePanel, to: usize, cx: &mut Context<Self>) {
+        self.update_layout(cx, |layout| {
+            if let Some(from) = layout.iter().position(|entry| entry.panel == panel) {
+                let entry = layout.remove(from);
+                layout.insert(to.min(layout.len()), entry);
+            }
+        });
+    }
+
+    fn toggle_layout_editing(&mut self, cx: &mut Context<Self>) {
+        self.editing_layout = !self.editing_layout;
+        if self.editing_layout {
+            telemetry::track(cx, "edit_profile_layout");
+        }
+        cx.notify();
+    }
+
+    fn layout_control(label: &'static str, focus: &FocusHandle) -> Div {
+        div()
+            .px_2()
+            .text_xs()
+            .rounded_sm()
+            .cursor_pointer()
+            .bg(rgb(0x313244))
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label)
+    }
+
+    /// Lays out the profile panels in the user's order. In edit mode each panel gets a toolbar
+    /// and can be dragged onto another panel to take its place.
+    /// Lays out each panel's content (`None` when it has nothing to show) in the saved order.
+    fn render_panels(
+        &mut self,
+        mut contents: Vec<(ProfilePanel, Option<Div>)>,
+        spacing: &Spacing,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let layout = cx.global::<AppState>().settings().profile_layout();
+        sync_focus_handles(&mut self.layout_focus, layout.len() * LAYOUT_CONTROLS, cx);
+        let last = layout.len().saturating_sub(1);
+
+        div()
+            .flex()
+            .flex_wrap()
+            .gap(spacing.section_gap)
+            .children(layout.into_iter().enumerate().filter_map(|(idx, entry)| {
+                let content = contents
+                    .iter_mut()
+                    .find(|(panel, _)| *panel == entry.panel)
+                    .and_then(|(_, content)| content.take());
+                let frame = match entry.width {
+                    PanelWidth::Full => div().w_full(),
+                    PanelWidth::Half => div().flex_1().min_w(relative(0.4)),
+                };
+                if !self.editing_layout {
+                    return content.filter(|_| entry.visible).map(|content| frame.child(content));
+                }
+
+                let panel = entry.panel;
+                let focus = &self.layout_focus[idx * LAYOUT_CONTROLS..(idx + 1) * LAYOUT_CONTROLS];
+                let controls: [(&'static str, bool, LayoutHandler); LAYOUT_CONTROLS] = [
+                    ("↑", idx > 0, Rc::new(move |this: &mut Self, cx: &mut Context<Self>| this.move_panel(panel, idx.saturating_sub(1), cx))),
+                    ("↓", idx < last, Rc::new(move |this: &mut Self, cx: &mut Context<Self>| this.move_panel(panel, idx + 1, cx))),
+                    (
+                        if entry.width == PanelWidth::Full { "Half width" } else { "Full width" },
+                        true,
+                        Rc::new(move |this: &mut Self, cx: &mut Context<Self>| {
+                            this.update_layout(cx, |layout| {
+                                if let Some(entry) = layout.iter_mut().find(|entry| entry.panel == panel) {
+                                    entry.width = match entry.width {
+                                        PanelWidth::Full => PanelWidth::Half,
+                                        PanelWidth::Half => PanelWidth::Full,
+                                    };
+                                }
+                            })
+                        }),
+                    ),
+                    (
+                        if entry.visible { "Hide" } else { "Show" },
+                        true,
+                        Rc::new(move |this: &mut Self, cx: &mut Context<Self>| {
+                            this.update_layout(cx, |layout| {
+                                if let Some(entry) = layout.iter_mut().find(|entry| entry.panel == panel) {
+                                    entry.visible = !entry.visible;
+                                }
+                            })
+                        }),
+                    ),
+                ];
+
+                Some(
+                    frame.child(
+                        div()
+                            .id(("layout-panel", idx))
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .p_2()
+                            .rounded_lg()
+                            .border_1()
+                            .border_color(rgb(0x45475a))
+                            .cursor_grab()
+                            .on_drag(DraggedPanel(panel), |dragged, _offset, _window, cx| cx.new(|_| dragged.clone()))
+                            .drag_over::<DraggedPanel>(|style, _, _, _| style.border_color(rgb(0x89b4fa)))
+                            .on_drop(cx.listener(move |this, dragged: &DraggedPanel, _window, cx| {
+                                this.move_panel(dragged.0, idx, cx);
+                            }))
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .text_sm()
+                                            .font_weight(FontWeight::SEMIBOLD)
+                                            .child(format!("⠿ {}", panel.label())),
+                                    )
+                                    .children(controls.into_iter().zip(focus).filter(|((_, enabled, _), _)| *enabled).map(
+                                        |((label, _, action), focus)| {
+                                            let key_action = action.clone();
+                                            Self::layout_control(label, focus)
+                                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                                    cx.stop_propagation();
+                                                    action(this, cx);
+                                                }))
+                                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                                    if is_activation_key(event) {
+                                                        key_action(this, cx);
+                                                    }
+                                                }))
+                                        },
+                                    )),
+                            )
+                            .child(
+                                div()
+                                    .when(!entry.visible, |el| el.opacity(0.4))
+                                    .child(content.unwrap_or_else(|| {
+                                        div().text_sm().text_color(rgb(0x6c7086)).child("Nothing to show for this player")
+                                    })),
+                            ),
+                    ),
+                )
+            }))
+    }
+
+    /// A plugin's panel, evaluated over the loaded matches.
+    fn render_plugin_panel(
+        plugin: &Plugin,
+        matches: &[MatchData],
+        palette: Palette,
+        show_indicators: bool,
+        spacing: &Spacing,
+    ) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p(spacing.card)
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .flex()
+                    .items_baseline()
+                    .gap_2()
+                    .child(div().font_weight(FontWeight::SEMIBOLD).child(plugin.manifest.name.clone()))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(rgb(0x6c7086))
+                            .child(plugin.manifest.description.clone()),
+                    ),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(spacing.section_gap)
+                    .children(plugin.manifest.panel.iter().enumerate().map(|(idx, element)| match element {
+                        PanelElement::Stat { label, decimals, good_at, bad_at, .. } => {
+                            let value = plugin.evaluate(idx, matches);
+                            let rating = value.and_then(|value| {
+                                let thresholds = StatThresholds {
+                                    mode: ThresholdMode::Fixed,
+                                    good: (*good_at)?,
+                                    bad: (*bad_at)?,
+                                };
+                                Some(thresholds.rate(value))
+                            });
+                            let text = value
+                                .map(|value| format::decimal(value, *decimals))
+                                .unwrap_or_else(|| "—".to_string());
                             div()
                                 .flex()
                                 .flex_col()
-                                .flex_1()
-                                .p_3()
-                                .bg(rgb(0x1e1e2e))
-                                .rounded_md()
-                                .child(
-                                    div()
-                                        .text_sm()
-                                        .text_color(rgb(0x9399b2))
-                                        .child("K/D Ratio")
-                                )
+                                .child(div().text_sm().text_color(rgb(0x9399b2)).child(label.clone()))
                                 .child(
                                     div()
                                         .text_xl()
                                         .font_weight(FontWeight::BOLD)
-                                        .text_color(if kd_ratio >= 2.0 {
-                                            rgb(0x4daf4a) // Green
-                                        } else if kd_ratio >= 1.0 {
-                                            rgb(0xcdd6f4) // White
-                                        } else {
-                                            rgb(0xef4444) // Red
-                                        })
-                                        .child(if kd_ratio.is_infinite() {
-                                            "∞".to_string()
-                                        } else {
-                                            format!("{:.2}", kd_ratio)
-                                        })
+                                        .when_some(rating, |el, rating| el.text_color(palette.color(rating)))
+                                        .child(match rating {
+                                            Some(rating) => rating.decorate(text, show_indicators),
+                                            None => text,
+                                        }),
                                 )
-                                .child(
-                                    div()
-                                        .text_xs()
-                                        .text_color(rgb(0x6c7086))
-                                        .child(format!("{} K / {} D", self.total_kills, self.total_deaths))
-                                ),
-                            // Avg Placement Stat
+                        }
+                        PanelElement::Text { text } => div()
+                            .w_full()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(text.clone()),
+                    })),
+            )
+    }
+
+    fn render_records(&self, records: Vec<Record>, spacing: &Spacing, cx: &mut Context<Self>) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p(spacing.card)
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child("Records")
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(spacing.row_gap)
+                    .children(records.into_iter().zip(&self.record_focus).map(|(record, focus)| {
+                        let open_match = record.match_id.map(|match_id| OpenMatch {
+                            match_id,
+                            platform: self.platform.clone(),
+                        });
+                        div()
+                            .flex()
+                            .flex_col()
+                            .min_w(px(140.0))
+                            .p(spacing.card)
+                            .bg(rgb(0x1e1e2e))
+                            .rounded_md()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(rgb(0x9399b2))
+                                    .child(record.label)
+                            )
+                            .child(
+                                div()
+                                    .text_xl()
+                                    .font_weight(FontWeight::BOLD)
+                                    .child(record.value)
+                            )
+                            .when_some(open_match, |card, open_match| {
+                                let on_key = open_match.clone();
+                                card.cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x313244)))
+                                    .focus_ring(focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |_, _, window, cx| {
+                                        window.dispatch_action(Box::new(open_match.clone()), cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |_, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            window.dispatch_action(Box::new(on_key.clone()), cx);
+                                        }
+                                    }))
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(rgb(0x6c7086))
+                                            .child("View match →")
+                                    )
+                            })
+                    }))
+            )
+    }
+
+    /// Range and smoothing apply to every chart on every profile, so they're saved as settings.
+    fn set_chart_range(&mut self, range: ChartRange, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| s.chart_range = range);
+        cx.notify();
+    }
+
+    fn set_chart_smoothing(&mut self, smoothing: Smoothing, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| s.chart_smoothing = smoothing);
+        cx.notify();
+    }
+
+    fn chart_chip(label: &'static str, selected: bool, focus: &FocusHandle) -> Div {
+        Self::layout_control(label, focus)
+            .py_1()
+            .when(selected, |chip| chip.bg(rgb(0x89b4fa)).text_color(rgb(0x1e1e2e)))
+    }
+
+    fn render_chart_ranges(selected: ChartRange, focus: &[FocusHandle], cx: &mut Context<Self>) -> Div {
+        div().flex().gap_1().children(ChartRange::ALL.into_iter().zip(focus).map(|(range, focus)| {
+            Self::chart_chip(range.label(), range == selected, focus)
+                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.set_chart_range(range, cx)))
+                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                    if is_activation_key(event) {
+                        this.set_chart_range(range, cx);
+                    }
+                }))
+        }))
+    }
+
+    fn render_smoothing(selected: Smoothing, focus: &[FocusHandle], cx: &mut Context<Self>) -> Div {
+        div().flex().gap_1().children(Smoothing::ALL.into_iter().zip(focus).map(|(smoothing, focus)| {
+            Self::chart_chip(smoothing.label(), smoothing == selected, focus)
+                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                    this.set_chart_smoothing(smoothing, cx);
+                }))
+                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                    if is_activation_key(event) {
+                        this.set_chart_smoothing(smoothing, cx);
+                    }
+                }))
+        }))
+    }
+
+    /// How many losses in `rows` ended in each storm phase. `None` when none of them has phase
+    /// timings.
+    fn render_death_phases(&self, rows: &[Value], range: ChartRange, spacing: &Spacing, cx: &mut Context<Self>) -> Option<Div> {
+        let mut deaths: Vec<(u32, usize)> = Vec::new();
+        for phase in rows.iter().filter_map(death_phase) {
+            match deaths.iter_mut().find(|(number, _)| *number == phase) {
+                Some((_, count)) => *count += 1,
+                None => deaths.push((phase, 1)),
+            }
+        }
+        if deaths.is_empty() {
+            return None;
+        }
+        deaths.sort_by_key(|(number, _)| *number);
+        let total: usize = deaths.iter().map(|(_, count)| count).sum();
+
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .child(
                             div()
-                                .flex()
-                                .flex_col()
-                                .flex_1()
-                                .p_3()
-                                .bg(rgb(0x1e1e2e))
-                                .rounded_md()
-                                .child(
-                                    div()
-                                        .text_sm()
-                                        .text_color(rgb(0x9399b2))
-                                        .child("Avg Placement")
-                                )
-                                .child(
-                                    div()
-                                        .text_xl()
-                                        .font_weight(FontWeight::BOLD)
-                                        .text_color(if self.avg_placement <= 10.0 {
-                                            rgb(0x4daf4a) // Green
-                                        } else if self.avg_placement <= 20.0 {
-                                            rgb(0xf59e0b) // Yellow
-                                        } else {
-                                            rgb(0xef4444) // Red
-                                        })
-                                        .child(format!("#{:.1}", self.avg_placement))
-                                ),
-                            // Total Games Stat
+                                .text_lg()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child("Deaths by phase")
+                        )
+                        .child(Self::render_chart_ranges(range, &self.chart_focus[ChartRange::ALL.len() + Smoothing::ALL.len()..], cx))
+                )
+                .child(Histogram::new(
+                    deaths
+                        .into_iter()
+                        .map(|(phase, count)| Bar {
+                            label: format!("Phase {} · {} ({:.0}%)", phase, count, count as f64 / total as f64 * 100.0).into(),
+                            value: count as f64,
+                        })
+                        .collect(),
+                    rgb(0xf38ba8),
+                ))
+        )
+    }
+
+    fn render_teamplay(&self, spacing: &Spacing) -> Div {
+        let stats = TeamplayStats::from_matches(&self.matches);
+        let cards = [
+            ("Teamplay score", format!("{:.1}", stats.score()), "Assists, plus two per revive given, plus one per 1,000 healing or shielding, per game."),
+            ("Assists", format!("{:.1}", stats.assists), "Average assists per game."),
+            ("Revives given", format!("{:.2}", stats.revives_given), "Teammates revived per game."),
+            ("Revived by team", format!("{:.2}", stats.revives_received), "Times brought back by a teammate per game."),
+            ("Healing", format::integer(stats.healing.round() as i64), "Healing given per game."),
+            ("Shielding", format::integer(stats.shielding.round() as i64), "Damage absorbed by shields per game."),
+        ];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p(spacing.card)
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child("Teamplay")
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(spacing.row_gap)
+                    .children(cards.into_iter().map(|(label, value, description)| {
+                        div()
+                            .id(label)
+                            .tooltip(Tooltip::with_description(label, description))
+                            .flex()
+                            .flex_col()
+                            .min_w(px(120.0))
+                            .p(spacing.card)
+                            .bg(rgb(0x1e1e2e))
+                            .rounded_md()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(rgb(0x9399b2))
+                                    .child(label)
+                            )
+                            .child(
+                                div()
+                                    .text_xl()
+                                    .font_weight(FontWeight::BOLD)
+                                    .child(value)
+                            )
+                    }))
+            )
+    }
+
+    /// Win rate and placement against each enemy hero, compared with all analyzed matches.
+    /// Heroes met fewer than `min_games` times are greyed out. `None` until lobbies have been
+    /// analyzed.
+    fn render_matchups(&self, spacing: &Spacing, min_games: usize) -> Option<Div> {
+        let matchups = enemy_matchups(&self.player_id, self.match_details.values());
+        let placements: Vec<i64> = self
+            .match_details
+            .values()
+            .filter_map(|rows| own_row(&self.player_id, rows)?["placement"].as_i64())
+            .collect();
+        if matchups.is_empty() || placements.is_empty() {
+            return None;
+        }
+        let baseline = placements.iter().sum::<i64>() as f64 / placements.len() as f64;
+        let baseline_wins = placements.iter().filter(|placement| **placement == 1).count() as f64 / placements.len() as f64;
+
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .child("Enemy matchups")
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(rgb(0x9399b2))
+                        .child(format!(
+                            "Across {} analyzed matches: avg #{:.1}, {:.0}% wins. Greyed out: fewer than {} games.",
+                            placements.len(),
+                            baseline,
+                            baseline_wins * 100.0,
+                            min_games
+                        ))
+                )
+                .children(matchups.into_iter().map(|matchup| {
+                    let problem = matchup.is_problem(baseline, min_games);
+                    let low_sample = matchup.games < min_games;
+                    let delta = matchup.avg_placement - baseline;
+                    let delta_color = if low_sample {
+                        rgb(0x6c7086)
+                    } else if delta > 0.0 {
+                        rgb(0xf38ba8)
+                    } else {
+                        rgb(0xa6e3a1)
+                    };
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .text_sm()
+                        .when(low_sample, |row| row.text_color(rgb(0x6c7086)))
+                        .child(div().w(px(100.0)).font_weight(FontWeight::MEDIUM).child(matchup.hero.clone()))
+                        .child(div().w(px(70.0)).text_color(rgb(0x9399b2)).child(format!("{} games", matchup.games)))
+                        .child(
                             div()
-                                .flex()
-                                .flex_col()
-                                .flex_1()
-                                .p_3()
-                                .bg(rgb(0x1e1e2e))
-                                .rounded_md()
-                                .child(
-                                    div()
-                                        .text_sm()
-                                        .text_color(rgb(0x9399b2))
-                                        .child("Total Games")
-                                )
-                                .child(
-                                    div()
-                                        .text_xl()
-                                        .font_weight(FontWeight::BOLD)
-                                        .child(format!("{}", self.total_games))
-                                ),
-                        ])
+                                .w(px(130.0))
+                                .child(format!("{} wins", confidence::rate_with_interval(matchup.wins, matchup.games)))
+                        )
+                        .child(
+                            div()
+                                .w(px(120.0))
+                                .text_color(delta_color)
+                                .child(format!("#{:.1} ({:+.1})", matchup.avg_placement, delta))
+                        )
+                        .when(problem, |row| {
+                            row.child(
+                                div()
+                                    .px_1()
+                                    .rounded_sm()
+                                    .bg(rgb(0x45273a))
+                                    .text_xs()
+                                    .text_color(rgb(0xf38ba8))
+                                    .child("Problem matchup")
+                            )
+                        })
+                }))
+        )
+    }
+
+    /// Best and worst ally role mixes by win rate, against the overall win rate of analyzed matches.
+    fn render_compositions(&self, spacing: &Spacing, min_games: usize) -> Option<Div> {
+        /// Compositions shown at each end of the ranking.
+        const SHOWN: usize = 3;
+
+        let comps = ally_compositions(&self.player_id, self.match_details.values(), min_games);
+        if comps.is_empty() {
+            return None;
+        }
+        let results: Vec<bool> = self
+            .match_details
+            .values()
+            .filter_map(|rows| Some(own_row(&self.player_id, rows)?["placement"].as_i64() == Some(1)))
+            .collect();
+        let overall = results.iter().filter(|won| **won).count() as f64 / results.len().max(1) as f64;
+        let (top, bottom): (Vec<Composition>, Vec<Composition>) = if comps.len() <= SHOWN * 2 {
+            (comps, Vec::new())
+        } else {
+            (comps[..SHOWN].to_vec(), comps[comps.len() - SHOWN..].to_vec())
+        };
+        let comp_row = move |comp: Composition| {
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .text_sm()
+                .child(div().flex_1().child(format!("You + {}", comp.allies)))
+                .child(div().w(px(70.0)).text_color(rgb(0x9399b2)).child(format!("{} games", comp.games)))
+                .child(
+                    div()
+                        .w(px(130.0))
+                        .text_color(if comp.win_rate() >= overall { rgb(0xa6e3a1) } else { rgb(0xf38ba8) })
+                        .child(format!("{} wins", confidence::rate_with_interval(comp.wins, comp.games)))
                 )
-            })
-            // Matches Section
+        };
+
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .child("Team compositions")
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(rgb(0x9399b2))
+                        .child(format!(
+                            "{:.0}% wins overall across {} analyzed matches. Mixes with fewer than {} games are left out.",
+                            overall * 100.0,
+                            results.len(),
+                            min_games
+                        ))
+                )
+                .children(top.into_iter().map(comp_row))
+                .when(!bottom.is_empty(), |parent| {
+                    parent
+                        .child(div().text_xs().text_color(rgb(0x6c7086)).child("Weakest"))
+                        .children(bottom.into_iter().map(comp_row))
+                })
+        )
+    }
+
+    /// Heroes to play more or less, ranked by the player's own results on them and, once lobbies
+    /// are analyzed, how the whole field places with them.
+    fn render_suggestions(&self, spacing: &Spacing) -> Option<Div> {
+        /// Heroes shown under each heading.
+        const SHOWN: usize = 3;
+
+        let suggestions = hero_suggestions(&self.matches, self.match_details.values());
+        if suggestions.is_empty() {
+            return None;
+        }
+        let play_more: Vec<HeroSuggestion> = suggestions
+            .iter()
+            .filter(|suggestion| suggestion.score() > 0.0)
+            .take(SHOWN)
+            .cloned()
+            .collect();
+        let play_less: Vec<HeroSuggestion> = suggestions
+            .iter()
+            .rev()
+            .filter(|suggestion| suggestion.score() < 0.0)
+            .take(SHOWN)
+            .cloned()
+            .collect();
+        let suggestion_row = |suggestion: HeroSuggestion| {
+            let score = suggestion.score();
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .text_sm()
+                .child(div().w(px(100.0)).font_weight(FontWeight::MEDIUM).child(suggestion.hero.clone()))
+                .child(div().w(px(70.0)).text_color(rgb(0x9399b2)).child(format!("{} games", suggestion.games)))
+                .child(div().w(px(120.0)).child(format!("#{:.1} ({:+.1})", suggestion.avg_placement, -suggestion.personal)))
+                .child(
+                    div()
+                        .w(px(110.0))
+                        .text_color(rgb(0x9399b2))
+                        .child(match suggestion.field {
+                            Some(field) => format!("field {:+.1}", -field),
+                            None => "field n/a".to_string(),
+                        })
+                )
+                .child(
+                    div()
+                        .text_color(if score >= 0.0 { rgb(0xa6e3a1) } else { rgb(0xf38ba8) })
+                        .child(format!("score {:+.1}", score))
+                )
+        };
+
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .child("Suggested heroes")
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(rgb(0x9399b2))
+                        .child(format!(
+                            "Score = places gained over your average on the hero + {:.1} × places the hero gains over the average player in your analyzed lobbies. Heroes need 3 games.",
+                            FIELD_WEIGHT
+                        ))
+                )
+                .when(!play_more.is_empty(), |parent| {
+                    parent
+                        .child(div().text_xs().text_color(rgb(0x6c7086)).child("Play more"))
+                        .children(play_more.into_iter().map(suggestion_row))
+                })
+                .when(!play_less.is_empty(), |parent| {
+                    parent
+                        .child(div().text_xs().text_color(rgb(0x6c7086)).child("Play less"))
+                        .children(play_less.into_iter().map(suggestion_row))
+                })
+        )
+    }
+
+    /// Local calendar day each match started on.
+    fn match_day(match_item: &Value) -> Option<NaiveDate> {
+        parse_time(&match_item["match_start"]).map(|time| time.with_timezone(&Local).date_naive())
+    }
+
+    fn set_hovered_day(&mut self, day: Option<NaiveDate>, cx: &mut Context<Self>) {
+        if self.hovered_day != day {
+            self.hovered_day = day;
+            cx.notify();
+        }
+    }
+
+    fn set_day_filter(&mut self, day: Option<NaiveDate>, cx: &mut Context<Self>) {
+        self.day_filter = day;
+        cx.notify();
+    }
+
+    fn set_summary_mode(&mut self, mode: GameMode, cx: &mut Context<Self>) {
+        self.summary_mode = Some(mode);
+        cx.notify();
+    }
+
+    /// One card of the summary panel, over the games of the panel's mode.
+    fn render_summary_card(
+        stat: SummaryStat,
+        totals: &ModeTotals,
+        thresholds: &RatingThresholds,
+        palette: Palette,
+        show_indicators: bool,
+        spacing: &Spacing,
+    ) -> Stateful<Div> {
+        let games = totals.totals.games;
+        let share = |count: f64, of: f64| format!("{}%", format::decimal(count * 100.0 / of.max(1.0), 0));
+        let (id, title, description, value, color, detail) = match stat {
+            SummaryStat::KdRatio => {
+                let kd_ratio = totals.totals.kd_ratio();
+                let rating = thresholds.kd.rate(kd_ratio);
+                (
+                    "kd-card",
+                    "K/D Ratio",
+                    "Total kills divided by total deaths across the loaded matches.",
+                    rating.decorate(format::decimal(kd_ratio, 2), show_indicators),
+                    Some(palette.color(rating)),
+                    Some(format!("{} K / {} D", format::integer(totals.totals.kills), format::integer(totals.totals.deaths))),
+                )
+            }
+            SummaryStat::AvgPlacement => {
+                let avg = totals.totals.avg_placement();
+                let rating = thresholds.placement.rate(avg);
+                (
+                    "placement-card",
+                    "Avg Placement",
+                    "Mean finishing position across the loaded matches. Lower is better.",
+                    rating.decorate(format!("#{}", format::decimal(avg, 1)), show_indicators),
+                    Some(palette.color(rating)),
+                    None,
+                )
+            }
+            SummaryStat::Games => (
+                "games-card",
+                "Total Games",
+                "Matches with a recorded placement in the loaded history.",
+                format::integer(games as i64),
+                None,
+                None,
+            ),
+            SummaryStat::RoundWins => (
+                "round-wins-card",
+                "Round Wins",
+                "Rounds won across the loaded arena matches.",
+                format::integer(totals.round_wins),
+                None,
+                (totals.rounds_played > 0).then(|| {
+                    format!(
+                        "{} of {} rounds",
+                        share(totals.round_wins as f64, totals.rounds_played as f64),
+                        format::integer(totals.rounds_played)
+                    )
+                }),
+            ),
+            SummaryStat::MatchWins => (
+                "match-wins-card",
+                "Match Wins",
+                "Arena matches won across the loaded history.",
+                format::integer(totals.match_wins as i64),
+                None,
+                Some(format!("{} win rate", share(totals.match_wins as f64, games as f64))),
+            ),
+        };
+        div()
+            .id(id)
+            .tooltip(Tooltip::with_description(title, description))
+            .flex()
+            .flex_col()
+            .flex_1()
+            .p(spacing.card)
+            .bg(rgb(0x1e1e2e))
+            .rounded_md()
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child(title))
             .child(
-                if self.loading {
-                    div().child("Loading...")
-                } else {
+                div()
+                    .text_xl()
+                    .font_weight(FontWeight::BOLD)
+                    .when_some(color, |value, color| value.text_color(color))
+                    .child(value),
+            )
+            .when_some(detail, |card, detail| {
+                card.child(div().text_xs().text_color(rgb(0x6c7086)).child(detail))
+            })
+    }
+
+    fn set_role_filter(&mut self, role: Option<RoleGroup>, cx: &mut Context<Self>) {
+        self.role_filter = role;
+        cx.notify();
+    }
+
+    /// Games and average placement per role over the loaded history, for roles played at all.
+    fn role_totals(&self) -> Vec<(RoleGroup, usize, f64)> {
+        let mut placements: HashMap<RoleGroup, Vec<i64>> = HashMap::new();
+        for row in self.matches.iter() {
+            let (Some(role), Some(placement)) = (
+                row["hero"]["name"].as_str().and_then(RoleGroup::for_hero),
+                row["placement"].as_i64(),
+            ) else {
+                continue;
+            };
+            placements.entry(role).or_default().push(placement);
+        }
+        RoleGroup::ALL
+            .into_iter()
+            .filter_map(|role| {
+                let placements = placements.get(&role)?;
+                let avg = placements.iter().sum::<i64>() as f64 / placements.len() as f64;
+                Some((role, placements.len(), avg))
+            })
+            .collect()
+    }
+
+    fn render_role_filter(&self, totals: &[(RoleGroup, usize, f64)], cx: &mut Context<Self>) -> Div {
+        let chip = |selected: bool, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+        };
+        let options = std::iter::once((None, "All roles".to_string())).chain(totals.iter().map(|(role, games, avg)| {
+            (
+                Some(*role),
+                format!("{} · #{} avg · {} games", role.label(), format::decimal(*avg, 1), format::integer(*games as i64)),
+            )
+        }));
+        div()
+            .flex()
+            .flex_wrap()
+            .gap_2()
+            .text_sm()
+            .children(options.zip(&self.role_focus).map(|((role, label), focus)| {
+                chip(self.role_filter == role, focus)
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                        this.set_role_filter(role, cx);
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.set_role_filter(role, cx);
+                        }
+                    }))
+                    .child(label)
+            }))
+    }
+}
+
+impl EventEmitter<PlayerNotFound> for PlayerView {}
+
+impl Render for PlayerView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        sync_focus_handles(&mut self.match_focus, self.matches.len(), cx);
+        sync_focus_handles(&mut self.pin_focus, TileKind::ALL.len(), cx);
+        let settings = cx.global::<AppState>().settings();
+        let palette = settings.palette;
+        let show_indicators = settings.show_indicators;
+        let spacing = settings.density.spacing();
+        let is_watched = settings.is_watched(&self.platform, &self.player_id);
+        let is_my_account = settings.is_my_account(&self.platform, &self.player_id);
+        let plugins = cx.global::<AppState>().plugins.lock().unwrap().clone();
+        let thresholds = settings.rating_thresholds.for_history(&self.matches);
+        let mode_counts = game_modes::mode_counts(&self.matches);
+        sync_focus_handles(&mut self.mode_focus, mode_counts.len(), cx);
+        // The picked mode while the history has games of it, else the most played one
+        let summary_mode = self
+            .summary_mode
+            .filter(|mode| mode_counts.iter().any(|(played, _)| played == mode))
+            .or(mode_counts.first().map(|(mode, _)| *mode))
+            .unwrap_or(GameMode::BattleRoyale);
+        let mode_totals = ModeTotals::from_rows(summary_mode, &self.matches);
+
+        let summary_panel = (!self.loading && self.totals.games > 0).then(|| {
+            div()
+                .flex()
+                .gap(spacing.section_gap)
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .children(summary_mode.preset().iter().map(|stat| {
+                    Self::render_summary_card(*stat, &mode_totals, &thresholds, palette, show_indicators, &spacing)
+                }))
+                .child(
                     div()
                         .flex()
                         .flex_col()
+                        .items_start()
+                        .gap_1()
+                        .child(
+                            Self::layout_control(if self.copied { "Copied" } else { "Copy as Markdown" }, &self.copy_focus)
+                                .py_1()
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.copy_summary_markdown(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.copy_summary_markdown(cx);
+                                    }
+                                })),
+                        )
+                        // Only worth switching when the history mixes modes
+                        .when(mode_counts.len() > 1, |column| {
+                            column.children(mode_counts.iter().zip(&self.mode_focus).map(|((mode, games), focus)| {
+                                let mode = *mode;
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_xs()
+                                    .rounded_sm()
+                                    .cursor_pointer()
+                                    .bg(if mode == summary_mode { rgb(0x89b4fa) } else { rgb(0x313244) })
+                                    .text_color(if mode == summary_mode { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                        this.set_summary_mode(mode, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.set_summary_mode(mode, cx);
+                                        }
+                                    }))
+                                    .child(format!("{} ({})", mode.label(), format::integer(*games as i64)))
+                            }))
+                        }),
+                )
+        });
+        let plugin_panel = (!self.loading && self.totals.games > 0 && !plugins.is_empty()).then(|| {
+            let matches: Vec<MatchData> = self.matches.iter().map(MatchData::from_row).collect();
+            div()
+                .flex()
+                .flex_col()
+                .gap(spacing.section_gap)
+                .children(plugins.iter().map(|plugin| {
+                    Self::render_plugin_panel(plugin, &matches, palette, show_indicators, &spacing)
+                }))
+        });
+        let activity_panel = (!self.loading && self.totals.games > 0).then(|| {
+            let mut games_per_day: HashMap<NaiveDate, usize> = HashMap::new();
+            for day in self.matches.iter().filter_map(Self::match_day) {
+                *games_per_day.entry(day).or_default() += 1;
+            }
+            let view = cx.entity().downgrade();
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .child("Activity")
+                )
+                .child(
+                    ActivityCalendar::new(games_per_day, self.day_filter, {
+                        let view = view.clone();
+                        move |day, _window, cx| {
+                            view.update(cx, |this, cx| this.set_day_filter(day, cx)).ok();
+                        }
+                    })
+                    .on_hover(self.hovered_day, move |day, _window, cx| {
+                        view.update(cx, |this, cx| this.set_hovered_day(day, cx)).ok();
+                    })
+                    .track_focus(&self.calendar_focus),
+                )
+        });
+        let profile_records = records::records(&self.matches);
+        sync_focus_handles(&mut self.record_focus, profile_records.len(), cx);
+        let records_panel = (!self.loading && !profile_records.is_empty())
+            .then(|| self.render_records(profile_records, &spacing, cx));
+        sync_focus_handles(&mut self.chart_focus, ChartRange::ALL.len() * 2 + Smoothing::ALL.len(), cx);
+        let chart_rows = settings.chart_range.apply(&self.matches);
+        let phases_panel = if self.loading {
+            None
+        } else {
+            self.render_death_phases(chart_rows, settings.chart_range, &spacing, cx)
+        };
+        let teamplay_panel = (!self.loading && self.totals.games > 0).then(|| self.render_teamplay(&spacing));
+        // Every ranked game of the loaded history, so the panel survives a range without any
+        let has_rating_history = rank_points::rating_history(&self.matches).len() >= 2;
+        let rating_history = rank_points::rating_history(chart_rows);
+        let rank_panel = (!self.loading && has_rating_history).then(|| {
+            let change = match (rating_history.first(), rating_history.last()) {
+                (Some(first), Some(last)) => last - first,
+                _ => 0.0,
+            };
+            let (range_focus, smoothing_focus) = self.chart_focus.split_at(ChartRange::ALL.len());
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .p(spacing.card)
+                .bg(rgb(0x181825))
+                .rounded_lg()
+                .border_1()
+                .border_color(rgb(0x313244))
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child("RP over time")
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(if change >= 0.0 { rgb(0xa6e3a1) } else { rgb(0xf38ba8) })
+                                .child(format!("{:+.0} RP over {} ranked games", change, rating_history.len()))
+                        )
+                )
+                .child(
+                    div()
+                        .flex()
+                        .flex_wrap()
                         .gap_3()
+                        .child(Self::render_chart_ranges(settings.chart_range, range_focus, cx))
+                        .child(Self::render_smoothing(settings.chart_smoothing, smoothing_focus, cx))
+                )
+                .when(rating_history.len() >= 2, |panel| {
+                    panel.child(LineChart::new(settings.chart_smoothing.apply(&rating_history)))
+                })
+                .when(rating_history.len() < 2, |panel| {
+                    panel.child(div().text_sm().text_color(rgb(0x9399b2)).child("Not enough ranked games in this range"))
+                })
+        });
+        let rating_deltas = rank_points::rating_deltas(&self.matches);
+        let matchups_panel = self.render_matchups(&spacing, settings.min_sample_games);
+        let compositions_panel = self.render_compositions(&spacing, settings.min_sample_games);
+        let suggestions_panel = if settings.show_hero_suggestions {
+            self.render_suggestions(&spacing)
+        } else {
+            None
+        };
+        let day_filter = self.day_filter;
+        let role_filter = self.role_filter;
+        let role_totals = self.role_totals();
+        sync_focus_handles(&mut self.role_focus, role_totals.len() + 1, cx);
+        let matches_panel = if let Some(limit) = self.rate_limit {
+            div().child(RateLimitBanner::new(limit))
+        } else if self.loading {
+            div().child("Loading...")
+        } else if self.history_state != HistoryState::Loaded {
+            self.render_empty_state(cx)
+        } else {
+            div()
+                .flex()
+                .flex_col()
+                .gap(spacing.row_gap)
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
                         .child(
                             div()
                                 .text_lg()
                                 .font_weight(FontWeight::SEMIBOLD)
                                 .child("Recent Matches")
                         )
-                        .children(self.matches.iter().map(|match_item| {
-                            let match_id = match_item["match_id"].as_str().unwrap_or("").to_string();
-                            let placement = match_item["placement"].as_i64().unwrap_or(0);
-                            let hero_name = match_item["hero"]["name"].as_str().unwrap_or("Unknown").to_string();
-                            let hero_image = match_item["hero"]["head_image_url"]
-                                .as_str()
-                                .or(match_item["hero"]["image_url"].as_str())
-                                .unwrap_or("")
-                                .to_string();
-                            let platform = self.platform.clone();
-                            
-                            // Get K/D for this match
-                            let kills = match_item["stats"]["Kills"].as_i64().unwrap_or(0);
-                            let deaths = match_item["stats"]["Deaths"].as_i64().unwrap_or(0);
-                            
-                            // Placement color
-                            let placement_color = if placement <= 10 {
-                                rgb(0x4daf4a) // Green
-                            } else if placement <= 20 {
-                                rgb(0xf59e0b) // Yellow
-                            } else {
-                                rgb(0xef4444) // Red
-                            };
-
+                        .child(
                             div()
                                 .flex()
-                                .items_center()
-                                .gap_3()
-                                .p_4()
-                                .bg(rgb(0x313244))
-                                .rounded_md()
-                                .cursor_pointer()
-                                .hover(|s| s.bg(rgb(0x45475a)))
-                                .on_mouse_down(MouseButton::Left, cx.listener(move |_, _, window, cx| {
-                                    window.dispatch_action(Box::new(OpenMatch {
-                                        match_id: match_id.clone(),
-                                        platform: platform.clone(),
-                                    }), cx);
-                                }))
-                                // Hero Image
-                                .when(!hero_image.is_empty(), |parent| {
+                                .gap_2()
+                                .when(self.lobby_progress.is_none(), |parent| {
+                                    parent.child(
+                                        div()
+                                            .px_3()
+                                            .py_1()
+                                            .text_sm()
+                                            .bg(rgb(0x313244))
+                                            .rounded_md()
+                                            .cursor_pointer()
+                                            .hover(|s| s.bg(rgb(0x45475a)))
+                                            .focus_ring(&self.lobby_focus)
+                                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                                this.analyze_lobbies(cx);
+                                            }))
+                                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                                if is_activation_key(event) {
+                                                    this.analyze_lobbies(cx);
+                                                }
+                                            }))
+                                            .child("Analyze lobbies")
+                                    )
+                                })
+                                .when(self.backfill.is_none() && self.pages_loaded < self.last_page.min(MAX_HISTORY_PAGES), |parent| {
                                     parent.child(
-                                        img(hero_image)
-                                            .w(px(48.0))
-                                            .h(px(48.0))
-                                            .rounded(px(8.0))
-                                            .object_fit(gpui::ObjectFit::Cover)
+                                        div()
+                                            .px_3()
+                                            .py_1()
+                                            .text_sm()
+                                            .bg(rgb(0x313244))
+                                            .rounded_md()
+                                            .cursor_pointer()
+                                            .hover(|s| s.bg(rgb(0x45475a)))
+                                            .focus_ring(&self.action_focus)
+                                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                                this.backfill_history(cx);
+                                            }))
+                                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                                if is_activation_key(event) {
+                                                    this.backfill_history(cx);
+                                                }
+                                            }))
+                                            .child("Load full history")
                                     )
                                 })
-                                // Match Info
+                        )
+                )
+                .when_some(self.backfill.clone(), |parent, progress| {
+                    parent.child(ProgressBar::new(progress))
+                })
+                .when_some(self.lobby_progress.clone(), |parent, progress| {
+                    parent.child(ProgressBar::new(progress))
+                })
+                .when_some(day_filter, |parent, day| {
+                    parent.child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(format!("Showing matches on {}", format::date(day)))
+                            .child(
+                                div()
+                                    .px_2()
+                                    .rounded_md()
+                                    .bg(rgb(0x313244))
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(&self.clear_filter_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.set_day_filter(None, cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.set_day_filter(None, cx);
+                                        }
+                                    }))
+                                    .child("Clear")
+                            )
+                    )
+                })
+                .when(!role_totals.is_empty(), |parent| parent.child(self.render_role_filter(&role_totals, cx)))
+                .children(self.matches.iter().zip(&self.match_focus).zip(&rating_deltas).filter(|((match_item, _), _)| {
+                    (day_filter.is_none() || Self::match_day(match_item) == day_filter)
+                        && role_filter.is_none_or(|role| {
+                            match_item["hero"]["name"].as_str().and_then(RoleGroup::for_hero) == Some(role)
+                        })
+                }).map(|((match_item, focus), rating_delta)| {
+                    let match_id = match_item["match_id"].as_str().unwrap_or("").to_string();
+                    let image_id = ElementId::Name(format!("hero-{}", match_id).into());
+                    let placement = match_item["placement"].as_i64().unwrap_or(0);
+                    let hero_name = match_item["hero"]["name"].as_str().unwrap_or("Unknown").to_string();
+                    let hero_image = match_item["hero"]["head_image_url"]
+                        .as_str()
+                        .or(match_item["hero"]["image_url"].as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let open_match = OpenMatch {
+                        match_id: match_id.clone(),
+                        platform: self.platform.clone(),
+                    };
+                    
+                    // Get K/D for this match
+                    let kills = match_item["stats"]["Kills"].as_i64().unwrap_or(0);
+                    let deaths = match_item["stats"]["Deaths"].as_i64().unwrap_or(0);
+                    
+                    // Placement color, plus a W/L tag so the result doesn't rely on color
+                    let placement_rating = thresholds.placement.rate(placement as f64);
+                    let placement_color = palette.color(placement_rating);
+                    let is_win = placement == 1;
+                    let own_tier = rank_of(match_item).and_then(RankTier::parse).map(|tier| tier.index() as f64);
+                    let lobby = self.match_details.get(&match_id).and_then(|details| lobby_rating(details)).map(|rating| {
+                        let difficulty = LobbyDifficulty::new(rating, own_tier);
+                        let color = match difficulty {
+                            LobbyDifficulty::Soft => rgb(0xa6e3a1),
+                            LobbyDifficulty::Even => rgb(0x9399b2),
+                            LobbyDifficulty::Hard => rgb(0xf38ba8),
+                        };
+                        (format!("{} · ~{}", difficulty.label(), nearest_tier(rating).label()), color)
+                    });
+
+                    div()
+                        .id(ElementId::Name(format!("match-{}", match_id).into()))
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .p(spacing.row)
+                        .bg(rgb(0x313244))
+                        .rounded_md()
+                        .cursor_pointer()
+                        .hover(|s| s.bg(rgb(0x45475a)))
+                        .focus_ring(focus)
+                        .on_mouse_down(MouseButton::Left, cx.listener({
+                            let open = open_match.clone();
+                            move |_, _, window, cx| {
+                                window.dispatch_action(Box::new(open.clone()), cx);
+                            }
+                        }))
+                        .on_key_down(cx.listener(move |_, event: &KeyDownEvent, window, cx| {
+                            if is_activation_key(event) {
+                                window.dispatch_action(Box::new(open_match.clone()), cx);
+                            }
+                        }))
+                        // Hero Image
+                        .child(HeroImage::new(
+                            image_id,
+                            hero_image,
+                            hero_name.clone(),
+                        ))
+                        // Match Info
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .flex_1()
+                                .gap_1()
+                                .child(
+                                    div()
+                                        .text_base()
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .child(hero_name)
+                                )
                                 .child(
                                     div()
                                         .flex()
-                                        .flex_col()
-                                        .flex_1()
-                                        .gap_1()
+                                        .gap_3()
+                                        .text_sm()
+                                        .when(show_indicators, |parent| {
+                                            parent.child(
+                                                div()
+                                                    .px_1()
+                                                    .rounded_sm()
+                                                    .font_weight(FontWeight::BOLD)
+                                                    .bg(rgb(0x1e1e2e))
+                                                    .text_color(placement_color)
+                                                    .child(if is_win { "W" } else { "L" })
+                                            )
+                                        })
                                         .child(
                                             div()
-                                                .text_base()
-                                                .font_weight(FontWeight::SEMIBOLD)
-                                                .child(hero_name)
+                                                .text_color(placement_color)
+                                                .font_weight(FontWeight::MEDIUM)
+                                                .child(placement_rating.decorate(
+                                                    format!("#{} Placement", placement),
+                                                    show_indicators,
+                                                ))
                                         )
+                                        .children(lobby.map(|(label, color)| {
+                                            div()
+                                                .px_1()
+                                                .rounded_sm()
+                                                .bg(rgb(0x1e1e2e))
+                                                .text_color(color)
+                                                .child(label)
+                                        }))
+                                        .children(rating_delta.map(|delta| {
+                                            div()
+                                                .font_weight(FontWeight::MEDIUM)
+                                                .text_color(if delta >= 0 { rgb(0xa6e3a1) } else { rgb(0xf38ba8) })
+                                                .child(format!("{:+} RP", delta))
+                                        }))
                                         .child(
                                             div()
-                                                .flex()
-                                                .gap_3()
-                                                .text_sm()
-                                                .child(
-                                                    div()
-                                                        .text_color(placement_color)
-                                                        .font_weight(FontWeight::MEDIUM)
-                                                        .child(format!("#{} Placement", placement))
-                                                )
-                                                .child(
-                                                    div()
-                                                        .text_color(rgb(0x9399b2))
-                                                        .child(format!("{} K / {} D", kills, deaths))
-                                                )
+                                                .id("kd")
+                                                .tooltip(Tooltip::text("Kills / Deaths"))
+                                                .text_color(rgb(0x9399b2))
+                                                .child(format!("{} K / {} D", kills, deaths))
                                         )
                                 )
-                        }))
-                }
+                        )
+                }))
+        };
+
+        let shown = PlayerRef {
+            platform: self.platform.clone(),
+            player_id: self.player_id.clone(),
+            name: self.title().to_string(),
+        };
+
+        div()
+            .id("player-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            // Dropping another player here compares them with this one
+            .drag_over::<DraggedPlayer>(|style, _, _, _| style.bg(rgb(0x181825)))
+            .on_drop(move |dragged: &DraggedPlayer, window, cx| {
+                window.dispatch_action(
+                    Box::new(OpenCompare {
+                        left: shown.clone(),
+                        right: dragged.0.clone(),
+                    }),
+                    cx,
+                );
+            })
+            // Header
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_2xl()
+                            .font_weight(FontWeight::BOLD)
+                            .child(format!("Player: {}", self.player_id))
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .when(self.editing_layout, |parent| {
+                                parent.child(
+                                    div()
+                                        .px_3()
+                                        .py_1()
+                                        .text_sm()
+                                        .bg(rgb(0x313244))
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .hover(|s| s.bg(rgb(0x45475a)))
+                                        .focus_ring(&self.reset_layout_focus)
+                                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                            this.update_layout(cx, |layout| layout.clear());
+                                        }))
+                                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                this.update_layout(cx, |layout| layout.clear());
+                                            }
+                                        }))
+                                        .child("Reset layout")
+                                )
+                            })
+                            .child(
+                                div()
+                                    .px_3()
+                                    .py_1()
+                                    .text_sm()
+                                    .bg(if self.editing_layout { rgb(0x89b4fa) } else { rgb(0x313244) })
+                                    .text_color(if self.editing_layout { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(&self.edit_layout_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.toggle_layout_editing(cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.toggle_layout_editing(cx);
+                                        }
+                                    }))
+                                    .child(if self.editing_layout { "Done" } else { "Edit layout" })
+                            )
+                            .child(
+                                div()
+                                    .px_3()
+                                    .py_1()
+                                    .text_sm()
+                                    .bg(if is_my_account { rgb(0x89b4fa) } else { rgb(0x313244) })
+                                    .text_color(if is_my_account { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(&self.account_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.toggle_my_account(cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.toggle_my_account(cx);
+                                        }
+                                    }))
+                                    .child(if is_my_account { "My account" } else { "This is me" })
+                            )
+                            .child(
+                                div()
+                                    .px_3()
+                                    .py_1()
+                                    .text_sm()
+                                    .bg(if is_watched { rgb(0x89b4fa) } else { rgb(0x313244) })
+                                    .text_color(if is_watched { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(&self.watch_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.toggle_watch(cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.toggle_watch(cx);
+                                        }
+                                    }))
+                                    .child(if is_watched { "Watching" } else { "Watch" })
+                            )
+                    )
             )
+            .when(self.editing_layout, |parent| {
+                parent.child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(div().text_sm().text_color(rgb(0x9399b2)).child("Pin to home:"))
+                        .children(TileKind::ALL.into_iter().zip(self.pin_focus.clone()).map(|(kind, focus)| {
+                            let pinned = settings.is_pinned(kind, &self.platform, &self.player_id);
+                            div()
+                                .px_3()
+                                .py_1()
+                                .text_sm()
+                                .bg(if pinned { rgb(0x89b4fa) } else { rgb(0x313244) })
+                                .text_color(if pinned { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(|s| s.bg(rgb(0x45475a)))
+                                .focus_ring(&focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.toggle_pin(kind, cx);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.toggle_pin(kind, cx);
+                                    }
+                                }))
+                                .child(kind.label())
+                        })),
+                )
+            })
+            .child(self.render_panels(
+                vec![
+                    (ProfilePanel::Summary, summary_panel),
+                    (ProfilePanel::Activity, activity_panel),
+                    (ProfilePanel::Records, records_panel),
+                    (ProfilePanel::Phases, phases_panel),
+                    (ProfilePanel::Teamplay, teamplay_panel),
+                    (ProfilePanel::RankProgress, rank_panel),
+                    (ProfilePanel::Matchups, matchups_panel),
+                    (ProfilePanel::Compositions, compositions_panel),
+                    (ProfilePanel::Suggestions, suggestions_panel),
+                    (ProfilePanel::Plugins, plugin_panel),
+                    (ProfilePanel::Matches, Some(matches_panel)),
+                ],
+                &spacing,
+                cx,
+            ))
     }
 }
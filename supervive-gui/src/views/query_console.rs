@@ -0,0 +1,379 @@
+use crate::archive_query::{self, QueryResult, EXAMPLES, MAX_ROWS, SCHEMA};
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::text_input::{impl_text_input_handler, input_handler, InputChange, TextInput};
+use crate::format;
+use crate::state::AppState;
+use crate::tasks::RequestGeneration;
+use crate::telemetry;
+use gpui::prelude::*;
+use gpui::*;
+use rusqlite::Connection;
+use std::sync::{Arc, Mutex};
+
+const CELL_WIDTH: f32 = 140.0;
+/// Tallest the results grid grows before it scrolls.
+const GRID_HEIGHT: f32 = 520.0;
+
+/// Read-only SQL over a snapshot of the match archive and the linked accounts' cached history,
+/// for questions the dashboards don't answer.
+pub struct QueryConsoleView {
+    scroll_handle: ScrollHandle,
+    grid_scroll: ScrollHandle,
+    input: TextInput,
+    focus_handle: FocusHandle,
+    /// Built on first use and kept until "Reload data"
+    db: Option<Arc<Mutex<Connection>>>,
+    loading: bool,
+    running: bool,
+    result: Option<QueryResult>,
+    error: Option<String>,
+    export_status: Option<Result<String, String>>,
+    requests: RequestGeneration,
+    run_focus: FocusHandle,
+    export_focus: FocusHandle,
+    reload_focus: FocusHandle,
+    example_focus: Vec<FocusHandle>,
+}
+
+impl QueryConsoleView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            grid_scroll: ScrollHandle::new(),
+            input: TextInput::default(),
+            focus_handle: cx.focus_handle(),
+            db: None,
+            loading: false,
+            running: false,
+            result: None,
+            error: None,
+            export_status: None,
+            requests: RequestGeneration::default(),
+            run_focus: cx.focus_handle().tab_stop(true),
+            export_focus: cx.focus_handle().tab_stop(true),
+            reload_focus: cx.focus_handle().tab_stop(true),
+            example_focus: Vec::new(),
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Query archive".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    /// Drops the snapshot so the next query sees newly imported matches and history.
+    pub fn reload(&mut self, cx: &mut Context<Self>) {
+        self.db = None;
+        self.run_query(cx);
+    }
+
+    fn paste(&mut self, cx: &mut Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        // Queries are often copied over several lines; the box holds one
+        if self.input.replace_utf16(None, &text.replace(['\r', '\n', '\t'], " ")) {
+            cx.notify();
+        }
+    }
+
+    fn use_example(&mut self, sql: &str, cx: &mut Context<Self>) {
+        self.input.set_text(sql);
+        self.run_query(cx);
+    }
+
+    fn run_query(&mut self, cx: &mut Context<Self>) {
+        let sql = self.input.text().trim().to_string();
+        if sql.is_empty() {
+            return;
+        }
+        telemetry::track(cx, "archive_query");
+        let app_state = cx.global::<AppState>();
+        let (service, archive) = (app_state.service.clone(), app_state.match_archive.clone());
+        let accounts = app_state.settings().accounts;
+        let db = self.db.clone();
+        let request = self.requests.start();
+        self.loading = db.is_none();
+        self.running = true;
+        self.error = None;
+        self.export_status = None;
+        cx.notify();
+
+        cx.spawn(async move |view, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let db = match db {
+                        Some(db) => db,
+                        None => {
+                            let archive = archive.lock().unwrap();
+                            let mut service = service.lock().unwrap();
+                            Arc::new(Mutex::new(archive_query::snapshot(&archive, &mut service, &accounts)?))
+                        }
+                    };
+                    let result = archive_query::run(&db.lock().unwrap(), &sql);
+                    anyhow::Ok((db, result))
+                })
+                .await;
+
+            view.update(cx, |this, cx| {
+                if !this.requests.is_current(request) {
+                    return;
+                }
+                this.loading = false;
+                this.running = false;
+                match result {
+                    Ok((db, result)) => {
+                        this.db = Some(db);
+                        match result {
+                            Ok(result) => {
+                                this.result = Some(result);
+                                this.grid_scroll.set_offset(Point::default());
+                            }
+                            Err(err) => this.error = Some(err.to_string()),
+                        }
+                    }
+                    Err(err) => this.error = Some(format!("Couldn't load the archive: {}", err)),
+                }
+                cx.notify();
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn export(&mut self, cx: &mut Context<Self>) {
+        let Some(result) = self.result.clone() else {
+            return;
+        };
+        telemetry::track(cx, "export_query");
+        self.export_status = Some(
+            archive_query::export_csv(&result)
+                .map(|path| format!("Saved to {}", path.display()))
+                .map_err(|err| format!("Export failed: {}", err)),
+        );
+        cx.notify();
+    }
+
+    fn button(label: &'static str, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_sm()
+            .rounded_md()
+            .bg(rgb(0x313244))
+            .cursor_pointer()
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label)
+    }
+
+    fn render_grid(result: &QueryResult) -> Div {
+        let cell = |text: String| {
+            div()
+                .w(px(CELL_WIDTH))
+                .flex_none()
+                .px_2()
+                .py_1()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(text)
+        };
+        div()
+            .flex()
+            .flex_col()
+            .text_sm()
+            .child(
+                div()
+                    .flex()
+                    .bg(rgb(0x11111b))
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .children(result.columns.iter().map(|column| cell(column.clone()))),
+            )
+            .children(result.rows.iter().enumerate().map(|(idx, row)| {
+                div()
+                    .flex()
+                    .when(idx % 2 == 1, |row| row.bg(rgb(0x181825)))
+                    .children(row.iter().map(|value| cell(value.clone())))
+            }))
+    }
+}
+
+impl_text_input_handler!(QueryConsoleView, input, |_: &mut QueryConsoleView, _: &mut Context<QueryConsoleView>| {});
+
+impl Render for QueryConsoleView {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let spacing = cx.global::<AppState>().settings().density.spacing();
+        sync_focus_handles(&mut self.example_focus, EXAMPLES.len(), cx);
+        let input_focused = self.focus_handle.is_focused(window);
+        let status = match (&self.result, self.loading, self.running) {
+            (_, true, _) => Some("Loading the archive…".to_string()),
+            (_, _, true) => Some("Running…".to_string()),
+            (Some(result), _, _) if result.truncated > 0 => Some(format!(
+                "Showing the first {} of {} rows",
+                format::integer(MAX_ROWS as i64),
+                format::integer((MAX_ROWS + result.truncated) as i64)
+            )),
+            (Some(result), _, _) if result.rows.len() == 1 => Some("1 row".to_string()),
+            (Some(result), _, _) => Some(format!("{} rows", format::integer(result.rows.len() as i64))),
+            (None, _, _) => None,
+        };
+
+        div()
+            .id("query-console-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Query archive"))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child("SQL (SQLite) over your imported matches and your linked accounts' loaded history. Queries run on a read-only copy.")
+                    .children(SCHEMA.lines().map(|table| div().font_family("monospace").text_xs().child(table.to_string()))),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap_2()
+                    .children(EXAMPLES.iter().zip(&self.example_focus).map(|((label, sql), focus)| {
+                        let sql = *sql;
+                        div()
+                            .px_2()
+                            .py_1()
+                            .text_xs()
+                            .rounded_md()
+                            .bg(rgb(0x181825))
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.use_example(sql, cx)))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.use_example(sql, cx);
+                                }
+                            }))
+                            .child(*label)
+                    })),
+            )
+            .child(
+                div()
+                    .p_2()
+                    .bg(rgb(0x313244))
+                    .rounded_md()
+                    .border_1()
+                    .border_color(if input_focused { rgb(0xf9e2af) } else { rgb(0x45475a) })
+                    .font_family("monospace")
+                    .text_sm()
+                    .cursor_text()
+                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                        window.focus(&this.focus_handle);
+                        cx.notify();
+                    }))
+                    .child(
+                        div()
+                            .track_focus(&self.focus_handle)
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                let keystroke = &event.keystroke;
+                                match keystroke.key.as_str() {
+                                    "enter" => {
+                                        this.run_query(cx);
+                                        return;
+                                    }
+                                    "v" if keystroke.modifiers.secondary() => {
+                                        this.paste(cx);
+                                        return;
+                                    }
+                                    _ => {}
+                                }
+                                if this.input.handle_key(keystroke) != InputChange::Ignored {
+                                    cx.notify();
+                                }
+                            }))
+                            .relative()
+                            .child(self.input.render_text(input_focused, "SELECT … FROM participants"))
+                            .child(input_handler(cx.entity(), self.focus_handle.clone())),
+                    ),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        Self::button("Run", &self.run_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.run_query(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.run_query(cx);
+                                }
+                            })),
+                    )
+                    .when(self.result.is_some(), |row| {
+                        row.child(
+                            Self::button("Export CSV", &self.export_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.export(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.export(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .child(
+                        Self::button("Reload data", &self.reload_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.reload(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.reload(cx);
+                                }
+                            })),
+                    )
+                    .children(status.map(|status| div().text_sm().text_color(rgb(0x9399b2)).child(status))),
+            )
+            .when_some(self.export_status.clone(), |parent, status| {
+                let (text, color) = match status {
+                    Ok(text) => (text, rgb(0xa6e3a1)),
+                    Err(text) => (text, rgb(0xf38ba8)),
+                };
+                parent.child(div().text_sm().text_color(color).child(text))
+            })
+            .when_some(self.error.clone(), |parent, error| {
+                parent.child(
+                    div()
+                        .p_3()
+                        .text_sm()
+                        .bg(rgb(0x45273a))
+                        .text_color(rgb(0xf38ba8))
+                        .rounded_md()
+                        .font_family("monospace")
+                        .child(error),
+                )
+            })
+            .when_some(self.result.as_ref(), |parent, result| {
+                parent.child(
+                    div()
+                        .id("query-results")
+                        .max_h(px(GRID_HEIGHT))
+                        .overflow_scroll()
+                        .track_scroll(&self.grid_scroll)
+                        .rounded_md()
+                        .border_1()
+                        .border_color(rgb(0x313244))
+                        .child(Self::render_grid(result)),
+                )
+            })
+    }
+}
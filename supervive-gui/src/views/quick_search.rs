@@ -0,0 +1,171 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::text_input::{impl_text_input_handler, input_handler, InputChange, TextInput};
+use crate::components::truncated_text::TruncatedText;
+use crate::player_index::IndexedPlayer;
+use crate::state::AppState;
+use crate::telemetry;
+use crate::views::root::RootView;
+use crate::views::{OpenPlayer, SearchPlayer};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Players from the index offered while typing.
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Search box that drops down from the header on any page (Ctrl+F by default). Enter runs the
+/// same search as the search page and a suggestion opens that profile; either closes it.
+pub struct QuickSearch {
+    root: WeakEntity<RootView>,
+    input: TextInput,
+    focus_handle: FocusHandle,
+    suggestions: Vec<IndexedPlayer>,
+    suggestion_focus: Vec<FocusHandle>,
+}
+
+impl QuickSearch {
+    pub fn new(root: WeakEntity<RootView>, cx: &mut Context<Self>) -> Self {
+        Self {
+            root,
+            input: TextInput::default(),
+            focus_handle: cx.focus_handle(),
+            suggestions: Vec::new(),
+            suggestion_focus: Vec::new(),
+        }
+    }
+
+    pub fn focus(&self, window: &mut Window) {
+        window.focus(&self.focus_handle);
+    }
+
+    fn close(&self, cx: &mut Context<Self>) {
+        self.root.update(cx, |root, cx| root.close_quick_search(cx)).ok();
+    }
+
+    fn submit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let query = self.input.text().trim().to_string();
+        if query.is_empty() {
+            return;
+        }
+        telemetry::track(cx, "quick_search");
+        // Dispatched before closing so it still bubbles from the popover up to the root view
+        window.dispatch_action(Box::new(SearchPlayer { query }), cx);
+        self.close(cx);
+    }
+
+    fn open_suggestion(&mut self, player: &IndexedPlayer, window: &mut Window, cx: &mut Context<Self>) {
+        window.dispatch_action(
+            Box::new(OpenPlayer {
+                player_id: player.player_id.clone(),
+                platform: player.platform.clone(),
+            }),
+            cx,
+        );
+        self.close(cx);
+    }
+
+    fn update_suggestions(&mut self, cx: &mut Context<Self>) {
+        let index = cx.global::<AppState>().player_index.clone();
+        self.suggestions = index.lock().unwrap().search(self.input.text(), MAX_SUGGESTIONS);
+    }
+}
+
+impl_text_input_handler!(QuickSearch, input, |this: &mut QuickSearch, cx: &mut Context<QuickSearch>| {
+    this.update_suggestions(cx)
+});
+
+impl Render for QuickSearch {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        sync_focus_handles(&mut self.suggestion_focus, self.suggestions.len(), cx);
+        let input_focused = self.focus_handle.is_focused(window);
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .w(px(320.0))
+            .p_2()
+            .mt_1()
+            .rounded_md()
+            .bg(rgb(0x181825))
+            .border_1()
+            .border_color(rgb(0x313244))
+            .text_sm()
+            // Escape closes from the box or any suggestion; a click elsewhere dismisses it too
+            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                if event.keystroke.key == "escape" {
+                    this.close(cx);
+                }
+            }))
+            .on_mouse_down_out(cx.listener(|this, _, _window, cx| this.close(cx)))
+            .child(
+                div()
+                    .p_2()
+                    .bg(rgb(0x313244))
+                    .rounded_md()
+                    .border_1()
+                    .border_color(if input_focused { rgb(0xf9e2af) } else { rgb(0x45475a) })
+                    .cursor_text()
+                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                        this.focus(window);
+                        cx.notify();
+                    }))
+                    .child(
+                        div()
+                            .track_focus(&self.focus_handle)
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if event.keystroke.key == "enter" {
+                                    this.submit(window, cx);
+                                    return;
+                                }
+                                match this.input.handle_key(&event.keystroke) {
+                                    InputChange::Edited => {
+                                        this.update_suggestions(cx);
+                                        cx.notify();
+                                    }
+                                    InputChange::Moved => cx.notify(),
+                                    InputChange::Ignored => {}
+                                }
+                            }))
+                            .relative()
+                            .child(self.input.render_text(input_focused, "Search player, profile URL or ID..."))
+                            .child(input_handler(cx.entity(), self.focus_handle.clone())),
+                    ),
+            )
+            .children(self.suggestions.iter().zip(&self.suggestion_focus).enumerate().map(|(idx, (player, focus))| {
+                let click_player = player.clone();
+                let key_player = player.clone();
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .px_2()
+                    .py_1()
+                    .rounded_sm()
+                    .cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(focus)
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                        this.open_suggestion(&click_player, window, cx);
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                        if is_activation_key(event) {
+                            this.open_suggestion(&key_player, window, cx);
+                        }
+                    }))
+                    .child(TruncatedText::new(("quick-search-name", idx), player.unique_display_name.clone()).flex_1())
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(rgb(0x6c7086))
+                            .child(player.platform.to_uppercase()),
+                    )
+            }))
+            .child(
+                div()
+                    .px_1()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child("Enter to search · Esc to close"),
+            )
+    }
+}
@@ -0,0 +1,169 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::format;
+use crate::match_notes::MatchNote;
+use crate::state::AppState;
+use crate::views::OpenMatch;
+use gpui::prelude::*;
+use gpui::*;
+
+/// Every tagged or annotated match across the archive, filterable by tag.
+pub struct ReviewsView {
+    scroll_handle: ScrollHandle,
+    /// Only matches with this tag; `None` lists every note
+    tag_filter: Option<String>,
+    /// "All", then one per tag
+    filter_focus: Vec<FocusHandle>,
+    row_focus: Vec<FocusHandle>,
+}
+
+impl ReviewsView {
+    pub fn new(_cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            tag_filter: None,
+            filter_focus: Vec::new(),
+            row_focus: Vec::new(),
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Reviews".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    fn set_filter(&mut self, tag: Option<String>, cx: &mut Context<Self>) {
+        self.tag_filter = tag;
+        cx.notify();
+    }
+
+    fn summary(note: &MatchNote) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(start) = note.match_start {
+            parts.push(format::date_time(&start));
+        }
+        if let Some(hero) = &note.hero {
+            parts.push(hero.clone());
+        }
+        if let Some(placement) = note.placement {
+            parts.push(format!("#{}", placement));
+        }
+        if parts.is_empty() {
+            parts.push(format!("Match {}", note.match_id.chars().take(8).collect::<String>()));
+        }
+        parts.join(" · ")
+    }
+}
+
+impl Render for ReviewsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let spacing = cx.global::<AppState>().settings().density.spacing();
+        let (tags, notes) = {
+            let store = cx.global::<AppState>().match_notes.lock().unwrap();
+            (store.all_tags(), store.list(self.tag_filter.as_deref()))
+        };
+        sync_focus_handles(&mut self.filter_focus, tags.len() + 1, cx);
+        sync_focus_handles(&mut self.row_focus, notes.len(), cx);
+        let filters: Vec<Option<String>> = std::iter::once(None).chain(tags.into_iter().map(Some)).collect();
+
+        div()
+            .id("reviews-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Reviews"))
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap_2()
+                    .children(filters.into_iter().zip(&self.filter_focus).map(|(tag, focus)| {
+                        let selected = tag == self.tag_filter;
+                        let label = tag.clone().unwrap_or_else(|| "All".to_string());
+                        let key_tag = tag.clone();
+                        div()
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .text_sm()
+                            .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                            .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.set_filter(tag.clone(), cx);
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.set_filter(key_tag.clone(), cx);
+                                }
+                            }))
+                            .child(label)
+                    })),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(spacing.row_gap)
+                    .when(notes.is_empty(), |parent| {
+                        parent.child(
+                            div()
+                                .text_sm()
+                                .text_color(rgb(0x9399b2))
+                                .child("Tag a match or add a note from its page to list it here."),
+                        )
+                    })
+                    .children(notes.iter().zip(&self.row_focus).map(|(note, focus)| {
+                        let action = OpenMatch {
+                            match_id: note.match_id.clone(),
+                            platform: note.platform.clone(),
+                        };
+                        let key_action = action.clone();
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_1()
+                            .p(spacing.row)
+                            .bg(rgb(0x181825))
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(focus)
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                window.dispatch_action(Box::new(action.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_action.clone()), cx);
+                                }
+                            })
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(div().flex_1().font_weight(FontWeight::SEMIBOLD).child(Self::summary(note)))
+                                    .children(note.tags.iter().map(|tag| {
+                                        div()
+                                            .px_2()
+                                            .text_xs()
+                                            .rounded_md()
+                                            .bg(rgb(0x313244))
+                                            .child(tag.clone())
+                                    })),
+                            )
+                            .when(!note.note.is_empty(), |row| {
+                                row.child(div().text_sm().text_color(rgb(0xbac2de)).child(note.note.clone()))
+                            })
+                    })),
+            )
+    }
+}
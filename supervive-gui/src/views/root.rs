@@ -1,70 +1,1065 @@
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::tooltip::Tooltip;
+use crate::components::truncated_text::TruncatedText;
+use crate::connectivity;
+use crate::demo;
+use crate::frame_stats::FrameStats;
+use crate::overlay;
+use crate::settings::PlayerRef;
+use crate::views::compare::CompareView;
+use crate::state::AppState;
+use crate::steam;
+use crate::telemetry;
+use crate::updates::{self, ReleaseInfo};
+use crate::views::jobs::JobsView;
+use crate::views::lobby::LobbyView;
+use crate::views::logs::LogsView;
 use crate::views::match_detail::MatchDetailView;
-use crate::views::player::PlayerView;
+use crate::views::match_import::MatchImportView;
+use crate::views::meta::MetaView;
+use crate::views::modal::{Modal, MODAL_FOCUS_SLOTS};
+use crate::views::onboarding::OnboardingView;
+use crate::views::query_console::QueryConsoleView;
+use crate::views::quick_search::QuickSearch;
+use crate::views::player::{PlayerNotFound, PlayerView};
+use crate::views::reviews::ReviewsView;
+use crate::views::scout::ScoutView;
 use crate::views::search::SearchView;
-use crate::views::{OpenMatch, OpenPlayer};
+use crate::views::series::SeriesView;
+use crate::views::settings::SettingsView;
+use crate::views::{
+    DraggedPlayer, FocusNext, FocusPrevious, GoBack, GoHome, OpenCompare, OpenJobs, OpenLobby,
+    OpenLogs, OpenMatch, OpenMatchImport, OpenMeta, OpenPlayer, OpenQueryConsole, OpenQuickSearch, OpenReviews,
+    OpenScout, OpenSeries, OpenSettings, Refresh, SearchPlayer, ToggleOverlay,
+};
 use gpui::prelude::*;
 use gpui::*;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Maximum number of player/match views kept alive for instant re-opening.
+const VIEW_CACHE_CAPACITY: usize = 16;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum ViewKey {
+    Player { platform: String, player_id: String },
+    Match { platform: String, match_id: String },
+    Compare { left: (String, String), right: (String, String) },
+    Settings,
+    Jobs,
+    Logs,
+    Reviews,
+    Lobby,
+    Scout,
+    MatchImport,
+    QueryConsole,
+    Series,
+    Meta,
+}
+
+/// Least-recently-used cache of opened views, most recent at the front.
+struct ViewCache {
+    entries: VecDeque<(ViewKey, NavEntry)>,
+}
+
+impl ViewCache {
+    fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: &ViewKey) -> Option<NavEntry> {
+        let idx = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(idx)?;
+        let result = entry.1.clone();
+        self.entries.push_front(entry);
+        Some(result)
+    }
+
+    fn remove(&mut self, key: &ViewKey) {
+        self.entries.retain(|(k, _)| k != key);
+    }
+
+    /// The cached entry for `key`, or a new one from `create`, cached from then on.
+    fn get_or_insert_with(&mut self, key: ViewKey, create: impl FnOnce() -> NavEntry) -> NavEntry {
+        if let Some(entry) = self.get(&key) {
+            return entry;
+        }
+        let entry = create();
+        self.insert(key, entry.clone());
+        entry
+    }
+
+    fn insert(&mut self, key: ViewKey, entry: NavEntry) {
+        self.entries.retain(|(k, _)| k != &key);
+        self.entries.push_front((key, entry));
+        self.entries.truncate(VIEW_CACHE_CAPACITY);
+    }
+}
+
+type RefreshHandler = Rc<dyn Fn(&mut App)>;
+
+/// A view on the back stack. The entity stays alive while it is buried so going back is
+/// instant, and its scroll offset is saved when covered and restored when re-activated.
+#[derive(Clone)]
+struct NavEntry {
+    view: AnyView,
+    scroll_handle: ScrollHandle,
+    scroll_offset: Point<Pixels>,
+    title: Rc<dyn Fn(&App) -> SharedString>,
+    refresh: Option<RefreshHandler>,
+    /// The profile shown, so players dropped on its breadcrumb can be compared with it
+    player: Option<PlayerRef>,
+}
+
+impl NavEntry {
+    fn new<V: Render>(
+        entity: Entity<V>,
+        scroll_handle: ScrollHandle,
+        title: impl Fn(&V) -> SharedString + 'static,
+    ) -> Self {
+        let title_entity = entity.clone();
+        Self {
+            view: entity.into(),
+            scroll_handle,
+            scroll_offset: Point::default(),
+            title: Rc::new(move |cx| title(title_entity.read(cx))),
+            refresh: None,
+            player: None,
+        }
+    }
+
+    fn with_player(mut self, platform: &str, player_id: &str) -> Self {
+        self.player = Some(PlayerRef {
+            platform: platform.to_string(),
+            player_id: player_id.to_string(),
+            name: String::new(),
+        });
+        self
+    }
+
+    /// Lets the `Refresh` command reload this view.
+    fn with_refresh<V: Render>(
+        mut self,
+        entity: Entity<V>,
+        refresh: impl Fn(&mut V, &mut Context<V>) + 'static,
+    ) -> Self {
+        self.refresh = Some(Rc::new(move |cx| entity.update(cx, |view, cx| refresh(view, cx))));
+        self
+    }
+}
+
+const APP_TITLE: &str = "Supervive Dashboard";
 
 pub struct RootView {
-    active_view: AnyView,
+    stack: Vec<NavEntry>,
+    search_view: Entity<SearchView>,
+    view_cache: ViewCache,
+    window_title: String,
+    back_focus: FocusHandle,
+    home_focus: FocusHandle,
+    settings_focus: FocusHandle,
+    jobs_focus: FocusHandle,
+    reviews_focus: FocusHandle,
+    my_account_focus: FocusHandle,
+    account_menu_focus: FocusHandle,
+    account_menu_open: bool,
+    account_focus: Vec<FocusHandle>,
+    launch_focus: FocusHandle,
+    quick_search_focus: FocusHandle,
+    /// Header search popover, open while set
+    quick_search: Option<Entity<QuickSearch>>,
+    crumb_focus: Vec<FocusHandle>,
+    available_update: Option<ReleaseInfo>,
+    update_focus: Vec<FocusHandle>,
+    clipboard_focus: Vec<FocusHandle>,
+    offline_focus: FocusHandle,
+    /// Profile that turned out not to exist, reported in a banner after it is closed
+    missing_player: Option<String>,
+    missing_player_focus: FocusHandle,
+    /// Fed every frame while the frame-time overlay is on
+    frame_stats: FrameStats,
+    /// First-run wizard, shown instead of the page until finished or skipped
+    onboarding: Option<Entity<OnboardingView>>,
+    demo_focus: FocusHandle,
+    /// Dialog covering the window, see `views::modal`
+    modal: Option<Modal>,
+    modal_focus: Vec<FocusHandle>,
 }
 
 impl RootView {
-    pub fn new(cx: &mut Context<Self>, window: &mut Window) -> Self {
-        let search_view = cx.new(|cx| SearchView::new(cx));
+    pub fn new(cx: &mut Context<Self>, _window: &mut Window) -> Self {
+        let search_view = cx.new(SearchView::new);
+        let scroll_handle = search_view.read(cx).scroll_handle();
+        let settings = cx.global::<AppState>().settings();
+        if settings.check_for_updates && !demo::is_enabled() {
+            Self::check_for_updates(cx);
+        }
+        // Existing users who linked an account before the wizard existed skip it
+        let onboarding = (!settings.onboarding_done && settings.my_account.is_none()).then(|| {
+            let root = cx.entity().downgrade();
+            cx.new(|cx| OnboardingView::new(root, cx))
+        });
         Self {
-            active_view: search_view.into(),
+            stack: vec![NavEntry::new(search_view.clone(), scroll_handle, |view| view.title())],
+            search_view,
+            view_cache: ViewCache::new(),
+            window_title: APP_TITLE.to_string(),
+            back_focus: cx.focus_handle().tab_stop(true),
+            home_focus: cx.focus_handle().tab_stop(true),
+            settings_focus: cx.focus_handle().tab_stop(true),
+            jobs_focus: cx.focus_handle().tab_stop(true),
+            reviews_focus: cx.focus_handle().tab_stop(true),
+            my_account_focus: cx.focus_handle().tab_stop(true),
+            account_menu_focus: cx.focus_handle().tab_stop(true),
+            account_menu_open: false,
+            account_focus: Vec::new(),
+            launch_focus: cx.focus_handle().tab_stop(true),
+            quick_search_focus: cx.focus_handle().tab_stop(true),
+            quick_search: None,
+            crumb_focus: Vec::new(),
+            available_update: None,
+            update_focus: Vec::new(),
+            clipboard_focus: Vec::new(),
+            offline_focus: cx.focus_handle().tab_stop(true),
+            missing_player: None,
+            missing_player_focus: cx.focus_handle().tab_stop(true),
+            frame_stats: FrameStats::default(),
+            onboarding,
+            demo_focus: cx.focus_handle().tab_stop(true),
+            modal: None,
+            modal_focus: Vec::new(),
+        }
+    }
+
+    pub fn open_modal(&mut self, modal: Modal, window: &mut Window, cx: &mut Context<Self>) {
+        self.modal = Some(modal);
+        sync_focus_handles(&mut self.modal_focus, MODAL_FOCUS_SLOTS, cx);
+        window.focus(&self.modal_focus[0]);
+        cx.notify();
+    }
+
+    pub fn close_modal(&mut self, cx: &mut Context<Self>) {
+        self.modal = None;
+        cx.notify();
+    }
+
+    /// Passes a key press to an open text prompt.
+    pub fn edit_modal(&mut self, key: &str, cx: &mut Context<Self>) {
+        if self.modal.as_mut().is_some_and(|modal| modal.edit(key)) {
+            cx.notify();
+        }
+    }
+
+    /// Runs the dialog's action and closes it; an empty prompt stays open.
+    pub fn submit_modal(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(modal) = self.modal.take() else {
+            return;
+        };
+        if modal.submit(window, cx) {
+            cx.notify();
+        } else {
+            self.modal = Some(modal);
+        }
+    }
+
+    pub fn finish_onboarding(&mut self, cx: &mut Context<Self>) {
+        self.onboarding = None;
+        self.search_view.update(cx, |search, cx| search.load_report(cx));
+        cx.notify();
+    }
+
+    fn check_for_updates(cx: &mut Context<Self>) {
+        cx.spawn(async move |view, cx| {
+            let result = cx.background_executor().spawn(async move { updates::check_latest() }).await;
+            match result {
+                Ok(Some(release)) => {
+                    view.update(cx, |this, cx| {
+                        this.available_update = Some(release);
+                        cx.notify();
+                    }).ok();
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::warn!(error = %err, "Update check failed");
+                    view.update(cx, |_, cx| telemetry::track_error(cx, "update_check_failed")).ok();
+                }
+            }
+        }).detach();
+    }
+
+    fn handle_search_player(&mut self, action: &SearchPlayer, window: &mut Window, cx: &mut Context<Self>) {
+        self.go_home(cx);
+        let query = action.query.clone();
+        self.search_view.update(cx, |view, cx| view.search_for(query, window, cx));
+    }
+
+    /// Focuses the header search box, opening it first. On the search page the page's own box is
+    /// focused instead.
+    fn open_quick_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.stack.len() == 1 && self.onboarding.is_none() {
+            self.search_view.read(cx).focus_input(window);
+            return;
+        }
+        let root = cx.entity().downgrade();
+        let quick_search = self
+            .quick_search
+            .get_or_insert_with(|| cx.new(|cx| QuickSearch::new(root, cx)))
+            .clone();
+        quick_search.read(cx).focus(window);
+        cx.notify();
+    }
+
+    pub fn close_quick_search(&mut self, cx: &mut Context<Self>) {
+        self.quick_search = None;
+        cx.notify();
+    }
+
+    /// Makes `account` the active one for the header shortcut and the session overlay.
+    fn switch_account(&mut self, account: PlayerRef, cx: &mut Context<Self>) {
+        telemetry::track(cx, "switch_account");
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| s.my_account = Some(account));
+        self.account_menu_open = false;
+        overlay::refresh(cx);
+        self.search_view.update(cx, |view, cx| view.load_report(cx));
+        cx.refresh_windows();
+    }
+
+    fn toggle_account_menu(&mut self, cx: &mut Context<Self>) {
+        self.account_menu_open = !self.account_menu_open;
+        cx.notify();
+    }
+
+    /// Drop-down listing the linked accounts, shown below the header switcher.
+    fn render_account_menu(&self, accounts: Vec<PlayerRef>, active: Option<&PlayerRef>, cx: &mut Context<Self>) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .min_w(px(180.0))
+            .p_1()
+            .mt_1()
+            .rounded_md()
+            .bg(rgb(0x181825))
+            .border_1()
+            .border_color(rgb(0x313244))
+            .children(accounts.into_iter().zip(self.account_focus.iter()).map(|(account, focus)| {
+                let is_active = active == Some(&account);
+                let key_account = account.clone();
+                div()
+                    .flex()
+                    .justify_between()
+                    .gap_3()
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .rounded_sm()
+                    .cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(focus)
+                    .when(is_active, |row| row.text_color(rgb(0x89b4fa)))
+                    .child(account.name.clone())
+                    .child(div().text_color(rgb(0x9399b2)).child(account.platform.clone()))
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                        this.switch_account(account.clone(), cx);
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.switch_account(key_account.clone(), cx);
+                        }
+                    }))
+            }))
+    }
+
+    fn take_clipboard_name(cx: &mut Context<Self>) -> Option<String> {
+        let name = cx.global::<AppState>().clipboard_name.lock().unwrap().take();
+        cx.notify();
+        name
+    }
+
+    fn look_up_clipboard_name(window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(query) = Self::take_clipboard_name(cx) {
+            telemetry::track(cx, "clipboard_lookup");
+            window.dispatch_action(Box::new(SearchPlayer { query }), cx);
+        }
+    }
+
+    /// One-click prompt for a player name the clipboard watcher picked up.
+    fn render_clipboard_prompt(&self, name: &str, cx: &mut Context<Self>) -> Div {
+        div()
+            .flex()
+            .items_center()
+            .gap_3()
+            .px_4()
+            .py_2()
+            .bg(rgb(0x181825))
+            .border_b_1()
+            .border_color(rgb(0xf9e2af))
+            .child(div().flex_1().child(format!("Look up {}?", name)))
+            .child(
+                div()
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .bg(rgb(0x313244))
+                    .hover(|s| s.bg(rgb(0x45475a)))
+                    .focus_ring(&self.clipboard_focus[0])
+                    .on_mouse_down(MouseButton::Left, cx.listener(|_, _, window, cx| {
+                        Self::look_up_clipboard_name(window, cx);
+                    }))
+                    .on_key_down(cx.listener(|_, event: &KeyDownEvent, window, cx| {
+                        if is_activation_key(event) {
+                            Self::look_up_clipboard_name(window, cx);
+                        }
+                    }))
+                    .child("Look up"),
+            )
+            .child(
+                div()
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(&self.clipboard_focus[1])
+                    .on_mouse_down(MouseButton::Left, cx.listener(|_, _, _window, cx| {
+                        Self::take_clipboard_name(cx);
+                    }))
+                    .on_key_down(cx.listener(|_, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            Self::take_clipboard_name(cx);
+                        }
+                    }))
+                    .child("Dismiss"),
+            )
+    }
+
+    /// Shown while op.gg can't be reached; pages keep working from cached data.
+    fn render_offline_banner(&self) -> Div {
+        div()
+            .flex()
+            .items_center()
+            .gap_3()
+            .px_4()
+            .py_2()
+            .bg(rgb(0x181825))
+            .border_b_1()
+            .border_color(rgb(0xf38ba8))
+            .child(
+                div()
+                    .flex_1()
+                    .child("You're offline. Showing cached data until op.gg can be reached again."),
+            )
+            .child(
+                div()
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .bg(rgb(0x313244))
+                    .hover(|s| s.bg(rgb(0x45475a)))
+                    .focus_ring(&self.offline_focus)
+                    .on_mouse_down(MouseButton::Left, |_, _window, cx| connectivity::check_now(cx))
+                    .on_key_down(|event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            connectivity::check_now(cx);
+                        }
+                    })
+                    .child("Check now"),
+            )
+    }
+
+    fn render_missing_player_banner(&self, message: &str, cx: &mut Context<Self>) -> Div {
+        div()
+            .flex()
+            .items_center()
+            .gap_3()
+            .px_4()
+            .py_2()
+            .bg(rgb(0x181825))
+            .border_b_1()
+            .border_color(rgb(0xf38ba8))
+            .child(div().flex_1().child(message.to_string()))
+            .child(
+                div()
+                    .px_2()
+                    .py_1()
+                    .text_sm()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(&self.missing_player_focus)
+                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                        this.missing_player = None;
+                        cx.notify();
+                    }))
+                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.missing_player = None;
+                            cx.notify();
+                        }
+                    }))
+                    .child("Dismiss"),
+            )
+    }
+
+    /// Debug readout in the corner: frame times, live page views and portrait cache counters.
+    fn render_frame_overlay(&self, cx: &App) -> Div {
+        let ms = |duration: Option<std::time::Duration>| {
+            duration.map_or("—".to_string(), |duration| format!("{:.1} ms", duration.as_secs_f64() * 1000.0))
+        };
+        let fps = self
+            .frame_stats
+            .average()
+            .filter(|average| !average.is_zero())
+            .map_or(0.0, |average| 1.0 / average.as_secs_f64());
+        // Buried and cached entries often hold the same entity
+        let mut live_views: Vec<EntityId> = self
+            .stack
+            .iter()
+            .chain(self.view_cache.entries.iter().map(|(_, entry)| entry))
+            .map(|entry| entry.view.entity_id())
+            .collect();
+        live_views.sort();
+        live_views.dedup();
+        let portraits = cx.global::<AppState>().hero_assets.lock().unwrap().stats();
+
+        div()
+            .absolute()
+            .bottom_2()
+            .right_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .px_3()
+            .py_2()
+            .rounded_md()
+            .bg(rgba(0x11111bdd))
+            .border_1()
+            .border_color(rgb(0x45475a))
+            .text_xs()
+            .child(format!(
+                "Frame {} · avg {} · worst {} · {:.0} fps",
+                ms(self.frame_stats.last()),
+                ms(self.frame_stats.average()),
+                ms(self.frame_stats.worst()),
+                fps
+            ))
+            .child(format!(
+                "Views: {} live ({} on the back stack, {} cached)",
+                live_views.len(),
+                self.stack.len(),
+                self.view_cache.entries.len()
+            ))
+            .child(format!(
+                "Portraits: {} from disk, {} remote, {} downloading, {} failed",
+                portraits.local_hits, portraits.remote_loads, portraits.downloading, portraits.failed_downloads
+            ))
+    }
+
+    /// Non-blocking banner for a newer release. Links open in the browser; nothing is installed.
+    fn render_update_banner(&self, release: &ReleaseInfo, cx: &mut Context<Self>) -> Div {
+        let mut links = vec![("View release", release.page_url.clone())];
+        if let Some(url) = &release.download_url {
+            links.push(("Download", url.clone()));
         }
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .px_4()
+            .py_2()
+            .bg(rgb(0x181825))
+            .border_b_1()
+            .border_color(rgb(0x89b4fa))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        div()
+                            .flex_1()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .child(format!(
+                                "Version {} is available (you have {})",
+                                release.version,
+                                updates::CURRENT_VERSION
+                            )),
+                    )
+                    .children(links.into_iter().enumerate().map(|(idx, (label, url))| {
+                        let activate_url = url.clone();
+                        div()
+                            .px_2()
+                            .py_1()
+                            .text_sm()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .bg(rgb(0x313244))
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(&self.update_focus[idx])
+                            .on_mouse_down(MouseButton::Left, move |_, _window, cx| cx.open_url(&url))
+                            .on_key_down(move |event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    cx.open_url(&activate_url);
+                                }
+                            })
+                            .child(label)
+                    }))
+                    .child(
+                        div()
+                            .px_2()
+                            .py_1()
+                            .text_sm()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x313244)))
+                            .focus_ring(&self.update_focus[2])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                this.available_update = None;
+                                cx.notify();
+                            }))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.available_update = None;
+                                    cx.notify();
+                                }
+                            }))
+                            .child("Dismiss"),
+                    ),
+            )
+            .when(!release.changelog.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child(release.changelog.lines().take(6).collect::<Vec<_>>().join("\n")),
+                )
+            })
     }
 
     fn handle_open_player(
         &mut self,
         event: &OpenPlayer,
-        window: &mut Window,
+        _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let player_view =
-            cx.new(|cx| PlayerView::new(cx, event.player_id.clone(), event.platform.clone()));
-        self.active_view = player_view.into();
-        cx.notify();
+        telemetry::track(cx, "open_player");
+        let key = ViewKey::Player {
+            platform: event.platform.clone(),
+            player_id: event.player_id.clone(),
+        };
+        self.missing_player = None;
+        let entry = self.view_cache.get_or_insert_with(key.clone(), || {
+            let player_view = cx.new(|cx| {
+                PlayerView::new(cx, event.player_id.clone(), event.platform.clone())
+            });
+            // Every way of opening a profile ends here, so a bad id is caught once for all of them
+            cx.subscribe(&player_view, {
+                let key = key.clone();
+                let label = format!("No {} player found with id {}", event.platform, event.player_id);
+                move |this, player_view, _: &PlayerNotFound, cx| {
+                    this.close_missing_player(&key, player_view.entity_id(), label.clone(), cx);
+                }
+            })
+            .detach();
+            let scroll_handle = player_view.read(cx).scroll_handle();
+            NavEntry::new(player_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(player_view, |view, cx| view.refresh(cx))
+                .with_player(&event.platform, &event.player_id)
+        });
+        self.push_entry(entry, cx);
     }
 
     fn handle_open_match(
         &mut self,
         event: &OpenMatch,
-        window: &mut Window,
+        _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let match_view =
-            cx.new(|cx| MatchDetailView::new(cx, event.match_id.clone(), event.platform.clone()));
-        self.active_view = match_view.into();
+        telemetry::track(cx, "open_match");
+        let key = ViewKey::Match {
+            platform: event.platform.clone(),
+            match_id: event.match_id.clone(),
+        };
+        let entry = self.view_cache.get_or_insert_with(key, || {
+            let match_view = cx.new(|cx| {
+                MatchDetailView::new(cx, event.match_id.clone(), event.platform.clone())
+            });
+            let scroll_handle = match_view.read(cx).scroll_handle();
+            NavEntry::new(match_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(match_view, |view, cx| view.refresh(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    /// While a player is dragged from a page other than a profile, lists the profiles still
+    /// open in the view cache as drop targets for a comparison.
+    fn render_compare_targets(&self, cx: &mut Context<Self>) -> Option<Div> {
+        /// Most recently opened profiles offered as targets.
+        const SHOWN: usize = 5;
+
+        let on_profile = self.stack.last().is_some_and(|entry| entry.player.is_some());
+        if !cx.has_active_drag() || on_profile {
+            return None;
+        }
+        let targets: Vec<PlayerRef> = self
+            .view_cache
+            .entries
+            .iter()
+            .filter_map(|(_, entry)| {
+                let player = entry.player.clone()?;
+                Some(PlayerRef { name: (entry.title)(cx).to_string(), ..player })
+            })
+            .take(SHOWN)
+            .collect();
+        if targets.is_empty() {
+            return None;
+        }
+        Some(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .px_4()
+                .py_2()
+                .bg(rgb(0x181825))
+                .border_b_1()
+                .border_color(rgb(0x313244))
+                .child(div().text_sm().text_color(rgb(0x9399b2)).child("Drop on a profile to compare:"))
+                .children(targets.into_iter().map(|player| {
+                    div()
+                        .px_2()
+                        .py_1()
+                        .text_sm()
+                        .rounded_md()
+                        .border_1()
+                        .border_color(rgb(0x45475a))
+                        .drag_over::<DraggedPlayer>(|style, _, _, _| style.bg(rgb(0x45475a)).border_color(rgb(0x89b4fa)))
+                        .child(player.name.clone())
+                        .on_drop(move |dragged: &DraggedPlayer, window, cx| {
+                            window.dispatch_action(
+                                Box::new(OpenCompare {
+                                    left: player.clone(),
+                                    right: dragged.0.clone(),
+                                }),
+                                cx,
+                            );
+                        })
+                })),
+        )
+    }
+
+    fn handle_open_compare(&mut self, event: &OpenCompare, cx: &mut Context<Self>) {
+        if event.left.platform == event.right.platform && event.left.player_id == event.right.player_id {
+            return;
+        }
+        let key = ViewKey::Compare {
+            left: (event.left.platform.clone(), event.left.player_id.clone()),
+            right: (event.right.platform.clone(), event.right.player_id.clone()),
+        };
+        let entry = self.view_cache.get_or_insert_with(key, || {
+            let compare_view = cx.new(|cx| CompareView::new(cx, event.left.clone(), event.right.clone()));
+            let scroll_handle = compare_view.read(cx).scroll_handle();
+            NavEntry::new(compare_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(compare_view, |view, cx| view.refresh(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_settings(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "open_settings");
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Settings, || {
+            let settings_view = cx.new(SettingsView::new);
+            let scroll_handle = settings_view.read(cx).scroll_handle();
+            NavEntry::new(settings_view, scroll_handle, |view| view.title())
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_jobs(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "open_jobs");
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Jobs, || {
+            let jobs_view = cx.new(JobsView::new);
+            let scroll_handle = jobs_view.read(cx).scroll_handle();
+            NavEntry::new(jobs_view, scroll_handle, |view| view.title())
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_logs(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "open_logs");
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Logs, || {
+            let logs_view = cx.new(LogsView::new);
+            let scroll_handle = logs_view.read(cx).scroll_handle();
+            NavEntry::new(logs_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(logs_view, |view, cx| view.refresh(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_reviews(&mut self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "open_reviews");
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Reviews, || {
+            let reviews_view = cx.new(ReviewsView::new);
+            let scroll_handle = reviews_view.read(cx).scroll_handle();
+            NavEntry::new(reviews_view, scroll_handle, |view| view.title())
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_lobby(&mut self, cx: &mut Context<Self>) {
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Lobby, || {
+            let lobby_view = cx.new(LobbyView::new);
+            let scroll_handle = lobby_view.read(cx).scroll_handle();
+            NavEntry::new(lobby_view, scroll_handle, |view| view.title())
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_scout(&mut self, cx: &mut Context<Self>) {
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Scout, || {
+            let scout_view = cx.new(ScoutView::new);
+            let scroll_handle = scout_view.read(cx).scroll_handle();
+            NavEntry::new(scout_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(scout_view, |view, cx| view.refresh(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_match_import(&mut self, cx: &mut Context<Self>) {
+        let entry = self.view_cache.get_or_insert_with(ViewKey::MatchImport, || {
+            let import_view = cx.new(MatchImportView::new);
+            let scroll_handle = import_view.read(cx).scroll_handle();
+            NavEntry::new(import_view, scroll_handle, |view| view.title())
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_query_console(&mut self, cx: &mut Context<Self>) {
+        let entry = self.view_cache.get_or_insert_with(ViewKey::QueryConsole, || {
+            let console_view = cx.new(QueryConsoleView::new);
+            let scroll_handle = console_view.read(cx).scroll_handle();
+            NavEntry::new(console_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(console_view, |view, cx| view.reload(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_series(&mut self, cx: &mut Context<Self>) {
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Series, || {
+            let series_view = cx.new(SeriesView::new);
+            let scroll_handle = series_view.read(cx).scroll_handle();
+            NavEntry::new(series_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(series_view, |view, cx| view.refresh(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    fn handle_open_meta(&mut self, cx: &mut Context<Self>) {
+        let entry = self.view_cache.get_or_insert_with(ViewKey::Meta, || {
+            let meta_view = cx.new(MetaView::new);
+            let scroll_handle = meta_view.read(cx).scroll_handle();
+            NavEntry::new(meta_view.clone(), scroll_handle, |view| view.title())
+                .with_refresh(meta_view, |view, cx| view.refresh(cx))
+        });
+        self.push_entry(entry, cx);
+    }
+
+    /// Drops a profile whose id op.gg doesn't know from the cache and the back stack.
+    fn close_missing_player(&mut self, key: &ViewKey, view: EntityId, message: String, cx: &mut Context<Self>) {
+        self.view_cache.remove(key);
+        let was_active = self.stack.last().is_some_and(|entry| entry.view.entity_id() == view);
+        self.stack.retain(|entry| entry.view.entity_id() != view);
+        if was_active {
+            self.restore_scroll();
+        }
+        self.missing_player = Some(message);
         cx.notify();
     }
 
-    pub fn set_active_view(&mut self, view: AnyView, cx: &mut Context<Self>) {
-        self.active_view = view;
+    fn push_entry(&mut self, mut entry: NavEntry, cx: &mut Context<Self>) {
+        // Already showing it, e.g. after a double click on a match row
+        if self.stack.last().map(|active| active.view.entity_id()) == Some(entry.view.entity_id()) {
+            return;
+        }
+        if let Some(current) = self.stack.last_mut() {
+            current.scroll_offset = current.scroll_handle.offset();
+        }
+        entry.scroll_offset = Point::default();
+        self.stack.push(entry);
         cx.notify();
     }
+
+    pub fn go_back(&mut self, cx: &mut Context<Self>) {
+        if self.stack.len() > 1 {
+            self.stack.pop();
+            self.restore_scroll();
+            cx.notify();
+        }
+    }
+
+    /// Returns to the search view at the bottom of the stack.
+    pub fn go_home(&mut self, cx: &mut Context<Self>) {
+        self.go_to_depth(0, cx);
+    }
+
+    /// Pops the stack back to the entry at `depth` (0 is the search view).
+    pub fn go_to_depth(&mut self, depth: usize, cx: &mut Context<Self>) {
+        if depth + 1 < self.stack.len() {
+            self.stack.truncate(depth + 1);
+            self.restore_scroll();
+            cx.notify();
+        }
+    }
+
+    /// Keeps the OS window title in sync with the active view so windows are distinguishable.
+    fn update_window_title(&mut self, current: Option<&SharedString>, window: &mut Window) {
+        let title = match current {
+            Some(view_title) if self.stack.len() > 1 => format!("{} — {}", view_title, APP_TITLE),
+            _ => APP_TITLE.to_string(),
+        };
+        if title != self.window_title {
+            window.set_window_title(&title);
+            self.window_title = title;
+        }
+    }
+
+    fn refresh_active(&self, cx: &mut Context<Self>) {
+        telemetry::track(cx, "refresh");
+        if let Some(refresh) = self.stack.last().and_then(|entry| entry.refresh.clone()) {
+            refresh(cx);
+        }
+    }
+
+    fn restore_scroll(&self) {
+        if let Some(entry) = self.stack.last() {
+            entry.scroll_handle.set_offset(entry.scroll_offset);
+        }
+    }
 }
 
 impl Render for RootView {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let active_view = self.stack.last().map(|entry| entry.view.clone());
+        let can_go_back = self.stack.len() > 1;
+        let crumbs: Vec<SharedString> = self.stack.iter().map(|entry| (entry.title)(cx)).collect();
+        let last_crumb = crumbs.len().saturating_sub(1);
+        let crumb_players: Vec<Option<PlayerRef>> = self
+            .stack
+            .iter()
+            .zip(&crumbs)
+            .map(|(entry, title)| {
+                entry.player.clone().map(|player| PlayerRef { name: title.to_string(), ..player })
+            })
+            .collect();
+        self.update_window_title(crumbs.last(), window);
+        // All rem-based text and spacing scale from the configured base font size
+        window.set_rem_size(px(cx.global::<AppState>().settings().font_size));
+        sync_focus_handles(&mut self.crumb_focus, crumbs.len(), cx);
+        sync_focus_handles(&mut self.update_focus, 3, cx);
+        let update_banner = self
+            .available_update
+            .clone()
+            .map(|release| self.render_update_banner(&release, cx));
+        sync_focus_handles(&mut self.clipboard_focus, 2, cx);
+        let clipboard_name = cx.global::<AppState>().clipboard_name.lock().unwrap().clone();
+        let clipboard_prompt = clipboard_name.map(|name| self.render_clipboard_prompt(&name, cx));
+        let crumb_focus = self.crumb_focus.clone();
+        let settings = cx.global::<AppState>().settings();
+        sync_focus_handles(&mut self.account_focus, settings.accounts.len(), cx);
+        let account_menu = (self.account_menu_open && settings.accounts.len() > 1)
+            .then(|| self.render_account_menu(settings.accounts.clone(), settings.my_account.as_ref(), cx));
+        let has_alts = settings.accounts.len() > 1;
+        let my_account = settings.my_account;
+        let is_demo = demo::is_enabled();
+        let offline_banner = (!is_demo && connectivity::is_offline()).then(|| self.render_offline_banner());
+        let missing_player_banner = self
+            .missing_player
+            .clone()
+            .map(|message| self.render_missing_player_banner(&message, cx));
+        let compare_targets = self.render_compare_targets(cx);
+        sync_focus_handles(&mut self.modal_focus, MODAL_FOCUS_SLOTS, cx);
+        let modal = self.modal.as_ref().map(|modal| modal.render(&self.modal_focus, window, cx));
+        let frame_overlay = if settings.show_frame_overlay {
+            self.frame_stats.record_frame();
+            // Keep frames coming so the readout measures steady-state rendering, not idle time
+            window.request_animation_frame();
+            Some(self.render_frame_overlay(cx))
+        } else {
+            self.frame_stats.reset();
+            None
+        };
+        // The wizard waits while sample data is shown and returns when demo mode is left
+        let onboarding = self.onboarding.clone().filter(|_| !is_demo);
+
         div()
+            .relative()
             .size_full()
             .bg(rgb(0x1e1e2e)) // Dark background
             .text_color(rgb(0xcdd6f4)) // Light text
-            .on_action(cx.listener(|this, action: &OpenPlayer, _window, cx| {
-                let view = cx.new(|cx| {
-                    PlayerView::new(cx, action.player_id.clone(), action.platform.clone())
-                });
-                this.set_active_view(view.into(), cx);
-            }))
-            .on_action(cx.listener(|this, action: &OpenMatch, _window, cx| {
-                let view = cx.new(|cx| {
-                    MatchDetailView::new(cx, action.match_id.clone(), action.platform.clone())
-                });
-                this.set_active_view(view.into(), cx);
+            .on_action(cx.listener(|this, action: &OpenPlayer, window, cx| {
+                this.handle_open_player(action, window, cx);
+            }))
+            .on_action(cx.listener(|this, action: &OpenMatch, window, cx| {
+                this.handle_open_match(action, window, cx);
+            }))
+            .on_action(cx.listener(|this, action: &OpenCompare, _window, cx| {
+                this.handle_open_compare(action, cx);
+            }))
+            .on_action(cx.listener(|this, action: &SearchPlayer, window, cx| {
+                this.handle_search_player(action, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenQuickSearch, window, cx| {
+                this.open_quick_search(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &GoBack, _window, cx| {
+                this.go_back(cx);
+            }))
+            .on_action(cx.listener(|this, _: &GoHome, _window, cx| {
+                this.go_home(cx);
+            }))
+            .on_action(cx.listener(|this, _: &Refresh, _window, cx| {
+                this.refresh_active(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenSettings, _window, cx| {
+                this.handle_open_settings(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenJobs, _window, cx| {
+                this.handle_open_jobs(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenLogs, _window, cx| {
+                this.handle_open_logs(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenReviews, _window, cx| {
+                this.handle_open_reviews(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenLobby, _window, cx| {
+                this.handle_open_lobby(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenScout, _window, cx| {
+                this.handle_open_scout(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenMatchImport, _window, cx| {
+                this.handle_open_match_import(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenQueryConsole, _window, cx| {
+                this.handle_open_query_console(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenSeries, _window, cx| {
+                this.handle_open_series(cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenMeta, _window, cx| {
+                this.handle_open_meta(cx);
             }))
+            .on_action(|_: &ToggleOverlay, _window, cx| overlay::toggle(cx))
+            .on_action(|_: &FocusNext, window, _cx| window.focus_next())
+            .on_action(|_: &FocusPrevious, window, _cx| window.focus_prev())
             .child(
                 div()
                     .flex()
@@ -75,26 +1070,304 @@ impl Render for RootView {
                             .h_12()
                             .flex()
                             .items_center()
+                            .gap_3()
                             .px_4()
                             .bg(rgb(0x11111b))
                             .border_b_1()
                             .border_color(rgb(0x313244))
+                            .when(can_go_back, |parent| {
+                                parent.child(
+                                    div()
+                                        .px_2()
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .hover(|s| s.bg(rgb(0x313244)))
+                                        .focus_ring(&self.back_focus)
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(|this, _, _window, cx| this.go_back(cx)),
+                                        )
+                                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                this.go_back(cx);
+                                            }
+                                        }))
+                                        .child("←"),
+                                )
+                            })
                             .child(
                                 div()
                                     .text_xl()
                                     .font_weight(FontWeight::BOLD)
                                     .cursor_pointer()
+                                    .rounded_md()
+                                    .focus_ring(&self.home_focus)
                                     .on_mouse_down(
                                         MouseButton::Left,
-                                        cx.listener(|this, _, window, cx| {
-                                            let view = cx.new(|cx| SearchView::new(cx));
-                                            this.set_active_view(view.into(), cx);
+                                        cx.listener(|this, _, _window, cx| this.go_home(cx)),
+                                    )
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.go_home(cx);
+                                        }
+                                    }))
+                                    .child(APP_TITLE),
+                            )
+                            // Breadcrumb trail
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .min_w_0()
+                                    .overflow_hidden()
+                                    .gap_1()
+                                    .text_sm()
+                                    .text_color(rgb(0x9399b2))
+                                    .children(crumbs.into_iter().zip(crumb_players).enumerate().map(|(depth, (title, player))| {
+                                        let is_current = depth == last_crumb;
+                                        div()
+                                            .flex()
+                                            .items_center()
+                                            .gap_1()
+                                            .when(depth > 0, |parent| parent.child("›"))
+                                            .child(
+                                                div()
+                                                    .px_1()
+                                                    .rounded_sm()
+                                                    .when(is_current, |crumb| crumb.text_color(rgb(0xcdd6f4)))
+                                                    // Dropping a player on a profile's crumb compares the two
+                                                    .when_some(player, |crumb, player| {
+                                                        crumb
+                                                            .drag_over::<DraggedPlayer>(|style, _, _, _| style.bg(rgb(0x45475a)))
+                                                            .on_drop(move |dragged: &DraggedPlayer, window, cx| {
+                                                                window.dispatch_action(
+                                                                    Box::new(OpenCompare {
+                                                                        left: player.clone(),
+                                                                        right: dragged.0.clone(),
+                                                                    }),
+                                                                    cx,
+                                                                );
+                                                            })
+                                                    })
+                                                    .when(!is_current, |crumb| {
+                                                        crumb
+                                                            .cursor_pointer()
+                                                            .hover(|s| s.bg(rgb(0x313244)))
+                                                            .focus_ring(&crumb_focus[depth])
+                                                            .on_mouse_down(
+                                                                MouseButton::Left,
+                                                                cx.listener(move |this, _, _window, cx| {
+                                                                    this.go_to_depth(depth, cx)
+                                                                }),
+                                                            )
+                                                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                                                if is_activation_key(event) {
+                                                                    this.go_to_depth(depth, cx)
+                                                                }
+                                                            }))
+                                                    })
+                                                    .child(TruncatedText::new(("crumb-title", depth), title).max_w(px(160.0))),
+                                            )
+                                    })),
+                            )
+                            .child(div().flex_1())
+                            .when(is_demo, |parent| {
+                                parent.child(
+                                    div()
+                                        .id("demo-badge")
+                                        .px_2()
+                                        .py_1()
+                                        .text_sm()
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .bg(rgb(0xf9e2af))
+                                        .text_color(rgb(0x1e1e2e))
+                                        .focus_ring(&self.demo_focus)
+                                        .tooltip(Tooltip::text("Showing bundled sample data. Click to go back to live data."))
+                                        .on_mouse_down(MouseButton::Left, |_, window, cx| demo::set_enabled(false, window, cx))
+                                        .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                            if is_activation_key(event) {
+                                                demo::set_enabled(false, window, cx);
+                                            }
+                                        })
+                                        .child("Demo data"),
+                                )
+                            })
+                            .when_some(my_account, |parent, account| {
+                                let action = OpenPlayer {
+                                    player_id: account.player_id.clone(),
+                                    platform: account.platform.clone(),
+                                };
+                                let key_action = action.clone();
+                                parent.child(
+                                    div()
+                                        .id("my-account")
+                                        .px_2()
+                                        .py_1()
+                                        .text_sm()
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .hover(|s| s.bg(rgb(0x313244)))
+                                        .focus_ring(&self.my_account_focus)
+                                        // Click rather than mouse-down so the account can be dragged onto a profile
+                                        .on_click(move |_, window, cx| {
+                                            window.dispatch_action(Box::new(action.clone()), cx);
+                                        })
+                                        .on_drag(DraggedPlayer(account.clone()), |dragged, _offset, _window, cx| {
+                                            cx.new(|_| dragged.clone())
+                                        })
+                                        .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                            if is_activation_key(event) {
+                                                window.dispatch_action(Box::new(key_action.clone()), cx);
+                                            }
+                                        })
+                                        .child(account.name.clone()),
+                                )
+                            })
+                            .when(has_alts, |parent| {
+                                parent.child(
+                                    div()
+                                        .child(
+                                            div()
+                                                .px_1()
+                                                .py_1()
+                                                .text_sm()
+                                                .rounded_md()
+                                                .cursor_pointer()
+                                                .hover(|s| s.bg(rgb(0x313244)))
+                                                .focus_ring(&self.account_menu_focus)
+                                                .on_mouse_down(
+                                                    MouseButton::Left,
+                                                    cx.listener(|this, _, _window, cx| this.toggle_account_menu(cx)),
+                                                )
+                                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                                    if is_activation_key(event) {
+                                                        this.toggle_account_menu(cx);
+                                                    }
+                                                }))
+                                                .child("▾"),
+                                        )
+                                        // Floats over the page instead of growing the header
+                                        .when_some(account_menu, |parent, menu| {
+                                            parent.child(deferred(anchored().snap_to_window().child(menu)))
                                         }),
+                                )
+                            })
+                            .child(
+                                div()
+                                    .child(
+                                        div()
+                                            .px_2()
+                                            .py_1()
+                                            .text_sm()
+                                            .rounded_md()
+                                            .cursor_pointer()
+                                            .hover(|s| s.bg(rgb(0x313244)))
+                                            .focus_ring(&self.quick_search_focus)
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(|this, _, window, cx| this.open_quick_search(window, cx)),
+                                            )
+                                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                                if is_activation_key(event) {
+                                                    this.open_quick_search(window, cx);
+                                                }
+                                            }))
+                                            .child("Search…"),
+                                    )
+                                    .when_some(self.quick_search.clone(), |parent, quick_search| {
+                                        parent.child(deferred(anchored().snap_to_window().child(quick_search)))
+                                    }),
+                            )
+                            .child(
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_sm()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x313244)))
+                                    .focus_ring(&self.launch_focus)
+                                    .on_mouse_down(MouseButton::Left, |_, _window, cx| cx.open_url(&steam::launch_url()))
+                                    .on_key_down(|event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            cx.open_url(&steam::launch_url());
+                                        }
+                                    })
+                                    .child("Launch Supervive"),
+                            )
+                            .child(
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_sm()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x313244)))
+                                    .focus_ring(&self.jobs_focus)
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|this, _, _window, cx| this.handle_open_jobs(cx)),
+                                    )
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.handle_open_jobs(cx);
+                                        }
+                                    }))
+                                    .child("Jobs"),
+                            )
+                            .child(
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_sm()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x313244)))
+                                    .focus_ring(&self.reviews_focus)
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|this, _, _window, cx| this.handle_open_reviews(cx)),
+                                    )
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.handle_open_reviews(cx);
+                                        }
+                                    }))
+                                    .child("Reviews"),
+                            )
+                            .child(
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_sm()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(rgb(0x313244)))
+                                    .focus_ring(&self.settings_focus)
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|this, _, _window, cx| this.handle_open_settings(cx)),
                                     )
-                                    .child("Supervive Dashboard"),
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.handle_open_settings(cx);
+                                        }
+                                    }))
+                                    .child("Settings"),
                             ),
                     )
-                    .child(div().flex_1().child(self.active_view.clone())),
+                    .children(compare_targets)
+                    .children(offline_banner)
+                    .children(missing_player_banner)
+                    .children(update_banner)
+                    .children(clipboard_prompt)
+                    .child(match onboarding {
+                        Some(onboarding) => div().flex_1().min_h_0().child(onboarding),
+                        None => div().flex_1().min_h_0().children(active_view),
+                    }),
             )
+            .children(modal)
+            .children(frame_overlay)
     }
 }
@@ -0,0 +1,432 @@
+use crate::api::take_rows;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::hero_image::HeroImage;
+use crate::components::tooltip::Tooltip;
+use crate::components::truncated_text::TruncatedText;
+use crate::format;
+use crate::live_match;
+use crate::opponent_report::{self, OpponentReport, PlayerReport, MAX_ROSTER};
+use crate::scout::{self, ScoutReport, MAX_SCOUT_NAMES};
+use crate::state::AppState;
+use crate::tasks::RequestGeneration;
+use crate::telemetry;
+use crate::views::OpenPlayer;
+use gpui::prelude::*;
+use gpui::*;
+
+const CARD_WIDTH: f32 = 210.0;
+
+#[derive(Debug, Clone)]
+enum Scouted {
+    Resolving,
+    NotFound,
+    Failed(String),
+    Ready(ScoutReport),
+}
+
+#[derive(Debug, Clone)]
+struct ScoutEntry {
+    name: String,
+    state: Scouted,
+}
+
+/// Pre-game scouting for customs: names pasted one per line are looked up together and shown
+/// as a grid of cards with rank, K/D and main hero from each player's recent matches.
+pub struct ScoutView {
+    scroll_handle: ScrollHandle,
+    entries: Vec<ScoutEntry>,
+    /// Pasted names beyond the limit that weren't scouted
+    skipped: usize,
+    requests: RequestGeneration,
+    paste_focus: FocusHandle,
+    live_lobby_focus: FocusHandle,
+    clear_focus: FocusHandle,
+    report_focus: FocusHandle,
+    card_focus: Vec<FocusHandle>,
+    building_report: bool,
+    /// Where the last opponent report was saved, or why it couldn't be
+    report_status: Option<Result<String, String>>,
+}
+
+impl ScoutView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            entries: Vec::new(),
+            skipped: 0,
+            requests: RequestGeneration::default(),
+            paste_focus: cx.focus_handle().tab_stop(true),
+            live_lobby_focus: cx.focus_handle().tab_stop(true),
+            clear_focus: cx.focus_handle().tab_stop(true),
+            report_focus: cx.focus_handle().tab_stop(true),
+            card_focus: Vec::new(),
+            building_report: false,
+            report_status: None,
+        }
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Scout lobby".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    fn paste_names(&mut self, cx: &mut Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        let (names, skipped) = scout::parse_names(&text);
+        if names.is_empty() {
+            return;
+        }
+        telemetry::track(cx, "scout_lobby");
+        self.scout(names, skipped, cx);
+    }
+
+    /// Scouts the players the game log saw in the current match.
+    fn scout_live_lobby(&mut self, cx: &mut Context<Self>) {
+        let lobby = cx.global::<AppState>().live_lobby.lock().unwrap().clone();
+        let names: Vec<String> = lobby.players.into_iter().map(|player| player.name).collect();
+        let skipped = names.len().saturating_sub(MAX_SCOUT_NAMES);
+        self.scout(names.into_iter().take(MAX_SCOUT_NAMES).collect(), skipped, cx);
+    }
+
+    fn scout(&mut self, names: Vec<String>, skipped: usize, cx: &mut Context<Self>) {
+        self.entries = names
+            .into_iter()
+            .map(|name| ScoutEntry {
+                name,
+                state: Scouted::Resolving,
+            })
+            .collect();
+        self.skipped = skipped;
+        self.requests.start();
+        for idx in 0..self.entries.len() {
+            self.resolve(idx, cx);
+        }
+        cx.notify();
+    }
+
+    /// Runs every scout again, e.g. once the lobby has played a few more games.
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let names = self.entries.iter().map(|entry| entry.name.clone()).collect();
+        self.scout(names, self.skipped, cx);
+    }
+
+    fn clear(&mut self, cx: &mut Context<Self>) {
+        self.entries.clear();
+        self.skipped = 0;
+        self.report_status = None;
+        self.requests.start();
+        cx.notify();
+    }
+
+    /// Looks one name up on its own task, so the cards fill in as their players resolve.
+    fn resolve(&mut self, idx: usize, cx: &mut Context<Self>) {
+        let Some(entry) = self.entries.get_mut(idx) else {
+            return;
+        };
+        entry.state = Scouted::Resolving;
+        let name = entry.name.clone();
+        let app_state = cx.global::<AppState>();
+        let (service, index) = (app_state.service.clone(), app_state.player_index.clone());
+        let request = self.requests.current();
+
+        cx.spawn(async move |view, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let Some(profile) = live_match::find_profile(&name, &service, &index) else {
+                        return Ok(None);
+                    };
+                    let matches = service
+                        .lock()
+                        .unwrap()
+                        .get_player_matches(&profile.platform, &profile.player_id, 1)?;
+                    anyhow::Ok(Some(ScoutReport::new(profile, matches)))
+                })
+                .await;
+
+            view.update(cx, |this, cx| {
+                if !this.requests.is_current(request) {
+                    return;
+                }
+                if let Some(entry) = this.entries.get_mut(idx) {
+                    entry.state = match result {
+                        Ok(Some(report)) => Scouted::Ready(report),
+                        Ok(None) => Scouted::NotFound,
+                        Err(err) => Scouted::Failed(err.to_string()),
+                    };
+                }
+                cx.notify();
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    /// The scouted players, when they fit one enemy squad and have all been found.
+    fn roster(&self) -> Option<Vec<ScoutReport>> {
+        if self.entries.is_empty() || self.entries.len() > MAX_ROSTER {
+            return None;
+        }
+        self.entries
+            .iter()
+            .map(|entry| match &entry.state {
+                Scouted::Ready(report) => Some(report.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Writes a scouting report on the roster to the downloads folder. It digs deeper than the
+    /// cards: a few history pages per player, fetched in turn, plus any archived matches.
+    fn export_report(&mut self, cx: &mut Context<Self>) {
+        let Some(roster) = self.roster() else {
+            return;
+        };
+        if self.building_report {
+            return;
+        }
+        telemetry::track(cx, "opponent_report");
+        self.building_report = true;
+        self.report_status = None;
+        cx.notify();
+        let app_state = cx.global::<AppState>();
+        let (service, archive) = (app_state.service.clone(), app_state.match_archive.clone());
+
+        cx.spawn(async move |view, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let profiles: Vec<_> = roster.iter().map(|report| report.profile.clone()).collect();
+                    let mut rows = opponent_report::archive_rows(&archive.lock().unwrap(), &profiles);
+                    for (profile, rows) in profiles.iter().zip(rows.iter_mut()) {
+                        for page in 1..=opponent_report::HISTORY_PAGES {
+                            let data = service
+                                .lock()
+                                .unwrap()
+                                .get_player_matches(&profile.platform, &profile.player_id, page)?;
+                            let page_rows = take_rows(data);
+                            if page_rows.is_empty() {
+                                break;
+                            }
+                            rows.extend(page_rows);
+                        }
+                    }
+                    let players = roster
+                        .into_iter()
+                        .zip(&rows)
+                        .map(|(report, rows)| PlayerReport::new(report.profile, report.rank, rows))
+                        .collect();
+                    OpponentReport::new(players, &rows).export()
+                })
+                .await;
+            view.update(cx, |this, cx| {
+                this.building_report = false;
+                this.report_status = Some(match result {
+                    Ok(path) => Ok(format!("Saved report to {}", path.display())),
+                    Err(err) => Err(format!("Couldn't build the report: {}", err)),
+                });
+                cx.notify();
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn button(label: &'static str, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_sm()
+            .rounded_md()
+            .bg(rgb(0x313244))
+            .cursor_pointer()
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label)
+    }
+
+    fn render_card(&self, idx: usize, entry: &ScoutEntry, cx: &mut Context<Self>) -> Div {
+        let focus = &self.card_focus[idx];
+        let card = div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .w(px(CARD_WIDTH))
+            .p_3()
+            .bg(rgb(0x181825))
+            .rounded_md()
+            .text_sm();
+        let name = TruncatedText::new(("scout-name", idx), entry.name.clone()).font_weight(FontWeight::SEMIBOLD);
+        let muted = |text: String| div().text_color(rgb(0x9399b2)).child(text);
+
+        match &entry.state {
+            Scouted::Resolving => card.child(name).child(muted("Looking up…".to_string())),
+            Scouted::NotFound => card.child(name).child(muted("Not found".to_string())),
+            Scouted::Failed(error) => card
+                .cursor_pointer()
+                .hover(|s| s.bg(rgb(0x313244)))
+                .focus_ring(focus)
+                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.resolve(idx, cx)))
+                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                    if is_activation_key(event) {
+                        this.resolve(idx, cx);
+                    }
+                }))
+                .child(name)
+                .child(div().text_color(rgb(0xf38ba8)).child(error.clone()))
+                .child(muted("Click to retry".to_string())),
+            Scouted::Ready(report) => {
+                let action = OpenPlayer {
+                    player_id: report.profile.player_id.clone(),
+                    platform: report.profile.platform.clone(),
+                };
+                let key_action = action.clone();
+                let record = match report.kd_ratio {
+                    Some(kd) => format!("K/D {} · {} games", format::decimal(kd, 2), format::integer(report.games as i64)),
+                    None => "No recent matches".to_string(),
+                };
+                card.cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(focus)
+                    .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                        window.dispatch_action(Box::new(action.clone()), cx);
+                    })
+                    .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                        if is_activation_key(event) {
+                            window.dispatch_action(Box::new(key_action.clone()), cx);
+                        }
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .when_some(
+                                report.main_hero.clone().zip(report.main_hero_image.clone()),
+                                |row, (hero, url)| row.child(HeroImage::new(("scout-hero", idx), url, hero).size(px(32.0))),
+                            )
+                            .child(div().flex_1().min_w_0().child(name)),
+                    )
+                    .child(div().child(report.rank.clone().unwrap_or_else(|| "Unranked".to_string())))
+                    .child(muted(record))
+                    .when_some(report.main_hero.clone(), |card, hero| card.child(muted(format!("Main: {}", hero))))
+            }
+        }
+    }
+}
+
+impl Render for ScoutView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let app_state = cx.global::<AppState>();
+        let spacing = app_state.settings().density.spacing();
+        let has_live_lobby = !app_state.live_lobby.lock().unwrap().players.is_empty();
+        sync_focus_handles(&mut self.card_focus, self.entries.len(), cx);
+        let can_report = self.roster().is_some();
+        let resolving = self
+            .entries
+            .iter()
+            .filter(|entry| matches!(entry.state, Scouted::Resolving))
+            .count();
+        let cards: Vec<Div> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| self.render_card(idx, entry, cx))
+            .collect();
+
+        div()
+            .id("scout-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Scout lobby"))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child(format!("Copy up to {} player names, one per line, then paste them here.", MAX_SCOUT_NAMES)),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .child(
+                        Self::button("Paste names", &self.paste_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.paste_names(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.paste_names(cx);
+                                }
+                            })),
+                    )
+                    .when(has_live_lobby, |row| {
+                        row.child(
+                            Self::button("Use live lobby", &self.live_lobby_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.scout_live_lobby(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.scout_live_lobby(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .when(can_report, |row| {
+                        row.child(
+                            Self::button(
+                                if self.building_report { "Building report…" } else { "Opponent report" },
+                                &self.report_focus,
+                            )
+                            .id("opponent-report")
+                            .tooltip(Tooltip::text(
+                                "Save a report on these players: main heroes, placements, form and games together",
+                            ))
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.export_report(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.export_report(cx);
+                                }
+                            })),
+                        )
+                    })
+                    .when(!self.entries.is_empty(), |row| {
+                        row.child(
+                            Self::button("Clear", &self.clear_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.clear(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.clear(cx);
+                                    }
+                                })),
+                        )
+                    }),
+            )
+            .when(!self.entries.is_empty(), |parent| {
+                let mut status = if resolving > 0 {
+                    format!("Looking up {} of {} players…", resolving, self.entries.len())
+                } else {
+                    format!("{} players scouted", self.entries.len())
+                };
+                if self.skipped > 0 {
+                    status.push_str(&format!(" · {} more names left out", self.skipped));
+                }
+                parent.child(div().text_sm().text_color(rgb(0x9399b2)).child(status))
+            })
+            .when_some(self.report_status.clone(), |parent, status| {
+                let (text, color) = match status {
+                    Ok(text) => (text, rgb(0x9399b2)),
+                    Err(text) => (text, rgb(0xf38ba8)),
+                };
+                parent.child(div().text_sm().text_color(color).child(text))
+            })
+            .child(div().flex().flex_wrap().gap(spacing.row_gap).children(cards))
+    }
+}
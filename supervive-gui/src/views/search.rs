@@ -1,47 +1,1292 @@
 use gpui::*;
 use gpui::prelude::*;
+use crate::api::{parse_profile_reference, rate_limit_of, take_rows, MatchRows, PlayerSummary, MAX_HISTORY_PAGES};
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::components::progress_bar::ProgressBar;
+use crate::components::rate_limit::{RateLimit, RateLimitBanner};
+use crate::components::text_input::{impl_text_input_handler, input_handler, InputChange, TextInput};
+use crate::components::tooltip::Tooltip;
+use crate::components::truncated_text::TruncatedText;
+use crate::confidence;
+use crate::format;
+use crate::goals::{Goal, RankTier};
+use crate::match_notes::{ChecklistItem, MatchNote, MatchNotes};
+use crate::player_index::IndexedPlayer;
+use crate::reports::{self, Report, ReportPeriod};
+use crate::scheduler::{self, JobKind, Scheduler};
+use crate::session::{parse_time, SessionStats};
+use crate::settings::{PinnedTile, PlayerRef, Settings, TileKind};
 use crate::state::AppState;
-use crate::views::OpenPlayer;
+use crate::steam::{self, SteamLogin};
+use crate::tasks::{RequestGeneration, TaskProgress};
+use crate::telemetry;
+use crate::theme::Spacing;
+use crate::tiles::{self, LeaderboardEntry, TILE_ROWS};
+use crate::views::{DraggedPlayer, OpenMatch, OpenMatchImport, OpenMeta, OpenPlayer, OpenScout, OpenSeries};
+use chrono::{Local, Utc};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::time::Duration;
 
 pub struct SearchView {
-    query: String,
+    input: TextInput,
     results: Vec<Value>,
+    /// Indices into `results` of the accounts sharing a name, one card per name
+    result_groups: Vec<Vec<usize>>,
+    // Per-result details keyed by userId, filled in after the search returns
+    details: HashMap<String, PlayerSummary>,
+    details_progress: Option<TaskProgress>,
+    /// Results whose details are being looked up, so "Show more" doesn't queue them twice
+    details_pending: HashSet<String>,
+    /// Results whose details lookup failed, skipped until retried from their card
+    details_failed: HashSet<String>,
+    /// How many results are listed; "Show more" reveals the next `RESULTS_PAGE`
+    shown_results: usize,
+    results_scroll: ScrollHandle,
+    show_more_focus: FocusHandle,
+    /// Set while the network search is in flight
+    searching: bool,
+    /// Query of the last finished search, named when it found nobody
+    searched_query: Option<String>,
+    /// One per recent search chip in the empty state
+    recent_focus: Vec<FocusHandle>,
+    /// Bumped per search; detail lookups stop once a newer search replaced their results
+    search_requests: RequestGeneration,
+    /// Set while a search or detail lookup waits out op.gg's rate limit
+    rate_limit: Option<RateLimit>,
+    // Instant matches from players seen before, shown above the network results
+    suggestions: Vec<IndexedPlayer>,
+    // Inline error shown when a profile fails the existence check
     focus_handle: FocusHandle,
-    cursor_position: usize,
+    search_button_focus: FocusHandle,
+    scout_focus: FocusHandle,
+    import_focus: FocusHandle,
+    series_focus: FocusHandle,
+    meta_focus: FocusHandle,
+    suggestion_focus: Vec<FocusHandle>,
+    result_focus: Vec<FocusHandle>,
+    /// Retry link on a result whose details failed to load, by result index
+    retry_focus: Vec<FocusHandle>,
+    scroll_handle: ScrollHandle,
+    // Locally signed-in Steam account and the matching Supervive profile, if one was found
+    steam_login: Option<(SteamLogin, Option<PlayerRef>)>,
+    steam_focus: Vec<FocusHandle>,
+    // Summary of the linked account's week or month, shown above the search results
+    report_period: ReportPeriod,
+    report: Option<Report>,
+    report_loading: bool,
+    report_status: Option<Result<String, String>>,
+    report_requests: RequestGeneration,
+    report_focus: Vec<FocusHandle>,
+    // History rows behind the report, also used to measure goal progress
+    account_rows: Vec<Value>,
+    goal_focus: Vec<FocusHandle>,
+    /// "Refresh now", then `TILE_CONTROLS` per pinned tile
+    tile_focus: Vec<FocusHandle>,
+    /// `QUEUE_CONTROLS` per match in the review queue
+    queue_focus: Vec<FocusHandle>,
 }
 
+/// Results listed per page. The search endpoint takes no offset, so every match arrives at
+/// once; pages only limit what is listed and how many detail lookups run.
+const RESULTS_PAGE: usize = 20;
+/// Tallest the result list grows before it scrolls on its own.
+const RESULTS_HEIGHT: f32 = 640.0;
+/// Focusable controls per pinned tile: open the player, unpin.
+const TILE_CONTROLS: usize = 2;
+/// Focusable controls per queued review: open the match, each checklist step, clear.
+const QUEUE_CONTROLS: usize = ChecklistItem::ALL.len() + 2;
+/// Focusable controls per goal row: lower, raise, hero, remove.
+const GOAL_CONTROLS: usize = 4;
+/// Buttons that add a rank, placement or K/D goal.
+const NEW_GOAL_BUTTONS: usize = 3;
+
+type GoalAction = Rc<dyn Fn(&mut SearchView, &mut Context<SearchView>)>;
+
 impl SearchView {
     pub fn new(cx: &mut Context<Self>) -> Self {
-        let focus_handle = cx.focus_handle();
+        let focus_handle = cx.focus_handle().tab_stop(true);
+        let settings = cx.global::<AppState>().settings();
+        if settings.my_account.is_none() && !settings.steam_prompt_dismissed {
+            Self::detect_steam_account(cx);
+        }
+        cx.spawn(async move |view, cx| {
+            view.update(cx, |this, cx| this.load_report(cx)).ok();
+        }).detach();
         Self {
-            query: String::new(),
+            input: TextInput::default(),
             results: Vec::new(),
+            result_groups: Vec::new(),
+            details: HashMap::new(),
+            details_progress: None,
+            details_pending: HashSet::new(),
+            details_failed: HashSet::new(),
+            shown_results: RESULTS_PAGE,
+            results_scroll: ScrollHandle::new(),
+            show_more_focus: cx.focus_handle().tab_stop(true),
+            searching: false,
+            searched_query: None,
+            recent_focus: Vec::new(),
+            search_requests: RequestGeneration::default(),
+            rate_limit: None,
+            suggestions: Vec::new(),
             focus_handle,
-            cursor_position: 0,
+            search_button_focus: cx.focus_handle().tab_stop(true),
+            scout_focus: cx.focus_handle().tab_stop(true),
+            import_focus: cx.focus_handle().tab_stop(true),
+            series_focus: cx.focus_handle().tab_stop(true),
+            meta_focus: cx.focus_handle().tab_stop(true),
+            suggestion_focus: Vec::new(),
+            result_focus: Vec::new(),
+            retry_focus: Vec::new(),
+            scroll_handle: ScrollHandle::new(),
+            steam_login: None,
+            steam_focus: Vec::new(),
+            report_period: ReportPeriod::Week,
+            report: None,
+            report_loading: false,
+            report_status: None,
+            report_requests: RequestGeneration::default(),
+            report_focus: Vec::new(),
+            account_rows: Vec::new(),
+            goal_focus: Vec::new(),
+            tile_focus: Vec::new(),
+            queue_focus: Vec::new(),
+        }
+    }
+
+    /// Rebuilds the report for the active account, fetching history pages until they reach
+    /// back past the start of the period.
+    pub fn load_report(&mut self, cx: &mut Context<Self>) {
+        let app_state = cx.global::<AppState>();
+        let Some(account) = app_state.settings().my_account else {
+            self.report_requests.start();
+            self.report = None;
+            self.account_rows.clear();
+            cx.notify();
+            return;
+        };
+        let service = app_state.service.clone();
+        let period = self.report_period;
+        let start = period.start();
+        let request = self.report_requests.start();
+        self.report_loading = true;
+        self.report_status = None;
+        cx.notify();
+
+        cx.spawn(async move |view, cx| {
+            let rows = cx.background_executor().spawn(async move {
+                let mut service = service.lock().unwrap();
+                let mut rows: Vec<Value> = Vec::new();
+                for page in 1..=MAX_HISTORY_PAGES {
+                    let items = match service.get_player_matches(&account.platform, &account.player_id, page as i32) {
+                        Ok(data) => take_rows(data),
+                        Err(err) => {
+                            tracing::warn!(error = %err, page, "Failed to load history for report");
+                            break;
+                        }
+                    };
+                    let reached_start = items
+                        .last()
+                        .and_then(|row| parse_time(&row["match_start"]))
+                        .is_none_or(|time| time < start);
+                    rows.extend(items);
+                    if reached_start {
+                        break;
+                    }
+                }
+                rows
+            }).await;
+
+            view.update(cx, |this, cx| {
+                // Ignore results for a period or account the user has since switched away from
+                if this.report_requests.is_current(request) {
+                    this.report = Some(Report::build(period, &rows));
+                    this.account_rows = rows;
+                    this.report_loading = false;
+                    cx.notify();
+                }
+            }).ok();
+        }).detach();
+    }
+
+    fn set_report_period(&mut self, period: ReportPeriod, cx: &mut Context<Self>) {
+        if self.report_period != period {
+            self.report_period = period;
+            self.load_report(cx);
+        }
+    }
+
+    fn export_report(&mut self, cx: &mut Context<Self>) {
+        let Some(report) = self.report.clone() else {
+            return;
+        };
+        let name = cx
+            .global::<AppState>()
+            .settings()
+            .my_account
+            .map(|account| account.name)
+            .unwrap_or_default();
+        telemetry::track(cx, "export_report");
+        self.report_status = Some(
+            reports::export_markdown(&report, &name)
+                .map(|path| format!("Saved to {}", path.display()))
+                .map_err(|err| format!("Export failed: {}", err)),
+        );
+        cx.notify();
+    }
+
+    fn update_goals(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Vec<Goal>)) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| f(&mut s.goals));
+        cx.notify();
+    }
+
+    /// Cycles a K/D goal through all heroes, then every hero played in the loaded history.
+    fn cycle_goal_hero(&mut self, index: usize, cx: &mut Context<Self>) {
+        let mut heroes: Vec<String> = self
+            .account_rows
+            .iter()
+            .filter_map(|row| row["hero"]["name"].as_str().map(|name| name.to_string()))
+            .collect();
+        heroes.sort();
+        heroes.dedup();
+        self.update_goals(cx, |goals| {
+            if let Some(Goal::KdRatio { hero, .. }) = goals.get_mut(index) {
+                let next = match hero.as_ref().and_then(|current| heroes.iter().position(|name| name == current)) {
+                    Some(position) => heroes.get(position + 1).cloned(),
+                    None if hero.is_none() => heroes.first().cloned(),
+                    None => None,
+                };
+                *hero = next;
+            }
+        });
+    }
+
+    fn render_goals(&self, goals: &[Goal], cx: &mut Context<Self>) -> Div {
+        let button = |label: &'static str, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(rgb(0x313244))
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+        let new_goals: [(&'static str, Goal); NEW_GOAL_BUTTONS] = [
+            ("+ Rank", Goal::Rank { tier: RankTier::Diamond }),
+            ("+ Placement", Goal::AvgPlacement { target: 8.0 }),
+            ("+ K/D", Goal::KdRatio { target: 2.0, hero: None }),
+        ];
+        let add_focus = &self.goal_focus[..NEW_GOAL_BUTTONS];
+        let row_focus = &self.goal_focus[NEW_GOAL_BUTTONS..];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .p_4()
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(div().flex_1().font_weight(FontWeight::SEMIBOLD).child("Goals"))
+                    .children(new_goals.into_iter().zip(add_focus).map(|((label, goal), focus)| {
+                        let add = goal.clone();
+                        button(label, focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                let goal = add.clone();
+                                this.update_goals(cx, |goals| goals.push(goal));
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    let goal = goal.clone();
+                                    this.update_goals(cx, |goals| goals.push(goal));
+                                }
+                            }))
+                    })),
+            )
+            .when(goals.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child("Set a goal to track it as new matches come in."),
+                )
+            })
+            .children(goals.iter().enumerate().map(|(index, goal)| {
+                let progress = goal.progress(&self.account_rows);
+                let fraction = progress.as_ref().map_or(0.0, |progress| progress.fraction);
+                let focus = &row_focus[index * GOAL_CONTROLS..(index + 1) * GOAL_CONTROLS];
+                // Label, whether the control applies to this goal, and its action
+                let controls: [(&'static str, bool, GoalAction); GOAL_CONTROLS] = [
+                    ("−", true, Rc::new(move |this: &mut Self, cx: &mut Context<Self>| {
+                        this.update_goals(cx, |goals| {
+                            if let Some(goal) = goals.get_mut(index) {
+                                goal.step(-1.0);
+                            }
+                        })
+                    })),
+                    ("+", true, Rc::new(move |this: &mut Self, cx: &mut Context<Self>| {
+                        this.update_goals(cx, |goals| {
+                            if let Some(goal) = goals.get_mut(index) {
+                                goal.step(1.0);
+                            }
+                        })
+                    })),
+                    ("Hero", matches!(goal, Goal::KdRatio { .. }), Rc::new(move |this: &mut Self, cx: &mut Context<Self>| {
+                        this.cycle_goal_hero(index, cx)
+                    })),
+                    ("×", true, Rc::new(move |this: &mut Self, cx: &mut Context<Self>| {
+                        this.update_goals(cx, |goals| {
+                            if index < goals.len() {
+                                goals.remove(index);
+                            }
+                        })
+                    })),
+                ];
+
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(div().flex_1().text_sm().child(goal.label()))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(rgb(0x9399b2))
+                                    .child(match &progress {
+                                        Some(progress) => progress.current.clone(),
+                                        None => "No data yet".to_string(),
+                                    }),
+                            )
+                            .children(controls.into_iter().zip(focus).filter(|((_, applies, _), _)| *applies).map(
+                                |((label, _, action), focus)| {
+                                    let on_key = action.clone();
+                                    button(label, focus)
+                                        .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| action(this, cx)))
+                                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                on_key(this, cx);
+                                            }
+                                        }))
+                                },
+                            )),
+                    )
+                    .child(
+                        div()
+                            .w_full()
+                            .h_1()
+                            .rounded_full()
+                            .bg(rgb(0x313244))
+                            .child(
+                                div()
+                                    .h_full()
+                                    .rounded_full()
+                                    .bg(if fraction >= 1.0 { rgb(0xa6e3a1) } else { rgb(0x89b4fa) })
+                                    .w(relative(fraction)),
+                            ),
+                    )
+            }))
+    }
+
+    fn unpin_tile(&mut self, index: usize, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+            if index < s.pinned_tiles.len() {
+                s.pinned_tiles.remove(index);
+            }
+        });
+        cx.notify();
+    }
+
+    /// Body lines of a pinned tile, built from the rows the poller last fetched.
+    fn tile_lines(tile: &PinnedTile, rows: &MatchRows, settings: &Settings, job_state: &Scheduler) -> Vec<(String, bool)> {
+        match tile.kind {
+            TileKind::Session => {
+                let session = SessionStats::from_matches(rows);
+                if session.games == 0 {
+                    return vec![("Not in a session right now".to_string(), false)];
+                }
+                vec![
+                    (format!("{} games · {} wins", session.games, session.wins), false),
+                    (format!("K/D {:.2} · Avg #{:.1}", session.kd_ratio(), session.avg_placement), false),
+                ]
+            }
+            TileKind::HeroWinRates => tiles::hero_win_rates(rows, settings.preferred_region.as_deref())
+                .into_iter()
+                .take(TILE_ROWS)
+                .map(|hero| {
+                    let mut line = format!(
+                        "{} · {} of {} games",
+                        hero.hero,
+                        confidence::rate_with_interval(hero.wins, hero.games),
+                        hero.games
+                    );
+                    if hero.games < settings.min_sample_games {
+                        line.push_str(" · too few to tell");
+                    }
+                    (line, false)
+                })
+                .collect(),
+            TileKind::Leaderboard => {
+                let mut players: Vec<(PlayerRef, MatchRows)> = settings
+                    .watched_players
+                    .iter()
+                    .filter(|player| player.platform != tile.player.platform || player.player_id != tile.player.player_id)
+                    .filter_map(|player| {
+                        let polled = job_state.polled_rows(&player.platform, &player.player_id)?;
+                        Some((player.clone(), polled.rows))
+                    })
+                    .collect();
+                players.push((tile.player.clone(), rows.clone()));
+                let standings = tiles::leaderboard(players, settings.preferred_region.as_deref());
+                let is_tile_player = |entry: &LeaderboardEntry| {
+                    entry.player.platform == tile.player.platform && entry.player.player_id == tile.player.player_id
+                };
+                // Top rows, plus the tile's player when they rank below them
+                standings
+                    .iter()
+                    .enumerate()
+                    .filter(|(rank, entry)| *rank < TILE_ROWS || is_tile_player(entry))
+                    .map(|(rank, entry)| {
+                        (
+                            format!("{}. {} · Avg #{:.1} over {} games", rank + 1, entry.player.name, entry.avg_placement, entry.games),
+                            is_tile_player(entry),
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Tiles pinned from profiles, refreshed whenever "Poll watched players" runs.
+    fn render_pinned_tiles(&self, tiles: &[PinnedTile], settings: &Settings, cx: &mut Context<Self>) -> Div {
+        let refresh_focus = &self.tile_focus[0];
+        let tile_focus = &self.tile_focus[1..];
+        let job_state = cx.global::<AppState>().scheduler.clone();
+        let job_state = job_state.lock().unwrap();
+        let polling = job_state.status(JobKind::PollWatched).running;
+        let chip = |label: &'static str, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(rgb(0x313244))
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(div().flex_1().font_weight(FontWeight::SEMIBOLD).child("Pinned"))
+                    .child(
+                        chip(if polling { "Refreshing…" } else { "Refresh now" }, refresh_focus)
+                            .on_mouse_down(MouseButton::Left, |_, _window, cx| scheduler::run_now(JobKind::PollWatched, cx))
+                            .on_key_down(|event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    scheduler::run_now(JobKind::PollWatched, cx);
+                                }
+                            }),
+                    ),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap_3()
+                    .children(tiles.iter().enumerate().map(|(index, tile)| {
+                        let polled = job_state.polled_rows(&tile.player.platform, &tile.player.player_id);
+                        let focus = &tile_focus[index * TILE_CONTROLS..(index + 1) * TILE_CONTROLS];
+                        let open = OpenPlayer {
+                            player_id: tile.player.player_id.clone(),
+                            platform: tile.player.platform.clone(),
+                        };
+                        let key_open = open.clone();
+
+                        div()
+                            .w(px(280.0))
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .p_4()
+                            .bg(rgb(0x181825))
+                            .rounded_lg()
+                            .border_1()
+                            .border_color(rgb(0x313244))
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .flex()
+                                            .flex_col()
+                                            .rounded_md()
+                                            .cursor_pointer()
+                                            .focus_ring(&focus[0])
+                                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                                window.dispatch_action(Box::new(open.clone()), cx);
+                                            })
+                                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                                if is_activation_key(event) {
+                                                    window.dispatch_action(Box::new(key_open.clone()), cx);
+                                                }
+                                            })
+                                            .child(div().font_weight(FontWeight::SEMIBOLD).child(tile.player.name.clone()))
+                                            .child(div().text_xs().text_color(rgb(0x9399b2)).child(
+                                                match settings.preferred_region.as_deref().filter(|_| tile.kind.uses_region()) {
+                                                    Some(region) => format!("{} · {}", tile.kind.label(), region.to_uppercase()),
+                                                    None => tile.kind.label().to_string(),
+                                                },
+                                            )),
+                                    )
+                                    .child(
+                                        chip("Unpin", &focus[1])
+                                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                                this.unpin_tile(index, cx);
+                                            }))
+                                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                                if is_activation_key(event) {
+                                                    this.unpin_tile(index, cx);
+                                                }
+                                            })),
+                                    ),
+                            )
+                            .map(|card| match &polled {
+                                Some(polled) => {
+                                    let lines = Self::tile_lines(tile, &polled.rows, settings, &job_state);
+                                    card.when(lines.is_empty(), |card| {
+                                        card.child(div().text_sm().text_color(rgb(0x9399b2)).child("No recent matches"))
+                                    })
+                                    .children(lines.into_iter().map(|(line, highlight)| {
+                                        div()
+                                            .text_sm()
+                                            .when(highlight, |line| line.text_color(rgb(0x89b4fa)))
+                                            .child(line)
+                                    }))
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(rgb(0x6c7086))
+                                            .child(format!(
+                                                "Updated {}",
+                                                polled.fetched_at.with_timezone(&Local).format("%H:%M")
+                                            )),
+                                    )
+                                }
+                                None => card.child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(rgb(0x9399b2))
+                                        .child("Waiting for the next poll"),
+                                ),
+                            })
+                    })),
+            )
+    }
+
+    /// Matches tagged "review later", shown until their review is cleared.
+    fn render_review_queue(&self, queue: &[MatchNote], cx: &mut Context<Self>) -> Div {
+        let chip = |label: String, selected: bool, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_4()
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(div().font_weight(FontWeight::SEMIBOLD).child(format!("Review queue ({})", queue.len())))
+            .children(queue.iter().enumerate().map(|(index, note)| {
+                let focus = &self.queue_focus[index * QUEUE_CONTROLS..(index + 1) * QUEUE_CONTROLS];
+                let open = OpenMatch {
+                    match_id: note.match_id.clone(),
+                    platform: note.platform.clone(),
+                };
+                let key_open = open.clone();
+                let mut label = note
+                    .match_start
+                    .map(|start| format::date_time(&start))
+                    .unwrap_or_else(|| format!("Match {}", note.match_id.chars().take(8).collect::<String>()));
+                if let Some(hero) = &note.hero {
+                    label = format!("{} · {}", label, hero);
+                }
+                let (platform, match_id) = (note.platform.clone(), note.match_id.clone());
+
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.text_color(rgb(0x89b4fa)))
+                            .focus_ring(&focus[0])
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                window.dispatch_action(Box::new(open.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_open.clone()), cx);
+                                }
+                            })
+                            .child(label),
+                    )
+                    .children(ChecklistItem::ALL.into_iter().zip(&focus[1..]).map(|(item, focus)| {
+                        let done = note.is_checked(item);
+                        let (click_platform, click_id) = (platform.clone(), match_id.clone());
+                        let (key_platform, key_id) = (platform.clone(), match_id.clone());
+                        chip(format!("{} {}", if done { "☑" } else { "☐" }, item.label()), done, focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.update_review(cx, |notes| notes.toggle_checklist(&click_platform, &click_id, item));
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.update_review(cx, |notes| notes.toggle_checklist(&key_platform, &key_id, item));
+                                }
+                            }))
+                    }))
+                    .child({
+                        let (click_platform, click_id) = (platform.clone(), match_id.clone());
+                        chip("Clear".to_string(), false, &focus[QUEUE_CONTROLS - 1])
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.update_review(cx, |notes| notes.clear_review(&click_platform, &click_id));
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.update_review(cx, |notes| notes.clear_review(&platform, &match_id));
+                                }
+                            }))
+                    })
+            }))
+    }
+
+    fn update_review(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut MatchNotes)) {
+        f(&mut cx.global::<AppState>().match_notes.lock().unwrap());
+        cx.notify();
+    }
+
+    fn render_report(&self, cx: &mut Context<Self>) -> Div {
+        let chip = |label: &'static str, selected: bool, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+                .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+        let export_focus = &self.report_focus[ReportPeriod::ALL.len()];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_4()
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(div().flex_1().font_weight(FontWeight::SEMIBOLD).child("Summary"))
+                    .children(ReportPeriod::ALL.iter().zip(&self.report_focus).map(|(period, focus)| {
+                        let period = *period;
+                        chip(period.label(), period == self.report_period, focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.set_report_period(period, cx);
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.set_report_period(period, cx);
+                                }
+                            }))
+                    }))
+                    .when(self.report.is_some() && !self.report_loading, |parent| {
+                        parent.child(
+                            chip("Export markdown", false, export_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.export_report(cx);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.export_report(cx);
+                                    }
+                                })),
+                        )
+                    }),
+            )
+            .child(match (&self.report, self.report_loading) {
+                (_, true) => div().text_sm().text_color(rgb(0x9399b2)).child("Loading…"),
+                (Some(report), false) if report.games > 0 => div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(div().text_base().child(report.headline()))
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(format!(
+                                "K/D {:.2} · Avg #{:.1} · {} K / {} D",
+                                report.kd_ratio(),
+                                report.avg_placement,
+                                report.kills,
+                                report.deaths
+                            )),
+                    ),
+                _ => div()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child(format!("No matches {}.", self.report_period.label().to_lowercase())),
+            })
+            .when_some(self.report_status.as_ref(), |parent, status| {
+                parent.child(match status {
+                    Ok(message) => div().text_xs().text_color(rgb(0xa6e3a1)).child(message.clone()),
+                    Err(message) => div().text_xs().text_color(rgb(0xf38ba8)).child(message.clone()),
+                })
+            })
+    }
+
+    /// Reads the local Steam login and looks for a Steam profile with the same name, so it
+    /// can be offered as "my account".
+    fn detect_steam_account(cx: &mut Context<Self>) {
+        let service = cx.global::<AppState>().service.clone();
+        cx.spawn(async move |view, cx| {
+            let detected = cx.background_executor().spawn(async move {
+                let login = steam::detect_login()?;
+                let name = login.persona_name.clone();
+                let results = service.lock().unwrap().search_players(&name).ok();
+                let profile = results
+                    .as_ref()
+                    .and_then(|data| data.as_array())
+                    .and_then(|players| {
+                        players.iter().find(|player| {
+                            player["platform"].as_str() == Some("steam")
+                                && [&player["uniqueDisplayName"], &player["displayName"]]
+                                    .iter()
+                                    .any(|value| value.as_str().is_some_and(|n| n.eq_ignore_ascii_case(&name)))
+                        })
+                    })
+                    .map(|player| PlayerRef {
+                        platform: "steam".to_string(),
+                        player_id: player["userId"].as_str().unwrap_or("").to_string(),
+                        name: player["uniqueDisplayName"].as_str().unwrap_or(&name).to_string(),
+                    });
+                Some((login, profile))
+            }).await;
+
+            if detected.is_some() {
+                view.update(cx, |this, cx| {
+                    this.steam_login = detected;
+                    cx.notify();
+                }).ok();
+            }
+        }).detach();
+    }
+
+    fn link_account(&mut self, account: PlayerRef, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| s.link_account(account));
+        self.steam_login = None;
+        self.load_report(cx);
+        cx.refresh_windows();
+    }
+
+    fn dismiss_steam_prompt(&mut self, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| s.steam_prompt_dismissed = true);
+        self.steam_login = None;
+        cx.notify();
+    }
+
+    pub fn search_for(&mut self, query: String, window: &mut Window, cx: &mut Context<Self>) {
+        self.input.set_text(query);
+        self.steam_login = None;
+        self.perform_search(window, cx);
+    }
+
+    fn render_steam_prompt(&self, login: &SteamLogin, profile: Option<&PlayerRef>, cx: &mut Context<Self>) -> Div {
+        let action_label = match profile {
+            Some(profile) => format!("Link {} as my account", profile.name),
+            None => format!("Search for {}", login.persona_name),
+        };
+        let profile = profile.cloned();
+        let persona = login.persona_name.clone();
+
+        div()
+            .flex()
+            .items_center()
+            .gap_3()
+            .p_3()
+            .bg(rgb(0x181825))
+            .rounded_md()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .flex_1()
+                    .text_sm()
+                    .child(format!("Signed in to Steam as {}.", login.persona_name)),
+            )
+            .child(
+                div()
+                    .px_3()
+                    .py_1()
+                    .text_sm()
+                    .bg(rgb(0x89b4fa))
+                    .text_color(rgb(0x1e1e2e))
+                    .rounded_md()
+                    .cursor_pointer()
+                    .focus_ring(&self.steam_focus[0])
+                    .on_mouse_down(MouseButton::Left, cx.listener({
+                        let (profile, persona) = (profile.clone(), persona.clone());
+                        move |this, _, window, cx| match profile.clone() {
+                            Some(profile) => this.link_account(profile, cx),
+                            None => this.search_for(persona.clone(), window, cx),
+                        }
+                    }))
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                        if is_activation_key(event) {
+                            match profile.clone() {
+                                Some(profile) => this.link_account(profile, cx),
+                                None => this.search_for(persona.clone(), window, cx),
+                            }
+                        }
+                    }))
+                    .child(action_label),
+            )
+            .child(
+                div()
+                    .px_3()
+                    .py_1()
+                    .text_sm()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .hover(|s| s.bg(rgb(0x313244)))
+                    .focus_ring(&self.steam_focus[1])
+                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                        this.dismiss_steam_prompt(cx);
+                    }))
+                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                        if is_activation_key(event) {
+                            this.dismiss_steam_prompt(cx);
+                        }
+                    }))
+                    .child("Don't ask again"),
+            )
+    }
+
+    /// What the result area shows instead of results: a spinner while searching, a note when
+    /// the last search found nobody, or tips and recent searches before the first one.
+    fn render_search_state(&self, recent: &[String], cx: &mut Context<Self>) -> Option<Div> {
+        const SPINNER: [&str; 4] = ["◐", "◓", "◑", "◒"];
+        if self.searching {
+            let query = self.input.text().trim().to_string();
+            return Some(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .text_sm()
+                    .text_color(rgb(0x9399b2))
+                    .child(div().text_color(rgb(0x89b4fa)).with_animation(
+                        "search-spinner",
+                        Animation::new(Duration::from_millis(800)).repeat(),
+                        |spinner, delta| spinner.child(SPINNER[((delta * 4.0) as usize).min(3)]),
+                    ))
+                    .child(format!("Searching for '{}'...", query)),
+            );
+        }
+        if !self.results.is_empty() {
+            return None;
+        }
+        if let Some(query) = &self.searched_query {
+            return Some(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .p_4()
+                    .bg(rgb(0x181825))
+                    .rounded_md()
+                    .child(div().font_weight(FontWeight::SEMIBOLD).child(format!("No players found for '{}'", query)))
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Check the spelling and the platform: names are unique per platform, so a Steam name won't find a console account. Pasting a profile URL or ID also works."),
+                    ),
+            );
+        }
+
+        let tip = |text: &'static str| div().text_sm().text_color(rgb(0x9399b2)).child(text);
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .p_4()
+                .bg(rgb(0x181825))
+                .rounded_md()
+                .child(div().font_weight(FontWeight::SEMIBOLD).child("Find a player"))
+                .child(tip("Search by in-game name; the #tag is optional."))
+                .child(tip("Paste an op.gg profile URL or a platform ID to open that profile directly."))
+                .child(tip("Players you've come across before are suggested as you type, even offline."))
+                .when(!recent.is_empty(), |parent| {
+                    parent
+                        .child(div().mt_2().text_xs().text_color(rgb(0x6c7086)).child("Recent searches"))
+                        .child(div().flex().flex_wrap().gap_2().children(recent.iter().zip(&self.recent_focus).map(
+                            |(query, focus)| {
+                                let (click_query, key_query) = (query.clone(), query.clone());
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_sm()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .bg(rgb(0x313244))
+                                    .hover(|s| s.bg(rgb(0x45475a)))
+                                    .focus_ring(focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                                        this.search_for(click_query.clone(), window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.search_for(key_query.clone(), window, cx);
+                                        }
+                                    }))
+                                    .child(query.clone())
+                            },
+                        )))
+                }),
+        )
+    }
+
+    /// Opens one account from a result card.
+    fn open_result(&mut self, idx: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(player) = self.results.get(idx) else {
+            return;
+        };
+        let platform = player["platform"].as_str().unwrap_or("steam").to_string();
+        let id = player["userId"].as_str().unwrap_or("").to_string();
+        self.open_player(platform, id, window, cx);
+    }
+
+    /// One card per name. A name held on a single platform makes the card itself the button;
+    /// with several, each platform badge opens its own account and the card opens the first.
+    fn render_result_card(&self, group: &[usize], settings: &Settings, spacing: &Spacing, cx: &mut Context<Self>) -> Stateful<Div> {
+        let primary_idx = group[0];
+        let player = &self.results[primary_idx];
+        let name = player["uniqueDisplayName"].as_str().unwrap_or("Unknown").to_string();
+        let display_name = player["displayName"].as_str().unwrap_or("").to_string();
+        let id = player["userId"].as_str().unwrap_or("").to_string();
+        let platform = player["platform"].as_str().unwrap_or("steam").to_string();
+        let exact_match = name.eq_ignore_ascii_case(self.input.text().trim());
+        let details = self.details.get(&id).cloned();
+        let details_failed = self.details_failed.contains(&id);
+        let other_region = settings.preferred_region.as_ref().is_some_and(|preferred| {
+            details
+                .as_ref()
+                .and_then(|details| details.region.as_ref())
+                .is_some_and(|region| !region.eq_ignore_ascii_case(preferred))
+        });
+        let merged = group.len() > 1;
+        let dragged = DraggedPlayer(PlayerRef {
+            platform: platform.clone(),
+            player_id: id.clone(),
+            name: name.clone(),
+        });
+
+        let mut meta: Vec<String> = Vec::new();
+        if !display_name.is_empty() && display_name != name {
+            meta.push(display_name);
+        }
+        if let Some(details) = &details {
+            if let Some(last_seen) = details.last_seen {
+                meta.push(format_last_seen(last_seen));
+            }
+            if let Some(level) = details.level {
+                meta.push(format!("Level {}", level));
+            }
+            if let Some(rank) = &details.rank {
+                meta.push(rank.clone());
+            }
+            if let Some(region) = &details.region {
+                meta.push(region.to_uppercase());
+            }
+        }
+
+        let badges: Vec<Stateful<Div>> = group.iter().map(|&idx| {
+            let account = &self.results[idx];
+            let account_platform = account["platform"].as_str().unwrap_or("steam").to_string();
+            let account_id = account["userId"].as_str().unwrap_or("");
+            let mut tooltip = vec![format!("Platform: {}", account_platform)];
+            if let Some(details) = self.details.get(account_id) {
+                tooltip.extend(details.level.map(|level| format!("Level {}", level)));
+                tooltip.extend(details.rank.clone());
+                tooltip.extend(details.region.as_ref().map(|region| region.to_uppercase()));
+            }
+            div()
+                .id(("platform", idx))
+                .tooltip(Tooltip::text(tooltip.join(" · ")))
+                .px_2()
+                .text_xs()
+                .bg(rgb(0x45475a))
+                .rounded_sm()
+                .when(merged, |badge| {
+                    badge
+                        .cursor_pointer()
+                        .hover(|s| s.bg(rgb(0x585b70)))
+                        .focus_ring(&self.result_focus[idx])
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            cx.stop_propagation();
+                            this.open_result(idx, window, cx);
+                        }))
+                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                            if is_activation_key(event) {
+                                this.open_result(idx, window, cx);
+                            }
+                        }))
+                })
+                .child(account_platform.to_uppercase())
+        }).collect();
+
+        div()
+            .id(ElementId::Name(format!("result-{}-{}", platform, id).into()))
+            .flex()
+            .items_center()
+            .gap_3()
+            .p(spacing.row)
+            .bg(rgb(0x313244))
+            .rounded_md()
+            .cursor_pointer()
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .when(other_region, |row| row.opacity(0.6))
+            // Click rather than mouse-down so the result can be dragged onto a profile
+            .on_click(cx.listener(move |this, _, window, cx| this.open_result(primary_idx, window, cx)))
+            .on_drag(dragged, |dragged, _offset, _window, cx| cx.new(|_| dragged.clone()))
+            .when(!merged, |row| {
+                row.focus_ring(&self.result_focus[primary_idx])
+                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                        if is_activation_key(event) {
+                            this.open_result(primary_idx, window, cx);
+                        }
+                    }))
+            })
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_w_0()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(TruncatedText::new("name", name).font_weight(FontWeight::SEMIBOLD))
+                            .when(exact_match, |parent| {
+                                parent.child(
+                                    div()
+                                        .px_2()
+                                        .text_xs()
+                                        .bg(rgb(0xa6e3a1))
+                                        .text_color(rgb(0x1e1e2e))
+                                        .rounded_sm()
+                                        .child("Exact match")
+                                )
+                            })
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .flex_wrap()
+                            .gap_x_3()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .when(details.is_none() && !details_failed, |parent| parent.child("Loading details..."))
+                            .when(details_failed, |parent| {
+                                let (click_id, key_id) = (id.clone(), id.clone());
+                                parent.child("Details unavailable").child(
+                                    div()
+                                        .id(ElementId::Name(format!("retry-{}", id).into()))
+                                        .text_color(rgb(0x89b4fa))
+                                        .cursor_pointer()
+                                        .hover(|s| s.underline())
+                                        .focus_ring(&self.retry_focus[primary_idx])
+                                        .on_click(cx.listener(move |this, _, _window, cx| {
+                                            cx.stop_propagation();
+                                            this.retry_details(&click_id, cx);
+                                        }))
+                                        // Handled here so Enter doesn't also open the result
+                                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                cx.stop_propagation();
+                                                this.retry_details(&key_id, cx);
+                                            }
+                                        }))
+                                        .child("Retry")
+                                )
+                            })
+                            .children(meta)
+                    )
+            )
+            .child(div().flex().gap_1().children(badges))
+    }
+
+    pub fn focus_input(&self, window: &mut Window) {
+        window.focus(&self.focus_handle);
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Search".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    /// Esc in the search box: clears the query, or the results once the query is empty.
+    fn clear_search(&mut self, cx: &mut Context<Self>) {
+        if self.input.clear() {
+            self.update_suggestions(cx);
+        } else {
+            // Drops a search still in flight along with the shown results
+            self.search_requests.start();
+            self.searching = false;
+            self.searched_query = None;
+            self.results.clear();
+            self.result_groups.clear();
+            self.details.clear();
+            self.details_pending.clear();
+            self.details_failed.clear();
+            self.details_progress = None;
         }
+        cx.notify();
     }
 
-    fn perform_search(&mut self, cx: &mut Context<Self>) {
-        let query = self.query.clone();
+    fn perform_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let query = self.input.text().to_string();
         if query.is_empty() {
             return;
         }
+        // Back to the box after searching from the button or a chip, so the query can be refined
+        window.focus(&self.focus_handle);
 
-        let app_state = cx.global::<AppState>();
-        let service = app_state.service.clone();
+        // Pasted profile URLs and platform ids open the profile directly
+        if let Some((platform, player_id)) = parse_profile_reference(&query) {
+            telemetry::track(cx, "open_profile_link");
+            self.open_player(platform, player_id, window, cx);
+            return;
+        }
+
+        telemetry::track(cx, "search");
+        self.search(cx);
+    }
+
+    /// Runs the network search for the current query; also the retry after a rate limit.
+    fn search(&mut self, cx: &mut Context<Self>) {
+        let query = self.input.text().to_string();
+        let service = cx.global::<AppState>().service.clone();
+        let request = self.search_requests.start();
+        self.searching = true;
+        cx.notify();
 
         let view = cx.entity();
         cx.spawn(async move |_, cx| {
+            let searched = query.clone();
             let result = cx.background_executor().spawn(async move {
                 let mut service = service.lock().unwrap();
                 service.search_players(&query)
             }).await;
 
             view.update(cx, |this, cx| {
+                // Pressing Enter repeatedly or searching again supersedes this search
+                if !this.search_requests.is_current(request) {
+                    return;
+                }
+                this.searching = false;
+                this.rate_limit = match &result {
+                    Err(err) => rate_limit_of(err)
+                        .map(|retry_after| RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::search, cx)),
+                    Ok(_) => None,
+                };
                 if let Ok(data) = result {
                     if let Some(array) = data.as_array() {
+                        this.searched_query = Some(searched.clone());
+                        cx.global::<AppState>()
+                            .player_index
+                            .lock()
+                            .unwrap()
+                            .record_search_results(array);
                         this.results = array.clone();
+                        // Players on the user's own platform first, otherwise in API order
+                        let platform = cx.global::<AppState>().settings().preferred_platform;
+                        this.results
+                            .sort_by_key(|player| player["platform"].as_str().unwrap_or("steam") != platform);
+                        this.result_groups = group_by_name(&this.results);
+                        if !array.is_empty() {
+                            cx.global::<AppState>()
+                                .settings
+                                .lock()
+                                .unwrap()
+                                .update(|s| s.record_recent_search(&searched));
+                        }
+                        this.details.clear();
+                        this.details_pending.clear();
+                        this.details_failed.clear();
+                        this.shown_results = RESULTS_PAGE;
+                        this.results_scroll.set_offset(Point::default());
+                        this.fetch_details(cx);
                     }
                 }
                 cx.notify();
@@ -49,20 +1294,149 @@ impl SearchView {
         }).detach();
     }
 
-    fn on_input(&mut self, text: &str, cx: &mut Context<Self>) {
-        self.query = text.to_string();
+    /// Opens the profile; the root view closes it again if op.gg doesn't know the id.
+    fn open_player(&mut self, platform: String, player_id: String, window: &mut Window, cx: &mut Context<Self>) {
+        window.dispatch_action(Box::new(OpenPlayer { player_id, platform }), cx);
+    }
+
+    /// Lists the next page of results and looks up their details.
+    fn show_more_results(&mut self, cx: &mut Context<Self>) {
+        self.shown_results = (self.shown_results + RESULTS_PAGE).min(self.result_groups.len());
+        self.fetch_details(cx);
+        cx.notify();
+    }
+
+    fn retry_details(&mut self, id: &str, cx: &mut Context<Self>) {
+        self.details_failed.remove(id);
+        self.fetch_details(cx);
         cx.notify();
     }
+
+    /// Looks up last-seen/level/rank/region for each listed result so same-named accounts can
+    /// be told apart. Results already looked up or queued are skipped.
+    fn fetch_details(&mut self, cx: &mut Context<Self>) {
+        let app_state = cx.global::<AppState>();
+        let service = app_state.service.clone();
+        let targets: Vec<(String, String)> = self
+            .result_groups
+            .iter()
+            .take(self.shown_results)
+            .flatten()
+            .map(|&idx| &self.results[idx])
+            .filter_map(|player| {
+                let id = player["userId"].as_str()?.to_string();
+                let platform = player["platform"].as_str().unwrap_or("steam").to_string();
+                Some((platform, id))
+            })
+            .filter(|(_, id)| {
+                !self.details.contains_key(id) && !self.details_pending.contains(id) && !self.details_failed.contains(id)
+            })
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+        self.details_pending.extend(targets.iter().map(|(_, id)| id.clone()));
+        self.details_progress = Some(TaskProgress::new("players loaded", targets.len()));
+        let request = self.search_requests.current();
+
+        cx.spawn(async move |view, cx| {
+            for (platform, id) in targets {
+                let service = service.clone();
+                let lookup_id = id.clone();
+                let result = cx.background_executor().spawn(async move {
+                    let mut service = service.lock().unwrap();
+                    service.get_player_summary(&platform, &lookup_id)
+                }).await;
+
+                let current = view.update(cx, |this, cx| {
+                    if !this.search_requests.is_current(request) {
+                        return false;
+                    }
+                    // Looking up the rest now would hit the limit too; start over once it lifts
+                    if let Some(retry_after) = result.as_ref().err().and_then(rate_limit_of) {
+                        this.details_pending.clear();
+                        this.rate_limit =
+                            Some(RateLimit::start(retry_after, |this: &mut Self| &mut this.rate_limit, Self::fetch_details, cx));
+                        cx.notify();
+                        return false;
+                    }
+                    this.details_pending.remove(&id);
+                    match result {
+                        Ok(summary) => {
+                            this.details.insert(id, summary);
+                        }
+                        Err(err) => {
+                            tracing::warn!(player_id = %id, error = %err, "Failed to load search result details");
+                            this.details_failed.insert(id);
+                        }
+                    }
+                    if let Some(progress) = this.details_progress.as_mut() {
+                        progress.advance();
+                    }
+                    cx.notify();
+                    true
+                });
+                if !matches!(current, Ok(true)) {
+                    break;
+                }
+            }
+        }).detach();
+    }
+
+    fn update_suggestions(&mut self, cx: &mut Context<Self>) {
+        let index = cx.global::<AppState>().player_index.clone();
+        self.suggestions = index.lock().unwrap().search(self.input.text(), 8);
+    }
 }
 
+// Typed text reaches the search box through the platform's input handler rather than key
+// presses, so IMEs can compose CJK names in place.
+impl_text_input_handler!(SearchView, input, |this: &mut SearchView, cx: &mut Context<SearchView>| {
+    this.update_suggestions(cx)
+});
+
 impl Render for SearchView {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        sync_focus_handles(&mut self.suggestion_focus, self.suggestions.len(), cx);
+        let shown_results = self.shown_results.min(self.result_groups.len());
+        sync_focus_handles(&mut self.result_focus, self.results.len(), cx);
+        sync_focus_handles(&mut self.retry_focus, self.results.len(), cx);
+        sync_focus_handles(&mut self.steam_focus, 2, cx);
+        sync_focus_handles(&mut self.report_focus, ReportPeriod::ALL.len() + 1, cx);
+        let goals = cx.global::<AppState>().settings().goals;
+        sync_focus_handles(&mut self.goal_focus, NEW_GOAL_BUTTONS + goals.len() * GOAL_CONTROLS, cx);
+        let input_focused = self.focus_handle.is_focused(window);
+        let settings = cx.global::<AppState>().settings();
+        sync_focus_handles(&mut self.tile_focus, 1 + settings.pinned_tiles.len() * TILE_CONTROLS, cx);
+        let review_queue = cx.global::<AppState>().match_notes.lock().unwrap().review_queue();
+        sync_focus_handles(&mut self.queue_focus, review_queue.len() * QUEUE_CONTROLS, cx);
+        let spacing = settings.density.spacing();
+        sync_focus_handles(&mut self.recent_focus, settings.recent_searches.len(), cx);
+        let search_state = self.render_search_state(&settings.recent_searches, cx);
+        let steam_prompt = self
+            .steam_login
+            .clone()
+            .map(|(login, profile)| self.render_steam_prompt(&login, profile.as_ref(), cx));
+        let report = settings.my_account.is_some().then(|| self.render_report(cx));
+        let goals = settings.my_account.is_some().then(|| self.render_goals(&goals, cx));
+        let review_queue = (!review_queue.is_empty()).then(|| self.render_review_queue(&review_queue, cx));
+        let pinned_tiles = (!settings.pinned_tiles.is_empty())
+            .then(|| self.render_pinned_tiles(&settings.pinned_tiles, &settings, cx));
+
         div()
+            .id("search-view")
             .flex()
             .flex_col()
             .size_full()
-            .p_8()
-            .gap_4()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .children(steam_prompt)
+            .children(report)
+            .children(goals)
+            .children(pinned_tiles)
+            .children(review_queue)
             .child(
                 div()
                     .flex()
@@ -74,7 +1448,7 @@ impl Render for SearchView {
                             .bg(rgb(0x313244))
                             .rounded_md()
                             .border_1()
-                            .border_color(rgb(0x45475a))
+                            .border_color(if input_focused { rgb(0xf9e2af) } else { rgb(0x45475a) })
                             .cursor_text()
                             .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
                                 window.focus(&this.focus_handle);
@@ -83,69 +1457,30 @@ impl Render for SearchView {
                             .child(
                                 div()
                                     .track_focus(&self.focus_handle)
-                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
                                         match event.keystroke.key.as_str() {
-                                            "backspace" => {
-                                                if this.cursor_position > 0 && !this.query.is_empty() {
-                                                    this.query.remove(this.cursor_position - 1);
-                                                    this.cursor_position -= 1;
-                                                    cx.notify();
-                                                }
-                                            }
-                                            "delete" => {
-                                                if this.cursor_position < this.query.len() {
-                                                    this.query.remove(this.cursor_position);
-                                                    cx.notify();
-                                                }
-                                            }
-                                            "left" => {
-                                                if this.cursor_position > 0 {
-                                                    this.cursor_position -= 1;
-                                                    cx.notify();
-                                                }
-                                            }
-                                            "right" => {
-                                                if this.cursor_position < this.query.len() {
-                                                    this.cursor_position += 1;
-                                                    cx.notify();
-                                                }
-                                            }
-                                            "home" => {
-                                                this.cursor_position = 0;
-                                                cx.notify();
-                                            }
-                                            "end" => {
-                                                this.cursor_position = this.query.len();
-                                                cx.notify();
-                                            }
                                             "enter" => {
-                                                this.perform_search(cx);
+                                                this.perform_search(window, cx);
+                                                return;
                                             }
-                                            key if key.len() == 1 => {
-                                                this.query.insert_str(this.cursor_position, key);
-                                                this.cursor_position += key.len();
-                                                cx.notify();
+                                            "escape" => {
+                                                this.clear_search(cx);
+                                                return;
                                             }
                                             _ => {}
                                         }
-                                    }))
-                                    .child({
-                                        let is_focused = self.focus_handle.is_focused(window);
-                                        if self.query.is_empty() && !is_focused {
-                                            "Search player...".to_string()
-                                        } else {
-                                            let mut display_text = self.query.clone();
-                                            if is_focused {
-                                                // Insert caret at cursor position
-                                                display_text.insert(self.cursor_position, '|');
-                                            }
-                                            if display_text.is_empty() {
-                                                "|".to_string()
-                                            } else {
-                                                display_text
+                                        match this.input.handle_key(&event.keystroke) {
+                                            InputChange::Edited => {
+                                                this.update_suggestions(cx);
+                                                cx.notify();
                                             }
+                                            InputChange::Moved => cx.notify(),
+                                            InputChange::Ignored => {}
                                         }
-                                    })
+                                    }))
+                                    .relative()
+                                    .child(self.input.render_text(input_focused, "Search player, profile URL or ID..."))
+                                    .child(input_handler(cx.entity(), self.focus_handle.clone()))
                             )
                     )
                     .child(
@@ -155,35 +1490,247 @@ impl Render for SearchView {
                             .text_color(rgb(0x1e1e2e))
                             .rounded_md()
                             .cursor_pointer()
-                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.perform_search(cx)))
+                            .focus_ring(&self.search_button_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.perform_search(window, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.perform_search(window, cx);
+                                }
+                            }))
                             .child("Search")
                     )
+                    .child(
+                        div()
+                            .id("scout-lobby")
+                            .p_2()
+                            .bg(rgb(0x313244))
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(&self.scout_focus)
+                            .tooltip(Tooltip::text("Look up a whole custom lobby from a pasted list of names"))
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                window.dispatch_action(Box::new(OpenScout), cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(OpenScout), cx);
+                                }
+                            })
+                            .child("Scout lobby")
+                    )
+                    .child(
+                        div()
+                            .id("import-matches")
+                            .p_2()
+                            .bg(rgb(0x313244))
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(&self.import_focus)
+                            .tooltip(Tooltip::text("Fetch and keep a list of matches by ID, e.g. a tournament's games"))
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                window.dispatch_action(Box::new(OpenMatchImport), cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(OpenMatchImport), cx);
+                                }
+                            })
+                            .child("Import matches")
+                    )
+                    .child(
+                        div()
+                            .id("open-series")
+                            .p_2()
+                            .bg(rgb(0x313244))
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(&self.series_focus)
+                            .tooltip(Tooltip::text("Group scrim games into a named series and score them together"))
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                window.dispatch_action(Box::new(OpenSeries), cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(OpenSeries), cx);
+                                }
+                            })
+                            .child("Series")
+                    )
+                    .child(
+                        div()
+                            .id("open-meta")
+                            .p_2()
+                            .bg(rgb(0x313244))
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgb(0x45475a)))
+                            .focus_ring(&self.meta_focus)
+                            .tooltip(Tooltip::text("Follow a group of top players and see what they pick"))
+                            .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                window.dispatch_action(Box::new(OpenMeta), cx);
+                            })
+                            .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(OpenMeta), cx);
+                                }
+                            })
+                            .child("Meta")
+                    )
             )
+            .children(self.rate_limit.map(RateLimitBanner::new))
+            .when_some(
+                self.details_progress.clone().filter(|progress| !progress.is_done()),
+                |parent, progress| parent.child(ProgressBar::new(progress)),
+            )
+            .when(!self.suggestions.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(rgb(0x9399b2))
+                                .child("Seen before")
+                        )
+                        .children(self.suggestions.iter().zip(&self.suggestion_focus).map(|(player, focus)| {
+                            let player_id = player.player_id.clone();
+                            let platform = player.platform.clone();
+                            let name = player.unique_display_name.clone();
+
+                            let dragged = DraggedPlayer(PlayerRef {
+                                platform: platform.clone(),
+                                player_id: player_id.clone(),
+                                name: name.clone(),
+                            });
+
+                            div()
+                                .id(ElementId::Name(format!("seen-{}-{}", platform, player_id).into()))
+                                .flex()
+                                .items_center()
+                                .gap_3()
+                                .px_4()
+                                .py_2()
+                                .bg(rgb(0x181825))
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(|s| s.bg(rgb(0x313244)))
+                                .focus_ring(focus)
+                                .on_click(cx.listener({
+                                    let (platform, player_id) = (platform.clone(), player_id.clone());
+                                    move |this, _, window, cx| {
+                                        this.open_player(platform.clone(), player_id.clone(), window, cx);
+                                    }
+                                }))
+                                .on_drag(dragged, |dragged, _offset, _window, cx| cx.new(|_| dragged.clone()))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                    if is_activation_key(event) {
+                                        this.open_player(platform.clone(), player_id.clone(), window, cx);
+                                    }
+                                }))
+                                .child(TruncatedText::new("name", player.unique_display_name.clone()).flex_1())
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(rgb(0x6c7086))
+                                        .child(player.platform.to_uppercase())
+                                )
+                        }))
+                )
+            })
+            .children(search_state)
+            .when(!self.results.is_empty(), |parent| {
+                parent.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(0x9399b2))
+                        .child(if shown_results < self.result_groups.len() {
+                            format!(
+                                "Showing {} of {} players",
+                                format::integer(shown_results as i64),
+                                format::integer(self.result_groups.len() as i64)
+                            )
+                        } else if self.result_groups.len() == 1 {
+                            "1 player found".to_string()
+                        } else {
+                            format!("{} players found", format::integer(self.result_groups.len() as i64))
+                        }),
+                )
+            })
             .child(
                 div()
+                    .id("search-results")
                     .flex()
                     .flex_col()
-                    .gap_2()
-                    .children(self.results.iter().map(|player| {
-                        let name = player["uniqueDisplayName"].as_str().unwrap_or("Unknown").to_string();
-                        let id = player["userId"].as_str().unwrap_or("").to_string();
-                        // Assuming platform is available or defaulting to "pc"
-                        let platform = player["platform"].as_str().unwrap_or("steam").to_string(); 
-
+                    .gap(spacing.row_gap)
+                    .max_h(px(RESULTS_HEIGHT))
+                    .overflow_y_scroll()
+                    .track_scroll(&self.results_scroll)
+                    .children(
+                        self.result_groups
+                            .iter()
+                            .take(shown_results)
+                            .map(|group| self.render_result_card(group, &settings, &spacing, cx))
+                            .collect::<Vec<_>>(),
+                    )
+            )
+            .when(shown_results < self.result_groups.len(), |parent| {
+                parent.child(
+                    div().flex().justify_center().child(
                         div()
-                            .p_4()
-                            .bg(rgb(0x313244))
+                            .px_3()
+                            .py_1()
+                            .text_sm()
                             .rounded_md()
                             .cursor_pointer()
+                            .bg(rgb(0x313244))
                             .hover(|s| s.bg(rgb(0x45475a)))
-                            .on_mouse_down(MouseButton::Left, cx.listener(move |_, _, window, cx| {
-                                window.dispatch_action(Box::new(OpenPlayer {
-                                    player_id: id.clone(),
-                                    platform: platform.clone(),
-                                }), cx);
+                            .focus_ring(&self.show_more_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.show_more_results(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.show_more_results(cx);
+                                }
                             }))
-                            .child(name)
-                    }))
-            )
+                            .child(format!(
+                                "Show {} more",
+                                RESULTS_PAGE.min(self.result_groups.len() - shown_results)
+                            )),
+                    ),
+                )
+            })
+    }
+}
+
+/// Groups results by name, case-insensitively, so one player's accounts on several platforms
+/// share a card. Groups and the accounts within them keep the results' order.
+fn group_by_name(results: &[Value]) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+    for (idx, player) in results.iter().enumerate() {
+        let name = player["uniqueDisplayName"].as_str().unwrap_or("").to_lowercase();
+        match by_name.get(&name) {
+            Some(&group) if !name.is_empty() => groups[group].push(idx),
+            _ => {
+                by_name.insert(name, groups.len());
+                groups.push(vec![idx]);
+            }
+        }
+    }
+    groups
+}
+
+fn format_last_seen(last_seen: chrono::DateTime<Utc>) -> String {
+    let elapsed = Utc::now().signed_duration_since(last_seen);
+    if elapsed.num_days() > 0 {
+        format!("Last seen {}d ago", elapsed.num_days())
+    } else if elapsed.num_hours() > 0 {
+        format!("Last seen {}h ago", elapsed.num_hours())
+    } else {
+        format!("Last seen {}m ago", elapsed.num_minutes().max(0))
     }
 }
@@ -0,0 +1,912 @@
+use crate::api::take_rows;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::format;
+use crate::match_archive;
+use crate::series::{self, GameResult, PointSystem, Series, SeriesGame, Standings, MAX_SCORED_PLACEMENTS};
+use crate::state::AppState;
+use crate::tasks::RequestGeneration;
+use crate::telemetry;
+use crate::theme::team_color;
+use crate::views::modal::{self, Modal};
+use crate::views::OpenMatch;
+use gpui::prelude::*;
+use gpui::*;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Focus handles per game: open, label, remove.
+const GAME_CONTROLS: usize = 3;
+/// Focus handles per point stepper: minus, plus.
+const STEPPER_CONTROLS: usize = 2;
+
+/// Scrim series: custom matches grouped under a name and scored together, with standings, a
+/// breakdown per game and totals per map label. Scoreboards are kept in the match archive, so a
+/// series opens offline once loaded.
+pub struct SeriesView {
+    scroll_handle: ScrollHandle,
+    selected: Option<u64>,
+    /// Scoreboards of the selected series' games, by match id
+    loaded: HashMap<String, Vec<Value>>,
+    /// Games of the selected series still being fetched
+    pending: usize,
+    status: Option<Result<String, String>>,
+    /// Point system being tried out; standings are scored with it until applied or discarded
+    draft: Option<PointSystem>,
+    requests: RequestGeneration,
+    new_focus: FocusHandle,
+    series_focus: Vec<FocusHandle>,
+    latest_focus: FocusHandle,
+    paste_focus: FocusHandle,
+    rename_focus: FocusHandle,
+    delete_focus: FocusHandle,
+    what_if_focus: FocusHandle,
+    /// Steppers for each scored placement, then one for kills
+    stepper_focus: Vec<FocusHandle>,
+    add_place_focus: FocusHandle,
+    remove_place_focus: FocusHandle,
+    apply_focus: FocusHandle,
+    discard_focus: FocusHandle,
+    game_focus: Vec<FocusHandle>,
+}
+
+impl SeriesView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let selected = cx.global::<AppState>().series.lock().unwrap().list().first().map(|series| series.id);
+        let mut view = Self {
+            scroll_handle: ScrollHandle::new(),
+            selected: None,
+            loaded: HashMap::new(),
+            pending: 0,
+            status: None,
+            draft: None,
+            requests: RequestGeneration::default(),
+            new_focus: cx.focus_handle().tab_stop(true),
+            series_focus: Vec::new(),
+            latest_focus: cx.focus_handle().tab_stop(true),
+            paste_focus: cx.focus_handle().tab_stop(true),
+            rename_focus: cx.focus_handle().tab_stop(true),
+            delete_focus: cx.focus_handle().tab_stop(true),
+            what_if_focus: cx.focus_handle().tab_stop(true),
+            stepper_focus: Vec::new(),
+            add_place_focus: cx.focus_handle().tab_stop(true),
+            remove_place_focus: cx.focus_handle().tab_stop(true),
+            apply_focus: cx.focus_handle().tab_stop(true),
+            discard_focus: cx.focus_handle().tab_stop(true),
+            game_focus: Vec::new(),
+        };
+        if let Some(id) = selected {
+            view.select(id, cx);
+        }
+        view
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Series".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    fn selected_series(&self, cx: &App) -> Option<Series> {
+        let id = self.selected?;
+        cx.global::<AppState>().series.lock().unwrap().get(id)
+    }
+
+    fn select(&mut self, id: u64, cx: &mut Context<Self>) {
+        self.selected = Some(id);
+        self.loaded.clear();
+        self.status = None;
+        self.draft = None;
+        self.requests.start();
+        self.load_games(cx);
+        cx.notify();
+    }
+
+    /// Loads the selected series again, e.g. after its games were played on.
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        if let Some(id) = self.selected {
+            self.select(id, cx);
+        }
+    }
+
+    /// Fetches the scoreboards the view doesn't have yet, one at a time, archiving each so the
+    /// series survives the API cache expiring.
+    fn load_games(&mut self, cx: &mut Context<Self>) {
+        let Some(series) = self.selected_series(cx) else {
+            return;
+        };
+        let missing: Vec<SeriesGame> = series
+            .games
+            .into_iter()
+            .filter(|game| !self.loaded.contains_key(&game.match_id))
+            .collect();
+        self.pending = missing.len();
+        if missing.is_empty() {
+            return;
+        }
+        let app_state = cx.global::<AppState>();
+        let (service, archive, index) = (
+            app_state.service.clone(),
+            app_state.match_archive.clone(),
+            app_state.player_index.clone(),
+        );
+        let request = self.requests.current();
+
+        cx.spawn(async move |view, cx| {
+            let mut failures = Vec::new();
+            for game in missing {
+                let (service, archive, index) = (service.clone(), archive.clone(), index.clone());
+                let (platform, match_id) = (game.platform.clone(), game.match_id.clone());
+                let result = cx
+                    .background_executor()
+                    .spawn(async move {
+                        let archived = archive.lock().unwrap().get(&platform, &match_id);
+                        let data = match archived {
+                            Some(data) => data,
+                            None => {
+                                let data = service.lock().unwrap().get_match_for_archive(&platform, &match_id)?;
+                                archive.lock().unwrap().insert(&platform, &match_id, &data)?;
+                                data
+                            }
+                        };
+                        let rows = take_rows(data);
+                        index.lock().unwrap().record_match_rows(&platform, &rows);
+                        anyhow::Ok(rows)
+                    })
+                    .await;
+                let go_on = view.update(cx, |this, cx| {
+                    if !this.requests.is_current(request) {
+                        return false;
+                    }
+                    this.pending = this.pending.saturating_sub(1);
+                    match result {
+                        Ok(rows) => {
+                            this.loaded.insert(game.match_id.clone(), rows);
+                        }
+                        Err(err) => failures.push(format!("{}: {}", game.match_id.chars().take(8).collect::<String>(), err)),
+                    }
+                    cx.notify();
+                    true
+                });
+                if !matches!(go_on, Ok(true)) {
+                    return;
+                }
+            }
+            if !failures.is_empty() {
+                view.update(cx, |this, cx| {
+                    if this.requests.is_current(request) {
+                        this.status = Some(Err(format!("Couldn't load {} games: {}", failures.len(), failures.join("; "))));
+                        cx.notify();
+                    }
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn prompt_new_series(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt(
+            "New series",
+            "Name the series, e.g. \"Tuesday scrims vs TeamX\".",
+            "Series name",
+            move |name, _window, cx| {
+                view.update(cx, |this, cx| {
+                    telemetry::track(cx, "create_series");
+                    let id = cx.global::<AppState>().series.lock().unwrap().create(&name);
+                    this.select(id, cx);
+                })
+                .ok();
+            },
+        )
+        .confirm_label("Create");
+        modal::open(dialog, window, cx);
+    }
+
+    fn prompt_rename(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(series) = self.selected_series(cx) else {
+            return;
+        };
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt("Rename series", "", "Series name", move |name, _window, cx| {
+            view.update(cx, |_, cx| {
+                cx.global::<AppState>().series.lock().unwrap().rename(series.id, &name);
+                cx.notify();
+            })
+            .ok();
+        })
+        .initial_value(series.name.clone());
+        modal::open(dialog, window, cx);
+    }
+
+    fn confirm_delete(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(series) = self.selected_series(cx) else {
+            return;
+        };
+        let view = cx.entity().downgrade();
+        let dialog = Modal::confirm(
+            format!("Delete \"{}\"?", series.name),
+            "The series and its standings are removed. Its matches stay in the match archive.",
+            move |_window, cx| {
+                view.update(cx, |this, cx| {
+                    let store = cx.global::<AppState>().series.clone();
+                    store.lock().unwrap().delete(series.id);
+                    this.selected = None;
+                    this.loaded.clear();
+                    this.requests.start();
+                    let next = store.lock().unwrap().list().first().map(|next| next.id);
+                    if let Some(next) = next {
+                        this.select(next, cx);
+                    }
+                    cx.notify();
+                })
+                .ok();
+            },
+        )
+        .confirm_label("Delete")
+        .destructive();
+        modal::open(dialog, window, cx);
+    }
+
+    fn prompt_label(&mut self, game: SeriesGame, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(id) = self.selected else {
+            return;
+        };
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt(
+            "Label game",
+            "The map or anything else to total games by, e.g. \"Map A\".",
+            "Label",
+            move |label, _window, cx| {
+                view.update(cx, |_, cx| {
+                    cx.global::<AppState>().series.lock().unwrap().set_label(id, &game.match_id, &label);
+                    cx.notify();
+                })
+                .ok();
+            },
+        )
+        .initial_value(game.label.clone().unwrap_or_default());
+        modal::open(dialog, window, cx);
+    }
+
+    fn remove_game(&mut self, match_id: &str, cx: &mut Context<Self>) {
+        let Some(id) = self.selected else {
+            return;
+        };
+        cx.global::<AppState>().series.lock().unwrap().remove_game(id, match_id);
+        self.loaded.remove(match_id);
+        cx.notify();
+    }
+
+    fn add_games(&mut self, games: Vec<(String, String)>, cx: &mut Context<Self>) {
+        let Some(id) = self.selected else {
+            return;
+        };
+        let added = cx.global::<AppState>().series.lock().unwrap().add_games(id, games);
+        self.status = Some(Ok(format!("Added {} games", added)));
+        self.load_games(cx);
+        cx.notify();
+    }
+
+    fn paste_ids(&mut self, cx: &mut Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        let (matches, invalid) = match_archive::parse_match_list(&text);
+        if matches.is_empty() {
+            self.status = Some(Err(format!("No match IDs found in the clipboard ({} other pieces)", invalid.len())));
+            cx.notify();
+            return;
+        }
+        self.add_games(matches, cx);
+    }
+
+    /// Adds the linked account's latest run of back-to-back customs.
+    fn add_latest_customs(&mut self, cx: &mut Context<Self>) {
+        let Some(account) = cx.global::<AppState>().settings().my_account else {
+            self.status = Some(Err("Link your account on its profile page to find your customs".to_string()));
+            cx.notify();
+            return;
+        };
+        let service = cx.global::<AppState>().service.clone();
+        let request = self.requests.current();
+        self.status = Some(Ok("Looking for your latest customs…".to_string()));
+        cx.notify();
+
+        cx.spawn(async move |view, cx| {
+            let platform = account.platform.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let data = service
+                        .lock()
+                        .unwrap()
+                        .get_player_matches(&account.platform, &account.player_id, 1)?;
+                    anyhow::Ok(series::latest_custom_run(&platform, &take_rows(data)))
+                })
+                .await;
+            view.update(cx, |this, cx| {
+                if !this.requests.is_current(request) {
+                    return;
+                }
+                match result {
+                    Ok(games) if games.is_empty() => {
+                        this.status = Some(Err("No unranked matches in your latest history".to_string()));
+                        cx.notify();
+                    }
+                    Ok(games) => this.add_games(games, cx),
+                    Err(err) => {
+                        this.status = Some(Err(format!("Couldn't load your history: {}", err)));
+                        cx.notify();
+                    }
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    /// Opens the point editor on the series' own point system, or closes it keeping the draft
+    /// unapplied.
+    fn toggle_what_if(&mut self, cx: &mut Context<Self>) {
+        self.draft = match self.draft {
+            Some(_) => None,
+            None => self.selected_series(cx).map(|series| series.points),
+        };
+        cx.notify();
+    }
+
+    fn edit_draft(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut PointSystem)) {
+        if let Some(draft) = &mut self.draft {
+            f(draft);
+            cx.notify();
+        }
+    }
+
+    /// Steps the points of placement `idx`, or of a kill when `idx` is past the placements.
+    fn step_points(&mut self, idx: usize, up: bool, cx: &mut Context<Self>) {
+        self.edit_draft(cx, |draft| {
+            let points = match draft.placement.get_mut(idx) {
+                Some(points) => points,
+                None => &mut draft.per_kill,
+            };
+            *points = if up { *points + 1 } else { points.saturating_sub(1) };
+        });
+    }
+
+    fn add_place(&mut self, cx: &mut Context<Self>) {
+        self.edit_draft(cx, |draft| {
+            if draft.placement.len() < MAX_SCORED_PLACEMENTS {
+                draft.placement.push(0);
+            }
+        });
+    }
+
+    fn remove_place(&mut self, cx: &mut Context<Self>) {
+        self.edit_draft(cx, |draft| {
+            draft.placement.pop();
+        });
+    }
+
+    /// Saves the draft as the series' point system.
+    fn apply_draft(&mut self, cx: &mut Context<Self>) {
+        let (Some(id), Some(draft)) = (self.selected, self.draft.take()) else {
+            return;
+        };
+        telemetry::track(cx, "apply_series_points");
+        cx.global::<AppState>().series.lock().unwrap().set_points(id, draft);
+        self.status = Some(Ok("Point system saved".to_string()));
+        cx.notify();
+    }
+
+    fn button(label: impl Into<SharedString>, selected: bool, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_sm()
+            .rounded_md()
+            .cursor_pointer()
+            .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+            .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label.into())
+    }
+
+    /// Steppers for every scored placement and for kills, with the draft's changes to apply or
+    /// discard. The standings below are rescored on every step.
+    fn render_point_editor(&self, draft: &PointSystem, saved: &PointSystem, cx: &mut Context<Self>) -> Div {
+        let step_button = |label: &'static str, focus: &FocusHandle| {
+            div()
+                .w(px(22.0))
+                .flex()
+                .justify_center()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(rgb(0x313244))
+                .hover(|s| s.bg(rgb(0x45475a)))
+                .focus_ring(focus)
+                .child(label)
+        };
+        let mut steppers = Vec::new();
+        for idx in 0..=draft.placement.len() {
+            let (label, points, changed) = match draft.placement.get(idx) {
+                Some(points) => (format!("#{}", idx + 1), *points, saved.placement.get(idx) != Some(points)),
+                None => ("Per kill".to_string(), draft.per_kill, draft.per_kill != saved.per_kill),
+            };
+            let focus = &self.stepper_focus[idx * STEPPER_CONTROLS..(idx + 1) * STEPPER_CONTROLS];
+            steppers.push(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .bg(rgb(0x1e1e2e))
+                    .child(div().w(px(52.0)).text_xs().text_color(rgb(0x9399b2)).child(label))
+                    .child(
+                        step_button("−", &focus[0])
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.step_points(idx, false, cx)))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.step_points(idx, false, cx);
+                                }
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(px(28.0))
+                            .flex()
+                            .justify_center()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(if changed { rgb(0xf9e2af) } else { rgb(0xcdd6f4) })
+                            .child(points.to_string()),
+                    )
+                    .child(
+                        step_button("+", &focus[1])
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.step_points(idx, true, cx)))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.step_points(idx, true, cx);
+                                }
+                            })),
+                    ),
+            );
+        }
+        let changed = draft != saved;
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_3()
+            .bg(rgb(0x181825))
+            .rounded_md()
+            .text_sm()
+            .child(div().font_weight(FontWeight::SEMIBOLD).child("What if…"))
+            .child(div().text_xs().text_color(rgb(0x9399b2)).child(format!(
+                "Try another point system on this series; standings, map totals and games update as you go. Draft: {}.",
+                draft.describe()
+            )))
+            .child(div().flex().flex_wrap().gap_2().children(steppers))
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap_2()
+                    .when(draft.placement.len() < MAX_SCORED_PLACEMENTS, |parent| {
+                        parent.child(
+                            Self::button("+ Placement", false, &self.add_place_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.add_place(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.add_place(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .when(!draft.placement.is_empty(), |parent| {
+                        parent.child(
+                            Self::button("− Placement", false, &self.remove_place_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.remove_place(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.remove_place(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .child(div().flex_1())
+                    .when(changed, |parent| {
+                        parent.child(
+                            Self::button("Apply to series", true, &self.apply_focus)
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.apply_draft(cx)))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.apply_draft(cx);
+                                    }
+                                })),
+                        )
+                    })
+                    .child(
+                        Self::button(if changed { "Discard" } else { "Close" }, false, &self.discard_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.toggle_what_if(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.toggle_what_if(cx);
+                                }
+                            })),
+                    ),
+            )
+    }
+
+    /// Rank and points change against `baseline`, the same games under the saved point system.
+    fn standing_change(idx: usize, rank: usize, team: &series::TeamStanding, baseline: &Standings) -> Div {
+        let old_rank = baseline.ranking.iter().position(|&other| other == idx).unwrap_or(rank);
+        let moved = old_rank as i64 - rank as i64;
+        let (arrow, color) = match moved {
+            0 => ("–".to_string(), rgb(0x6c7086)),
+            moved if moved > 0 => (format!("▲{}", moved), rgb(0xa6e3a1)),
+            moved => (format!("▼{}", -moved), rgb(0xf38ba8)),
+        };
+        let delta = team.points as i64 - baseline.teams[idx].points as i64;
+        div()
+            .w(px(90.0))
+            .flex_shrink_0()
+            .flex()
+            .gap_2()
+            .child(div().w(px(30.0)).text_color(color).child(arrow))
+            .child(
+                div()
+                    .text_color(rgb(0x9399b2))
+                    .child(if delta == 0 { String::new() } else { format!("{:+}", delta) }),
+            )
+    }
+
+    /// The ranking; with a `baseline`, each team also shows how far the draft point system moved it.
+    fn render_standings(standings: &Standings, baseline: Option<&Standings>) -> Div {
+        let cell = |width: f32| div().w(px(width)).flex_shrink_0();
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .text_sm()
+            .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child("Standings"))
+            .child(
+                div()
+                    .flex()
+                    .gap_3()
+                    .px_3()
+                    .text_xs()
+                    .text_color(rgb(0x6c7086))
+                    .child(cell(28.0).child("#"))
+                    .child(div().flex_1().child("Team"))
+                    .child(cell(60.0).child("Points"))
+                    .child(cell(50.0).child("Games"))
+                    .child(cell(50.0).child("Wins"))
+                    .child(cell(50.0).child("Kills"))
+                    .child(cell(70.0).child("Avg place"))
+                    .when(baseline.is_some(), |row| row.child(cell(90.0).child("vs saved"))),
+            )
+            .children(standings.ranking.iter().enumerate().map(|(rank, &idx)| {
+                let team = &standings.teams[idx];
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .px_3()
+                    .py_2()
+                    .bg(rgb(0x181825))
+                    .rounded_md()
+                    .child(cell(28.0).child(format!("{}", rank + 1)))
+                    .child(div().flex_1().min_w_0().truncate().child(team.name()))
+                    .child(cell(60.0).font_weight(FontWeight::BOLD).child(format::integer(team.points as i64)))
+                    .child(cell(50.0).child(format::integer(team.games as i64)))
+                    .child(cell(50.0).child(format::integer(team.wins as i64)))
+                    .child(cell(50.0).child(format::integer(team.kills)))
+                    .child(cell(70.0).child(format!("#{}", format::decimal(team.avg_placement(), 1))))
+                    .when_some(baseline, |row, baseline| row.child(Self::standing_change(idx, rank, team, baseline)))
+            }))
+    }
+
+    fn render_labels(standings: &Standings) -> Option<Div> {
+        if standings.labels.is_empty() {
+            return None;
+        }
+        Some(
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .text_sm()
+                .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child("By map"))
+                .children(standings.labels.iter().map(|totals| {
+                    let leaders: Vec<String> = totals
+                        .points
+                        .iter()
+                        .take(3)
+                        .map(|(team, points)| format!("{} {}", standings.teams[*team].name(), points))
+                        .collect();
+                    div()
+                        .flex()
+                        .gap_3()
+                        .px_3()
+                        .py_2()
+                        .bg(rgb(0x181825))
+                        .rounded_md()
+                        .child(div().w(px(140.0)).font_weight(FontWeight::SEMIBOLD).child(totals.label.clone()))
+                        .child(div().w(px(70.0)).text_color(rgb(0x9399b2)).child(format!("{} games", totals.games)))
+                        .child(div().flex_1().min_w_0().truncate().child(leaders.join(" · ")))
+                })),
+        )
+    }
+
+    fn render_game(&self, number: usize, result: &GameResult, standings: &Standings, focus: &[FocusHandle], cx: &mut Context<Self>) -> Div {
+        let action = OpenMatch {
+            match_id: result.game.match_id.clone(),
+            platform: result.game.platform.clone(),
+        };
+        let key_action = action.clone();
+        let mut heading = format!("Game {}", number);
+        if let Some(label) = &result.game.label {
+            heading.push_str(&format!(" · {}", label));
+        }
+        if let Some(start) = result.match_start {
+            heading.push_str(&format!(" · {}", format::date_time(&start)));
+        }
+        let (label_game, label_key_game) = (result.game.clone(), result.game.clone());
+        let (remove_id, remove_key_id) = (result.game.match_id.clone(), result.game.match_id.clone());
+        let control = |label: &'static str, focus: &FocusHandle| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .rounded_md()
+                .cursor_pointer()
+                .text_color(rgb(0x9399b2))
+                .hover(|s| s.bg(rgb(0x313244)))
+                .focus_ring(focus)
+                .child(label)
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_3()
+            .bg(rgb(0x181825))
+            .rounded_md()
+            .text_sm()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(div().flex_1().font_weight(FontWeight::SEMIBOLD).child(heading))
+                    .child(
+                        control("Open", &focus[0])
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                window.dispatch_action(Box::new(action.clone()), cx);
+                            })
+                            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    window.dispatch_action(Box::new(key_action.clone()), cx);
+                                }
+                            }),
+                    )
+                    .child(
+                        control("Label", &focus[1])
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                                this.prompt_label(label_game.clone(), window, cx);
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.prompt_label(label_key_game.clone(), window, cx);
+                                }
+                            })),
+                    )
+                    .child(
+                        control("Remove", &focus[2])
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                this.remove_game(&remove_id, cx);
+                            }))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.remove_game(&remove_key_id, cx);
+                                }
+                            })),
+                    ),
+            )
+            .children(result.teams.iter().map(|team| {
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .child(div().size_2().rounded_full().bg(team_color(team.team as i64)))
+                    .child(
+                        div()
+                            .w(px(36.0))
+                            .child(team.placement.map(|placement| format!("#{}", placement)).unwrap_or_default()),
+                    )
+                    .child(div().flex_1().min_w_0().truncate().child(standings.teams[team.team].name()))
+                    .child(div().w(px(70.0)).text_color(rgb(0x9399b2)).child(format!("{} kills", team.kills)))
+                    .child(div().w(px(60.0)).font_weight(FontWeight::SEMIBOLD).child(format!("+{}", team.points)))
+            }))
+    }
+}
+
+impl Render for SeriesView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let app_state = cx.global::<AppState>();
+        let spacing = app_state.settings().density.spacing();
+        let all_series = app_state.series.lock().unwrap().list();
+        let selected = self.selected_series(cx);
+        sync_focus_handles(&mut self.series_focus, all_series.len(), cx);
+        let game_count = selected.as_ref().map(|series| series.games.len()).unwrap_or(0);
+        sync_focus_handles(&mut self.game_focus, game_count * GAME_CONTROLS, cx);
+        let stepper_count = self.draft.as_ref().map(|draft| draft.placement.len() + 1).unwrap_or(0);
+        sync_focus_handles(&mut self.stepper_focus, stepper_count * STEPPER_CONTROLS, cx);
+
+        let loaded_games: Vec<(SeriesGame, Vec<Value>)> = selected
+            .iter()
+            .flat_map(|series| series.games.iter())
+            .filter_map(|game| self.loaded.get(&game.match_id).map(|rows| (game.clone(), rows.clone())))
+            .collect();
+        let saved_points = selected.as_ref().map(|series| series.points.clone()).unwrap_or_default();
+        let draft = self.draft.clone().filter(|_| selected.is_some());
+        let standings = series::standings(&loaded_games, draft.as_ref().unwrap_or(&saved_points));
+        // Same games under the saved system, to show what the draft changes
+        let baseline = draft
+            .as_ref()
+            .filter(|draft| **draft != saved_points)
+            .map(|_| series::standings(&loaded_games, &saved_points));
+        let point_editor = draft.as_ref().map(|draft| self.render_point_editor(draft, &saved_points, cx));
+        let games: Vec<Div> = standings
+            .games
+            .iter()
+            .enumerate()
+            .map(|(idx, result)| {
+                let focus = self.game_focus[idx * GAME_CONTROLS..(idx + 1) * GAME_CONTROLS].to_vec();
+                self.render_game(idx + 1, result, &standings, &focus, cx)
+            })
+            .collect();
+
+        div()
+            .id("series-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(div().text_2xl().font_weight(FontWeight::BOLD).child("Series"))
+            .child(div().text_sm().text_color(rgb(0x9399b2)).child(format!(
+                "Group scrim games into a series and score them together: {}.",
+                saved_points.describe()
+            )))
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap_2()
+                    .children(all_series.iter().zip(&self.series_focus).map(|(series, focus)| {
+                        let id = series.id;
+                        Self::button(series.name.clone(), self.selected == Some(id), focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| this.select(id, cx)))
+                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.select(id, cx);
+                                }
+                            }))
+                    }))
+                    .child(
+                        Self::button("+ New series", false, &self.new_focus)
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.prompt_new_series(window, cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.prompt_new_series(window, cx);
+                                }
+                            })),
+                    ),
+            )
+            .when_some(selected, |parent, series| {
+                parent
+                    .child(
+                        div()
+                            .flex()
+                            .flex_wrap()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_xl()
+                                    .font_weight(FontWeight::BOLD)
+                                    .child(format!("{} · {} games", series.name, series.games.len())),
+                            )
+                            .child(
+                                Self::button("Add latest customs", false, &self.latest_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.add_latest_customs(cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.add_latest_customs(cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::button("Paste match IDs", false, &self.paste_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.paste_ids(cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.paste_ids(cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::button("What if…", self.draft.is_some(), &self.what_if_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.toggle_what_if(cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.toggle_what_if(cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::button("Rename", false, &self.rename_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.prompt_rename(window, cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.prompt_rename(window, cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::button("Delete", false, &self.delete_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| this.confirm_delete(window, cx)))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.confirm_delete(window, cx);
+                                        }
+                                    })),
+                            ),
+                    )
+                    .when(self.pending > 0, |parent| {
+                        parent.child(
+                            div()
+                                .text_sm()
+                                .text_color(rgb(0x9399b2))
+                                .child(format!("Loading {} games…", self.pending)),
+                        )
+                    })
+                    .when_some(self.status.clone(), |parent, status| {
+                        let (text, color) = match status {
+                            Ok(text) => (text, rgb(0x9399b2)),
+                            Err(text) => (text, rgb(0xf38ba8)),
+                        };
+                        parent.child(div().text_sm().text_color(color).child(text))
+                    })
+                    .when(series.games.is_empty(), |parent| {
+                        parent.child(
+                            div()
+                                .text_sm()
+                                .text_color(rgb(0x9399b2))
+                                .child("No games yet. Add your latest customs or paste match IDs or op.gg links."),
+                        )
+                    })
+                    .children(point_editor)
+                    .when(!standings.teams.is_empty(), |parent| {
+                        parent.child(Self::render_standings(&standings, baseline.as_ref()))
+                    })
+                    .children(Self::render_labels(&standings))
+                    .when(!games.is_empty(), |parent| {
+                        parent
+                            .child(div().text_lg().font_weight(FontWeight::SEMIBOLD).child("Games"))
+                            .children(games)
+                    })
+            })
+    }
+}
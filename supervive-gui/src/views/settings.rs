@@ -0,0 +1,1642 @@
+use crate::backup::{self, AppData};
+use crate::demo;
+use crate::components::focus::{is_activation_key, sync_focus_handles, FocusRingExt};
+use crate::diagnostics;
+use crate::format;
+use crate::global_hotkeys::{self, GLOBAL_COMMANDS};
+use crate::keymap::{self, COMMANDS};
+use crate::live_match;
+use crate::notifier::{Webhook, WebhookFormat};
+use crate::plugins;
+use crate::scheduler::{self, JobKind};
+use crate::settings::{ArchiveRetention, Settings, SettingsStore, MIN_SAMPLE_PRESETS};
+use crate::state::AppState;
+use crate::telemetry;
+use crate::theme::{Density, Palette, RatedStat, Rating, ThresholdMode};
+use crate::views::modal::{self, Modal};
+use crate::views::onboarding::REGIONS;
+use crate::views::{OpenLobby, OpenLogs};
+use gpui::prelude::*;
+use gpui::*;
+use std::path::PathBuf;
+
+const FONT_SIZES: [f32; 5] = [12.0, 14.0, 16.0, 18.0, 20.0];
+
+/// Focus slots of the per-stat threshold controls: mode, good −/+, bad −/+.
+const THRESHOLD_FOCUS_OFFSET: usize = Palette::ALL.len() + 1;
+const THRESHOLD_CONTROLS: usize = 5;
+/// Focus slots of the minimum sample size choices, after the thresholds.
+const SAMPLE_FOCUS_OFFSET: usize = THRESHOLD_FOCUS_OFFSET + RatedStat::ALL.len() * THRESHOLD_CONTROLS;
+/// Focus slots for the display options, in render order.
+const DISPLAY_FOCUS_OFFSET: usize = SAMPLE_FOCUS_OFFSET + MIN_SAMPLE_PRESETS.len();
+/// Focus slots before the per-command rebind/reset buttons.
+const SHORTCUT_FOCUS_OFFSET: usize = DISPLAY_FOCUS_OFFSET + FONT_SIZES.len() + Density::ALL.len();
+/// Focus slot of the update check toggle, after the shortcut buttons.
+const UPDATES_FOCUS_OFFSET: usize = SHORTCUT_FOCUS_OFFSET + COMMANDS.len() * 2;
+const DIAGNOSTICS_FOCUS_OFFSET: usize = UPDATES_FOCUS_OFFSET + 1;
+const TELEMETRY_FOCUS_OFFSET: usize = DIAGNOSTICS_FOCUS_OFFSET + 3;
+const LIVE_MATCH_FOCUS_OFFSET: usize = TELEMETRY_FOCUS_OFFSET + 2;
+/// Focus slot of the global hotkey toggle, followed by its per-command rebind/reset buttons.
+const GLOBAL_HOTKEYS_FOCUS_OFFSET: usize = LIVE_MATCH_FOCUS_OFFSET + 2;
+const CLIPBOARD_FOCUS_OFFSET: usize = GLOBAL_HOTKEYS_FOCUS_OFFSET + 1 + GLOBAL_COMMANDS.len() * 2;
+const BACKUP_FOCUS_OFFSET: usize = CLIPBOARD_FOCUS_OFFSET + 1;
+/// Focus slots of the retention presets, then Apply and Cancel for the pending choice, then the
+/// integrity check.
+const ARCHIVE_FOCUS_OFFSET: usize = BACKUP_FOCUS_OFFSET + 6;
+const SYNC_FOCUS_OFFSET: usize = ARCHIVE_FOCUS_OFFSET + ArchiveRetention::PRESETS.len() + 3;
+const PLUGINS_FOCUS_OFFSET: usize = SYNC_FOCUS_OFFSET + 2;
+const SUGGESTIONS_FOCUS_OFFSET: usize = PLUGINS_FOCUS_OFFSET + 1;
+/// Focus slots of "All regions", then one per region.
+const REGION_FOCUS_OFFSET: usize = SUGGESTIONS_FOCUS_OFFSET + 1;
+const DEMO_FOCUS_OFFSET: usize = REGION_FOCUS_OFFSET + 1 + REGIONS.len();
+
+/// Archived matches listed in the retention preview before the rest are summed up.
+const RETENTION_PREVIEW_ROWS: usize = 5;
+
+/// Which kind of binding the recorder is capturing.
+#[derive(Clone, Copy, PartialEq)]
+enum Recording {
+    Shortcut(&'static str),
+    GlobalHotkey(&'static str),
+}
+
+pub struct SettingsView {
+    scroll_handle: ScrollHandle,
+    option_focus: Vec<FocusHandle>,
+    // Command currently being rebound and the keystrokes captured so far
+    recording: Option<Recording>,
+    recorded: Vec<String>,
+    recorder_focus: FocusHandle,
+    // Cancels recording when the recorder loses focus, so the keymap is always restored
+    recorder_blur: Option<Subscription>,
+    exporting: bool,
+    export_result: Option<Result<PathBuf, String>>,
+    backup_busy: bool,
+    backup_result: Option<Result<String, String>>,
+    // Add button, then edit/remove per webhook
+    webhook_focus: Vec<FocusHandle>,
+    webhook_error: Option<String>,
+    /// Retention choice that would delete archived matches, waiting for Apply
+    pending_retention: Option<ArchiveRetention>,
+}
+
+impl SettingsView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            scroll_handle: ScrollHandle::new(),
+            option_focus: Vec::new(),
+            recording: None,
+            recorded: Vec::new(),
+            recorder_focus: cx.focus_handle(),
+            recorder_blur: None,
+            exporting: false,
+            export_result: None,
+            backup_busy: false,
+            backup_result: None,
+            webhook_focus: Vec::new(),
+            webhook_error: None,
+            pending_retention: None,
+        }
+    }
+
+    /// Asks for confirmation in a dialog before running `action` on this view.
+    fn confirm(
+        &self,
+        title: &str,
+        message: &str,
+        confirm_label: &str,
+        action: impl Fn(&mut Self, &mut Context<Self>) + 'static,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let view = cx.entity().downgrade();
+        let dialog = Modal::confirm(title.to_string(), message.to_string(), move |_window, cx| {
+            view.update(cx, |this, cx| action(this, cx)).ok();
+        })
+        .confirm_label(confirm_label.to_string())
+        .destructive();
+        modal::open(dialog, window, cx);
+    }
+
+    fn confirm_clear_telemetry(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.confirm(
+            "Clear usage statistics?",
+            "The counters collected on this machine are deleted and nothing is uploaded for them.",
+            "Clear",
+            |_, cx| {
+                cx.global::<AppState>().telemetry.lock().unwrap().clear();
+                cx.notify();
+            },
+            window,
+            cx,
+        );
+    }
+
+    fn confirm_import_app_data(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.confirm(
+            "Restore from a backup?",
+            "Your current settings, watched players and linked accounts are replaced by the ones in the backup. Players, match notes, series and archived matches from the backup are added to the ones you have.",
+            "Choose backup…",
+            |this, cx| this.import_app_data(cx),
+            window,
+            cx,
+        );
+    }
+
+    fn confirm_clear_api_cache(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.confirm(
+            "Clear the API cache?",
+            "Every cached search, match and profile response is dropped and fetched again from op.gg when next needed.",
+            "Clear cache",
+            |this, cx| this.clear_api_cache(cx),
+            window,
+            cx,
+        );
+    }
+
+    fn confirm_delete_player_archive(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.confirm(
+            "Delete the player archive?",
+            "Every player remembered from searches and matches is forgotten, so \"Seen before\" suggestions start empty. This can't be undone unless you exported a backup.",
+            "Delete",
+            |this, cx| this.delete_player_archive(cx),
+            window,
+            cx,
+        );
+    }
+
+    fn confirm_remove_webhook(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(url) = cx.global::<AppState>().settings().webhooks.get(index).map(|webhook| webhook.url.clone()) else {
+            return;
+        };
+        self.confirm(
+            "Remove webhook?",
+            &format!("{} will no longer be notified about watched players' matches.", url),
+            "Remove",
+            move |this, cx| this.update_settings(cx, |s| {
+                if index < s.webhooks.len() {
+                    s.webhooks.remove(index);
+                }
+            }),
+            window,
+            cx,
+        );
+    }
+
+    fn clear_api_cache(&mut self, cx: &mut Context<Self>) {
+        let removed = cx.global::<AppState>().service.lock().unwrap().clear_cache();
+        self.backup_result = Some(Ok(format!("Removed {} cached responses", removed)));
+        cx.notify();
+    }
+
+    fn delete_player_archive(&mut self, cx: &mut Context<Self>) {
+        let removed = cx.global::<AppState>().player_index.lock().unwrap().clear();
+        self.backup_result = Some(Ok(format!("Deleted {} archived players", removed)));
+        cx.notify();
+    }
+
+    /// Switches the archive retention straight away when nothing would be deleted; otherwise holds
+    /// the choice back until the preview of what it deletes is applied.
+    fn choose_retention(&mut self, retention: ArchiveRetention, cx: &mut Context<Self>) {
+        let expired = cx.global::<AppState>().match_archive.lock().unwrap().expired(retention);
+        if expired.is_empty() {
+            self.pending_retention = None;
+            self.update_settings(cx, |s| s.archive_retention = retention);
+        } else {
+            self.pending_retention = Some(retention);
+            cx.notify();
+        }
+    }
+
+    fn apply_retention(&mut self, cx: &mut Context<Self>) {
+        let Some(retention) = self.pending_retention.take() else {
+            return;
+        };
+        self.update_settings(cx, |s| s.archive_retention = retention);
+        scheduler::run_now(JobKind::PruneArchive, cx);
+    }
+
+    fn cancel_retention(&mut self, cx: &mut Context<Self>) {
+        self.pending_retention = None;
+        cx.notify();
+    }
+
+    fn render_retention_preview(&self, retention: ArchiveRetention, focus: &[FocusHandle], cx: &mut Context<Self>) -> Div {
+        let expired = cx.global::<AppState>().match_archive.lock().unwrap().expired(retention);
+        let bytes: u64 = expired.iter().map(|entry| entry.bytes).sum();
+        let muted = |text: String| div().text_sm().text_color(rgb(0x9399b2)).child(text);
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_3()
+            .rounded_md()
+            .border_1()
+            .border_color(rgb(0xf38ba8))
+            .child(div().text_sm().child(format!(
+                "Switching to \"{}\" deletes {} archived matches ({}):",
+                retention.label(),
+                format::integer(expired.len() as i64),
+                format::bytes(bytes)
+            )))
+            .children(expired.iter().take(RETENTION_PREVIEW_ROWS).map(|entry| {
+                muted(format!(
+                    "{} · {} · {} players",
+                    format::date_time(&entry.played_at()),
+                    entry.match_id,
+                    entry.players
+                ))
+            }))
+            .when(expired.len() > RETENTION_PREVIEW_ROWS, |parent| {
+                parent.child(muted(format!("…and {} more", expired.len() - RETENTION_PREVIEW_ROWS)))
+            })
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .child(
+                        Self::option_chip("Apply and delete", false, &focus[ARCHIVE_FOCUS_OFFSET + ArchiveRetention::PRESETS.len()])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.apply_retention(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.apply_retention(cx);
+                                }
+                            })),
+                    )
+                    .child(
+                        Self::option_chip("Cancel", false, &focus[ARCHIVE_FOCUS_OFFSET + ArchiveRetention::PRESETS.len() + 1])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| this.cancel_retention(cx)))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.cancel_retention(cx);
+                                }
+                            })),
+                    ),
+            )
+    }
+
+    /// Prompts for a webhook URL, adding a webhook or replacing the URL of the one at `index`.
+    fn prompt_webhook_url(&mut self, index: Option<usize>, window: &mut Window, cx: &mut Context<Self>) {
+        let current = index
+            .and_then(|index| cx.global::<AppState>().settings().webhooks.get(index).map(|webhook| webhook.url.clone()))
+            .unwrap_or_default();
+        let view = cx.entity().downgrade();
+        let dialog = Modal::prompt(
+            if index.is_some() { "Edit webhook" } else { "Add webhook" },
+            "Posted to whenever a watched player finishes a match. Discord webhook URLs get a Discord embed, anything else the raw match JSON.",
+            "https://…",
+            move |url, _window, cx| {
+                view.update(cx, |this, cx| this.save_webhook_url(index, url, cx)).ok();
+            },
+        )
+        .initial_value(current);
+        modal::open(dialog, window, cx);
+    }
+
+    fn save_webhook_url(&mut self, index: Option<usize>, url: String, cx: &mut Context<Self>) {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            self.webhook_error = Some(format!("'{}' is not an http(s) URL", url));
+            cx.notify();
+            return;
+        }
+        self.webhook_error = None;
+        let format = if url.contains("discord.com/api/webhooks") {
+            WebhookFormat::Discord
+        } else {
+            WebhookFormat::Json
+        };
+        self.update_settings(cx, |s| match index.and_then(|index| s.webhooks.get_mut(index)) {
+            Some(webhook) => {
+                webhook.url = url;
+                webhook.format = format;
+            }
+            None => s.webhooks.push(Webhook { url, format, ..Webhook::default() }),
+        });
+    }
+
+    pub fn title(&self) -> SharedString {
+        "Settings".into()
+    }
+
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.scroll_handle.clone()
+    }
+
+    fn update_settings(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Settings)) {
+        cx.global::<AppState>().settings.lock().unwrap().update(f);
+        // Settings affect every view, not just this one
+        cx.refresh_windows();
+    }
+
+    fn apply_keymap(&self, cx: &mut Context<Self>) {
+        let keybindings = cx.global::<AppState>().settings().keybindings;
+        keymap::apply(&keybindings, cx);
+    }
+
+    fn start_recording(&mut self, target: Recording, window: &mut Window, cx: &mut Context<Self>) {
+        self.recording = Some(target);
+        self.recorded.clear();
+        // Unbind everything so the keys being recorded don't trigger their current commands
+        cx.clear_key_bindings();
+        if self.recorder_blur.is_none() {
+            // Clicking elsewhere or leaving the page would otherwise leave every shortcut unbound
+            self.recorder_blur = Some(cx.on_blur(&self.recorder_focus, window, |this, _window, cx| {
+                if this.recording.is_some() {
+                    this.stop_recording(false, cx);
+                }
+            }));
+        }
+        window.focus(&self.recorder_focus);
+        cx.notify();
+    }
+
+    fn stop_recording(&mut self, save: bool, cx: &mut Context<Self>) {
+        let keystrokes = self.recorded.join(" ");
+        match self.recording.take() {
+            Some(Recording::Shortcut(id)) if save && keymap::is_valid_binding(&keystrokes) => {
+                cx.global::<AppState>()
+                    .settings
+                    .lock()
+                    .unwrap()
+                    .update(|s| {
+                        s.keybindings.insert(id.to_string(), keystrokes);
+                    });
+            }
+            Some(Recording::GlobalHotkey(id)) if save => {
+                if let Some(hotkey) = global_hotkeys::from_keystroke(&keystrokes) {
+                    cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+                        s.global_hotkeys.insert(id.to_string(), hotkey);
+                    });
+                }
+            }
+            _ => {}
+        }
+        self.recorded.clear();
+        self.apply_keymap(cx);
+        cx.notify();
+    }
+
+    fn reset_binding(&mut self, id: &'static str, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+            s.keybindings.remove(id);
+        });
+        self.apply_keymap(cx);
+        cx.notify();
+    }
+
+    fn reset_global_hotkey(&mut self, id: &'static str, cx: &mut Context<Self>) {
+        cx.global::<AppState>().settings.lock().unwrap().update(|s| {
+            s.global_hotkeys.remove(id);
+        });
+        cx.notify();
+    }
+
+    /// Field showing a binding, or the keys captured so far while it is being recorded.
+    fn binding_field(&self, is_recording: bool, current: String, cx: &mut Context<Self>) -> Div {
+        div()
+            .min_w(px(140.0))
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(rgb(0x1e1e2e))
+            .text_sm()
+            .when(is_recording, |field| {
+                field
+                    .track_focus(&self.recorder_focus)
+                    .border_1()
+                    .border_color(rgb(0xf9e2af))
+                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                        this.on_recorder_key(event, cx);
+                    }))
+                    .child(if self.recorded.is_empty() {
+                        "Press keys...".to_string()
+                    } else {
+                        self.recorded.join(" ")
+                    })
+            })
+            .when(!is_recording, |field| field.child(current))
+    }
+
+    fn on_recorder_key(&mut self, event: &KeyDownEvent, cx: &mut Context<Self>) {
+        let keystroke = &event.keystroke;
+        let no_modifiers = !keystroke.modifiers.modified();
+        match keystroke.key.as_str() {
+            "escape" if no_modifiers => self.stop_recording(false, cx),
+            "enter" if no_modifiers && !self.recorded.is_empty() => self.stop_recording(true, cx),
+            _ => {
+                // Allow two-step chords like "ctrl-k ctrl-s"; a third key starts over
+                if self.recorded.len() == 2 {
+                    self.recorded.clear();
+                }
+                self.recorded.push(keystroke.unparse());
+                cx.notify();
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    fn export_diagnostics(&mut self, cx: &mut Context<Self>) {
+        if self.exporting {
+            return;
+        }
+        self.exporting = true;
+        telemetry::track(cx, "export_diagnostics");
+        cx.notify();
+
+        let settings = cx.global::<AppState>().settings();
+        cx.spawn(async move |view, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { diagnostics::export_bundle(&settings) })
+                .await;
+            view.update(cx, |this, cx| {
+                this.exporting = false;
+                this.export_result = Some(result.map_err(|err| err.to_string()));
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    fn export_app_data(&mut self, cx: &mut Context<Self>) {
+        if self.backup_busy {
+            return;
+        }
+        self.backup_busy = true;
+        telemetry::track(cx, "export_app_data");
+        cx.notify();
+
+        let app_state = cx.global::<AppState>();
+        let settings = app_state.settings();
+        let dir = settings.backup_dir.clone().map(PathBuf::from).unwrap_or_else(backup::default_dir);
+        let compress = settings.backup_compress;
+        let data = AppData {
+            settings,
+            players: app_state.player_index.lock().unwrap().snapshot(),
+            notes: app_state.match_notes.lock().unwrap().snapshot(),
+            series: app_state.series.lock().unwrap().snapshot(),
+            match_files: app_state.match_archive.lock().unwrap().files(),
+        };
+        cx.spawn(async move |view, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { backup::export(&data, &dir, compress) })
+                .await;
+            view.update(cx, |this, cx| {
+                this.backup_busy = false;
+                this.backup_result = Some(
+                    result
+                        .map(|path| format!("Saved to {}", path.display()))
+                        .map_err(|err| format!("Export failed: {}", err)),
+                );
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    fn choose_backup_dir(&mut self, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: false,
+            directories: true,
+            multiple: false,
+            prompt: Some("Back up here".into()),
+        });
+        cx.spawn(async move |view, cx| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let Some(dir) = paths.into_iter().next() else {
+                return;
+            };
+            view.update(cx, |this, cx| {
+                this.update_settings(cx, |s| s.backup_dir = Some(dir.display().to_string()));
+            }).ok();
+        }).detach();
+    }
+
+    fn import_app_data(&mut self, cx: &mut Context<Self>) {
+        if self.backup_busy {
+            return;
+        }
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: Some("Restore".into()),
+        });
+        let match_archive = cx.global::<AppState>().match_archive.clone();
+        self.backup_busy = true;
+        cx.notify();
+
+        cx.spawn(async move |view, cx| {
+            let path = match paths.await {
+                Ok(Ok(Some(paths))) => paths.into_iter().next(),
+                _ => None,
+            };
+            let result = match path {
+                Some(path) => Some(
+                    cx.background_executor()
+                        .spawn(async move {
+                            let data = backup::import(&path)?;
+                            let matches = backup::restore_matches(&path, &match_archive)?;
+                            anyhow::Ok((data, matches))
+                        })
+                        .await,
+                ),
+                None => None,
+            };
+            view.update(cx, |this, cx| {
+                this.backup_busy = false;
+                match result {
+                    Some(Ok((data, matches))) => {
+                        telemetry::track(cx, "import_app_data");
+                        let app_state = cx.global::<AppState>();
+                        let added = app_state.player_index.lock().unwrap().merge(data.players);
+                        let notes = app_state.match_notes.lock().unwrap().merge(data.notes);
+                        let series = app_state.series.lock().unwrap().merge(data.series);
+                        app_state.settings.lock().unwrap().apply_restored(data.settings);
+                        this.apply_keymap(cx);
+                        this.backup_result = Some(Ok(format!(
+                            "Restored settings, {} players, {} match notes, {} series and {} archived matches",
+                            added, notes, series, matches
+                        )));
+                        cx.refresh_windows();
+                    }
+                    Some(Err(err)) => {
+                        telemetry::track_error(cx, "import_app_data_failed");
+                        this.backup_result = Some(Err(format!("Restore failed: {:#}", err)));
+                    }
+                    // Picker cancelled
+                    None => {}
+                }
+                cx.notify();
+            }).ok();
+        }).detach();
+    }
+
+    fn reload_plugins(&mut self, cx: &mut Context<Self>) {
+        let loaded = plugins::load_all();
+        *cx.global::<AppState>().plugins.lock().unwrap() = loaded;
+        cx.refresh_windows();
+    }
+
+    /// Nudges a fixed threshold by one step, keeping the value positive.
+    fn step_threshold(&mut self, stat: RatedStat, good: bool, direction: f64, cx: &mut Context<Self>) {
+        self.update_settings(cx, |s| {
+            let thresholds = s.rating_thresholds.get_mut(stat);
+            let value = if good { &mut thresholds.good } else { &mut thresholds.bad };
+            // Round to the step so repeated 0.1 increments don't drift
+            *value = (((*value + direction * stat.step()) / stat.step()).round() * stat.step()).max(stat.step());
+        });
+    }
+
+    fn section(title: &str) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .p_4()
+            .bg(rgb(0x181825))
+            .rounded_lg()
+            .border_1()
+            .border_color(rgb(0x313244))
+            .child(
+                div()
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(title.to_string()),
+            )
+    }
+
+    fn option_chip(label: impl Into<SharedString>, selected: bool, focus: &FocusHandle) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .bg(if selected { rgb(0x89b4fa) } else { rgb(0x313244) })
+            .text_color(if selected { rgb(0x1e1e2e) } else { rgb(0xcdd6f4) })
+            .hover(|s| s.bg(rgb(0x45475a)))
+            .focus_ring(focus)
+            .child(label.into())
+    }
+}
+
+impl Render for SettingsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let settings = cx.global::<AppState>().settings();
+        sync_focus_handles(
+            &mut self.option_focus,
+            DEMO_FOCUS_OFFSET + 1,
+            cx,
+        );
+        let focus = self.option_focus.clone();
+        sync_focus_handles(&mut self.webhook_focus, 1 + settings.webhooks.len() * 2, cx);
+        let webhook_focus = self.webhook_focus.clone();
+        let is_demo = demo::is_enabled();
+        let spacing = settings.density.spacing();
+        let plugin_names: Vec<String> = cx
+            .global::<AppState>()
+            .plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|plugin| plugin.manifest.name.clone())
+            .collect();
+        let sync_status = cx.global::<AppState>().scheduler.lock().unwrap().status(JobKind::SyncSettings);
+        let prune_status = cx.global::<AppState>().scheduler.lock().unwrap().status(JobKind::PruneArchive);
+        let check_status = cx.global::<AppState>().scheduler.lock().unwrap().status(JobKind::CheckArchive);
+        let (archived_matches, archived_bytes, quarantine_dir) = {
+            let archive = cx.global::<AppState>().match_archive.lock().unwrap();
+            (archive.len(), archive.total_bytes(), archive.quarantine_dir())
+        };
+        let backup_dir = settings.backup_dir.clone().map(PathBuf::from).unwrap_or_else(backup::default_dir);
+        let pending_retention = self.pending_retention;
+        let retention_preview = self
+            .pending_retention
+            .map(|retention| self.render_retention_preview(retention, &focus, cx));
+        let telemetry_preview = serde_json::to_string_pretty(
+            &cx.global::<AppState>().telemetry.lock().unwrap().pending_batch(),
+        )
+        .unwrap_or_default();
+
+        div()
+            .id("settings-view")
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .p(spacing.page)
+            .gap(spacing.section_gap)
+            .child(
+                div()
+                    .text_2xl()
+                    .font_weight(FontWeight::BOLD)
+                    .child("Settings"),
+            )
+            .child(
+                Self::section("Stat colors")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Palette used for good / average / bad stats."),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .children(Palette::ALL.iter().enumerate().map(|(idx, palette)| {
+                                let palette = *palette;
+                                Self::option_chip(palette.label(), settings.palette == palette, &focus[idx])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                        this.update_settings(cx, |s| s.palette = palette);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.update_settings(cx, |s| s.palette = palette);
+                                        }
+                                    }))
+                            })),
+                    )
+                    // Preview of the selected palette
+                    .child(
+                        div()
+                            .flex()
+                            .gap_4()
+                            .text_sm()
+                            .children([Rating::Good, Rating::Average, Rating::Bad].map(|rating| {
+                                let label = match rating {
+                                    Rating::Good => "Good",
+                                    Rating::Average => "Average",
+                                    Rating::Bad => "Bad",
+                                };
+                                div()
+                                    .text_color(settings.palette.color(rating))
+                                    .child(if settings.show_indicators {
+                                        format!("{} {}", rating.symbol(), label)
+                                    } else {
+                                        label.to_string()
+                                    })
+                            })),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if settings.show_indicators {
+                                "Shape indicators: on"
+                            } else {
+                                "Shape indicators: off"
+                            },
+                            settings.show_indicators,
+                            &focus[Palette::ALL.len()],
+                        )
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.update_settings(cx, |s| s.show_indicators = !s.show_indicators);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.update_settings(cx, |s| s.show_indicators = !s.show_indicators);
+                            }
+                        })),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Thresholds per stat. \"Own history\" rates each player against their best and worst quarter of games once they have 10 or more."),
+                    )
+                    .children(RatedStat::ALL.iter().enumerate().map(|(idx, stat)| {
+                        let stat = *stat;
+                        let thresholds = *settings.rating_thresholds.get(stat);
+                        let personal = thresholds.mode == ThresholdMode::Personal;
+                        let focus = &focus[THRESHOLD_FOCUS_OFFSET + idx * THRESHOLD_CONTROLS..];
+                        let decimals = if stat.step() < 1.0 { 1 } else { 0 };
+                        let stepper = |label: &'static str, good: bool, value: f64, focus: &[FocusHandle]| {
+                            div()
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .text_sm()
+                                .child(label)
+                                .children([(-1.0, "−"), (1.0, "+")].into_iter().zip(focus).map(|((direction, symbol), focus)| {
+                                    Self::option_chip(symbol, false, focus)
+                                        .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                            this.step_threshold(stat, good, direction, cx);
+                                        }))
+                                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                this.step_threshold(stat, good, direction, cx);
+                                            }
+                                        }))
+                                }))
+                                .child(format!("{:.*}", decimals, value))
+                        };
+
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(div().w(px(90.0)).child(stat.label()))
+                            .child(
+                                Self::option_chip(if personal { "Own history" } else { "Fixed" }, personal, &focus[0])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                        this.update_settings(cx, |s| {
+                                            let thresholds = s.rating_thresholds.get_mut(stat);
+                                            thresholds.mode = if personal { ThresholdMode::Fixed } else { ThresholdMode::Personal };
+                                        });
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                        if is_activation_key(event) {
+                                            this.update_settings(cx, |s| {
+                                                let thresholds = s.rating_thresholds.get_mut(stat);
+                                                thresholds.mode = if personal { ThresholdMode::Fixed } else { ThresholdMode::Personal };
+                                            });
+                                        }
+                                    })),
+                            )
+                            .child(stepper("Good at", true, thresholds.good, &focus[1..3]))
+                            .child(stepper("Bad past", false, thresholds.bad, &focus[3..5]))
+                    }))
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Minimum games before a hero, matchup or composition win rate counts. Smaller samples are greyed out."),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .children(MIN_SAMPLE_PRESETS.iter().enumerate().map(|(idx, games)| {
+                                let games = *games;
+                                Self::option_chip(
+                                    if games == 1 { "Any".to_string() } else { format!("{} games", games) },
+                                    settings.min_sample_games == games,
+                                    &focus[SAMPLE_FOCUS_OFFSET + idx],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.min_sample_games = games);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.min_sample_games = games);
+                                    }
+                                }))
+                            })),
+                    ),
+            )
+            .child(
+                Self::section("Display")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Base font size"),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .children(FONT_SIZES.iter().enumerate().map(|(idx, size)| {
+                                let size = *size;
+                                Self::option_chip(
+                                    format!("{}px", size),
+                                    settings.font_size == size,
+                                    &focus[DISPLAY_FOCUS_OFFSET + idx],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.font_size = size);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.font_size = size);
+                                    }
+                                }))
+                            })),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Density"),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .children(Density::ALL.iter().enumerate().map(|(idx, density)| {
+                                let density = *density;
+                                Self::option_chip(
+                                    density.label(),
+                                    settings.density == density,
+                                    &focus[DISPLAY_FOCUS_OFFSET + FONT_SIZES.len() + idx],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.density = density);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.density = density);
+                                    }
+                                }))
+                            })),
+                    ),
+            )
+            .child(
+                Self::section("Keyboard shortcuts")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Click Rebind, press the new keys (up to a two-step chord), then Enter to save or Esc to cancel."),
+                    )
+                    .children(COMMANDS.iter().enumerate().map(|(idx, command)| {
+                        let id = command.id;
+                        let is_recording = self.recording == Some(Recording::Shortcut(id));
+                        let current = keymap::binding_for(command, &settings.keybindings).to_string();
+                        let is_default = current == command.default;
+                        let rebind_focus = &focus[SHORTCUT_FOCUS_OFFSET + idx * 2];
+                        let reset_focus = &focus[SHORTCUT_FOCUS_OFFSET + idx * 2 + 1];
+
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(div().flex_1().child(command.label))
+                            .child(self.binding_field(is_recording, current, cx))
+                            .child(
+                                Self::option_chip("Rebind", false, rebind_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                                        this.start_recording(Recording::Shortcut(id), window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.start_recording(Recording::Shortcut(id), window, cx);
+                                        }
+                                    })),
+                            )
+                            .when(!is_default, |row| {
+                                row.child(
+                                    Self::option_chip("Reset", false, reset_focus)
+                                        .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                            this.reset_binding(id, cx);
+                                        }))
+                                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                this.reset_binding(id, cx);
+                                            }
+                                        })),
+                                )
+                            })
+                    })),
+            )
+            .child(
+                Self::section("Updates")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Check GitHub for a newer release on startup. Updates are never installed automatically."),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if settings.check_for_updates {
+                                "Check for updates: on"
+                            } else {
+                                "Check for updates: off"
+                            },
+                            settings.check_for_updates,
+                            &focus[UPDATES_FOCUS_OFFSET],
+                        )
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.update_settings(cx, |s| s.check_for_updates = !s.check_for_updates);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.update_settings(cx, |s| s.check_for_updates = !s.check_for_updates);
+                            }
+                        })),
+                    ),
+            )
+            .child(
+                Self::section("Diagnostics")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Bundle recent logs, crash reports and settings into a zip for bug reports. Webhook URLs and other secrets are redacted. The frame-time overlay helps track down slow rendering."),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(
+                                Self::option_chip(
+                                    if self.exporting { "Exporting…" } else { "Export diagnostics" },
+                                    false,
+                                    &focus[DIAGNOSTICS_FOCUS_OFFSET],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.export_diagnostics(cx);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.export_diagnostics(cx);
+                                    }
+                                })),
+                            )
+                            .child(
+                                Self::option_chip("Open log viewer", false, &focus[DIAGNOSTICS_FOCUS_OFFSET + 1])
+                                    .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                        window.dispatch_action(Box::new(OpenLogs), cx);
+                                    })
+                                    .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            window.dispatch_action(Box::new(OpenLogs), cx);
+                                        }
+                                    }),
+                            )
+                            .child(
+                                Self::option_chip(
+                                    if settings.show_frame_overlay {
+                                        "Frame-time overlay: on"
+                                    } else {
+                                        "Frame-time overlay: off"
+                                    },
+                                    settings.show_frame_overlay,
+                                    &focus[DIAGNOSTICS_FOCUS_OFFSET + 2],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.show_frame_overlay = !s.show_frame_overlay);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.show_frame_overlay = !s.show_frame_overlay);
+                                    }
+                                })),
+                            ),
+                    )
+                    .when_some(self.export_result.as_ref(), |parent, result| {
+                        parent.child(match result {
+                            Ok(path) => div()
+                                .text_sm()
+                                .text_color(rgb(0xa6e3a1))
+                                .child(format!("Saved to {}", path.display())),
+                            Err(err) => div()
+                                .text_sm()
+                                .text_color(rgb(0xf38ba8))
+                                .child(format!("Export failed: {}", err)),
+                        })
+                    }),
+            )
+            .child(
+                Self::section("Usage statistics")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("When enabled, counts of features used and error categories are kept on this machine and sent once a day. No player names, ids or searches are included. This is exactly what the next upload contains:"),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if settings.telemetry_enabled {
+                                "Share usage statistics: on"
+                            } else {
+                                "Share usage statistics: off"
+                            },
+                            settings.telemetry_enabled,
+                            &focus[TELEMETRY_FOCUS_OFFSET],
+                        )
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.update_settings(cx, |s| s.telemetry_enabled = !s.telemetry_enabled);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.update_settings(cx, |s| s.telemetry_enabled = !s.telemetry_enabled);
+                            }
+                        })),
+                    )
+                    .child(
+                        div()
+                            .p_2()
+                            .rounded_md()
+                            .bg(rgb(0x1e1e2e))
+                            .text_xs()
+                            .font_family("monospace")
+                            .child(telemetry_preview),
+                    )
+                    .child(
+                        Self::option_chip("Clear collected data", false, &focus[TELEMETRY_FOCUS_OFFSET + 1])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                this.confirm_clear_telemetry(window, cx);
+                            }))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                if is_activation_key(event) {
+                                    this.confirm_clear_telemetry(window, cx);
+                                }
+                            })),
+                    ),
+            )
+            .child(
+                Self::section("Live lobby scout")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(format!(
+                                "Follows the game log and opens a lobby page with stats for every player name it finds when a match starts. Log file: {}",
+                                settings
+                                    .live_match
+                                    .log_path
+                                    .clone()
+                                    .or_else(|| live_match::default_log_path().map(|path| path.display().to_string()))
+                                    .unwrap_or_else(|| "not found".to_string())
+                            )),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(
+                                Self::option_chip(
+                                    if settings.live_match.enabled {
+                                        "Live lobby scout: on"
+                                    } else {
+                                        "Live lobby scout: off"
+                                    },
+                                    settings.live_match.enabled,
+                                    &focus[LIVE_MATCH_FOCUS_OFFSET],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.live_match.enabled = !s.live_match.enabled);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.live_match.enabled = !s.live_match.enabled);
+                                    }
+                                })),
+                            )
+                            .child(
+                                Self::option_chip("Open lobby", false, &focus[LIVE_MATCH_FOCUS_OFFSET + 1])
+                                    .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                        window.dispatch_action(Box::new(OpenLobby), cx);
+                                    })
+                                    .on_key_down(|event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            window.dispatch_action(Box::new(OpenLobby), cx);
+                                        }
+                                    }),
+                            ),
+                    ),
+            )
+            .child(
+                Self::section("Global hotkeys")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Work even while the game has focus. Hotkeys are single keystrokes; one already taken by another application is skipped. On Linux this requires X11."),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if settings.global_hotkeys_enabled {
+                                "Global hotkeys: on"
+                            } else {
+                                "Global hotkeys: off"
+                            },
+                            settings.global_hotkeys_enabled,
+                            &focus[GLOBAL_HOTKEYS_FOCUS_OFFSET],
+                        )
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.update_settings(cx, |s| s.global_hotkeys_enabled = !s.global_hotkeys_enabled);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.update_settings(cx, |s| s.global_hotkeys_enabled = !s.global_hotkeys_enabled);
+                            }
+                        })),
+                    )
+                    .children(GLOBAL_COMMANDS.iter().enumerate().map(|(idx, command)| {
+                        let id = command.id;
+                        let is_recording = self.recording == Some(Recording::GlobalHotkey(id));
+                        let current = global_hotkeys::hotkey_for(command, &settings.global_hotkeys).to_string();
+                        let is_default = current == command.default;
+                        let rebind_focus = &focus[GLOBAL_HOTKEYS_FOCUS_OFFSET + 1 + idx * 2];
+                        let reset_focus = &focus[GLOBAL_HOTKEYS_FOCUS_OFFSET + 1 + idx * 2 + 1];
+
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(div().flex_1().child(command.label))
+                            .child(self.binding_field(is_recording, current, cx))
+                            .child(
+                                Self::option_chip("Rebind", false, rebind_focus)
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                                        this.start_recording(Recording::GlobalHotkey(id), window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.start_recording(Recording::GlobalHotkey(id), window, cx);
+                                        }
+                                    })),
+                            )
+                            .when(!is_default, |row| {
+                                row.child(
+                                    Self::option_chip("Reset", false, reset_focus)
+                                        .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                            this.reset_global_hotkey(id, cx);
+                                        }))
+                                        .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                            if is_activation_key(event) {
+                                                this.reset_global_hotkey(id, cx);
+                                            }
+                                        })),
+                                )
+                            })
+                    })),
+            )
+            .child(
+                Self::section("Clipboard")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Watch the clipboard for player names, e.g. copied from the death screen, and offer to look them up. Clipboard contents never leave this machine."),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if settings.clipboard_watch {
+                                "Watch clipboard: on"
+                            } else {
+                                "Watch clipboard: off"
+                            },
+                            settings.clipboard_watch,
+                            &focus[CLIPBOARD_FOCUS_OFFSET],
+                        )
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.update_settings(cx, |s| s.clipboard_watch = !s.clipboard_watch);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.update_settings(cx, |s| s.clipboard_watch = !s.clipboard_watch);
+                            }
+                        })),
+                    ),
+            )
+            .child(
+                Self::section("Webhooks")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Notified when a watched player finishes a match. Filters are edited in settings.json."),
+                    )
+                    .children(settings.webhooks.iter().enumerate().map(|(idx, webhook)| {
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_sm()
+                                    .text_color(if webhook.enabled { rgb(0xcdd6f4) } else { rgb(0x6c7086) })
+                                    .child(webhook.url.clone()),
+                            )
+                            .child(
+                                Self::option_chip("Edit", false, &webhook_focus[1 + idx * 2])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                                        this.prompt_webhook_url(Some(idx), window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.prompt_webhook_url(Some(idx), window, cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::option_chip("Remove", false, &webhook_focus[2 + idx * 2])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, window, cx| {
+                                        this.confirm_remove_webhook(idx, window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.confirm_remove_webhook(idx, window, cx);
+                                        }
+                                    })),
+                            )
+                    }))
+                    .child(
+                        div().flex().child(
+                            Self::option_chip("Add webhook…", false, &webhook_focus[0])
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                    this.prompt_webhook_url(None, window, cx);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                    if is_activation_key(event) {
+                                        this.prompt_webhook_url(None, window, cx);
+                                    }
+                                })),
+                        ),
+                    )
+                    .when_some(self.webhook_error.clone(), |parent, error| {
+                        parent.child(div().text_sm().text_color(rgb(0xf38ba8)).child(error))
+                    }),
+            )
+            .child(
+                Self::section("Backup")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Save your settings, watched players, linked accounts, the local player archive, match notes, series and archived matches to a timestamped file, e.g. to move them to another machine. The file includes webhook URLs, so keep it private. Restoring replaces your settings and adds the backup's players, notes, series and matches to yours."),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                Self::option_chip(
+                                    format!("Folder: {}", backup_dir.display()),
+                                    false,
+                                    &focus[BACKUP_FOCUS_OFFSET + 4],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.choose_backup_dir(cx);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.choose_backup_dir(cx);
+                                    }
+                                })),
+                            )
+                            .child(
+                                Self::option_chip(
+                                    if settings.backup_compress { "Compress: on" } else { "Compress: off" },
+                                    settings.backup_compress,
+                                    &focus[BACKUP_FOCUS_OFFSET + 5],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.backup_compress = !s.backup_compress);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.backup_compress = !s.backup_compress);
+                                    }
+                                })),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(
+                                Self::option_chip(
+                                    if self.backup_busy { "Working…" } else { "Back up now" },
+                                    false,
+                                    &focus[BACKUP_FOCUS_OFFSET],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.export_app_data(cx);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.export_app_data(cx);
+                                    }
+                                })),
+                            )
+                            .child(
+                                Self::option_chip("Restore from backup…", false, &focus[BACKUP_FOCUS_OFFSET + 1])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                        this.confirm_import_app_data(window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.confirm_import_app_data(window, cx);
+                                        }
+                                    })),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(
+                                Self::option_chip("Clear API cache", false, &focus[BACKUP_FOCUS_OFFSET + 2])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                        this.confirm_clear_api_cache(window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.confirm_clear_api_cache(window, cx);
+                                        }
+                                    })),
+                            )
+                            .child(
+                                Self::option_chip("Delete player archive", false, &focus[BACKUP_FOCUS_OFFSET + 3])
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                        this.confirm_delete_player_archive(window, cx);
+                                    }))
+                                    .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                                        if is_activation_key(event) {
+                                            this.confirm_delete_player_archive(window, cx);
+                                        }
+                                    })),
+                            ),
+                    )
+                    .when_some(self.backup_result.as_ref(), |parent, result| {
+                        parent.child(match result {
+                            Ok(message) => div().text_sm().text_color(rgb(0xa6e3a1)).child(message.clone()),
+                            Err(message) => div().text_sm().text_color(rgb(0xf38ba8)).child(message.clone()),
+                        })
+                    }),
+            )
+            .child(
+                Self::section("Match archive")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(format!(
+                                "How long matches imported by ID are kept. The \"Prune match archive\" background job deletes the rest. {} matches are stored, using {}.",
+                                format::integer(archived_matches as i64),
+                                format::bytes(archived_bytes)
+                            )),
+                    )
+                    .child(div().flex().flex_wrap().gap_2().children(
+                        ArchiveRetention::PRESETS.iter().enumerate().map(|(idx, &retention)| {
+                            let selected = match pending_retention {
+                                Some(pending) => pending == retention,
+                                None => settings.archive_retention == retention,
+                            };
+                            Self::option_chip(retention.label(), selected, &focus[ARCHIVE_FOCUS_OFFSET + idx])
+                                .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                    this.choose_retention(retention, cx);
+                                }))
+                                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.choose_retention(retention, cx);
+                                    }
+                                }))
+                        }),
+                    ))
+                    .children(retention_preview)
+                    .when_some(prune_status.last_result, |parent, result| {
+                        parent.child(match result {
+                            Ok(message) => div().text_sm().text_color(rgb(0x9399b2)).child(message),
+                            Err(message) => div()
+                                .text_sm()
+                                .text_color(rgb(0xf38ba8))
+                                .child(format!("Pruning failed: {}", message)),
+                        })
+                    })
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                Self::option_chip(
+                                    if check_status.running { "Checking…" } else { "Check integrity" },
+                                    false,
+                                    &focus[ARCHIVE_FOCUS_OFFSET + ArchiveRetention::PRESETS.len() + 2],
+                                )
+                                .on_mouse_down(MouseButton::Left, |_, _window, cx| {
+                                    scheduler::run_now(JobKind::CheckArchive, cx);
+                                })
+                                .on_key_down(|event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        scheduler::run_now(JobKind::CheckArchive, cx);
+                                    }
+                                }),
+                            )
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(rgb(0x9399b2))
+                                    .child(format!("Unreadable files are moved to {}", quarantine_dir.display())),
+                            ),
+                    )
+                    .when_some(check_status.last_result, |parent, result| {
+                        parent.child(match result {
+                            Ok(message) => div().text_sm().text_color(rgb(0xa6e3a1)).child(message),
+                            Err(message) => div()
+                                .text_sm()
+                                .text_color(rgb(0xf38ba8))
+                                .child(format!("Integrity check failed: {}", message)),
+                        })
+                    }),
+            )
+            .child(
+                Self::section("Sync")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(format!(
+                                "Keep settings, watched players, linked accounts and match notes in step across machines through a WebDAV folder. Whichever machine changed its settings last wins; each note keeps its most recent edit. Set the folder URL and credentials under \"sync\" in {}; the credentials are never uploaded.",
+                                SettingsStore::default_path().display()
+                            )),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .child(
+                                Self::option_chip(
+                                    if settings.sync.enabled { "Sync: on" } else { "Sync: off" },
+                                    settings.sync.enabled,
+                                    &focus[SYNC_FOCUS_OFFSET],
+                                )
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.update_settings(cx, |s| s.sync.enabled = !s.sync.enabled);
+                                }))
+                                .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        this.update_settings(cx, |s| s.sync.enabled = !s.sync.enabled);
+                                    }
+                                })),
+                            )
+                            .child(
+                                Self::option_chip(
+                                    if sync_status.running { "Syncing…" } else { "Sync now" },
+                                    false,
+                                    &focus[SYNC_FOCUS_OFFSET + 1],
+                                )
+                                .on_mouse_down(MouseButton::Left, |_, _window, cx| {
+                                    scheduler::run_now(JobKind::SyncSettings, cx);
+                                })
+                                .on_key_down(|event: &KeyDownEvent, _window, cx| {
+                                    if is_activation_key(event) {
+                                        scheduler::run_now(JobKind::SyncSettings, cx);
+                                    }
+                                }),
+                            ),
+                    )
+                    .when_some(sync_status.last_result, |parent, result| {
+                        parent.child(match result {
+                            Ok(message) => div().text_sm().text_color(rgb(0xa6e3a1)).child(message),
+                            Err(message) => div()
+                                .text_sm()
+                                .text_color(rgb(0xf38ba8))
+                                .child(format!("Sync failed: {}", message)),
+                        })
+                    }),
+            )
+            .child(
+                Self::section("Plugins")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child(format!(
+                                "Custom panels on player profiles, defined in JSON files in {}. Files that fail to load are listed in the log viewer.",
+                                plugins::plugin_dir().display()
+                            )),
+                    )
+                    .child(div().text_sm().child(if plugin_names.is_empty() {
+                        "No plugins loaded.".to_string()
+                    } else {
+                        format!("Loaded: {}", plugin_names.join(", "))
+                    }))
+                    .child(
+                        Self::option_chip("Reload plugins", false, &focus[PLUGINS_FOCUS_OFFSET])
+                            .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                this.reload_plugins(cx);
+                            }))
+                            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                                if is_activation_key(event) {
+                                    this.reload_plugins(cx);
+                                }
+                            })),
+                    ),
+            )
+            .child(
+                Self::section("Hero suggestions")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Suggest heroes to play more or less on player profiles, based on that player's placements with each hero and how the field places with it in analyzed lobbies."),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if settings.show_hero_suggestions {
+                                "Show suggestions: on"
+                            } else {
+                                "Show suggestions: off"
+                            },
+                            settings.show_hero_suggestions,
+                            &focus[SUGGESTIONS_FOCUS_OFFSET],
+                        )
+                        .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                            this.update_settings(cx, |s| s.show_hero_suggestions = !s.show_hero_suggestions);
+                        }))
+                        .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                            if is_activation_key(event) {
+                                this.update_settings(cx, |s| s.show_hero_suggestions = !s.show_hero_suggestions);
+                            }
+                        })),
+                    ),
+            )
+            .child(
+                Self::section("Region")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Your server region. Search results from other regions are dimmed, and the hero win rate and leaderboard tiles only count its matches. \"All regions\" merges them."),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .flex_wrap()
+                            .gap_2()
+                            .children(
+                                std::iter::once((None, "All regions"))
+                                    .chain(REGIONS.iter().map(|(code, label)| (Some(*code), *label)))
+                                    .enumerate()
+                                    .map(|(idx, (code, label))| {
+                                        let region = code.map(str::to_string);
+                                        let key_region = region.clone();
+                                        Self::option_chip(label, settings.preferred_region.as_deref() == code, &focus[REGION_FOCUS_OFFSET + idx])
+                                            .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _window, cx| {
+                                                let region = region.clone();
+                                                this.update_settings(cx, |s| s.preferred_region = region);
+                                            }))
+                                            .on_key_down(cx.listener(move |this, event: &KeyDownEvent, _window, cx| {
+                                                if is_activation_key(event) {
+                                                    let region = key_region.clone();
+                                                    this.update_settings(cx, |s| s.preferred_region = region);
+                                                }
+                                            }))
+                                    }),
+                            ),
+                    ),
+            )
+            .child(
+                Self::section("Demo mode")
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(0x9399b2))
+                            .child("Browse bundled sample players and matches without network access. Players you open, notes and series stay in a separate demo set that is discarded when you leave. Also available by starting the app with --demo."),
+                    )
+                    .child(
+                        Self::option_chip(
+                            if is_demo { "Leave demo mode" } else { "Explore sample data" },
+                            is_demo,
+                            &focus[DEMO_FOCUS_OFFSET],
+                        )
+                        .on_mouse_down(MouseButton::Left, move |_, window, cx| demo::set_enabled(!is_demo, window, cx))
+                        .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                            if is_activation_key(event) {
+                                demo::set_enabled(!is_demo, window, cx);
+                            }
+                        }),
+                    ),
+            )
+    }
+}